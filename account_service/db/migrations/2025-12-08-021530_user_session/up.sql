@@ -0,0 +1,25 @@
+-- Server side record of signed in sessions, so that a session can be
+-- renewed or revoked before its session token expires.
+
+CREATE SEQUENCE user_session_id_seq;
+CREATE TABLE user_session (
+  id BIGINT PRIMARY KEY DEFAULT xtea(
+    NEXTVAL('user_session_id_seq'),
+    BYTEA '\x31b9d4daa8a376cd237439c345b17cb7',
+    TRUE
+  ),
+  account_type TEXT NOT NULL,
+  -- Either customer_account.id or handyman_account.id depending on account_type
+  account_id BIGINT NOT NULL,
+  -- Hex encoded `jti` of the latest session token, rotated on every renewal
+  -- so that tokens issued before the renewal are rejected.
+  jti TEXT NOT NULL UNIQUE,
+  expires_at TIMESTAMP NOT NULL,
+  revoked_at TIMESTAMP,
+
+  created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+  updated_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC')
+);
+ALTER SEQUENCE user_session_id_seq OWNED BY user_session.id;
+SELECT diesel_manage_updated_at('user_session');
+CREATE INDEX user_session_account_idx ON user_session(account_type, account_id);
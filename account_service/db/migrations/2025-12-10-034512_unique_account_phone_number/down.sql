@@ -0,0 +1,5 @@
+DROP INDEX handyman_account_phone_number_idx;
+CREATE INDEX handyman_account_phone_number_idx ON handyman_account(phone_number);
+
+DROP INDEX customer_account_phone_number_idx;
+CREATE INDEX customer_account_phone_number_idx ON customer_account(phone_number);
@@ -0,0 +1,8 @@
+-- Phone numbers can be changed after sign up, enforce that each one
+-- belongs to at most one account of the same type.
+
+DROP INDEX customer_account_phone_number_idx;
+CREATE UNIQUE INDEX customer_account_phone_number_idx ON customer_account(phone_number);
+
+DROP INDEX handyman_account_phone_number_idx;
+CREATE UNIQUE INDEX handyman_account_phone_number_idx ON handyman_account(phone_number);
@@ -0,0 +1,22 @@
+-- Staff accounts operating the platform, created by god or another admin
+-- instead of self registration.
+
+CREATE SEQUENCE admin_account_id_seq;
+CREATE TABLE admin_account (
+  -- ```bash
+  -- openssl rand -hex 16
+  -- ```
+  id BIGINT PRIMARY KEY DEFAULT xtea(
+    NEXTVAL('admin_account_id_seq'),
+    BYTEA '\x5c83ee0f64b033ea3f9bbe367df01b75',
+    TRUE
+  ),
+  phone_number TEXT NOT NULL,
+  password_hash TEXT NOT NULL,
+
+  created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+  updated_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC')
+);
+ALTER SEQUENCE admin_account_id_seq OWNED BY admin_account.id;
+SELECT diesel_manage_updated_at('admin_account');
+CREATE UNIQUE INDEX admin_account_phone_number_idx ON admin_account(phone_number);
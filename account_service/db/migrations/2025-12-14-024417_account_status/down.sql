@@ -0,0 +1,2 @@
+ALTER TABLE handyman_account DROP COLUMN status;
+ALTER TABLE customer_account DROP COLUMN status;
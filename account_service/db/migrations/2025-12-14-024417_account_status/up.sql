@@ -0,0 +1,5 @@
+-- Customer and handyman accounts can be suspended by staff or deleted by their owner.
+
+-- Map to rust enum AccountStatus
+ALTER TABLE customer_account ADD COLUMN status TEXT NOT NULL DEFAULT 'ACTIVE';
+ALTER TABLE handyman_account ADD COLUMN status TEXT NOT NULL DEFAULT 'ACTIVE';
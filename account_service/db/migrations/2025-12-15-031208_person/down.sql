@@ -0,0 +1,7 @@
+DROP INDEX handyman_account_person_id_idx;
+ALTER TABLE handyman_account DROP COLUMN person_id;
+
+DROP INDEX customer_account_person_id_idx;
+ALTER TABLE customer_account DROP COLUMN person_id;
+
+DROP TABLE person;
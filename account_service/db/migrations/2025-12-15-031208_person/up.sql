@@ -0,0 +1,41 @@
+-- A person owns at most one customer and one handyman account registered
+-- with the same phone number, so that they can switch role without signing in again.
+
+CREATE SEQUENCE person_id_seq;
+CREATE TABLE person (
+  -- ```bash
+  -- openssl rand -hex 16
+  -- ```
+  id BIGINT PRIMARY KEY DEFAULT xtea(
+    NEXTVAL('person_id_seq'),
+    BYTEA '\x8196dd2e53a3607d0cb3b25ba8c18df1',
+    TRUE
+  ),
+  phone_number TEXT NOT NULL,
+
+  created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+  updated_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC')
+);
+ALTER SEQUENCE person_id_seq OWNED BY person.id;
+SELECT diesel_manage_updated_at('person');
+CREATE UNIQUE INDEX person_phone_number_idx ON person(phone_number);
+
+INSERT INTO person (phone_number)
+SELECT phone_number FROM customer_account WHERE status <> 'DELETED'
+UNION
+SELECT phone_number FROM handyman_account WHERE status <> 'DELETED';
+
+-- Deleted accounts are unlinked from their person
+ALTER TABLE customer_account ADD COLUMN person_id BIGINT REFERENCES person(id);
+UPDATE customer_account SET person_id = person.id
+FROM person
+WHERE customer_account.phone_number = person.phone_number
+  AND customer_account.status <> 'DELETED';
+CREATE UNIQUE INDEX customer_account_person_id_idx ON customer_account(person_id);
+
+ALTER TABLE handyman_account ADD COLUMN person_id BIGINT REFERENCES person(id);
+UPDATE handyman_account SET person_id = person.id
+FROM person
+WHERE handyman_account.phone_number = person.phone_number
+  AND handyman_account.status <> 'DELETED';
+CREATE UNIQUE INDEX handyman_account_person_id_idx ON handyman_account(person_id);
@@ -0,0 +1,2 @@
+ALTER TABLE handyman_profile DROP COLUMN years_of_experience;
+ALTER TABLE handyman_profile DROP COLUMN bio;
@@ -0,0 +1,3 @@
+-- Handymen introduce themselves on their profile, both fields are optional.
+ALTER TABLE handyman_profile ADD COLUMN bio TEXT;
+ALTER TABLE handyman_profile ADD COLUMN years_of_experience INT CHECK (years_of_experience >= 0);
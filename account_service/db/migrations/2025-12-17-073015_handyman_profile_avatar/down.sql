@@ -0,0 +1 @@
+ALTER TABLE handyman_profile DROP COLUMN avatar_key;
@@ -0,0 +1,2 @@
+-- Key of the avatar image in the blob store, see `blob_store::BlobKey`
+ALTER TABLE handyman_profile ADD COLUMN avatar_key TEXT;
@@ -0,0 +1,40 @@
+use entity_type::AccountStatus;
+use error::{Error, Result, error_details::ErrorInfo};
+
+/// Reject sign in and sessions of accounts which are suspended or deleted
+pub fn require_active_account(status: AccountStatus) -> Result<()> {
+    let reason = match status {
+        AccountStatus::Active => return Ok(()),
+        AccountStatus::Suspended => "SUSPENDED",
+        AccountStatus::Deleted => "DELETED",
+    };
+
+    Err(Error::permission_denied_with(
+        format!("Account is {}", reason.to_lowercase()),
+        Some(ErrorInfo {
+            reason: reason.into(),
+            domain: "ACCOUNT_STATUS".into(),
+            ..Default::default()
+        }),
+    ))
+}
+
+/// Staff can suspend or reinstate accounts, but deletion is final
+pub(crate) fn require_status_change(from: AccountStatus, to: AccountStatus) -> Result<()> {
+    if to == AccountStatus::Deleted {
+        return Err(Error::invalid_argument(
+            "Accounts are deleted with their own API to anonymize personal data",
+        ));
+    }
+    if from == AccountStatus::Deleted {
+        return Err(Error::failed_precondition(
+            "Deleted accounts can't be restored",
+        ));
+    }
+    Ok(())
+}
+
+/// Deleted accounts release their phone number, the replacement stays unique per account
+pub(crate) fn anonymized_phone_number(account_id: i64) -> String {
+    format!("deleted:{account_id}")
+}
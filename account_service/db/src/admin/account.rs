@@ -0,0 +1,167 @@
+use crate::schema::admin_account;
+use actor_auth::ActorAuth;
+use argon2_hash::Argon2Hash;
+use diesel::prelude::*;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use entity_type::AdminId;
+use error::{Error, Result};
+use phonenumber::PhoneNumber;
+
+#[derive(Debug, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = admin_account)]
+pub struct AdminAccount {
+    pub id: AdminId,
+    pub phone_number: String,
+    pub password_hash: String,
+}
+
+impl AdminAccount {
+    /// Verify if provided plain text password matches with hashed password
+    pub fn verify_password(self, password: &str) -> Result<Self> {
+        Argon2Hash::verify_password(password, &self.password_hash)
+            .map_err(|_| Error::unauthenticated("Credentials not found"))?;
+        Ok(self)
+    }
+
+    /// Whether the plain text password matches, failing only if the hash can't be verified
+    pub fn password_matches(&self, password: &str) -> Result<bool> {
+        Argon2Hash::password_matches(password, &self.password_hash)
+    }
+}
+
+impl AdminAccount {
+    /// Admins can't register by themselves, they are created by god or another admin
+    pub async fn create(
+        actor_auth: &ActorAuth,
+        NewAdminAccount {
+            phone_number,
+            password,
+        }: NewAdminAccount<'_>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_god_or_admin()?;
+        typesafe::check_password_safety(password)?;
+        let password_hash = Argon2Hash::hash_password(password)?;
+        let phone_number_str = typesafe::phone_number_to_e164_format(phone_number);
+
+        if Self::phone_exist(&phone_number_str, conn).await? {
+            return Err(Error::already_exists("Phone number already exist"));
+        }
+
+        diesel::insert_into(admin_account::table)
+            .values((
+                admin_account::phone_number.eq(&phone_number_str),
+                admin_account::password_hash.eq(password_hash),
+            ))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .map_err(Error::from)
+    }
+
+    /// Replace the password, e.g. after the owner proves the phone number with an OTP code
+    pub async fn update_password(
+        actor_auth: &ActorAuth,
+        id: AdminId,
+        password: &str,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_god_or_admin()?;
+        typesafe::check_password_safety(password)?;
+        let password_hash = Argon2Hash::hash_password(password)?;
+
+        diesel::update(admin_account::table.find(id))
+            .set(admin_account::password_hash.eq(password_hash))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Account not found"))
+    }
+
+    /// Move the account to another phone number, which must not be used by another account
+    pub async fn update_phone_number(
+        actor_auth: &ActorAuth,
+        id: AdminId,
+        phone_number: &PhoneNumber,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_god_or_admin()?;
+        let phone_number_str = typesafe::phone_number_to_e164_format(phone_number);
+
+        if Self::phone_exist(&phone_number_str, conn).await? {
+            return Err(Error::already_exists("Phone number already exist"));
+        }
+
+        diesel::update(admin_account::table.find(id))
+            .set(admin_account::phone_number.eq(&phone_number_str))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Account not found"))
+    }
+
+    pub async fn get_by_id(
+        actor_auth: &ActorAuth,
+        id: AdminId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_god_or_admin()?;
+        admin_account::table
+            .find(id)
+            .select(Self::as_select())
+            .first::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Account not found"))
+    }
+
+    pub async fn phone_exist(
+        e164_phone_number_str: &str,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<bool> {
+        diesel::select(diesel::dsl::exists(
+            admin_account::table.filter(admin_account::phone_number.eq(e164_phone_number_str)),
+        ))
+        .get_result::<bool>(conn)
+        .await
+        .map_err(Error::from)
+    }
+
+    /// Find account by phone
+    pub async fn find_by_phone_number(
+        e164_phone_number_str: &str,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Option<Self>> {
+        let account = admin_account::table
+            .filter(admin_account::phone_number.eq(e164_phone_number_str))
+            .select(Self::as_select())
+            .first::<Self>(conn)
+            .await
+            .optional()?;
+        Ok(account)
+    }
+
+    /// Load many accounts by ids
+    pub async fn load_by_ids(
+        actor_auth: &ActorAuth,
+        ids: &[AdminId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        actor_auth.require_god_or_admin()?;
+        let result = admin_account::table
+            .filter(admin_account::id.eq_any(ids))
+            .select(Self::as_select())
+            .load::<Self>(conn)
+            .await?;
+        Ok(result)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NewAdminAccount<'a> {
+    pub phone_number: &'a PhoneNumber,
+    /// User input plain text password
+    pub password: &'a str,
+}
@@ -0,0 +1,2 @@
+mod account;
+pub use account::*;
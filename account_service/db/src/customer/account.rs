@@ -1,9 +1,12 @@
-use crate::schema::customer_account;
+use crate::{
+    Person, anonymized_phone_number, require_active_account, require_status_change,
+    schema::customer_account,
+};
 use actor_auth::ActorAuth;
 use argon2_hash::Argon2Hash;
 use diesel::prelude::*;
 use diesel_async::{AsyncPgConnection, RunQueryDsl};
-use entity_type::CustomerId;
+use entity_type::{AccountStatus, CustomerId, PersonId};
 use error::{Error, Result};
 use phonenumber::PhoneNumber;
 
@@ -13,6 +16,9 @@ pub struct CustomerAccount {
     pub id: CustomerId,
     pub phone_number: String,
     pub password_hash: String,
+    pub status: AccountStatus,
+    /// `None` once the account is deleted
+    pub person_id: Option<PersonId>,
 }
 
 impl CustomerAccount {
@@ -22,6 +28,17 @@ impl CustomerAccount {
             .map_err(|_| Error::unauthenticated("Credentials not found"))?;
         Ok(self)
     }
+
+    /// Whether the plain text password matches, failing only if the hash can't be verified
+    pub fn password_matches(&self, password: &str) -> Result<bool> {
+        Argon2Hash::password_matches(password, &self.password_hash)
+    }
+
+    /// Reject suspended and deleted accounts, see [`require_active_account`]
+    pub fn require_active(self) -> Result<Self> {
+        require_active_account(self.status)?;
+        Ok(self)
+    }
 }
 
 impl CustomerAccount {
@@ -41,11 +58,13 @@ impl CustomerAccount {
         if Self::phone_exist(&phone_number_str, conn).await? {
             return Err(Error::already_exists("Phone number already exist"));
         }
+        let person = Person::find_or_create(&phone_number_str, conn).await?;
 
         diesel::insert_into(customer_account::table)
             .values((
                 customer_account::phone_number.eq(&phone_number_str),
                 customer_account::password_hash.eq(password_hash),
+                customer_account::person_id.eq(person.id),
             ))
             .returning(Self::as_select())
             .get_result::<Self>(conn)
@@ -53,6 +72,135 @@ impl CustomerAccount {
             .map_err(Error::from)
     }
 
+    /// Replace the password, e.g. after the owner proves the phone number with an OTP code
+    pub async fn update_password(
+        actor_auth: &ActorAuth,
+        id: CustomerId,
+        password: &str,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_customer_access(id)?;
+        typesafe::check_password_safety(password)?;
+        let password_hash = Argon2Hash::hash_password(password)?;
+
+        diesel::update(customer_account::table.find(id))
+            .set(customer_account::password_hash.eq(password_hash))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Account not found"))
+    }
+
+    /// Move the account to another phone number, which must not be used by another person.
+    /// Other accounts of the same person are moved along.
+    pub async fn update_phone_number(
+        actor_auth: &ActorAuth,
+        id: CustomerId,
+        phone_number: &PhoneNumber,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_customer_access(id)?;
+        let phone_number_str = typesafe::phone_number_to_e164_format(phone_number);
+
+        let person_id = Self::find_person_id(id, conn)
+            .await?
+            .ok_or_else(|| Error::not_found("Account not found"))?;
+        Person::update_phone_number(person_id, &phone_number_str, conn).await?;
+
+        customer_account::table
+            .find(id)
+            .select(Self::as_select())
+            .first::<Self>(conn)
+            .await
+            .map_err(Error::from)
+    }
+
+    /// Suspend or reinstate the account, only staff can do this
+    pub async fn update_status(
+        actor_auth: &ActorAuth,
+        id: CustomerId,
+        status: AccountStatus,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_god_or_admin()?;
+        let account = customer_account::table
+            .find(id)
+            .select(Self::as_select())
+            .for_update()
+            .first::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Account not found"))?;
+        require_status_change(account.status, status)?;
+
+        diesel::update(customer_account::table.find(id))
+            .set(customer_account::status.eq(status))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .map_err(Error::from)
+    }
+
+    /// Mark the account as deleted and anonymize its phone number and password,
+    /// the phone number can be registered again afterward.
+    pub async fn delete(
+        actor_auth: &ActorAuth,
+        id: CustomerId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_customer_access(id)?;
+        let person_id = Self::find_person_id(id, conn).await?;
+
+        let account = diesel::update(customer_account::table.find(id))
+            .set((
+                customer_account::status.eq(AccountStatus::Deleted),
+                customer_account::phone_number.eq(anonymized_phone_number(id.0)),
+                customer_account::password_hash.eq(""),
+                customer_account::person_id.eq(None::<PersonId>),
+            ))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Account not found"))?;
+
+        if let Some(person_id) = person_id {
+            Person::delete_if_unlinked(person_id, conn).await?;
+        }
+        Ok(account)
+    }
+
+    /// Person of the account, `None` if the account is deleted or not found
+    async fn find_person_id(
+        id: CustomerId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Option<PersonId>> {
+        let person_id = customer_account::table
+            .find(id)
+            .select(customer_account::person_id)
+            .first::<Option<PersonId>>(conn)
+            .await
+            .optional()?
+            .flatten();
+        Ok(person_id)
+    }
+
+    pub async fn get_by_id(
+        actor_auth: &ActorAuth,
+        id: CustomerId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_customer_access(id)?;
+        customer_account::table
+            .find(id)
+            .select(Self::as_select())
+            .first::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Account not found"))
+    }
+
     pub async fn phone_exist(
         e164_phone_number_str: &str,
         conn: &mut AsyncPgConnection,
@@ -80,6 +228,20 @@ impl CustomerAccount {
         Ok(account)
     }
 
+    /// Get the customer account of a person, if they have registered one
+    pub async fn find_by_person(
+        person_id: PersonId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Option<Self>> {
+        let account = customer_account::table
+            .filter(customer_account::person_id.eq(person_id))
+            .select(Self::as_select())
+            .first::<Self>(conn)
+            .await
+            .optional()?;
+        Ok(account)
+    }
+
     /// Load many accounts by ids
     pub async fn load_by_ids(
         // TODO: define read permission for customer account
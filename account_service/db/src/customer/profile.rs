@@ -30,6 +30,40 @@ impl CustomerProfile {
             .map_err(Error::from)
     }
 
+    pub async fn update(
+        actor_auth: &ActorAuth,
+        customer_id: CustomerId,
+        changeset: CustomerProfileChangeset<'_>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_customer_access(customer_id)?;
+        changeset.validate()?;
+
+        diesel::update(customer_profile::table.find(customer_id))
+            .set(changeset)
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Customer profile not found"))
+    }
+
+    /// Remove personal data of a deleted account
+    pub async fn delete(
+        actor_auth: &ActorAuth,
+        customer_id: CustomerId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Option<Self>> {
+        actor_auth.require_customer_access(customer_id)?;
+
+        diesel::delete(customer_profile::table.find(customer_id))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .optional()
+            .map_err(Error::from)
+    }
+
     /// Load many profiles by ids
     pub async fn load_by_ids(
         // TODO: define read permission for customer profile
@@ -57,3 +91,19 @@ impl<'a> NewCustomerProfile<'a> {
         typesafe::require_trimmed_and_not_empty_str(self.nick_name, "nick_name")
     }
 }
+
+/// `None` fields are left unchanged.
+#[derive(Debug, Clone, Copy, AsChangeset)]
+#[diesel(table_name = customer_profile)]
+pub struct CustomerProfileChangeset<'a> {
+    pub nick_name: Option<&'a str>,
+}
+
+impl<'a> CustomerProfileChangeset<'a> {
+    fn validate(&self) -> Result<()> {
+        if let Some(nick_name) = self.nick_name {
+            typesafe::require_trimmed_and_not_empty_str(nick_name, "nick_name")?;
+        }
+        Ok(())
+    }
+}
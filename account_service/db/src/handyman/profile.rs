@@ -3,7 +3,13 @@ use actor_auth::ActorAuth;
 use diesel::prelude::*;
 use diesel_async::{AsyncPgConnection, RunQueryDsl};
 use entity_type::HandymanId;
-use error::{Error, Result};
+use error::{
+    Error, Result,
+    error_details::{BadRequest, bad_request::FieldViolation},
+};
+
+const MAX_BIO_CHARS: usize = 1000;
+const MAX_YEARS_OF_EXPERIENCE: i32 = 80;
 
 #[derive(Debug, Queryable, Selectable)]
 #[diesel(table_name = handyman_profile)]
@@ -11,6 +17,10 @@ pub struct HandymanProfile {
     pub handyman_id: HandymanId,
     pub first_name: String,
     pub last_name: String,
+    pub bio: Option<String>,
+    pub years_of_experience: Option<i32>,
+    /// Key of the avatar image in the blob store
+    pub avatar_key: Option<String>,
 }
 
 impl HandymanProfile {
@@ -37,6 +47,58 @@ impl HandymanProfile {
             .map_err(Error::from)
     }
 
+    pub async fn update(
+        actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        changeset: HandymanProfileChangeset<'_>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_handyman_access(handyman_id)?;
+        changeset.validate()?;
+
+        diesel::update(handyman_profile::table.find(handyman_id))
+            .set(changeset)
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Handyman profile not found"))
+    }
+
+    /// Lock the profile until the end of the transaction, e.g. to replace its avatar
+    pub async fn find_for_update(
+        actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        handyman_profile::table
+            .find(handyman_id)
+            .select(Self::as_select())
+            .for_update()
+            .first::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Handyman profile not found"))
+    }
+
+    /// Remove personal data of a deleted account
+    pub async fn delete(
+        actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Option<Self>> {
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        diesel::delete(handyman_profile::table.find(handyman_id))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .optional()
+            .map_err(Error::from)
+    }
+
     /// Load many profiles by ids
     pub async fn load_by_ids(
         // TODO: define read permission for handyman profile
@@ -66,3 +128,55 @@ impl<'a> NewHandymanProfile<'a> {
         typesafe::require_trimmed_and_not_empty_str(self.last_name, "last_name")
     }
 }
+
+/// `None` fields are left unchanged, `Some(None)` clears optional fields.
+#[derive(Debug, Clone, Copy, AsChangeset)]
+#[diesel(table_name = handyman_profile)]
+pub struct HandymanProfileChangeset<'a> {
+    pub first_name: Option<&'a str>,
+    pub last_name: Option<&'a str>,
+    pub bio: Option<Option<&'a str>>,
+    pub years_of_experience: Option<Option<i32>>,
+    pub avatar_key: Option<Option<&'a str>>,
+}
+
+impl<'a> HandymanProfileChangeset<'a> {
+    fn validate(&self) -> Result<()> {
+        if let Some(first_name) = self.first_name {
+            typesafe::require_trimmed_and_not_empty_str(first_name, "first_name")?;
+        }
+        if let Some(last_name) = self.last_name {
+            typesafe::require_trimmed_and_not_empty_str(last_name, "last_name")?;
+        }
+        if let Some(Some(bio)) = self.bio {
+            typesafe::require_trimmed_and_not_empty_str(bio, "bio")?;
+            if bio.chars().count() > MAX_BIO_CHARS {
+                return Err(out_of_range(
+                    format!("Bio must be at most {MAX_BIO_CHARS} characters"),
+                    "bio",
+                ));
+            }
+        }
+        if let Some(Some(years)) = self.years_of_experience {
+            if !(0..=MAX_YEARS_OF_EXPERIENCE).contains(&years) {
+                return Err(out_of_range(
+                    format!("Years of experience must be between 0 and {MAX_YEARS_OF_EXPERIENCE}"),
+                    "years_of_experience",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn out_of_range(message: String, field: &str) -> Error {
+    Error::invalid_argument_with(
+        message,
+        Some(BadRequest {
+            field_violations: vec![FieldViolation {
+                field: field.into(),
+                description: "OUT_OF_RANGE".into(),
+            }],
+        }),
+    )
+}
@@ -3,8 +3,20 @@ pub mod schema;
 mod migrations;
 pub use migrations::*;
 
+mod account_status;
+pub use account_status::*;
+
+mod person;
+pub use person::*;
+
 mod customer;
 pub use customer::*;
 
 mod handyman;
 pub use handyman::*;
+
+mod admin;
+pub use admin::*;
+
+mod user_session;
+pub use user_session::*;
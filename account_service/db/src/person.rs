@@ -0,0 +1,99 @@
+use crate::schema::{customer_account, handyman_account, person};
+use diesel::prelude::*;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use entity_type::PersonId;
+use error::{Error, Result};
+
+/// Identity of a person behind the customer and handyman accounts sharing a phone number
+#[derive(Debug, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = person)]
+pub struct Person {
+    pub id: PersonId,
+    pub phone_number: String,
+}
+
+impl Person {
+    /// Person owning the phone number, created on the first sign up with it
+    pub(crate) async fn find_or_create(
+        e164_phone_number_str: &str,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        diesel::insert_into(person::table)
+            .values(person::phone_number.eq(e164_phone_number_str))
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+
+        person::table
+            .filter(person::phone_number.eq(e164_phone_number_str))
+            .select(Self::as_select())
+            .first::<Self>(conn)
+            .await
+            .map_err(Error::from)
+    }
+
+    /// Move the person and every account of theirs to another phone number,
+    /// which must not belong to another person.
+    /// The caller is responsible for checking access to the accounts beforehand.
+    pub(crate) async fn update_phone_number(
+        id: PersonId,
+        e164_phone_number_str: &str,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        if Self::phone_exist(e164_phone_number_str, conn).await? {
+            return Err(Error::already_exists("Phone number already exist"));
+        }
+
+        let person = diesel::update(person::table.find(id))
+            .set(person::phone_number.eq(e164_phone_number_str))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Person not found"))?;
+
+        diesel::update(customer_account::table.filter(customer_account::person_id.eq(id)))
+            .set(customer_account::phone_number.eq(e164_phone_number_str))
+            .execute(conn)
+            .await?;
+        diesel::update(handyman_account::table.filter(handyman_account::person_id.eq(id)))
+            .set(handyman_account::phone_number.eq(e164_phone_number_str))
+            .execute(conn)
+            .await?;
+
+        Ok(person)
+    }
+
+    /// Remove the person once none of their accounts is left, releasing the phone number
+    pub(crate) async fn delete_if_unlinked(
+        id: PersonId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<usize> {
+        diesel::delete(
+            person::table
+                .find(id)
+                .filter(diesel::dsl::not(diesel::dsl::exists(
+                    customer_account::table.filter(customer_account::person_id.eq(id)),
+                )))
+                .filter(diesel::dsl::not(diesel::dsl::exists(
+                    handyman_account::table.filter(handyman_account::person_id.eq(id)),
+                ))),
+        )
+        .execute(conn)
+        .await
+        .map_err(Error::from)
+    }
+
+    /// Whether the phone number belongs to a person, regardless of their account types
+    pub async fn phone_exist(
+        e164_phone_number_str: &str,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<bool> {
+        diesel::select(diesel::dsl::exists(
+            person::table.filter(person::phone_number.eq(e164_phone_number_str)),
+        ))
+        .get_result::<bool>(conn)
+        .await
+        .map_err(Error::from)
+    }
+}
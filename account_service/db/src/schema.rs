@@ -1,5 +1,15 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    admin_account (id) {
+        id -> Int8,
+        phone_number -> Text,
+        password_hash -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     customer_account (id) {
         id -> Int8,
@@ -7,6 +17,8 @@ diesel::table! {
         password_hash -> Text,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        status -> entity_type::AccountStatusMapping,
+        person_id -> Nullable<Int8>,
     }
 }
 
@@ -26,6 +38,8 @@ diesel::table! {
         password_hash -> Text,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        status -> entity_type::AccountStatusMapping,
+        person_id -> Nullable<Int8>,
     }
 }
 
@@ -36,15 +50,45 @@ diesel::table! {
         last_name -> Text,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        bio -> Nullable<Text>,
+        years_of_experience -> Nullable<Int4>,
+        avatar_key -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    person (id) {
+        id -> Int8,
+        phone_number -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    user_session (id) {
+        id -> Int8,
+        account_type -> entity_type::AccountTypeMapping,
+        account_id -> Int8,
+        jti -> Text,
+        expires_at -> Timestamp,
+        revoked_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
+diesel::joinable!(customer_account -> person (person_id));
 diesel::joinable!(customer_profile -> customer_account (customer_id));
+diesel::joinable!(handyman_account -> person (person_id));
 diesel::joinable!(handyman_profile -> handyman_account (handyman_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    admin_account,
     customer_account,
     customer_profile,
     handyman_account,
     handyman_profile,
+    person,
+    user_session,
 );
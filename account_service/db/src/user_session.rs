@@ -0,0 +1,252 @@
+use crate::schema::{customer_account, handyman_account, user_session};
+use actor_auth::{ActorAuth, ActorKey};
+use chrono::{NaiveDateTime, Utc};
+use diesel::prelude::*;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use entity_type::{AccountStatus, AccountType, AdminId, CustomerId, HandymanId, UserSessionId};
+use error::{Error, Result};
+
+#[derive(Debug, Clone, Queryable, Selectable, Identifiable)]
+#[diesel(table_name = user_session)]
+pub struct UserSession {
+    pub id: UserSessionId,
+    pub account_type: AccountType,
+    pub account_id: i64,
+    /// Hex encoded `jti` of the latest session token
+    pub jti: String,
+    pub expires_at: NaiveDateTime,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl UserSession {
+    pub fn actor_key(&self) -> ActorKey {
+        match self.account_type {
+            AccountType::Customer => ActorKey::Customer(CustomerId(self.account_id)),
+            AccountType::Handyman => ActorKey::Handyman(HandymanId(self.account_id)),
+            AccountType::Admin => ActorKey::Admin(AdminId(self.account_id)),
+        }
+    }
+
+    /// The session is neither revoked nor expired
+    pub fn is_active(&self, now: NaiveDateTime) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
+
+impl UserSession {
+    pub async fn create(
+        actor_auth: &ActorAuth,
+        NewUserSession {
+            actor_key,
+            jti,
+            expires_at,
+        }: NewUserSession<'_>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_god_or_admin()?;
+        let (account_type, account_id) = account_columns(actor_key);
+
+        diesel::insert_into(user_session::table)
+            .values((
+                user_session::account_type.eq(account_type),
+                user_session::account_id.eq(account_id),
+                user_session::jti.eq(jti),
+                user_session::expires_at.eq(expires_at),
+            ))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .map_err(Error::from)
+    }
+
+    /// Get session by `jti` of its latest session token, along with the status of its account.
+    /// Admin accounts are always active.
+    pub async fn find_by_jti(
+        jti: &str,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Option<(Self, Option<AccountStatus>)>> {
+        let session = user_session::table
+            .left_join(
+                customer_account::table.on(user_session::account_type
+                    .eq(AccountType::Customer)
+                    .and(customer_account::id.eq(user_session::account_id))),
+            )
+            .left_join(
+                handyman_account::table.on(user_session::account_type
+                    .eq(AccountType::Handyman)
+                    .and(handyman_account::id.eq(user_session::account_id))),
+            )
+            .filter(user_session::jti.eq(jti))
+            .select((
+                Self::as_select(),
+                customer_account::status.nullable(),
+                handyman_account::status.nullable(),
+            ))
+            .first::<(Self, Option<AccountStatus>, Option<AccountStatus>)>(conn)
+            .await
+            .optional()?;
+
+        Ok(session.map(|(session, customer_status, handyman_status)| {
+            let status = match session.account_type {
+                AccountType::Customer => customer_status,
+                AccountType::Handyman => handyman_status,
+                AccountType::Admin => Some(AccountStatus::Active),
+            };
+            (session, status)
+        }))
+    }
+
+    /// Rotate the session token of an active session and extend its expiry
+    pub async fn renew(
+        actor_auth: &ActorAuth,
+        id: UserSessionId,
+        RenewUserSession { jti, expires_at }: RenewUserSession<'_>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        let session = Self::get_for_update(actor_auth, id, conn).await?;
+        if !session.is_active(Utc::now().naive_utc()) {
+            return Err(Error::unauthenticated("Session is revoked or expired"));
+        }
+
+        diesel::update(user_session::table.find(id))
+            .set((
+                user_session::jti.eq(jti),
+                user_session::expires_at.eq(expires_at),
+            ))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .map_err(Error::from)
+    }
+
+    /// Revoke a session, revoking an already revoked session is a no-op
+    pub async fn revoke(
+        actor_auth: &ActorAuth,
+        id: UserSessionId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        let session = Self::get_for_update(actor_auth, id, conn).await?;
+        if session.revoked_at.is_some() {
+            return Ok(session);
+        }
+
+        diesel::update(user_session::table.find(id))
+            .set(user_session::revoked_at.eq(Utc::now().naive_utc()))
+            .returning(Self::as_select())
+            .get_result::<Self>(conn)
+            .await
+            .map_err(Error::from)
+    }
+
+    /// Revoke all sessions of an account, returns number of revoked sessions
+    pub async fn revoke_all(
+        actor_auth: &ActorAuth,
+        actor_key: ActorKey,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<usize> {
+        require_account_access(actor_auth, actor_key)?;
+        let (account_type, account_id) = account_columns(actor_key);
+
+        diesel::update(
+            user_session::table
+                .filter(user_session::account_type.eq(account_type))
+                .filter(user_session::account_id.eq(account_id))
+                .filter(user_session::revoked_at.is_null()),
+        )
+        .set(user_session::revoked_at.eq(Utc::now().naive_utc()))
+        .execute(conn)
+        .await
+        .map_err(Error::from)
+    }
+
+    /// Sessions neither revoked nor expired of an account, the most recent first
+    pub async fn get_active_by_account(
+        actor_auth: &ActorAuth,
+        actor_key: ActorKey,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        require_account_access(actor_auth, actor_key)?;
+        let (account_type, account_id) = account_columns(actor_key);
+
+        let sessions = user_session::table
+            .filter(user_session::account_type.eq(account_type))
+            .filter(user_session::account_id.eq(account_id))
+            .filter(user_session::revoked_at.is_null())
+            .filter(user_session::expires_at.gt(Utc::now().naive_utc()))
+            .order_by(user_session::created_at.desc())
+            .select(Self::as_select())
+            .load::<Self>(conn)
+            .await?;
+        Ok(sessions)
+    }
+
+    /// Session actors can only read the sessions of their own account
+    pub async fn load_by_ids(
+        actor_auth: &ActorAuth,
+        ids: &[UserSessionId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let mut query = user_session::table
+            .filter(user_session::id.eq_any(ids))
+            .select(Self::as_select())
+            .into_boxed();
+
+        if let Some(actor) = actor_auth.session_actor() {
+            let (account_type, account_id) = account_columns(actor.actor_key());
+            query = query
+                .filter(user_session::account_type.eq(account_type))
+                .filter(user_session::account_id.eq(account_id));
+        }
+
+        let sessions = query.load::<Self>(conn).await?;
+        Ok(sessions)
+    }
+
+    async fn get_for_update(
+        actor_auth: &ActorAuth,
+        id: UserSessionId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        let session = user_session::table
+            .find(id)
+            .select(Self::as_select())
+            .for_update()
+            .first::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Session not found"))?;
+        require_account_access(actor_auth, session.actor_key())?;
+        Ok(session)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NewUserSession<'a> {
+    pub actor_key: ActorKey,
+    pub jti: &'a str,
+    pub expires_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RenewUserSession<'a> {
+    pub jti: &'a str,
+    pub expires_at: NaiveDateTime,
+}
+
+fn account_columns(actor_key: ActorKey) -> (AccountType, i64) {
+    match actor_key {
+        ActorKey::Customer(customer_id) => (AccountType::Customer, customer_id.0),
+        ActorKey::Handyman(handyman_id) => (AccountType::Handyman, handyman_id.0),
+        ActorKey::Admin(admin_id) => (AccountType::Admin, admin_id.0),
+    }
+}
+
+fn require_account_access(actor_auth: &ActorAuth, actor_key: ActorKey) -> Result<()> {
+    match actor_key {
+        ActorKey::Customer(customer_id) => actor_auth.require_customer_access(customer_id),
+        ActorKey::Handyman(handyman_id) => actor_auth.require_handyman_access(handyman_id),
+        ActorKey::Admin(_) => actor_auth.require_god_or_admin(),
+    }
+}
@@ -0,0 +1,165 @@
+use super::AccountService;
+use crate::UserAccount;
+use account_service_db as db;
+use actor_auth::{ActorAuth, ActorKey};
+use db_utils::with_mutable_db;
+use entity_type::AccountStatus;
+use error::{
+    Error, Result,
+    error_details::{BadRequest, bad_request::FieldViolation},
+};
+use scoped_futures::ScopedFutureExt;
+
+impl AccountService {
+    /// Suspend or reinstate a customer or handyman account,
+    /// every session of a suspended account is revoked.
+    #[tracing::instrument(skip(self))]
+    pub async fn update_account_status(
+        &self,
+        request: UpdateAccountStatusRequest,
+    ) -> Result<UpdateAccountStatusResponse> {
+        let UpdateAccountStatusRequest {
+            actor_auth,
+            actor_key,
+            status,
+        } = request;
+
+        let account = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            async move {
+                let account = match actor_key {
+                    ActorKey::Customer(id) => {
+                        db::CustomerAccount::update_status(&actor_auth, id, status, conn)
+                            .await
+                            .map(UserAccount::Customer)?
+                    }
+                    ActorKey::Handyman(id) => {
+                        db::HandymanAccount::update_status(&actor_auth, id, status, conn)
+                            .await
+                            .map(UserAccount::Handyman)?
+                    }
+                    ActorKey::Admin(_) => {
+                        return Err(Error::invalid_argument("Admin accounts have no status"));
+                    }
+                };
+                if status != AccountStatus::Active {
+                    db::UserSession::revoke_all(&ActorAuth::God, actor_key, conn).await?;
+                }
+                Ok(account)
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(UpdateAccountStatusResponse { account })
+    }
+
+    /// Delete a customer or handyman account, its profile is removed and
+    /// its phone number and password are anonymized, every session is revoked.
+    /// The caller is responsible for removing the returned avatar from the blob store.
+    #[tracing::instrument(skip_all)]
+    pub async fn delete_account(
+        &self,
+        request: DeleteAccountRequest,
+    ) -> Result<DeleteAccountResponse> {
+        let DeleteAccountRequest {
+            actor_auth,
+            actor_key,
+            password,
+        } = request;
+        if password.is_none() {
+            actor_auth.require_god_or_admin()?;
+        }
+
+        let (account, avatar_key) = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            async move {
+                let (account, avatar_key) = match actor_key {
+                    ActorKey::Customer(id) => {
+                        let account = db::CustomerAccount::get_by_id(&actor_auth, id, conn).await?;
+                        require_not_deleted(account.status)?;
+                        if let Some(password) = &password {
+                            require_password(account.password_matches(password)?)?;
+                        }
+                        db::CustomerProfile::delete(&actor_auth, id, conn).await?;
+                        let account = db::CustomerAccount::delete(&actor_auth, id, conn).await?;
+                        (UserAccount::Customer(account), None)
+                    }
+                    ActorKey::Handyman(id) => {
+                        let account = db::HandymanAccount::get_by_id(&actor_auth, id, conn).await?;
+                        require_not_deleted(account.status)?;
+                        if let Some(password) = &password {
+                            require_password(account.password_matches(password)?)?;
+                        }
+                        let profile = db::HandymanProfile::delete(&actor_auth, id, conn).await?;
+                        let account = db::HandymanAccount::delete(&actor_auth, id, conn).await?;
+                        (
+                            UserAccount::Handyman(account),
+                            profile.and_then(|p| p.avatar_key),
+                        )
+                    }
+                    ActorKey::Admin(_) => {
+                        return Err(Error::invalid_argument("Admin accounts can't be deleted"));
+                    }
+                };
+                db::UserSession::revoke_all(&ActorAuth::God, actor_key, conn).await?;
+                Ok((account, avatar_key))
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(DeleteAccountResponse {
+            account,
+            avatar_key,
+        })
+    }
+}
+
+/// Same as changing password, a typo shouldn't be treated as an unauthenticated session
+fn require_password(matches: bool) -> Result<()> {
+    if matches {
+        return Ok(());
+    }
+    Err(Error::invalid_argument_with(
+        "Password doesn't match",
+        Some(BadRequest {
+            field_violations: vec![FieldViolation {
+                field: "PASSWORD".into(),
+                description: "MISMATCHED".into(),
+            }],
+        }),
+    ))
+}
+
+fn require_not_deleted(status: AccountStatus) -> Result<()> {
+    if status == AccountStatus::Deleted {
+        return Err(Error::failed_precondition("Account is already deleted"));
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct UpdateAccountStatusRequest {
+    pub actor_auth: ActorAuth,
+    pub actor_key: ActorKey,
+    pub status: AccountStatus,
+}
+
+#[derive(Debug)]
+pub struct UpdateAccountStatusResponse {
+    pub account: UserAccount,
+}
+
+#[derive(Debug)]
+pub struct DeleteAccountRequest {
+    pub actor_auth: ActorAuth,
+    pub actor_key: ActorKey,
+    /// Current password of the account, only staff can delete an account without it
+    pub password: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct DeleteAccountResponse {
+    pub account: UserAccount,
+    /// Key of the avatar of the deleted profile in the blob store
+    pub avatar_key: Option<String>,
+}
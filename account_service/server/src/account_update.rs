@@ -0,0 +1,169 @@
+use super::AccountService;
+use crate::InitiateOrRenewSession;
+use account_service_db as db;
+use actor_auth::{ActorAuth, ActorType};
+use db_utils::with_mutable_db;
+use error::{
+    Error, Result,
+    error_details::{BadRequest, bad_request::FieldViolation},
+};
+use scoped_futures::ScopedFutureExt;
+
+impl AccountService {
+    /// Change password of the actor account, the current password is required.
+    /// Every session of the account is revoked and a new one is initiated for the caller.
+    #[tracing::instrument(skip_all)]
+    pub async fn change_password(
+        &self,
+        request: ChangePasswordRequest,
+    ) -> Result<ChangePasswordResponse> {
+        let ChangePasswordRequest {
+            actor_auth,
+            old_password,
+            new_password,
+        } = request;
+        let actor_type = *actor_auth.try_session_actor()?;
+
+        let account = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            async move {
+                let account = match actor_type {
+                    ActorType::Customer(actor) => {
+                        let id = actor.customer_id;
+                        let account = db::CustomerAccount::get_by_id(&actor_auth, id, conn).await?;
+                        require_old_password(account.password_matches(&old_password)?)?;
+                        db::CustomerAccount::update_password(&actor_auth, id, &new_password, conn)
+                            .await
+                            .map(UserAccount::Customer)?
+                    }
+                    ActorType::Handyman(actor) => {
+                        let id = actor.handyman_id;
+                        let account = db::HandymanAccount::get_by_id(&actor_auth, id, conn).await?;
+                        require_old_password(account.password_matches(&old_password)?)?;
+                        db::HandymanAccount::update_password(&actor_auth, id, &new_password, conn)
+                            .await
+                            .map(UserAccount::Handyman)?
+                    }
+                    ActorType::Admin(actor) => {
+                        let id = actor.admin_id;
+                        let account = db::AdminAccount::get_by_id(&actor_auth, id, conn).await?;
+                        require_old_password(account.password_matches(&old_password)?)?;
+                        db::AdminAccount::update_password(&actor_auth, id, &new_password, conn)
+                            .await
+                            .map(UserAccount::Admin)?
+                    }
+                };
+                db::UserSession::revoke_all(&ActorAuth::God, actor_type.actor_key(), conn).await?;
+                Ok(account)
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let initiate_session = self.context.initiate_user_session(actor_type).await?;
+
+        Ok(ChangePasswordResponse {
+            account,
+            initiate_session,
+        })
+    }
+
+    /// Move the actor account to another phone number,
+    /// the caller is responsible for verifying the ownership of the phone number beforehand.
+    #[tracing::instrument(skip_all, fields(phone_number=request.e164_phone_number_str))]
+    pub async fn change_phone_number(
+        &self,
+        request: ChangePhoneNumberRequest,
+    ) -> Result<ChangePhoneNumberResponse> {
+        let ChangePhoneNumberRequest {
+            actor_auth,
+            e164_phone_number_str,
+        } = request;
+        let actor_type = *actor_auth.try_session_actor()?;
+        let phone_number = typesafe::phone_number_from_str(&e164_phone_number_str)?;
+
+        let account = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            async move {
+                match actor_type {
+                    ActorType::Customer(actor) => db::CustomerAccount::update_phone_number(
+                        &actor_auth,
+                        actor.customer_id,
+                        &phone_number,
+                        conn,
+                    )
+                    .await
+                    .map(UserAccount::Customer),
+                    ActorType::Handyman(actor) => db::HandymanAccount::update_phone_number(
+                        &actor_auth,
+                        actor.handyman_id,
+                        &phone_number,
+                        conn,
+                    )
+                    .await
+                    .map(UserAccount::Handyman),
+                    ActorType::Admin(actor) => db::AdminAccount::update_phone_number(
+                        &actor_auth,
+                        actor.admin_id,
+                        &phone_number,
+                        conn,
+                    )
+                    .await
+                    .map(UserAccount::Admin),
+                }
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(ChangePhoneNumberResponse { account })
+    }
+}
+
+/// Signed in users shouldn't be signed out by a typo of the old password,
+/// so it's rejected as an invalid argument rather than being unauthenticated.
+fn require_old_password(matches: bool) -> Result<()> {
+    if matches {
+        return Ok(());
+    }
+    Err(Error::invalid_argument_with(
+        "Old password doesn't match",
+        Some(BadRequest {
+            field_violations: vec![FieldViolation {
+                field: "OLD_PASSWORD".into(),
+                description: "MISMATCHED".into(),
+            }],
+        }),
+    ))
+}
+
+#[derive(Debug)]
+/// Account of either account type
+pub enum UserAccount {
+    Customer(db::CustomerAccount),
+    Handyman(db::HandymanAccount),
+    Admin(db::AdminAccount),
+}
+
+#[derive(Debug)]
+pub struct ChangePasswordRequest {
+    pub actor_auth: ActorAuth,
+    pub old_password: String,
+    pub new_password: String,
+}
+
+#[derive(Debug)]
+pub struct ChangePasswordResponse {
+    pub account: UserAccount,
+    /// Session replacing the revoked one of the caller
+    pub initiate_session: InitiateOrRenewSession,
+}
+
+#[derive(Debug)]
+pub struct ChangePhoneNumberRequest {
+    pub actor_auth: ActorAuth,
+    pub e164_phone_number_str: String,
+}
+
+#[derive(Debug)]
+pub struct ChangePhoneNumberResponse {
+    pub account: UserAccount,
+}
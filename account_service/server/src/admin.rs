@@ -0,0 +1,78 @@
+use super::AccountService;
+use account_service_db as db;
+use actor_auth::ActorAuth;
+use db_utils::{with_mutable_db, with_readonly_db};
+use entity_type::AdminId;
+use error::Result;
+use scoped_futures::ScopedFutureExt;
+
+impl AccountService {
+    /// Create an admin account, admins can't register by themselves
+    #[tracing::instrument(skip_all, fields(phone_number=request.e164_phone_number_str))]
+    pub async fn admin_create_account(
+        &self,
+        request: AdminCreateAccountRequest,
+    ) -> Result<AdminCreateAccountResponse> {
+        let AdminCreateAccountRequest {
+            actor_auth,
+            e164_phone_number_str,
+            password,
+        } = request;
+
+        let phone_number = typesafe::phone_number_from_str(&e164_phone_number_str)?;
+        let account = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            db::AdminAccount::create(
+                &actor_auth,
+                db::NewAdminAccount {
+                    phone_number: &phone_number,
+                    password: &password,
+                },
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(AdminCreateAccountResponse { account })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn load_admin_account_by_ids(
+        &self,
+        request: LoadAdminAccountByIdsRequest,
+    ) -> Result<LoadAdminAccountByIdsResponse> {
+        let LoadAdminAccountByIdsRequest {
+            actor_auth,
+            account_ids,
+        } = request;
+        let admins = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            db::AdminAccount::load_by_ids(&actor_auth, &account_ids, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(LoadAdminAccountByIdsResponse { admins })
+    }
+}
+
+#[derive(Debug)]
+pub struct AdminCreateAccountRequest {
+    pub actor_auth: ActorAuth,
+    pub e164_phone_number_str: String,
+    pub password: String,
+}
+
+#[derive(Debug)]
+pub struct AdminCreateAccountResponse {
+    pub account: db::AdminAccount,
+}
+
+#[derive(Debug)]
+pub struct LoadAdminAccountByIdsRequest {
+    pub actor_auth: ActorAuth,
+    pub account_ids: Vec<AdminId>,
+}
+
+#[derive(Debug)]
+pub struct LoadAdminAccountByIdsResponse {
+    pub admins: Vec<db::AdminAccount>,
+}
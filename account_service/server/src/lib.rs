@@ -9,3 +9,21 @@ pub use load_customer::*;
 
 mod load_handyman;
 pub use load_handyman::*;
+
+mod user_session;
+pub use user_session::*;
+
+mod password_reset;
+pub use password_reset::*;
+
+mod account_update;
+pub use account_update::*;
+
+mod admin;
+pub use admin::*;
+
+mod profile_update;
+pub use profile_update::*;
+
+mod account_status;
+pub use account_status::*;
@@ -40,6 +40,26 @@ impl AccountService {
 
         Ok(LoadCustomerProfileByIdsResponse { profiles })
     }
+
+    /// Look up an account by phone number, only available to god and admins
+    #[tracing::instrument(skip(self))]
+    pub async fn find_customer_account_by_phone_number(
+        &self,
+        request: FindCustomerAccountByPhoneNumberRequest,
+    ) -> Result<FindCustomerAccountByPhoneNumberResponse> {
+        let FindCustomerAccountByPhoneNumberRequest {
+            actor_auth,
+            e164_phone_number_str,
+        } = request;
+        actor_auth.require_god_or_admin()?;
+
+        let customer = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            db::CustomerAccount::find_by_phone_number(&e164_phone_number_str, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(FindCustomerAccountByPhoneNumberResponse { customer })
+    }
 }
 
 #[derive(Debug)]
@@ -63,3 +83,14 @@ pub struct LoadCustomerProfileByIdsRequest {
 pub struct LoadCustomerProfileByIdsResponse {
     pub profiles: Vec<db::CustomerProfile>,
 }
+
+#[derive(Debug)]
+pub struct FindCustomerAccountByPhoneNumberRequest {
+    pub actor_auth: ActorAuth,
+    pub e164_phone_number_str: String,
+}
+
+#[derive(Debug)]
+pub struct FindCustomerAccountByPhoneNumberResponse {
+    pub customer: Option<db::CustomerAccount>,
+}
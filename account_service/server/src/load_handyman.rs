@@ -40,6 +40,41 @@ impl AccountService {
 
         Ok(LoadHandymanProfileByIdsResponse { profiles })
     }
+
+    /// Look up an account by phone number, only available to god and admins
+    #[tracing::instrument(skip(self))]
+    pub async fn find_handyman_account_by_phone_number(
+        &self,
+        request: FindHandymanAccountByPhoneNumberRequest,
+    ) -> Result<FindHandymanAccountByPhoneNumberResponse> {
+        let FindHandymanAccountByPhoneNumberRequest {
+            actor_auth,
+            e164_phone_number_str,
+        } = request;
+        actor_auth.require_god_or_admin()?;
+
+        let handyman = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            db::HandymanAccount::find_by_phone_number(&e164_phone_number_str, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(FindHandymanAccountByPhoneNumberResponse { handyman })
+    }
+
+    /// Ids of suspended and deleted handymen, only available to god and admins
+    #[tracing::instrument(skip(self))]
+    pub async fn load_inactive_handyman_ids(
+        &self,
+        request: LoadInactiveHandymanIdsRequest,
+    ) -> Result<LoadInactiveHandymanIdsResponse> {
+        let LoadInactiveHandymanIdsRequest { actor_auth } = request;
+        let handyman_ids = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            db::HandymanAccount::inactive_ids(&actor_auth, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(LoadInactiveHandymanIdsResponse { handyman_ids })
+    }
 }
 
 #[derive(Debug)]
@@ -63,3 +98,24 @@ pub struct LoadHandymanProfileByIdsRequest {
 pub struct LoadHandymanProfileByIdsResponse {
     pub profiles: Vec<db::HandymanProfile>,
 }
+
+#[derive(Debug)]
+pub struct FindHandymanAccountByPhoneNumberRequest {
+    pub actor_auth: ActorAuth,
+    pub e164_phone_number_str: String,
+}
+
+#[derive(Debug)]
+pub struct FindHandymanAccountByPhoneNumberResponse {
+    pub handyman: Option<db::HandymanAccount>,
+}
+
+#[derive(Debug)]
+pub struct LoadInactiveHandymanIdsRequest {
+    pub actor_auth: ActorAuth,
+}
+
+#[derive(Debug)]
+pub struct LoadInactiveHandymanIdsResponse {
+    pub handyman_ids: Vec<HandymanId>,
+}
@@ -0,0 +1,94 @@
+use super::AccountService;
+use crate::InitiateOrRenewSession;
+use account_service_db as db;
+use actor_auth::{ActorAuth, ActorType, CustomerActor, HandymanActor};
+use db_utils::with_mutable_db;
+use entity_type::AccountType;
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+
+impl AccountService {
+    /// Set a new password for the account owning the phone number,
+    /// the caller is responsible for verifying the ownership beforehand.
+    /// Every existing session of the account is revoked, and a new one is initiated.
+    /// Admin passwords can't be reset this way.
+    #[tracing::instrument(skip_all, fields(phone_number=request.e164_phone_number_str))]
+    pub async fn reset_password(
+        &self,
+        request: ResetPasswordRequest,
+    ) -> Result<ResetPasswordResponse> {
+        let ResetPasswordRequest {
+            e164_phone_number_str,
+            account_type,
+            password,
+        } = request;
+
+        let e164_phone_number_str = typesafe::normalize_phone_number_str(&e164_phone_number_str)?;
+        let actor_type = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            async move {
+                let actor_type = match account_type {
+                    AccountType::Customer => {
+                        let account =
+                            db::CustomerAccount::find_by_phone_number(&e164_phone_number_str, conn)
+                                .await?
+                                .ok_or_else(|| Error::not_found("Account not found"))?
+                                .require_active()?;
+                        db::CustomerAccount::update_password(
+                            &ActorAuth::God,
+                            account.id,
+                            &password,
+                            conn,
+                        )
+                        .await?;
+                        ActorType::Customer(CustomerActor {
+                            customer_id: account.id,
+                        })
+                    }
+                    AccountType::Handyman => {
+                        let account =
+                            db::HandymanAccount::find_by_phone_number(&e164_phone_number_str, conn)
+                                .await?
+                                .ok_or_else(|| Error::not_found("Account not found"))?
+                                .require_active()?;
+                        db::HandymanAccount::update_password(
+                            &ActorAuth::God,
+                            account.id,
+                            &password,
+                            conn,
+                        )
+                        .await?;
+                        ActorType::Handyman(HandymanActor {
+                            handyman_id: account.id,
+                        })
+                    }
+                    // Admin passwords are reset by other admins
+                    AccountType::Admin => {
+                        return Err(Error::permission_denied(
+                            "Admin accounts can't reset their password",
+                        ));
+                    }
+                };
+                db::UserSession::revoke_all(&ActorAuth::God, actor_type.actor_key(), conn).await?;
+                Ok(actor_type)
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let initiate_session = self.context.initiate_user_session(actor_type).await?;
+
+        Ok(ResetPasswordResponse { initiate_session })
+    }
+}
+
+#[derive(Debug)]
+pub struct ResetPasswordRequest {
+    pub e164_phone_number_str: String,
+    pub account_type: AccountType,
+    pub password: String,
+}
+
+#[derive(Debug)]
+pub struct ResetPasswordResponse {
+    pub initiate_session: InitiateOrRenewSession,
+}
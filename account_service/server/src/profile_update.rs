@@ -0,0 +1,123 @@
+use super::AccountService;
+use account_service_db as db;
+use actor_auth::ActorAuth;
+use db_utils::with_mutable_db;
+use entity_type::{CustomerId, HandymanId};
+use error::Result;
+use scoped_futures::ScopedFutureExt;
+
+impl AccountService {
+    #[tracing::instrument(skip(self))]
+    pub async fn customer_update_profile(
+        &self,
+        request: CustomerUpdateProfileRequest,
+    ) -> Result<CustomerUpdateProfileResponse> {
+        let CustomerUpdateProfileRequest {
+            actor_auth,
+            customer_id,
+            nick_name,
+        } = request;
+
+        let profile = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            db::CustomerProfile::update(
+                &actor_auth,
+                customer_id,
+                db::CustomerProfileChangeset {
+                    nick_name: nick_name.as_deref(),
+                },
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(CustomerUpdateProfileResponse { profile })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn handyman_update_profile(
+        &self,
+        request: HandymanUpdateProfileRequest,
+    ) -> Result<HandymanUpdateProfileResponse> {
+        let HandymanUpdateProfileRequest {
+            actor_auth,
+            handyman_id,
+            first_name,
+            last_name,
+            bio,
+            years_of_experience,
+            avatar_key,
+        } = request;
+
+        let (profile, replaced_avatar_key) =
+            with_mutable_db(&self.context.db_connection_pool, |conn| {
+                async move {
+                    // Lock the profile so that concurrent uploads each get the avatar they replaced
+                    let previous_avatar_key = if avatar_key.is_some() {
+                        db::HandymanProfile::find_for_update(&actor_auth, handyman_id, conn)
+                            .await?
+                            .avatar_key
+                    } else {
+                        None
+                    };
+                    let profile = db::HandymanProfile::update(
+                        &actor_auth,
+                        handyman_id,
+                        db::HandymanProfileChangeset {
+                            first_name: first_name.as_deref(),
+                            last_name: last_name.as_deref(),
+                            bio: bio.as_ref().map(|b| b.as_deref()),
+                            years_of_experience,
+                            avatar_key: avatar_key.as_ref().map(|k| k.as_deref()),
+                        },
+                        conn,
+                    )
+                    .await?;
+                    let replaced_avatar_key =
+                        previous_avatar_key.filter(|k| profile.avatar_key.as_ref() != Some(k));
+                    Ok((profile, replaced_avatar_key))
+                }
+                .scope_boxed()
+            })
+            .await?;
+
+        Ok(HandymanUpdateProfileResponse {
+            profile,
+            replaced_avatar_key,
+        })
+    }
+}
+
+/// `None` fields are left unchanged.
+#[derive(Debug)]
+pub struct CustomerUpdateProfileRequest {
+    pub actor_auth: ActorAuth,
+    pub customer_id: CustomerId,
+    pub nick_name: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct CustomerUpdateProfileResponse {
+    pub profile: db::CustomerProfile,
+}
+
+/// `None` fields are left unchanged, `Some(None)` clears optional fields.
+#[derive(Debug)]
+pub struct HandymanUpdateProfileRequest {
+    pub actor_auth: ActorAuth,
+    pub handyman_id: HandymanId,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub bio: Option<Option<String>>,
+    pub years_of_experience: Option<Option<i32>>,
+    /// Key of the avatar image in the blob store
+    pub avatar_key: Option<Option<String>>,
+}
+
+#[derive(Debug)]
+pub struct HandymanUpdateProfileResponse {
+    pub profile: db::HandymanProfile,
+    /// Key of the avatar replaced by the update,
+    /// the caller is responsible for removing it from the blob store.
+    pub replaced_avatar_key: Option<String>,
+}
@@ -1,10 +1,13 @@
-use actor_auth::{ActorType, Session};
+use account_service_db as db;
+use actor_auth::{ActorAuth, ActorType, Session};
 use chrono::{Duration, Utc};
-use db_utils::PgConnectionPool;
+use db_utils::{AsyncPgConnection, PgConnectionPool, with_mutable_db};
+use entity_type::UserSessionId;
 use error::Result;
 use hex_converter::HexConverter;
 use jwt_signer::{JwtClaims, JwtSigner};
 use random_util::Random;
+use scoped_futures::ScopedFutureExt;
 use std::sync::Arc;
 
 use crate::{InitiateOrRenewSession, SessionAndCsrfToken};
@@ -33,19 +36,82 @@ impl AccountServiceContext {
     pub(crate) async fn initiate_user_session(
         &self,
         actor_type: ActorType,
+    ) -> Result<InitiateOrRenewSession> {
+        with_mutable_db(&self.db_connection_pool, |conn| {
+            self.initiate_user_session_in(actor_type, conn)
+                .scope_boxed()
+        })
+        .await
+    }
+
+    /// Same as [`Self::initiate_user_session`] within the transaction of `conn`,
+    /// so that the session is only created along with the other changes of the transaction.
+    pub(crate) async fn initiate_user_session_in(
+        &self,
+        actor_type: ActorType,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<InitiateOrRenewSession> {
+        let claims = self.new_session_token_claims(actor_type).await;
+        let csrf_token = HexConverter::u128_to_hex(claims.jti);
+        let session = db::UserSession::create(
+            &ActorAuth::God,
+            db::NewUserSession {
+                actor_key: actor_type.actor_key(),
+                jti: &csrf_token,
+                expires_at: claims.exp,
+            },
+            conn,
+        )
+        .await?;
+
+        self.sign_user_session(session.id, claims, csrf_token)
+    }
+
+    /// Issue a new session token for an existing session, extending its expiry.
+    /// Tokens issued before are no longer valid.
+    pub(crate) async fn renew_user_session(
+        &self,
+        actor_auth: &ActorAuth,
+        session_id: UserSessionId,
+        actor_type: ActorType,
     ) -> Result<InitiateOrRenewSession> {
         let claims = self.new_session_token_claims(actor_type).await;
+        let csrf_token = HexConverter::u128_to_hex(claims.jti);
+        with_mutable_db(&self.db_connection_pool, |conn| {
+            db::UserSession::renew(
+                actor_auth,
+                session_id,
+                db::RenewUserSession {
+                    jti: &csrf_token,
+                    expires_at: claims.exp,
+                },
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+
+        self.sign_user_session(session_id, claims, csrf_token)
+    }
+
+    fn sign_user_session(
+        &self,
+        session_id: UserSessionId,
+        claims: JwtClaims<ActorType>,
+        csrf_token: String,
+    ) -> Result<InitiateOrRenewSession> {
         let session_token = self.jwt_signer.sign(&claims)?;
 
         Ok(InitiateOrRenewSession {
             session_token: SessionAndCsrfToken {
                 session_token,
-                csrf_token: HexConverter::u128_to_hex(claims.jti),
+                csrf_token,
             },
             session: Session {
+                session_id,
                 iat: claims.iat,
                 exp: claims.exp,
-                actor_type,
+                actor_type: claims.inner,
             },
         })
     }
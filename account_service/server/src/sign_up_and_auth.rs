@@ -1,6 +1,7 @@
 use super::AccountService;
 use account_service_db as db;
-use actor_auth::{ActorAuth, ActorType, CustomerActor, HandymanActor, Session};
+use actor_auth::{ActorAuth, ActorType, AdminActor, CustomerActor, HandymanActor, Session};
+use chrono::Utc;
 use db_utils::{with_mutable_db, with_readonly_db};
 use entity_type::{AccountType, CustomerId, HandymanId};
 use error::{Error, Result};
@@ -26,7 +27,10 @@ impl AccountService {
                         db::CustomerAccount::phone_exist(&e164_phone_number_str, conn).await
                     }
                     AccountType::Handyman => {
-                        db::CustomerAccount::phone_exist(&e164_phone_number_str, conn).await
+                        db::HandymanAccount::phone_exist(&e164_phone_number_str, conn).await
+                    }
+                    AccountType::Admin => {
+                        db::AdminAccount::phone_exist(&e164_phone_number_str, conn).await
                     }
                 }
             }
@@ -37,6 +41,24 @@ impl AccountService {
         Ok(AccountExistsResponse { exists })
     }
 
+    /// Whether the phone number belongs to a person having a customer or handyman account
+    #[tracing::instrument(skip_all, fields(phone_number=request.e164_phone_number_str))]
+    pub async fn person_exists(
+        &self,
+        request: PersonExistsRequest,
+    ) -> Result<PersonExistsResponse> {
+        let PersonExistsRequest {
+            e164_phone_number_str,
+        } = request;
+        let e164_phone_number_str = typesafe::normalize_phone_number_str(&e164_phone_number_str)?;
+        let exists = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            db::Person::phone_exist(&e164_phone_number_str, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(PersonExistsResponse { exists })
+    }
+
     #[tracing::instrument(skip_all, fields(phone_number=request.e164_phone_number_str))]
     pub async fn customer_register(
         &self,
@@ -177,6 +199,7 @@ impl AccountService {
         let Some(account) = account.and_then(|a| a.verify_password(&password).ok()) else {
             return Err(Error::unauthenticated("Creadentials not found"));
         };
+        let account = account.require_active()?;
 
         let initiate_session = self
             .context
@@ -206,6 +229,7 @@ impl AccountService {
         let Some(account) = account.and_then(|a| a.verify_password(&password).ok()) else {
             return Err(Error::unauthenticated("Creadentials not found"));
         };
+        let account = account.require_active()?;
 
         let initiate_session = self
             .context
@@ -217,6 +241,95 @@ impl AccountService {
         Ok(HandymanSigninWithPasswordResponse { initiate_session })
     }
 
+    #[tracing::instrument(skip_all, fields(phone_number=request.e164_phone_number_str))]
+    pub async fn admin_sign_in_with_password(
+        &self,
+        request: AdminSignInWithPasswordRequest,
+    ) -> Result<AdminSignInWithPasswordResponse> {
+        let AdminSignInWithPasswordRequest {
+            e164_phone_number_str,
+            password,
+        } = request;
+
+        let account = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            db::AdminAccount::find_by_phone_number(&e164_phone_number_str, conn).scope_boxed()
+        })
+        .await?;
+
+        let Some(account) = account.and_then(|a| a.verify_password(&password).ok()) else {
+            return Err(Error::unauthenticated("Creadentials not found"));
+        };
+
+        let initiate_session = self
+            .context
+            .initiate_user_session(ActorType::Admin(AdminActor {
+                admin_id: account.id,
+            }))
+            .await?;
+
+        Ok(AdminSignInWithPasswordResponse { initiate_session })
+    }
+
+    /// Sign in without a password,
+    /// the caller is responsible for verifying the ownership of the phone number beforehand.
+    /// Admins sign in with their password only.
+    #[tracing::instrument(skip_all, fields(phone_number=request.e164_phone_number_str))]
+    pub async fn sign_in_with_otp(
+        &self,
+        request: SignInWithOtpRequest,
+    ) -> Result<SignInWithOtpResponse> {
+        let SignInWithOtpRequest {
+            e164_phone_number_str,
+            account_type,
+        } = request;
+
+        let actor_type = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            async move {
+                let actor_type = match account_type {
+                    AccountType::Customer => {
+                        db::CustomerAccount::find_by_phone_number(&e164_phone_number_str, conn)
+                            .await?
+                            .map(|account| account.require_active())
+                            .transpose()?
+                            .map(|account| {
+                                ActorType::Customer(CustomerActor {
+                                    customer_id: account.id,
+                                })
+                            })
+                    }
+                    AccountType::Handyman => {
+                        db::HandymanAccount::find_by_phone_number(&e164_phone_number_str, conn)
+                            .await?
+                            .map(|account| account.require_active())
+                            .transpose()?
+                            .map(|account| {
+                                ActorType::Handyman(HandymanActor {
+                                    handyman_id: account.id,
+                                })
+                            })
+                    }
+                    // Admins sign in with their password only
+                    AccountType::Admin => {
+                        return Err(Error::permission_denied(
+                            "Admin accounts can't sign in with OTP",
+                        ));
+                    }
+                };
+                Ok(actor_type)
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        let Some(actor_type) = actor_type else {
+            return Err(Error::unauthenticated("Credentials not found"));
+        };
+
+        let initiate_session = self.context.initiate_user_session(actor_type).await?;
+
+        Ok(SignInWithOtpResponse { initiate_session })
+    }
+
     #[tracing::instrument(skip(self))]
     pub async fn validate_session_token(
         &self,
@@ -234,8 +347,24 @@ impl AccountService {
             verify_csrf_and_access_token_pairing(&claims, csrf)?;
         }
 
+        // A valid signature isn't enough, the session may be revoked or renewed
+        let jti = HexConverter::u128_to_hex(claims.jti);
+        let (user_session, status) = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            db::UserSession::find_by_jti(&jti, conn).scope_boxed()
+        })
+        .await?
+        .filter(|(s, _)| s.is_active(Utc::now().naive_utc()))
+        .filter(|(s, _)| s.actor_key() == claims.inner.actor_key())
+        .ok_or_else(|| Error::unauthenticated("Session is revoked or expired"))?;
+
+        // Sessions are revoked on suspension, this guards against tokens issued concurrently
+        db::require_active_account(
+            status.ok_or_else(|| Error::unauthenticated("Account not found"))?,
+        )?;
+
         Ok(ValidateSessionTokenResponse {
             session: Some(Session {
+                session_id: user_session.id,
                 iat: claims.iat,
                 exp: claims.exp,
                 actor_type: claims.inner,
@@ -255,6 +384,16 @@ pub struct AccountExistsResponse {
     pub exists: bool,
 }
 
+#[derive(Debug)]
+pub struct PersonExistsRequest {
+    pub e164_phone_number_str: String,
+}
+
+#[derive(Debug)]
+pub struct PersonExistsResponse {
+    pub exists: bool,
+}
+
 #[derive(Debug)]
 pub struct CustomerRegisterRequest {
     pub e164_phone_number_str: String,
@@ -324,6 +463,28 @@ pub struct HandymanSigninWithPasswordResponse {
     pub initiate_session: InitiateOrRenewSession,
 }
 
+#[derive(Debug)]
+pub struct AdminSignInWithPasswordRequest {
+    pub e164_phone_number_str: String,
+    pub password: String,
+}
+
+#[derive(Debug)]
+pub struct AdminSignInWithPasswordResponse {
+    pub initiate_session: InitiateOrRenewSession,
+}
+
+#[derive(Debug)]
+pub struct SignInWithOtpRequest {
+    pub e164_phone_number_str: String,
+    pub account_type: AccountType,
+}
+
+#[derive(Debug)]
+pub struct SignInWithOtpResponse {
+    pub initiate_session: InitiateOrRenewSession,
+}
+
 #[derive(Debug)]
 pub struct ValidateSessionTokenRequest {
     pub strategy: ValidateSessionTokenStrategy,
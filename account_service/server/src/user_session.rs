@@ -0,0 +1,248 @@
+use super::AccountService;
+use crate::InitiateOrRenewSession;
+use account_service_db as db;
+use actor_auth::{ActorAuth, ActorType, CustomerActor, HandymanActor};
+use db_utils::{with_mutable_db, with_readonly_db};
+use entity_type::UserSessionId;
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+
+impl AccountService {
+    /// Extend the session of the actor with a new session token
+    #[tracing::instrument(skip(self))]
+    pub async fn renew_session(
+        &self,
+        request: RenewSessionRequest,
+    ) -> Result<RenewSessionResponse> {
+        let RenewSessionRequest { actor_auth } = request;
+        let session = *actor_auth.try_session()?;
+
+        let renew_session = self
+            .context
+            .renew_user_session(&actor_auth, session.session_id, session.actor_type)
+            .await?;
+
+        Ok(RenewSessionResponse { renew_session })
+    }
+
+    /// Revoke the session of the actor
+    #[tracing::instrument(skip(self))]
+    pub async fn sign_out(&self, request: SignOutRequest) -> Result<SignOutResponse> {
+        let SignOutRequest { actor_auth } = request;
+        let session_id = actor_auth.try_session()?.session_id;
+
+        let session = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            db::UserSession::revoke(&actor_auth, session_id, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(SignOutResponse { session })
+    }
+
+    /// Replace the session of the actor with a session of the other role of the same person,
+    /// i.e. the handyman account of a customer and the other way around.
+    #[tracing::instrument(skip(self))]
+    pub async fn switch_active_role(
+        &self,
+        request: SwitchActiveRoleRequest,
+    ) -> Result<SwitchActiveRoleResponse> {
+        let SwitchActiveRoleRequest { actor_auth } = request;
+        let session = *actor_auth.try_session()?;
+
+        let context = &self.context;
+        let initiate_session = with_mutable_db(&context.db_connection_pool, |conn| {
+            async move {
+                let actor_type = match session.actor_type {
+                    ActorType::Customer(actor) => {
+                        let account =
+                            db::CustomerAccount::get_by_id(&actor_auth, actor.customer_id, conn)
+                                .await?;
+                        let handyman = match account.person_id {
+                            Some(person_id) => {
+                                db::HandymanAccount::find_by_person(person_id, conn).await?
+                            }
+                            None => None,
+                        }
+                        .ok_or_else(|| {
+                            Error::failed_precondition("No handyman account of the person")
+                        })?
+                        .require_active()?;
+                        ActorType::Handyman(HandymanActor {
+                            handyman_id: handyman.id,
+                        })
+                    }
+                    ActorType::Handyman(actor) => {
+                        let account =
+                            db::HandymanAccount::get_by_id(&actor_auth, actor.handyman_id, conn)
+                                .await?;
+                        let customer = match account.person_id {
+                            Some(person_id) => {
+                                db::CustomerAccount::find_by_person(person_id, conn).await?
+                            }
+                            None => None,
+                        }
+                        .ok_or_else(|| {
+                            Error::failed_precondition("No customer account of the person")
+                        })?
+                        .require_active()?;
+                        ActorType::Customer(CustomerActor {
+                            customer_id: customer.id,
+                        })
+                    }
+                    ActorType::Admin(_) => {
+                        return Err(Error::invalid_argument("Admin accounts have no other role"));
+                    }
+                };
+                // The current session is only revoked along with the creation of the new one
+                db::UserSession::revoke(&actor_auth, session.session_id, conn).await?;
+                context.initiate_user_session_in(actor_type, conn).await
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(SwitchActiveRoleResponse { initiate_session })
+    }
+
+    /// Revoke a session of the actor account, e.g. signing out another device
+    #[tracing::instrument(skip(self))]
+    pub async fn revoke_session(
+        &self,
+        request: RevokeSessionRequest,
+    ) -> Result<RevokeSessionResponse> {
+        let RevokeSessionRequest {
+            actor_auth,
+            session_id,
+        } = request;
+
+        let session = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            db::UserSession::revoke(&actor_auth, session_id, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(RevokeSessionResponse { session })
+    }
+
+    /// Revoke every session of the actor account, including the current one
+    #[tracing::instrument(skip(self))]
+    pub async fn revoke_all_sessions(
+        &self,
+        request: RevokeAllSessionsRequest,
+    ) -> Result<RevokeAllSessionsResponse> {
+        let RevokeAllSessionsRequest { actor_auth } = request;
+        let actor_key = actor_auth.try_session_actor()?.actor_key();
+
+        let revoked_count = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            db::UserSession::revoke_all(&actor_auth, actor_key, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(RevokeAllSessionsResponse { revoked_count })
+    }
+
+    /// Active sessions of the actor account, one per signed in device
+    #[tracing::instrument(skip(self))]
+    pub async fn active_sessions(
+        &self,
+        request: ActiveSessionsRequest,
+    ) -> Result<ActiveSessionsResponse> {
+        let ActiveSessionsRequest { actor_auth } = request;
+        let actor_key = actor_auth.try_session_actor()?.actor_key();
+
+        let sessions = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            db::UserSession::get_active_by_account(&actor_auth, actor_key, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(ActiveSessionsResponse { sessions })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn load_user_session_by_ids(
+        &self,
+        request: LoadUserSessionByIdsRequest,
+    ) -> Result<LoadUserSessionByIdsResponse> {
+        let LoadUserSessionByIdsRequest {
+            actor_auth,
+            session_ids,
+        } = request;
+        let sessions = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            db::UserSession::load_by_ids(&actor_auth, &session_ids, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(LoadUserSessionByIdsResponse { sessions })
+    }
+}
+
+#[derive(Debug)]
+pub struct RenewSessionRequest {
+    pub actor_auth: ActorAuth,
+}
+
+#[derive(Debug)]
+pub struct RenewSessionResponse {
+    pub renew_session: InitiateOrRenewSession,
+}
+
+#[derive(Debug)]
+pub struct SignOutRequest {
+    pub actor_auth: ActorAuth,
+}
+
+#[derive(Debug)]
+pub struct SignOutResponse {
+    pub session: db::UserSession,
+}
+
+#[derive(Debug)]
+pub struct RevokeSessionRequest {
+    pub actor_auth: ActorAuth,
+    pub session_id: UserSessionId,
+}
+
+#[derive(Debug)]
+pub struct RevokeSessionResponse {
+    pub session: db::UserSession,
+}
+
+#[derive(Debug)]
+pub struct RevokeAllSessionsRequest {
+    pub actor_auth: ActorAuth,
+}
+
+#[derive(Debug)]
+pub struct RevokeAllSessionsResponse {
+    pub revoked_count: usize,
+}
+
+#[derive(Debug)]
+pub struct ActiveSessionsRequest {
+    pub actor_auth: ActorAuth,
+}
+
+#[derive(Debug)]
+pub struct ActiveSessionsResponse {
+    pub sessions: Vec<db::UserSession>,
+}
+
+#[derive(Debug)]
+pub struct LoadUserSessionByIdsRequest {
+    pub actor_auth: ActorAuth,
+    pub session_ids: Vec<UserSessionId>,
+}
+
+#[derive(Debug)]
+pub struct LoadUserSessionByIdsResponse {
+    pub sessions: Vec<db::UserSession>,
+}
+
+#[derive(Debug)]
+pub struct SwitchActiveRoleRequest {
+    pub actor_auth: ActorAuth,
+}
+
+#[derive(Debug)]
+pub struct SwitchActiveRoleResponse {
+    pub initiate_session: InitiateOrRenewSession,
+}
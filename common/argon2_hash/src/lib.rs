@@ -1,6 +1,6 @@
 use argon2::{
     Argon2, PasswordHash, PasswordVerifier,
-    password_hash::{PasswordHasher, SaltString, rand_core::OsRng},
+    password_hash::{self, PasswordHasher, SaltString, rand_core::OsRng},
 };
 use error::Result;
 
@@ -24,4 +24,15 @@ impl Argon2Hash {
         let password_hash = PasswordHash::new(password_hash)?;
         Ok(Argon2::default().verify_password(password.as_bytes(), &password_hash)?)
     }
+
+    /// Like [Self::verify_password], but a mismatched password is `Ok(false)`
+    /// while a malformed hash is still an error
+    pub fn password_matches(password: &str, password_hash: &str) -> Result<bool> {
+        let password_hash = PasswordHash::new(password_hash)?;
+        match Argon2::default().verify_password(password.as_bytes(), &password_hash) {
+            Ok(()) => Ok(true),
+            Err(password_hash::Error::Password) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
 }
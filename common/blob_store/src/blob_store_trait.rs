@@ -0,0 +1,92 @@
+use crate::ImageType;
+use async_trait::async_trait;
+use chrono::Duration;
+use error::{Error, Result};
+use std::fmt;
+use uuid::Uuid;
+
+const PRIVATE_PREFIX: &str = "private";
+
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Store the blob, replacing any blob with the same key.
+    async fn put(&self, key: &BlobKey, content_type: &str, data: Vec<u8>) -> Result<()>;
+
+    /// Read the blob, `None` if there is no blob with the key.
+    async fn get(&self, key: &BlobKey) -> Result<Option<Vec<u8>>>;
+
+    /// Remove the blob, removing a missing blob is not an error.
+    async fn delete(&self, key: &BlobKey) -> Result<()>;
+
+    /// Public url clients download the blob from.
+    fn url(&self, key: &BlobKey) -> String;
+
+    /// Url clients download a private blob from, it expires after `expires_in`.
+    fn signed_url(&self, key: &BlobKey, expires_in: Duration) -> Result<String>;
+
+    /// Check the signature of a url created by [`BlobStore::signed_url`],
+    /// for stores whose blobs are served back by the server.
+    fn verify_signed_url(&self, key: &BlobKey, expires_at: i64, signature: &str) -> bool;
+}
+
+/// Path of a blob in a [`BlobStore`], e.g. `avatars/0199c5d4e6f07b3c9a1d2e3f4a5b6c7d.jpg`.
+///
+/// Keys are made of `/` separated segments of ASCII alphanumerics, `-`, `_` and `.`,
+/// so that they are safe to use as file paths and url paths without encoding.
+///
+/// Keys under `private/`, e.g. `private/task-photos/...`, are only served through signed urls,
+/// a bucket serving blobs publicly must exclude the prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlobKey(String);
+
+impl BlobKey {
+    /// Unique key of a new image under the prefix, e.g. `avatars`
+    pub fn new_image(prefix: &str, image_type: ImageType) -> Self {
+        Self(format!(
+            "{prefix}/{}.{}",
+            Uuid::now_v7().simple(),
+            image_type.extension()
+        ))
+    }
+
+    pub fn parse(key: &str) -> Result<Self> {
+        let is_valid = !key.is_empty()
+            && key.split('/').all(|segment| {
+                !segment.is_empty()
+                    && !segment.starts_with('.')
+                    && segment
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+            });
+        if !is_valid {
+            return Err(Error::invalid_argument(format!(
+                "Malformed blob key {key:?}"
+            )));
+        }
+        Ok(Self(key.into()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the blob is under the `private/` prefix, which is only served through signed urls
+    pub fn is_private(&self) -> bool {
+        self.0
+            .split_once('/')
+            .is_some_and(|(first, _)| first == PRIVATE_PREFIX)
+    }
+
+    /// Image type of keys created by [`BlobKey::new_image`]
+    pub fn image_type(&self) -> Option<ImageType> {
+        self.0
+            .rsplit_once('.')
+            .and_then(|(_, extension)| ImageType::from_extension(extension))
+    }
+}
+
+impl fmt::Display for BlobKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
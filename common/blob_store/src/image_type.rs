@@ -0,0 +1,101 @@
+use error::{
+    Error, Result,
+    error_details::{BadRequest, bad_request::FieldViolation},
+};
+
+/// Largest accepted image upload, photos from phone cameras are usually 2-4 MB.
+pub const MAX_IMAGE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Image formats accepted for uploads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageType {
+    Jpeg,
+    Png,
+    Webp,
+}
+
+impl ImageType {
+    /// Detect the image format from the leading magic bytes of the content.
+    pub fn sniff(data: &[u8]) -> Option<Self> {
+        if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(Self::Jpeg)
+        } else if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+            Some(Self::Webp)
+        } else {
+            None
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::Webp => "image/webp",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::Webp => "webp",
+        }
+    }
+
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "jpg" => Some(Self::Jpeg),
+            "png" => Some(Self::Png),
+            "webp" => Some(Self::Webp),
+            _ => None,
+        }
+    }
+}
+
+/// Check the size of an upload before reading it.
+pub fn require_image_size(size: u64) -> Result<()> {
+    if size == 0 {
+        return Err(invalid_file("Image is empty", "EMPTY"));
+    }
+    if size > MAX_IMAGE_BYTES {
+        return Err(invalid_file(
+            format!("Image must be at most {MAX_IMAGE_BYTES} bytes"),
+            "TOO_LARGE",
+        ));
+    }
+    Ok(())
+}
+
+/// Check an uploaded image, the content type declared by the client
+/// must match the format detected from the content.
+pub fn validate_image(data: &[u8], declared_content_type: Option<&str>) -> Result<ImageType> {
+    require_image_size(data.len() as u64)?;
+
+    let image_type = ImageType::sniff(data).ok_or_else(|| {
+        invalid_file("Image must be a JPEG, PNG or WebP file", "UNSUPPORTED_TYPE")
+    })?;
+    if declared_content_type.is_some_and(|c| c != image_type.content_type()) {
+        return Err(invalid_file(
+            format!(
+                "Declared content type {declared_content_type:?} doesn't match {}",
+                image_type.content_type()
+            ),
+            "MISMATCHED_CONTENT_TYPE",
+        ));
+    }
+    Ok(image_type)
+}
+
+fn invalid_file(message: impl Into<String>, description: &str) -> Error {
+    Error::invalid_argument_with(
+        message,
+        Some(BadRequest {
+            field_violations: vec![FieldViolation {
+                field: "file".into(),
+                description: description.into(),
+            }],
+        }),
+    )
+}
@@ -0,0 +1,11 @@
+mod blob_store_trait;
+pub use blob_store_trait::*;
+
+mod image_type;
+pub use image_type::*;
+
+mod local_blob_store;
+pub use local_blob_store::*;
+
+mod s3_blob_store;
+pub use s3_blob_store::*;
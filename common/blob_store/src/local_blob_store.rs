@@ -0,0 +1,98 @@
+//! Store blobs in a directory of the local filesystem. Used for local development and tests,
+//! blobs are served back by the server from [`LocalBlobStoreConfig::public_url`].
+
+use crate::{BlobKey, BlobStore};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use error::{Error, Result};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use std::{io::ErrorKind, path::PathBuf};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalBlobStoreConfig {
+    /// Directory blobs are written to, created on the first write
+    pub root_dir: PathBuf,
+    /// Base url serving the blobs, e.g. `http://localhost:8080/blobs`
+    pub public_url: String,
+    /// Secret of signed urls of private blobs
+    pub url_signing_secret: String,
+}
+
+pub struct LocalBlobStore {
+    config: LocalBlobStoreConfig,
+}
+
+impl LocalBlobStore {
+    pub fn new(config: LocalBlobStoreConfig) -> Self {
+        Self { config }
+    }
+
+    fn path(&self, key: &BlobKey) -> PathBuf {
+        self.config.root_dir.join(key.as_str())
+    }
+
+    fn signature_mac(&self, key: &BlobKey, expires_at: i64) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.config.url_signing_secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(format!("{key}\n{expires_at}").as_bytes());
+        mac
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put(&self, key: &BlobKey, _content_type: &str, data: Vec<u8>) -> Result<()> {
+        let path = self.path(key);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| Error::internal(format!("Cannot create blob directory {e:?}")))?;
+        }
+        tokio::fs::write(&path, data)
+            .await
+            .map_err(|e| Error::internal(format!("Cannot write blob {key} {e:?}")))
+    }
+
+    async fn get(&self, key: &BlobKey) -> Result<Option<Vec<u8>>> {
+        match tokio::fs::read(self.path(key)).await {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(Error::internal(format!("Cannot read blob {key} {e:?}"))),
+        }
+    }
+
+    async fn delete(&self, key: &BlobKey) -> Result<()> {
+        match tokio::fs::remove_file(self.path(key)).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(Error::internal(format!("Cannot delete blob {key} {e:?}"))),
+        }
+    }
+
+    fn url(&self, key: &BlobKey) -> String {
+        format!("{}/{key}", self.config.public_url.trim_end_matches('/'))
+    }
+
+    fn signed_url(&self, key: &BlobKey, expires_in: Duration) -> Result<String> {
+        let expires_at = (Utc::now() + expires_in).timestamp();
+        let signature = hex::encode(self.signature_mac(key, expires_at).finalize().into_bytes());
+        Ok(format!(
+            "{}?expires={expires_at}&signature={signature}",
+            self.url(key)
+        ))
+    }
+
+    fn verify_signed_url(&self, key: &BlobKey, expires_at: i64, signature: &str) -> bool {
+        let Ok(signature) = hex::decode(signature) else {
+            return false;
+        };
+        expires_at > Utc::now().timestamp()
+            && self
+                .signature_mac(key, expires_at)
+                .verify_slice(&signature)
+                .is_ok()
+    }
+}
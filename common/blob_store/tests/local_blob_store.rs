@@ -0,0 +1,118 @@
+use blob_store::{
+    BlobKey, BlobStore, ImageType, LocalBlobStore, LocalBlobStoreConfig, validate_image,
+};
+use chrono::Duration;
+use error::{ErrorVariant, Result};
+use uuid::Uuid;
+
+const PNG_HEADER: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+#[tokio::test]
+async fn put_get_and_delete_files() -> Result<()> {
+    let root_dir = std::env::temp_dir().join(format!("blob-store-{}", Uuid::now_v7()));
+    let store = LocalBlobStore::new(LocalBlobStoreConfig {
+        root_dir: root_dir.clone(),
+        public_url: "http://localhost:8080/blobs/".into(),
+        url_signing_secret: "url-signing-secret".into(),
+    });
+    let key = BlobKey::new_image("task-photos", ImageType::Jpeg);
+    assert_eq!(key.image_type(), Some(ImageType::Jpeg));
+
+    store
+        .put(&key, "image/jpeg", b"jpeg-bytes".to_vec())
+        .await?;
+    assert!(root_dir.join(key.as_str()).is_file());
+    assert_eq!(store.get(&key).await?.as_deref(), Some(&b"jpeg-bytes"[..]));
+    assert_eq!(
+        store.url(&key),
+        format!("http://localhost:8080/blobs/{}", key.as_str())
+    );
+
+    store.delete(&key).await?;
+    assert!(store.get(&key).await?.is_none());
+    store.delete(&key).await?;
+
+    std::fs::remove_dir_all(root_dir).ok();
+    Ok(())
+}
+
+#[test]
+fn sign_urls_of_private_blobs() -> Result<()> {
+    let store = LocalBlobStore::new(LocalBlobStoreConfig {
+        root_dir: std::env::temp_dir(),
+        public_url: "http://localhost:8080/blobs".into(),
+        url_signing_secret: "url-signing-secret".into(),
+    });
+    let key = BlobKey::new_image("private/task-photos", ImageType::Jpeg);
+    assert!(key.is_private());
+    assert!(!BlobKey::new_image("avatars", ImageType::Png).is_private());
+
+    let url = store.signed_url(&key, Duration::minutes(15))?;
+    assert!(url.starts_with(&format!("http://localhost:8080/blobs/{}?", key.as_str())));
+    let (expires_at, signature) = signed_query(&url);
+
+    assert!(store.verify_signed_url(&key, expires_at, &signature));
+    assert!(!store.verify_signed_url(&key, expires_at + 1, &signature));
+    let other_key = BlobKey::new_image("private/task-photos", ImageType::Jpeg);
+    assert!(!store.verify_signed_url(&other_key, expires_at, &signature));
+
+    let (expires_at, signature) = signed_query(&store.signed_url(&key, Duration::minutes(-1))?);
+    assert!(!store.verify_signed_url(&key, expires_at, &signature));
+
+    Ok(())
+}
+
+/// Expiry and signature of a signed url
+fn signed_query(url: &str) -> (i64, String) {
+    let (_, query) = url.split_once('?').unwrap();
+    let (expires, signature) = query.split_once('&').unwrap();
+    (
+        expires.strip_prefix("expires=").unwrap().parse().unwrap(),
+        signature.strip_prefix("signature=").unwrap().into(),
+    )
+}
+
+#[test]
+fn parse_blob_keys() {
+    assert!(BlobKey::parse("avatars/0199c5d4.jpg").is_ok());
+    for key in [
+        "",
+        "/avatars",
+        "avatars/",
+        "avatars/../secret",
+        "avatars/.hidden",
+        "a b",
+    ] {
+        assert!(
+            BlobKey::parse(key).is_err(),
+            "Expect {key:?} to be rejected"
+        );
+    }
+}
+
+#[test]
+fn validate_images() {
+    let png = [PNG_HEADER, b"rest"].concat();
+    assert_eq!(
+        validate_image(&png, Some("image/png")).unwrap(),
+        ImageType::Png
+    );
+    assert_eq!(validate_image(&png, None).unwrap(), ImageType::Png);
+
+    let webp = b"RIFF\x00\x00\x00\x00WEBPVP8 ".to_vec();
+    assert_eq!(validate_image(&webp, None).unwrap(), ImageType::Webp);
+
+    for (data, content_type, description) in [
+        (Vec::new(), None, "EMPTY"),
+        (b"GIF89a".to_vec(), None, "UNSUPPORTED_TYPE"),
+        (png.clone(), Some("image/jpeg"), "MISMATCHED_CONTENT_TYPE"),
+        (vec![0xFF; 6 * 1024 * 1024], None, "TOO_LARGE"),
+    ] {
+        let error = validate_image(&data, content_type).unwrap_err();
+        let ErrorVariant::InvalidArgument(Some(bad_request)) = *error.variant else {
+            panic!("Expect invalid argument, found {:?}", error.variant);
+        };
+        assert_eq!(bad_request.field_violations[0].field, "file");
+        assert_eq!(bad_request.field_violations[0].description, description);
+    }
+}
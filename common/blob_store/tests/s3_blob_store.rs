@@ -0,0 +1,67 @@
+use blob_store::{BlobKey, BlobStore, ImageType, S3BlobStore, S3BlobStoreConfig};
+use error::Result;
+use test_utils::FakeS3Server;
+
+const BUCKET: &str = "uploads";
+const ACCESS_KEY_ID: &str = "access-key-id";
+
+async fn setup() -> Result<(FakeS3Server, S3BlobStore)> {
+    let server = FakeS3Server::start(BUCKET, ACCESS_KEY_ID).await?;
+    let store = S3BlobStore::new(S3BlobStoreConfig {
+        endpoint: server.url.clone(),
+        region: "ap-southeast-1".into(),
+        bucket: BUCKET.into(),
+        access_key_id: ACCESS_KEY_ID.into(),
+        secret_access_key: "secret-access-key".into(),
+        public_url: "https://cdn.example.com/".into(),
+    })?;
+    Ok((server, store))
+}
+
+#[tokio::test]
+async fn put_get_and_delete_objects() -> Result<()> {
+    let (server, store) = setup().await?;
+    let key = BlobKey::new_image("avatars", ImageType::Png);
+
+    store.put(&key, "image/png", b"png-bytes".to_vec()).await?;
+    let object = server.object(key.as_str()).unwrap();
+    assert_eq!(object.content_type.as_deref(), Some("image/png"));
+    assert_eq!(object.data, b"png-bytes");
+    assert_eq!(store.get(&key).await?.as_deref(), Some(&b"png-bytes"[..]));
+    assert_eq!(
+        store.url(&key),
+        format!("https://cdn.example.com/{}", key.as_str())
+    );
+
+    store.delete(&key).await?;
+    assert!(server.object(key.as_str()).is_none());
+    assert!(store.get(&key).await?.is_none());
+
+    // Deleting a missing object is not an error
+    store.delete(&key).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reject_unknown_bucket() -> Result<()> {
+    let (server, _) = setup().await?;
+    let store = S3BlobStore::new(S3BlobStoreConfig {
+        endpoint: server.url.clone(),
+        region: "ap-southeast-1".into(),
+        bucket: "unknown".into(),
+        access_key_id: ACCESS_KEY_ID.into(),
+        secret_access_key: "secret-access-key".into(),
+        public_url: "https://cdn.example.com".into(),
+    })?;
+    let key = BlobKey::new_image("avatars", ImageType::Png);
+
+    assert!(
+        store
+            .put(&key, "image/png", b"png-bytes".to_vec())
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
@@ -35,7 +35,8 @@ impl<T: Query> Query for PaginatedOffset<T> {
 
 impl<T: Send> PaginatedOffset<T> {
     /// Load result of the query and count total matching records.
-    /// Returns NOT_FOUND error if page_size overflow total matching records.
+    /// Returns NOT_FOUND error if the page starts after the last matching record.
+    /// No matching record at all is an empty first page with a zero total count.
     ///
     /// NB: We need to manually express the lifetimes of this async fn to work around rustc's issue
     /// related to higher-rank lifetime evaluation for auto traits.
@@ -53,8 +54,9 @@ impl<T: Send> PaginatedOffset<T> {
         // Async block in non-async function so that we can be more expressive with the future
         // return type. This allows us to properly propagate the lifetimes.
         async {
+            let offset = self.paging_config.offset;
             let results = self.load::<(U, i64)>(conn).await?;
-            if results.is_empty() {
+            if results.is_empty() && offset > 0 {
                 return Err(diesel::result::Error::NotFound);
             }
             #[allow(clippy::get_first)]
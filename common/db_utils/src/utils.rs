@@ -1,3 +1,6 @@
+use diesel::QueryableByName;
+use diesel::sql_types::{Bool, Text};
+use diesel_async::RunQueryDsl;
 use diesel_async::pooled_connection::{AsyncDieselConnectionManager, deadpool::Pool};
 use diesel_async::scoped_futures::ScopedBoxFuture;
 use error::{Error, Result, error_details::ResourceInfo};
@@ -126,3 +129,32 @@ where
         .run(|conn| f(conn))
         .await
 }
+
+/// Hold a transaction level advisory lock of the key until the transaction ends,
+/// so that concurrent transactions locking the same key run one at a time.
+///
+/// Note that a repeatable read transaction takes its snapshot before waiting for the lock,
+/// use a read committed one to see the changes committed by the previous lock holder.
+pub async fn advisory_xact_lock(key: &str, conn: &mut AsyncPgConnection) -> Result<()> {
+    diesel::sql_query("SELECT pg_advisory_xact_lock(hashtext($1))")
+        .bind::<Text, _>(key)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+/// Same as [`advisory_xact_lock`] without waiting for the lock,
+/// returns whether the lock is held until the transaction ends.
+pub async fn try_advisory_xact_lock(key: &str, conn: &mut AsyncPgConnection) -> Result<bool> {
+    #[derive(QueryableByName)]
+    struct Locked {
+        #[diesel(sql_type = Bool)]
+        locked: bool,
+    }
+
+    let result = diesel::sql_query("SELECT pg_try_advisory_xact_lock(hashtext($1)) AS locked")
+        .bind::<Text, _>(key)
+        .get_result::<Locked>(conn)
+        .await?;
+    Ok(result.locked)
+}
@@ -49,8 +49,21 @@ impl From<Error> for ErrorExtensionValues {
             ErrorVariant::PermissionDenied(error_info) => {
                 add_object_field(&mut extensions, ERROR_DETAILS_FIELD_NAME, error_info)
             }
-            ErrorVariant::ResourceExhausted(quota_failure) => {
-                add_object_field(&mut extensions, ERROR_DETAILS_FIELD_NAME, quota_failure)
+            ErrorVariant::ResourceExhausted((quota_failure, retry_info)) => {
+                let mut out = IndexMap::with_capacity(2);
+                out.insert(
+                    Name::new("quotaFailure"),
+                    quota_failure.map(Value::from).unwrap_or(Value::Null),
+                );
+                out.insert(
+                    Name::new("retryInfo"),
+                    retry_info.map(Value::from).unwrap_or(Value::Null),
+                );
+                add_object_field(
+                    &mut extensions,
+                    ERROR_DETAILS_FIELD_NAME,
+                    Some(Value::Object(out)),
+                );
             }
             ErrorVariant::FailedPrecondition(precondition_failure) => add_object_field(
                 &mut extensions,
@@ -1,6 +1,6 @@
 use crate::{
     Error, ErrorVariant,
-    error_details::{DebugInfo, ErrorInfo, RetryInfo},
+    error_details::{DebugInfo, ErrorInfo, QuotaFailure, RetryInfo},
 };
 use prost::Message;
 use tonic::{Code, Status};
@@ -18,7 +18,13 @@ impl From<Status> for Error {
             Code::NotFound => Error::not_found_with(message, decode(&status)),
             Code::AlreadyExists => Error::already_exists_with(message, decode(&status)),
             Code::PermissionDenied => Error::permission_denied_with(message, decode(&status)),
-            Code::ResourceExhausted => Error::resource_exhausted_with(message, decode(&status)),
+            Code::ResourceExhausted => match decode::<ResourceExhaustedErrorDetail>(&status) {
+                Some(ResourceExhaustedErrorDetail {
+                    quota_failure,
+                    retry_info,
+                }) => Error::resource_exhausted_with(message, quota_failure, retry_info),
+                None => Error::resource_exhausted(message),
+            },
             Code::FailedPrecondition => Error::failed_precondition_with(message, decode(&status)),
             Code::Aborted => match decode::<AbortedErrorDetail>(&status) {
                 Some(AbortedErrorDetail {
@@ -65,10 +71,14 @@ impl From<Error> for Status {
             ErrorVariant::PermissionDenied(error_info) => {
                 Status::with_details(Code::PermissionDenied, message, encode(error_info).into())
             }
-            ErrorVariant::ResourceExhausted(quota_failure) => Status::with_details(
+            ErrorVariant::ResourceExhausted((quota_failure, retry_info)) => Status::with_details(
                 Code::ResourceExhausted,
                 message,
-                encode(quota_failure).into(),
+                encode(Some(ResourceExhaustedErrorDetail {
+                    quota_failure,
+                    retry_info,
+                }))
+                .into(),
             ),
             ErrorVariant::FailedPrecondition(precondition_failure) => Status::with_details(
                 Code::FailedPrecondition,
@@ -110,6 +120,14 @@ impl From<Error> for Status {
     }
 }
 
+#[derive(Message)]
+struct ResourceExhaustedErrorDetail {
+    #[prost(message, optional, tag = "1")]
+    quota_failure: Option<QuotaFailure>,
+    #[prost(message, optional, tag = "2")]
+    retry_info: Option<RetryInfo>,
+}
+
 #[derive(Message)]
 struct AbortedErrorDetail {
     #[prost(message, optional, tag = "1")]
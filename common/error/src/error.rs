@@ -69,7 +69,7 @@ pub enum ErrorVariant {
 
     /// Some resource has been exhausted, perhaps a per-user quota, or perhaps
     /// the entire file system is out of space.
-    ResourceExhausted(Option<QuotaFailure>),
+    ResourceExhausted((Option<QuotaFailure>, Option<RetryInfo>)),
 
     /// The operation was rejected because the system is not in a state required
     /// for the operation's execution.
@@ -255,14 +255,18 @@ impl Error {
     }
 
     pub fn resource_exhausted<S: Into<String>>(message: S) -> Self {
-        Error::new(message, ErrorVariant::ResourceExhausted(None))
+        Error::new(message, ErrorVariant::ResourceExhausted((None, None)))
     }
 
     pub fn resource_exhausted_with<S: Into<String>>(
         message: S,
         quota_failure: Option<QuotaFailure>,
+        retry_info: Option<RetryInfo>,
     ) -> Self {
-        Error::new(message, ErrorVariant::ResourceExhausted(quota_failure))
+        Error::new(
+            message,
+            ErrorVariant::ResourceExhausted((quota_failure, retry_info)),
+        )
     }
 
     pub fn failed_precondition<S: Into<String>>(message: S) -> Self {
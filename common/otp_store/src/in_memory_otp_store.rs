@@ -0,0 +1,52 @@
+//! Keep OTP codes in process memory. Used for local development and tests,
+//! codes don't survive restarts and aren't shared among replicas.
+
+use crate::{OtpKey, OtpPolicy, OtpRecord, OtpStore, OtpVerification};
+use async_trait::async_trait;
+use chrono::Utc;
+use error::Result;
+use std::collections::HashMap;
+use tokio::sync::Mutex;
+
+#[derive(Debug, Default)]
+pub struct InMemoryOtpStore {
+    policy: OtpPolicy,
+    records: Mutex<HashMap<OtpKey, OtpRecord>>,
+}
+
+impl InMemoryOtpStore {
+    pub fn new(policy: OtpPolicy) -> Self {
+        Self {
+            policy,
+            records: Mutex::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl OtpStore for InMemoryOtpStore {
+    fn policy(&self) -> &OtpPolicy {
+        &self.policy
+    }
+
+    async fn issue(&self, key: &OtpKey, code: &str) -> Result<()> {
+        let now = Utc::now().naive_utc();
+        let mut records = self.records.lock().await;
+        records.retain(|_, record| !record.is_stale(now, &self.policy));
+
+        let record = OtpRecord::issue(records.get(key), code, now, &self.policy)?;
+        records.insert(key.clone(), record);
+        Ok(())
+    }
+
+    async fn verify(&self, key: &OtpKey, code: &str) -> Result<()> {
+        let now = Utc::now().naive_utc();
+        let mut records = self.records.lock().await;
+
+        let verification = match records.get_mut(key) {
+            Some(record) => record.verify(code, now, &self.policy),
+            None => OtpVerification::Expired,
+        };
+        verification.into_result(now)
+    }
+}
@@ -0,0 +1,10 @@
+//! One-time password (OTP) storage with attempt limiting and resend cooldown.
+
+mod otp_store_trait;
+pub use otp_store_trait::*;
+
+mod otp_record;
+pub use otp_record::*;
+
+mod in_memory_otp_store;
+pub use in_memory_otp_store::*;
@@ -0,0 +1,267 @@
+//! Store-agnostic OTP state transitions, shared by every [`crate::OtpStore`] implementation.
+
+use crate::OtpPolicy;
+use chrono::NaiveDateTime;
+use error::{
+    Error, Result,
+    error_details::{
+        BadRequest, QuotaFailure, RetryInfo, bad_request::FieldViolation, quota_failure,
+    },
+};
+
+/// OTP state of a key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtpRecord {
+    /// Pending code, `None` once it's consumed or invalidated by a lockout
+    pub code: Option<String>,
+    pub expires_at: NaiveDateTime,
+    pub failed_attempts: i32,
+    pub last_sent_at: NaiveDateTime,
+    pub locked_until: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtpVerification {
+    Verified,
+    Mismatched,
+    /// No pending code, either expired, consumed or never issued
+    Expired,
+    LockedOut {
+        until: NaiveDateTime,
+    },
+}
+
+impl OtpRecord {
+    /// Record of a newly issued code, replacing the existing record if any.
+    pub fn issue(
+        existing: Option<&OtpRecord>,
+        code: &str,
+        now: NaiveDateTime,
+        policy: &OtpPolicy,
+    ) -> Result<Self> {
+        let mut failed_attempts = 0;
+        if let Some(existing) = existing {
+            if let Some(until) = existing.locked_until
+                && until > now
+            {
+                return Err(locked_out_error(until, now));
+            }
+            let resend_at = existing.last_sent_at + policy.resend_cooldown;
+            if resend_at > now {
+                let retry_seconds = (resend_at - now).num_seconds();
+                return Err(Error::resource_exhausted_with(
+                    format!("OTP code was sent recently, retry in {retry_seconds} seconds"),
+                    Some(QuotaFailure {
+                        violations: vec![quota_failure::Violation {
+                            subject: "OTP_RESEND".into(),
+                            description: "COOLDOWN".into(),
+                        }],
+                    }),
+                    Some(retry_after_seconds(retry_seconds)),
+                ));
+            }
+            // Resending doesn't reset the failed attempts of a pending code,
+            // otherwise the attempt limit could be bypassed by requesting new codes.
+            if existing.locked_until.is_none() && existing.expires_at > now {
+                failed_attempts = existing.failed_attempts;
+            }
+        }
+
+        Ok(Self {
+            code: Some(code.into()),
+            expires_at: now + policy.ttl,
+            failed_attempts,
+            last_sent_at: now,
+            locked_until: None,
+        })
+    }
+
+    /// Verify the code, updating the record in place.
+    pub fn verify(
+        &mut self,
+        code: &str,
+        now: NaiveDateTime,
+        policy: &OtpPolicy,
+    ) -> OtpVerification {
+        if let Some(until) = self.locked_until
+            && until > now
+        {
+            return OtpVerification::LockedOut { until };
+        }
+        let Some(pending) = self.code.as_deref().filter(|_| self.expires_at > now) else {
+            return OtpVerification::Expired;
+        };
+
+        if pending == code {
+            self.code = None;
+            self.failed_attempts = 0;
+            return OtpVerification::Verified;
+        }
+
+        self.failed_attempts += 1;
+        if self.failed_attempts >= policy.max_attempts {
+            let until = now + policy.lockout;
+            self.code = None;
+            self.locked_until = Some(until);
+            return OtpVerification::LockedOut { until };
+        }
+        OtpVerification::Mismatched
+    }
+
+    /// The record doesn't affect any future operation and can be dropped.
+    pub fn is_stale(&self, now: NaiveDateTime, policy: &OtpPolicy) -> bool {
+        self.expires_at <= now
+            && self.last_sent_at + policy.resend_cooldown <= now
+            && self.locked_until.is_none_or(|until| until <= now)
+    }
+}
+
+impl OtpVerification {
+    pub fn into_result(self, now: NaiveDateTime) -> Result<()> {
+        let description = match self {
+            OtpVerification::Verified => return Ok(()),
+            OtpVerification::LockedOut { until } => return Err(locked_out_error(until, now)),
+            OtpVerification::Mismatched => "FAILED",
+            OtpVerification::Expired => "EXPIRED",
+        };
+        Err(Error::invalid_argument_with(
+            "OTP verification failed",
+            Some(BadRequest {
+                field_violations: vec![FieldViolation {
+                    field: "OTP_VERIFICATION".into(),
+                    description: description.into(),
+                }],
+            }),
+        ))
+    }
+}
+
+fn locked_out_error(until: NaiveDateTime, now: NaiveDateTime) -> Error {
+    let retry_seconds = (until - now).num_seconds();
+    Error::resource_exhausted_with(
+        format!("Too many failed OTP attempts, retry in {retry_seconds} seconds"),
+        Some(QuotaFailure {
+            violations: vec![quota_failure::Violation {
+                subject: "OTP_VERIFICATION".into(),
+                description: "TOO_MANY_ATTEMPTS".into(),
+            }],
+        }),
+        Some(retry_after_seconds(retry_seconds)),
+    )
+}
+
+fn retry_after_seconds(seconds: i64) -> RetryInfo {
+    RetryInfo {
+        retry_delay: Some(prost_types::Duration { seconds, nanos: 0 }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::{Duration, NaiveDate};
+    use error::ErrorVariant;
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 12, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verify_consumes_code() {
+        let policy = OtpPolicy::default();
+        let mut record = OtpRecord::issue(None, "123456", now(), &policy).unwrap();
+
+        assert_eq!(
+            record.verify("123456", now(), &policy),
+            OtpVerification::Verified
+        );
+        assert_eq!(
+            record.verify("123456", now(), &policy),
+            OtpVerification::Expired
+        );
+    }
+
+    #[test]
+    fn test_verify_expired_code() {
+        let policy = OtpPolicy::default();
+        let mut record = OtpRecord::issue(None, "123456", now(), &policy).unwrap();
+
+        let later = now() + policy.ttl;
+        assert_eq!(
+            record.verify("123456", later, &policy),
+            OtpVerification::Expired
+        );
+        assert!(record.is_stale(later, &policy));
+    }
+
+    #[test]
+    fn test_lockout_after_max_attempts() {
+        let policy = OtpPolicy::default();
+        let mut record = OtpRecord::issue(None, "123456", now(), &policy).unwrap();
+
+        for _ in 1..policy.max_attempts {
+            assert_eq!(
+                record.verify("000000", now(), &policy),
+                OtpVerification::Mismatched
+            );
+        }
+        let until = now() + policy.lockout;
+        assert_eq!(
+            record.verify("000000", now(), &policy),
+            OtpVerification::LockedOut { until }
+        );
+        // The right code is rejected too during lockout
+        assert_eq!(
+            record.verify("123456", now(), &policy),
+            OtpVerification::LockedOut { until }
+        );
+
+        let error = OtpRecord::issue(Some(&record), "654321", now(), &policy).unwrap_err();
+        assert!(matches!(
+            *error.variant,
+            ErrorVariant::ResourceExhausted((Some(_), Some(_)))
+        ));
+
+        // A new code can be issued once the lockout is over, with fresh attempts
+        let record = OtpRecord::issue(Some(&record), "654321", until, &policy).unwrap();
+        assert_eq!(record.failed_attempts, 0);
+    }
+
+    #[test]
+    fn test_resend_cooldown() {
+        let policy = OtpPolicy::default();
+        let mut record = OtpRecord::issue(None, "123456", now(), &policy).unwrap();
+        assert_eq!(
+            record.verify("000000", now(), &policy),
+            OtpVerification::Mismatched
+        );
+
+        let error = OtpRecord::issue(
+            Some(&record),
+            "654321",
+            now() + policy.resend_cooldown - Duration::seconds(1),
+            &policy,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            *error.variant,
+            ErrorVariant::ResourceExhausted((Some(_), Some(_)))
+        ));
+
+        // Failed attempts are kept across resends
+        let later = now() + policy.resend_cooldown;
+        let mut record = OtpRecord::issue(Some(&record), "654321", later, &policy).unwrap();
+        assert_eq!(record.failed_attempts, 1);
+        assert_eq!(
+            record.verify("123456", later, &policy),
+            OtpVerification::Mismatched
+        );
+        assert_eq!(
+            record.verify("654321", later, &policy),
+            OtpVerification::Verified
+        );
+    }
+}
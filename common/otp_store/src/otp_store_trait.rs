@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use chrono::Duration;
+use entity_type::AccountType;
+use error::Result;
+
+#[async_trait]
+pub trait OtpStore: Send + Sync {
+    fn policy(&self) -> &OtpPolicy;
+
+    /// Store a newly generated code for the key, replacing the pending one if any.
+    /// Fails if the key is locked out or a code was sent too recently.
+    async fn issue(&self, key: &OtpKey, code: &str) -> Result<()>;
+
+    /// Check the code against the pending one, which is consumed on success.
+    /// Too many failed attempts lock the key out.
+    async fn verify(&self, key: &OtpKey, code: &str) -> Result<()>;
+}
+
+/// What the OTP code is sent for. Codes of different purposes don't interfere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OtpPurpose {
+    Registration,
+    PasswordReset,
+    PhoneNumberChange,
+    SignIn,
+}
+
+impl OtpPurpose {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OtpPurpose::Registration => "registration",
+            OtpPurpose::PasswordReset => "password_reset",
+            OtpPurpose::PhoneNumberChange => "phone_number_change",
+            OtpPurpose::SignIn => "sign_in",
+        }
+    }
+}
+
+/// Customer and handyman accounts may share a phone number,
+/// codes of different account types don't interfere.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OtpKey {
+    pub purpose: OtpPurpose,
+    pub account_type: AccountType,
+    pub e164_phone_number_str: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OtpPolicy {
+    /// How long an issued code stays valid
+    pub ttl: Duration,
+    /// Failed attempts allowed before the key is locked out
+    pub max_attempts: i32,
+    /// How long the key is locked out after too many failed attempts
+    pub lockout: Duration,
+    /// Minimum interval between two codes sent to the same key
+    pub resend_cooldown: Duration,
+}
+
+impl Default for OtpPolicy {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::minutes(15),
+            max_attempts: 5,
+            lockout: Duration::minutes(15),
+            resend_cooldown: Duration::seconds(60),
+        }
+    }
+}
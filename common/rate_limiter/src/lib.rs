@@ -0,0 +1,5 @@
+//! Fixed-window request rate limiting, keyed by arbitrary strings
+//! such as a remote address or a phone number.
+
+mod rate_limiter;
+pub use rate_limiter::*;
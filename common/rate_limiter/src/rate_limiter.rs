@@ -0,0 +1,174 @@
+use error::{
+    Error, Result,
+    error_details::{QuotaFailure, RetryInfo, quota_failure},
+};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Purge expired windows once this many keys are tracked,
+/// so that one-off keys (e.g. random phone numbers) don't accumulate.
+const PURGE_THRESHOLD: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// Maximum number of requests allowed within a time window.
+pub struct RateLimit {
+    pub max_requests: u32,
+    pub window_seconds: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitDecision {
+    Allowed,
+    Exceeded {
+        /// Time until the current window is over
+        retry_after: Duration,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    resets_at: Instant,
+    count: u32,
+}
+
+/// Keep request counters in process memory, counters aren't shared among replicas.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    windows: Mutex<HashMap<String, Window>>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count a request for the key, rejecting it if the key exceeded the limit.
+    /// Rejected requests aren't counted.
+    pub fn acquire(&self, key: &str, limit: &RateLimit) -> RateLimitDecision {
+        self.acquire_at(key, limit, Instant::now())
+    }
+
+    fn acquire_at(&self, key: &str, limit: &RateLimit, now: Instant) -> RateLimitDecision {
+        let mut windows = self.windows.lock().expect("Rate limiter state is poisoned");
+        if windows.len() >= PURGE_THRESHOLD {
+            windows.retain(|_, window| window.resets_at > now);
+        }
+
+        let window = windows.entry(key.into()).or_insert(Window {
+            resets_at: now,
+            count: 0,
+        });
+        if window.resets_at <= now {
+            *window = Window {
+                resets_at: now + Duration::from_secs(limit.window_seconds),
+                count: 0,
+            };
+        }
+        if window.count >= limit.max_requests {
+            return RateLimitDecision::Exceeded {
+                retry_after: window.resets_at - now,
+            };
+        }
+        window.count += 1;
+        RateLimitDecision::Allowed
+    }
+}
+
+impl RateLimitDecision {
+    /// Convert to `ResourceExhausted` error if exceeded,
+    /// the `subject` and `description` are returned as the quota violation.
+    pub fn into_result(self, subject: &str, description: &str) -> Result<()> {
+        let retry_after = match self {
+            RateLimitDecision::Allowed => return Ok(()),
+            RateLimitDecision::Exceeded { retry_after } => retry_after,
+        };
+        // Round up so that clients retrying after the delay don't hit the same window
+        let retry_seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+        Err(Error::resource_exhausted_with(
+            format!("Too many requests, retry in {retry_seconds} seconds"),
+            Some(QuotaFailure {
+                violations: vec![quota_failure::Violation {
+                    subject: subject.into(),
+                    description: description.into(),
+                }],
+            }),
+            Some(RetryInfo {
+                retry_delay: Some(prost_types::Duration {
+                    seconds: retry_seconds as i64,
+                    nanos: 0,
+                }),
+            }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use error::ErrorVariant;
+
+    const LIMIT: RateLimit = RateLimit {
+        max_requests: 3,
+        window_seconds: 60,
+    };
+
+    #[test]
+    fn test_reject_requests_over_limit() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+
+        for _ in 0..LIMIT.max_requests {
+            assert_eq!(
+                limiter.acquire_at("foo", &LIMIT, now),
+                RateLimitDecision::Allowed
+            );
+        }
+        let later = now + Duration::from_secs(20);
+        assert_eq!(
+            limiter.acquire_at("foo", &LIMIT, later),
+            RateLimitDecision::Exceeded {
+                retry_after: Duration::from_secs(40)
+            }
+        );
+        // Other keys have their own windows
+        assert_eq!(
+            limiter.acquire_at("bar", &LIMIT, later),
+            RateLimitDecision::Allowed
+        );
+    }
+
+    #[test]
+    fn test_reset_after_window() {
+        let limiter = RateLimiter::new();
+        let now = Instant::now();
+        for _ in 0..LIMIT.max_requests {
+            limiter.acquire_at("foo", &LIMIT, now);
+        }
+
+        let later = now + Duration::from_secs(LIMIT.window_seconds);
+        assert_eq!(
+            limiter.acquire_at("foo", &LIMIT, later),
+            RateLimitDecision::Allowed
+        );
+    }
+
+    #[test]
+    fn test_exceeded_into_error() {
+        let decision = RateLimitDecision::Exceeded {
+            retry_after: Duration::from_millis(1500),
+        };
+        let error = decision.into_result("FOO", "BAR").unwrap_err();
+        match *error.variant {
+            ErrorVariant::ResourceExhausted((Some(quota_failure), Some(retry_info))) => {
+                assert_eq!(quota_failure.violations[0].subject, "FOO");
+                assert_eq!(retry_info.retry_delay.unwrap().seconds, 2);
+            }
+            _ => panic!("Expect resource exhausted"),
+        }
+    }
+}
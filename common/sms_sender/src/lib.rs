@@ -4,6 +4,9 @@ pub use sms_sender_trait::*;
 mod zalo_sender;
 pub use zalo_sender::*;
 
+mod zalo_token_store;
+pub use zalo_token_store::*;
+
 mod terminal_sms_sender;
 pub use terminal_sms_sender::*;
 
@@ -16,6 +16,9 @@ pub struct SendSmsInput {
 #[derive(Debug)]
 pub enum MessageType {
     OtpVerificationForRegistration(OtpVerificationForRegistration),
+    OtpVerificationForPasswordReset(OtpVerificationForPasswordReset),
+    OtpVerificationForPhoneNumberChange(OtpVerificationForPhoneNumberChange),
+    OtpVerificationForSignIn(OtpVerificationForSignIn),
 }
 
 impl MessageType {
@@ -24,6 +27,15 @@ impl MessageType {
             MessageType::OtpVerificationForRegistration(inner) => {
                 format!("Mã xác thực của bạn là {}", inner.code)
             }
+            MessageType::OtpVerificationForPasswordReset(inner) => {
+                format!("Mã đặt lại mật khẩu của bạn là {}", inner.code)
+            }
+            MessageType::OtpVerificationForPhoneNumberChange(inner) => {
+                format!("Mã xác thực số điện thoại mới của bạn là {}", inner.code)
+            }
+            MessageType::OtpVerificationForSignIn(inner) => {
+                format!("Mã đăng nhập của bạn là {}", inner.code)
+            }
         }
     }
 }
@@ -33,10 +45,25 @@ pub struct OtpVerificationForRegistration {
     pub code: String,
 }
 
+#[derive(Debug)]
+pub struct OtpVerificationForPasswordReset {
+    pub code: String,
+}
+
+#[derive(Debug)]
+pub struct OtpVerificationForPhoneNumberChange {
+    pub code: String,
+}
+
+#[derive(Debug)]
+pub struct OtpVerificationForSignIn {
+    pub code: String,
+}
+
 #[cfg(feature = "test")]
 mod test {
     use super::*;
-    use error::Result;
+    use error::{Error, Result};
 
     impl MessageType {
         pub fn try_otp_verification_for_registration(
@@ -44,6 +71,32 @@ mod test {
         ) -> Result<OtpVerificationForRegistration> {
             match self {
                 MessageType::OtpVerificationForRegistration(inner) => Ok(inner),
+                _ => Err(Error::internal("Not a registration OTP message")),
+            }
+        }
+
+        pub fn try_otp_verification_for_password_reset(
+            self,
+        ) -> Result<OtpVerificationForPasswordReset> {
+            match self {
+                MessageType::OtpVerificationForPasswordReset(inner) => Ok(inner),
+                _ => Err(Error::internal("Not a password reset OTP message")),
+            }
+        }
+
+        pub fn try_otp_verification_for_phone_number_change(
+            self,
+        ) -> Result<OtpVerificationForPhoneNumberChange> {
+            match self {
+                MessageType::OtpVerificationForPhoneNumberChange(inner) => Ok(inner),
+                _ => Err(Error::internal("Not a phone number change OTP message")),
+            }
+        }
+
+        pub fn try_otp_verification_for_sign_in(self) -> Result<OtpVerificationForSignIn> {
+            match self {
+                MessageType::OtpVerificationForSignIn(inner) => Ok(inner),
+                _ => Err(Error::internal("Not a sign in OTP message")),
             }
         }
     }
@@ -1,12 +1,358 @@
-use crate::{SendSmsInput, SmsSender};
+//! Send SMS through Zalo Notification Service (ZNS).
+//!
+//! ZNS delivers pre-approved templates to the Zalo account linked with a phone number.
+//! See <https://developers.zalo.me/docs/zalo-notification-service/gui-tin-zns/gui-zns>
+
+use crate::{MessageType, SendSmsInput, SmsSender, ZaloToken, ZaloTokenStore};
 use async_trait::async_trait;
-use error::Result;
+use chrono::{TimeDelta, Utc};
+use error::{
+    Error, Result,
+    error_details::{
+        BadRequest, QuotaFailure, RetryInfo, bad_request::FieldViolation, quota_failure,
+    },
+};
+use phonenumber::Mode;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::Mutex;
+
+/// Path of the OAuth endpoint issuing Official Account (OA) access tokens.
+pub const ZALO_ACCESS_TOKEN_PATH: &str = "/v4/oa/access_token";
+
+/// Path of the ZNS endpoint sending a template message.
+pub const ZALO_ZNS_TEMPLATE_PATH: &str = "/message/template";
+
+/// Refresh the access token a bit earlier than its expiry to absorb network latency.
+const ACCESS_TOKEN_EXPIRY_MARGIN: TimeDelta = TimeDelta::seconds(60);
+
+/// ZNS error codes that need special handling.
+/// See <https://developers.zalo.me/docs/zalo-notification-service/phu-luc/bang-ma-loi>
+pub mod zns_error_code {
+    pub const SUCCESS: i32 = 0;
+    pub const RATE_LIMIT_EXCEEDED: i32 = -32;
+    pub const INVALID_PHONE_NUMBER: i32 = -108;
+    pub const ZALO_ACCOUNT_NOT_FOUND: i32 = -118;
+    pub const INVALID_ACCESS_TOKEN: i32 = -124;
+    pub const OUTSIDE_SENDING_HOURS: i32 = -133;
+    pub const DAILY_QUOTA_EXCEEDED: i32 = -144;
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZaloSmsSenderConfig {
+    /// Base url of Zalo OAuth server, e.g. `https://oauth.zaloapp.com`
+    pub oauth_url: String,
+    /// Base url of Zalo business open API, e.g. `https://business.openapi.zalo.me`
+    pub zns_url: String,
+    /// Id of the Zalo app linked with the Official Account
+    pub app_id: String,
+    /// Secret key of the Zalo app
+    pub secret_key: String,
+    /// Refresh token to obtain the first access token.
+    /// Zalo rotates refresh tokens, the subsequent ones are kept in the token store.
+    pub refresh_token: String,
+    /// Approved ZNS template ids
+    pub templates: ZaloTemplates,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZaloTemplates {
+    /// Template with a single `otp` parameter
+    pub otp_verification_for_registration: String,
+    /// Template with a single `otp` parameter
+    pub otp_verification_for_password_reset: String,
+    /// Template with a single `otp` parameter
+    pub otp_verification_for_phone_number_change: String,
+    /// Template with a single `otp` parameter
+    pub otp_verification_for_sign_in: String,
+}
+
+pub struct ZaloSmsSender {
+    client: reqwest::Client,
+    config: ZaloSmsSenderConfig,
+    /// Shares the rotated refresh token among restarts and replicas
+    token_store: Arc<dyn ZaloTokenStore>,
+    /// Token cached in memory, locked during refresh so that this replica
+    /// doesn't wait on the token store with concurrent refreshes.
+    token: Mutex<Option<ZaloToken>>,
+}
+
+impl ZaloSmsSender {
+    pub fn new(config: ZaloSmsSenderConfig, token_store: Arc<dyn ZaloTokenStore>) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| Error::internal(format!("Cannot create Zalo http client {e:?}")))?;
+
+        Ok(Self {
+            client,
+            config,
+            token_store,
+            token: Mutex::new(None),
+        })
+    }
+
+    /// Get the cached access token, or refresh it if it's missing, about to expire
+    /// or `rejected` by Zalo.
+    async fn access_token(&self, rejected: Option<&str>) -> Result<String> {
+        let mut cached = self.token.lock().await;
+        if let Some(token) = cached.as_ref()
+            && is_usable(token, rejected)
+        {
+            return Ok(token.access_token.clone());
+        }
+
+        let token = self
+            .token_store
+            .refresh(
+                &self.config.app_id,
+                Box::new(move |stored| {
+                    Box::pin(async move {
+                        match stored {
+                            // Another replica has refreshed the token already
+                            Some(token) if is_usable(&token, rejected) => Ok(token),
+                            Some(token) => self.request_token(&token.refresh_token).await,
+                            None => self.request_token(&self.config.refresh_token).await,
+                        }
+                    })
+                }),
+            )
+            .await?;
+
+        let access_token = token.access_token.clone();
+        *cached = Some(token);
+        Ok(access_token)
+    }
+
+    /// Exchange the refresh token for a new access token and a rotated refresh token.
+    async fn request_token(&self, refresh_token: &str) -> Result<ZaloToken> {
+        let response = self
+            .client
+            .post(format!("{}{ZALO_ACCESS_TOKEN_PATH}", self.config.oauth_url))
+            .header("secret_key", &self.config.secret_key)
+            .form(&[
+                ("refresh_token", refresh_token),
+                ("app_id", self.config.app_id.as_str()),
+                ("grant_type", "refresh_token"),
+            ])
+            .send()
+            .await
+            .map_err(unavailable_error)?;
+        let response = check_http_status(response)?
+            .json::<AccessTokenResponse>()
+            .await
+            .map_err(unavailable_error)?;
+
+        let (access_token, refresh_token, expires_in) = match response {
+            AccessTokenResponse::Success {
+                access_token,
+                refresh_token,
+                expires_in,
+            } => (access_token, refresh_token, expires_in),
+            AccessTokenResponse::Failure {
+                error,
+                error_name,
+                error_description,
+            } => {
+                return Err(Error::internal(format!(
+                    "Cannot refresh Zalo access token {error} {error_name}: {error_description}"
+                )));
+            }
+        };
+        let expires_in = expires_in
+            .parse::<i64>()
+            .map_err(|e| Error::internal(format!("Invalid Zalo token expiry {e:?}")))?;
+
+        Ok(ZaloToken {
+            refresh_token,
+            access_token,
+            expires_at: Utc::now() + TimeDelta::seconds(expires_in),
+        })
+    }
+
+    async fn send_template(
+        &self,
+        access_token: &str,
+        request: &ZnsTemplateRequest<'_>,
+    ) -> Result<ZnsResponse> {
+        let response = self
+            .client
+            .post(format!("{}{ZALO_ZNS_TEMPLATE_PATH}", self.config.zns_url))
+            .header("access_token", access_token)
+            .json(request)
+            .send()
+            .await
+            .map_err(unavailable_error)?;
+
+        check_http_status(response)?
+            .json::<ZnsResponse>()
+            .await
+            .map_err(unavailable_error)
+    }
 
-pub struct ZaloSmsSender {}
+    fn template(&self, message: MessageType) -> (&str, HashMap<&'static str, String>) {
+        let templates = &self.config.templates;
+        match message {
+            MessageType::OtpVerificationForRegistration(inner) => (
+                &templates.otp_verification_for_registration,
+                HashMap::from([("otp", inner.code)]),
+            ),
+            MessageType::OtpVerificationForPasswordReset(inner) => (
+                &templates.otp_verification_for_password_reset,
+                HashMap::from([("otp", inner.code)]),
+            ),
+            MessageType::OtpVerificationForPhoneNumberChange(inner) => (
+                &templates.otp_verification_for_phone_number_change,
+                HashMap::from([("otp", inner.code)]),
+            ),
+            MessageType::OtpVerificationForSignIn(inner) => (
+                &templates.otp_verification_for_sign_in,
+                HashMap::from([("otp", inner.code)]),
+            ),
+        }
+    }
+}
 
 #[async_trait]
 impl SmsSender for ZaloSmsSender {
-    async fn send(&self, _input: SendSmsInput) -> Result<()> {
-        todo!()
+    async fn send(&self, input: SendSmsInput) -> Result<()> {
+        let SendSmsInput { to, message } = input;
+        // ZNS expects the phone number without the leading `+`, e.g. 84912345678
+        let phone = to.format().mode(Mode::E164).to_string();
+        let (template_id, template_data) = self.template(message);
+        let request = ZnsTemplateRequest {
+            phone: phone.trim_start_matches('+'),
+            template_id,
+            template_data,
+            tracking_id: uuid::Uuid::now_v7().to_string(),
+        };
+
+        let access_token = self.access_token(None).await?;
+        let mut response = self.send_template(&access_token, &request).await?;
+        if response.error == zns_error_code::INVALID_ACCESS_TOKEN {
+            // The token is revoked before its expiry, retry once with a fresh one
+            let access_token = self.access_token(Some(&access_token)).await?;
+            response = self.send_template(&access_token, &request).await?;
+        }
+
+        match response.error {
+            zns_error_code::SUCCESS => {
+                tracing::debug!(tracking_id = request.tracking_id, "ZNS message sent");
+                Ok(())
+            }
+            code => Err(zns_error(code, response.message)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ZnsTemplateRequest<'a> {
+    phone: &'a str,
+    template_id: &'a str,
+    template_data: HashMap<&'static str, String>,
+    /// Our own id to trace the message in Zalo callbacks
+    tracking_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZnsResponse {
+    error: i32,
+    #[serde(default)]
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AccessTokenResponse {
+    Success {
+        access_token: String,
+        refresh_token: String,
+        /// Lifetime of the access token in seconds, encoded as a string
+        expires_in: String,
+    },
+    Failure {
+        error: i32,
+        #[serde(default)]
+        error_name: String,
+        #[serde(default)]
+        error_description: String,
+    },
+}
+
+/// Whether the access token can still be used, it's refreshed a bit earlier than its expiry.
+fn is_usable(token: &ZaloToken, rejected: Option<&str>) -> bool {
+    token.expires_at > Utc::now() + ACCESS_TOKEN_EXPIRY_MARGIN
+        && rejected.is_none_or(|rejected| token.access_token != rejected)
+}
+
+fn check_http_status(response: reqwest::Response) -> Result<reqwest::Response> {
+    let status = response.status();
+    if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(Error::unavailable_with(
+            format!("Zalo responded with {status}"),
+            None,
+            Some(retry_after(Duration::from_secs(5))),
+        ));
+    }
+    if !status.is_success() {
+        return Err(Error::internal(format!("Zalo responded with {status}")));
+    }
+    Ok(response)
+}
+
+fn unavailable_error(e: reqwest::Error) -> Error {
+    Error::unavailable_with(
+        format!("Cannot reach Zalo {e:?}"),
+        None,
+        Some(retry_after(Duration::from_secs(5))),
+    )
+}
+
+fn zns_error(code: i32, message: String) -> Error {
+    match code {
+        zns_error_code::INVALID_PHONE_NUMBER | zns_error_code::ZALO_ACCOUNT_NOT_FOUND => {
+            Error::invalid_argument_with(
+                format!("Cannot send ZNS message to the phone number: {message}"),
+                Some(BadRequest {
+                    field_violations: vec![FieldViolation {
+                        field: "phone_number".into(),
+                        description: "UNREACHABLE_PHONE_NUMBER".into(),
+                    }],
+                }),
+            )
+        }
+        zns_error_code::DAILY_QUOTA_EXCEEDED => Error::resource_exhausted_with(
+            format!("ZNS daily quota exceeded: {message}"),
+            Some(QuotaFailure {
+                violations: vec![quota_failure::Violation {
+                    subject: "zalo_zns".into(),
+                    description: "DAILY_QUOTA_EXCEEDED".into(),
+                }],
+            }),
+            None,
+        ),
+        zns_error_code::RATE_LIMIT_EXCEEDED => Error::unavailable_with(
+            format!("ZNS rate limit exceeded: {message}"),
+            None,
+            Some(retry_after(Duration::from_secs(60))),
+        ),
+        // ZNS only delivers from 6:00 to 22:00 (UTC+7)
+        zns_error_code::OUTSIDE_SENDING_HOURS => Error::unavailable_with(
+            format!("ZNS message sent outside of sending hours: {message}"),
+            None,
+            Some(retry_after(Duration::from_secs(60 * 60))),
+        ),
+        code => Error::internal(format!("ZNS error {code}: {message}")),
+    }
+}
+
+fn retry_after(delay: Duration) -> RetryInfo {
+    RetryInfo {
+        retry_delay: Some(prost_types::Duration {
+            seconds: delay.as_secs() as i64,
+            nanos: 0,
+        }),
     }
 }
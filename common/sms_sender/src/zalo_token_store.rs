@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use error::Result;
+use std::{collections::HashMap, future::Future, pin::Pin};
+use tokio::sync::Mutex;
+
+/// OAuth tokens of a Zalo app. Zalo rotates the refresh token on every refresh,
+/// so the latest one must be kept for the next refresh.
+#[derive(Debug, Clone)]
+pub struct ZaloToken {
+    pub refresh_token: String,
+    pub access_token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Obtain a new token given the stored one, `None` if no token is stored for the app yet.
+pub type ZaloTokenRefresh<'a> = Box<
+    dyn FnOnce(Option<ZaloToken>) -> Pin<Box<dyn Future<Output = Result<ZaloToken>> + Send + 'a>>
+        + Send
+        + 'a,
+>;
+
+#[async_trait]
+pub trait ZaloTokenStore: Send + Sync {
+    /// Run `refresh` on the stored token of the app and store the token it returns.
+    /// Refreshes of the same app run one at a time, including among replicas sharing the store,
+    /// since a refresh token can be used only once.
+    async fn refresh(&self, app_id: &str, refresh: ZaloTokenRefresh<'_>) -> Result<ZaloToken>;
+}
+
+/// Keep Zalo tokens in process memory. Used for local development and tests,
+/// tokens don't survive restarts and aren't shared among replicas.
+#[derive(Debug, Default)]
+pub struct InMemoryZaloTokenStore {
+    tokens: Mutex<HashMap<String, ZaloToken>>,
+}
+
+#[async_trait]
+impl ZaloTokenStore for InMemoryZaloTokenStore {
+    async fn refresh(&self, app_id: &str, refresh: ZaloTokenRefresh<'_>) -> Result<ZaloToken> {
+        let mut tokens = self.tokens.lock().await;
+        let token = refresh(tokens.get(app_id).cloned()).await?;
+        tokens.insert(app_id.into(), token.clone());
+        Ok(token)
+    }
+}
@@ -0,0 +1,161 @@
+use error::{ErrorVariant, Result};
+use sms_sender::{
+    InMemoryZaloTokenStore, MessageType, OtpVerificationForRegistration, SendSmsInput, SmsSender,
+    ZaloSmsSender, ZaloSmsSenderConfig, ZaloTemplates, ZaloTokenStore, zns_error_code,
+};
+use std::sync::Arc;
+use test_utils::FakeZaloServer;
+
+const APP_ID: &str = "app-id";
+const SECRET_KEY: &str = "secret-key";
+const REFRESH_TOKEN: &str = "initial-refresh-token";
+const OTP_TEMPLATE_ID: &str = "otp-template-id";
+const PASSWORD_RESET_TEMPLATE_ID: &str = "password-reset-template-id";
+const PHONE_NUMBER_CHANGE_TEMPLATE_ID: &str = "phone-number-change-template-id";
+const SIGN_IN_TEMPLATE_ID: &str = "sign-in-template-id";
+
+fn sender_config(server: &FakeZaloServer, refresh_token: &str) -> ZaloSmsSenderConfig {
+    ZaloSmsSenderConfig {
+        oauth_url: server.url.clone(),
+        zns_url: server.url.clone(),
+        app_id: APP_ID.into(),
+        secret_key: SECRET_KEY.into(),
+        refresh_token: refresh_token.into(),
+        templates: ZaloTemplates {
+            otp_verification_for_registration: OTP_TEMPLATE_ID.into(),
+            otp_verification_for_password_reset: PASSWORD_RESET_TEMPLATE_ID.into(),
+            otp_verification_for_phone_number_change: PHONE_NUMBER_CHANGE_TEMPLATE_ID.into(),
+            otp_verification_for_sign_in: SIGN_IN_TEMPLATE_ID.into(),
+        },
+    }
+}
+
+async fn setup() -> Result<(FakeZaloServer, ZaloSmsSender)> {
+    let server = FakeZaloServer::start(APP_ID, SECRET_KEY, REFRESH_TOKEN).await?;
+    let sender = ZaloSmsSender::new(
+        sender_config(&server, REFRESH_TOKEN),
+        Arc::new(InMemoryZaloTokenStore::default()),
+    )?;
+    Ok((server, sender))
+}
+
+fn otp_sms(code: &str) -> SendSmsInput {
+    SendSmsInput {
+        to: phonenumber::parse(None, "+84334445555").unwrap(),
+        message: MessageType::OtpVerificationForRegistration(OtpVerificationForRegistration {
+            code: code.into(),
+        }),
+    }
+}
+
+#[tokio::test]
+async fn send_otp_template() -> Result<()> {
+    let (server, sender) = setup().await?;
+
+    sender.send(otp_sms("123456")).await?;
+    sender.send(otp_sms("654321")).await?;
+
+    let messages = server.sent_messages();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].phone, "84334445555");
+    assert_eq!(messages[0].template_id, OTP_TEMPLATE_ID);
+    assert_eq!(messages[0].template_data["otp"], "123456");
+    assert_eq!(messages[1].template_data["otp"], "654321");
+    assert_ne!(messages[0].tracking_id, messages[1].tracking_id);
+    // The access token is reused until it expires
+    assert_eq!(server.issued_token_count(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn refresh_revoked_access_token() -> Result<()> {
+    let (server, sender) = setup().await?;
+    sender.send(otp_sms("123456")).await?;
+
+    server.revoke_access_token();
+    sender.send(otp_sms("654321")).await?;
+
+    // The rotated refresh token is used for the second refresh
+    assert_eq!(server.issued_token_count(), 2);
+    assert_eq!(server.sent_messages().len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn share_rotated_refresh_token() -> Result<()> {
+    let server = FakeZaloServer::start(APP_ID, SECRET_KEY, REFRESH_TOKEN).await?;
+    let token_store: Arc<dyn ZaloTokenStore> = Arc::new(InMemoryZaloTokenStore::default());
+    let sender = ZaloSmsSender::new(
+        sender_config(&server, REFRESH_TOKEN),
+        Arc::clone(&token_store),
+    )?;
+    let other_sender = ZaloSmsSender::new(
+        sender_config(&server, REFRESH_TOKEN),
+        Arc::clone(&token_store),
+    )?;
+
+    sender.send(otp_sms("123456")).await?;
+    // Another replica reuses the stored access token
+    other_sender.send(otp_sms("654321")).await?;
+    assert_eq!(server.issued_token_count(), 1);
+
+    // The configured refresh token is already used,
+    // so the refresh must go with the rotated one from the store
+    server.revoke_access_token();
+    other_sender.send(otp_sms("111111")).await?;
+    assert_eq!(server.issued_token_count(), 2);
+
+    // The access token refreshed by the other replica is picked up from the store
+    sender.send(otp_sms("222222")).await?;
+    assert_eq!(server.issued_token_count(), 2);
+    assert_eq!(server.sent_messages().len(), 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn map_zns_errors() -> Result<()> {
+    let (server, sender) = setup().await?;
+
+    server.fail_next_message(zns_error_code::DAILY_QUOTA_EXCEEDED);
+    let error = sender.send(otp_sms("123456")).await.unwrap_err();
+    assert!(matches!(
+        *error.variant,
+        ErrorVariant::ResourceExhausted((Some(_), None))
+    ));
+
+    server.fail_next_message(zns_error_code::RATE_LIMIT_EXCEEDED);
+    let error = sender.send(otp_sms("123456")).await.unwrap_err();
+    assert!(matches!(
+        *error.variant,
+        ErrorVariant::Unavailable((_, Some(_)))
+    ));
+
+    server.fail_next_message(zns_error_code::INVALID_PHONE_NUMBER);
+    let error = sender.send(otp_sms("123456")).await.unwrap_err();
+    assert!(matches!(
+        *error.variant,
+        ErrorVariant::InvalidArgument(Some(_))
+    ));
+
+    assert!(server.sent_messages().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reject_invalid_refresh_token() -> Result<()> {
+    let server = FakeZaloServer::start(APP_ID, SECRET_KEY, REFRESH_TOKEN).await?;
+    let sender = ZaloSmsSender::new(
+        sender_config(&server, "used-refresh-token"),
+        Arc::new(InMemoryZaloTokenStore::default()),
+    )?;
+
+    let error = sender.send(otp_sms("123456")).await.unwrap_err();
+    assert!(matches!(*error.variant, ErrorVariant::Internal(_)));
+    assert_eq!(server.issued_token_count(), 0);
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+DROP INDEX customer_task_request_assigned_handyman_id_idx;
+DROP INDEX customer_task_request_status_idx;
+DROP INDEX customer_task_request_customer_id_idx;
+
+ALTER TABLE customer_task_request
+    DROP COLUMN expired_at,
+    DROP COLUMN cancelled_at,
+    DROP COLUMN completed_at,
+    DROP COLUMN started_at,
+    DROP COLUMN assigned_at,
+    DROP COLUMN opened_at,
+    DROP COLUMN assigned_handyman_id,
+    DROP COLUMN status;
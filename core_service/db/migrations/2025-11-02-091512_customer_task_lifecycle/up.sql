@@ -0,0 +1,20 @@
+-- Lifecycle status of customer task requests
+
+ALTER TABLE customer_task_request
+    -- Map to rust enum CustomerTaskStatus
+    ADD COLUMN status TEXT NOT NULL DEFAULT 'OPEN',
+    ADD COLUMN assigned_handyman_id BIGINT,
+    ADD COLUMN opened_at TIMESTAMP,
+    ADD COLUMN assigned_at TIMESTAMP,
+    ADD COLUMN started_at TIMESTAMP,
+    ADD COLUMN completed_at TIMESTAMP,
+    ADD COLUMN cancelled_at TIMESTAMP,
+    ADD COLUMN expired_at TIMESTAMP;
+
+-- Existing tasks were published right away
+UPDATE customer_task_request SET opened_at = created_at;
+
+CREATE INDEX customer_task_request_customer_id_idx ON customer_task_request(customer_id);
+CREATE INDEX customer_task_request_status_idx ON customer_task_request(status);
+CREATE INDEX customer_task_request_assigned_handyman_id_idx
+    ON customer_task_request(assigned_handyman_id) WHERE (assigned_handyman_id IS NOT NULL);
@@ -0,0 +1,32 @@
+-- Handymen quote a price on open customer tasks
+
+CREATE SEQUENCE task_quote_seq;
+
+CREATE TABLE task_quote (
+    id BIGINT PRIMARY KEY DEFAULT xtea(
+        NEXTVAL('task_quote_seq'),
+        BYTEA '\x5ee1f91af0cc498b7af5880c37b8687f',
+        TRUE
+    ),
+    task_id BIGINT NOT NULL REFERENCES customer_task_request(id) ON DELETE CASCADE,
+    handyman_id BIGINT NOT NULL,
+    -- Quoted price in VND
+    price_vnd INT NOT NULL CHECK (price_vnd > 0),
+    -- Estimated time the handyman can get the task done
+    eta TIMESTAMP NOT NULL,
+    message TEXT,
+    -- Map to rust enum TaskQuoteStatus
+    status TEXT NOT NULL DEFAULT 'PENDING',
+
+    created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+    updated_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+
+    -- A handyman quotes a task at most once
+    UNIQUE (task_id, handyman_id)
+);
+
+ALTER SEQUENCE task_quote_seq OWNED BY task_quote.id;
+
+SELECT diesel_manage_updated_at('task_quote');
+
+CREATE INDEX task_quote_handyman_id_idx ON task_quote(handyman_id);
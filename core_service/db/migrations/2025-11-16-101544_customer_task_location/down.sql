@@ -0,0 +1,6 @@
+ALTER TABLE customer_task_request
+    DROP COLUMN location_city,
+    DROP COLUMN location_address_line1,
+    DROP COLUMN location_formatted_address,
+    DROP COLUMN location_lon,
+    DROP COLUMN location_lat;
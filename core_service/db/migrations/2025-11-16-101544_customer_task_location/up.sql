@@ -0,0 +1,10 @@
+-- Location where a customer task takes place. Nullable for tasks created before location was persisted.
+
+ALTER TABLE customer_task_request
+    ADD COLUMN location_city TEXT,
+    ADD COLUMN location_address_line1 TEXT,
+    ADD COLUMN location_formatted_address TEXT,
+    ADD COLUMN location_lon DOUBLE PRECISION,
+    ADD COLUMN location_lat DOUBLE PRECISION,
+    ADD CONSTRAINT customer_task_request_location_coordinates_check
+        CHECK ((location_lon IS NULL) = (location_lat IS NULL));
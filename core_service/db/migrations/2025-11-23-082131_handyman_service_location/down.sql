@@ -0,0 +1 @@
+DROP TABLE handyman_service_location;
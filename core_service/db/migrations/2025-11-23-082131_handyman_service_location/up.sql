@@ -0,0 +1,14 @@
+-- Location where a handyman offers their services. Indexed in search service for geo search.
+
+CREATE TABLE handyman_service_location (
+    handyman_id BIGINT PRIMARY KEY,
+    city TEXT NOT NULL,
+    address_line1 TEXT NOT NULL,
+    formatted_address TEXT NOT NULL,
+    lon DOUBLE PRECISION NOT NULL,
+    lat DOUBLE PRECISION NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+    updated_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC')
+);
+
+SELECT diesel_manage_updated_at('handyman_service_location');
@@ -0,0 +1,30 @@
+-- Customers rate handymen for completed tasks
+
+CREATE SEQUENCE handyman_review_seq;
+
+CREATE TABLE handyman_review (
+    id BIGINT PRIMARY KEY DEFAULT xtea(
+        NEXTVAL('handyman_review_seq'),
+        BYTEA '\x7f513a4e98cf52c81296e620a7b3a354',
+        TRUE
+    ),
+    -- A task is reviewed at most once
+    task_id BIGINT NOT NULL UNIQUE REFERENCES customer_task_request(id) ON DELETE CASCADE,
+    customer_id BIGINT NOT NULL,
+    handyman_id BIGINT NOT NULL,
+    -- Stars from 1 to 5
+    rating SMALLINT NOT NULL CHECK (rating >= 1 AND rating <= 5),
+    comment TEXT,
+    -- Whether the rating is reflected in the handyman's search index `avg_rating_score`
+    rating_indexed BOOLEAN NOT NULL DEFAULT FALSE,
+
+    created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+    updated_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC')
+);
+
+ALTER SEQUENCE handyman_review_seq OWNED BY handyman_review.id;
+
+SELECT diesel_manage_updated_at('handyman_review');
+
+CREATE INDEX handyman_review_handyman_id_idx ON handyman_review(handyman_id);
+CREATE INDEX handyman_review_rating_unindexed_idx ON handyman_review(handyman_id) WHERE NOT rating_indexed;
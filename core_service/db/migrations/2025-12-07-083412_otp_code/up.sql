@@ -0,0 +1,18 @@
+-- Pending OTP codes, shared by all core service replicas.
+-- One row per purpose and phone number, replaced when a new code is issued.
+
+CREATE TABLE otp_code (
+    purpose TEXT NOT NULL,
+    e164_phone_number TEXT NOT NULL,
+    -- NULL once the code is consumed or invalidated by a lockout
+    code TEXT,
+    expires_at TIMESTAMP NOT NULL,
+    failed_attempts INTEGER NOT NULL DEFAULT 0,
+    last_sent_at TIMESTAMP NOT NULL,
+    locked_until TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+    updated_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+    PRIMARY KEY (purpose, e164_phone_number)
+);
+
+SELECT diesel_manage_updated_at('otp_code');
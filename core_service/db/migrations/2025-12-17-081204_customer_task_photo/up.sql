@@ -0,0 +1,20 @@
+-- Photos customers attach to their tasks, the image itself lives in the blob store
+
+CREATE SEQUENCE customer_task_photo_seq;
+
+CREATE TABLE customer_task_photo (
+    id BIGINT PRIMARY KEY DEFAULT xtea(
+        NEXTVAL('customer_task_photo_seq'),
+        BYTEA '\x94cbe5bf282d6cda7493675da2f30bbb',
+        TRUE
+    ),
+    task_id BIGINT NOT NULL REFERENCES customer_task_request(id) ON DELETE CASCADE,
+    -- Key of the image in the blob store
+    blob_key TEXT NOT NULL,
+
+    created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC')
+);
+
+ALTER SEQUENCE customer_task_photo_seq OWNED BY customer_task_photo.id;
+
+CREATE INDEX customer_task_photo_task_id_idx ON customer_task_photo(task_id);
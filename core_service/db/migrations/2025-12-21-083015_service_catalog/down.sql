@@ -0,0 +1,4 @@
+ALTER TABLE customer_task_request DROP CONSTRAINT customer_task_request_service_fkey;
+ALTER TABLE handyman_service DROP CONSTRAINT handyman_service_service_fkey;
+DROP TABLE catalog_service;
+DROP TABLE catalog_service_group;
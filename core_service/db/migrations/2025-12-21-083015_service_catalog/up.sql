@@ -0,0 +1,56 @@
+-- Service catalog, groups and services can be added without a redeploy
+
+CREATE TABLE catalog_service_group (
+    -- Groups predating the catalog use rust enum `ServiceLayer1` values
+    code TEXT PRIMARY KEY CHECK (code ~ '^[A-Z][A-Z0-9_]*$'),
+    name_vi TEXT NOT NULL,
+    name_en TEXT NOT NULL,
+    -- Icon name known by the clients
+    icon TEXT,
+    display_order INT NOT NULL DEFAULT 0,
+    -- Inactive groups are hidden from the catalog but still resolve for existing rows
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+
+    created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+    updated_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC')
+);
+
+SELECT diesel_manage_updated_at('catalog_service_group');
+
+CREATE TABLE catalog_service (
+    -- Services predating the catalog use rust enum `ServiceLayer2` values
+    code TEXT PRIMARY KEY CHECK (code ~ '^[A-Z][A-Z0-9_]*$'),
+    group_code TEXT NOT NULL REFERENCES catalog_service_group(code),
+    name_vi TEXT NOT NULL,
+    name_en TEXT NOT NULL,
+    icon TEXT,
+    -- Reference pricing in VND shown to customers
+    recommended_rate_vnd INT CHECK (recommended_rate_vnd > 0),
+    display_order INT NOT NULL DEFAULT 0,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+
+    created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+    updated_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC')
+);
+
+SELECT diesel_manage_updated_at('catalog_service');
+
+CREATE INDEX catalog_service_group_code_idx ON catalog_service(group_code);
+
+INSERT INTO catalog_service_group (code, name_vi, name_en, icon, display_order) VALUES
+    ('AIR_CONDITIONER', 'Máy lạnh', 'Air conditioner', 'air_conditioner', 1),
+    ('WASHING_MACHINE', 'Máy giặt', 'Washing machine', 'washing_machine', 2),
+    ('OTHER', 'Khác', 'Other', 'other', 1000);
+
+INSERT INTO catalog_service (code, group_code, name_vi, name_en, icon, recommended_rate_vnd, display_order) VALUES
+    ('AIR_CONDITIONER_FIXING', 'AIR_CONDITIONER', 'Sửa máy lạnh', 'Air conditioner repair', 'air_conditioner_fixing', NULL, 1),
+    ('AIR_CONDITIONER_CLEANING', 'AIR_CONDITIONER', 'Vệ sinh máy lạnh', 'Air conditioner cleaning', 'air_conditioner_cleaning', 250000, 2),
+    ('WASHING_MACHINE_FIXING', 'WASHING_MACHINE', 'Sửa máy giặt', 'Washing machine repair', 'washing_machine_fixing', NULL, 1),
+    ('WASHING_MACHINE_CLEANING', 'WASHING_MACHINE', 'Vệ sinh máy giặt', 'Washing machine cleaning', 'washing_machine_cleaning', 450000, 2),
+    ('OTHER', 'OTHER', 'Dịch vụ khác', 'Other services', 'other', NULL, 1);
+
+-- Existing rows must keep pointing at a catalog entry
+ALTER TABLE handyman_service
+    ADD CONSTRAINT handyman_service_service_fkey FOREIGN KEY (service) REFERENCES catalog_service(code);
+ALTER TABLE customer_task_request
+    ADD CONSTRAINT customer_task_request_service_fkey FOREIGN KEY (service) REFERENCES catalog_service(code);
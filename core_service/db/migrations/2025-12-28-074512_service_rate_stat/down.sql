@@ -0,0 +1,2 @@
+DROP FUNCTION refresh_service_rate_stat(INT);
+DROP TABLE service_rate_stat;
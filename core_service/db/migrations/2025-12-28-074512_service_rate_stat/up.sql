@@ -0,0 +1,55 @@
+-- Price distribution of each service, computed from handyman rates and accepted quotes
+
+CREATE TABLE service_rate_stat (
+    id BIGINT PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+    service TEXT NOT NULL REFERENCES catalog_service(code) ON DELETE CASCADE,
+    -- Null for the distribution over all cities
+    city TEXT,
+    sample_count INT NOT NULL,
+    p25_vnd INT NOT NULL,
+    median_vnd INT NOT NULL,
+    p75_vnd INT NOT NULL,
+    refreshed_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+
+    UNIQUE NULLS NOT DISTINCT (service, city)
+);
+
+-- Replace every distribution, groups having less than `min_sample_count` prices are left out.
+-- Returns the number of distributions written.
+CREATE FUNCTION refresh_service_rate_stat(min_sample_count INT)
+RETURNS INT AS $$
+DECLARE
+    refreshed INT;
+BEGIN
+    DELETE FROM service_rate_stat;
+
+    INSERT INTO service_rate_stat (service, city, sample_count, p25_vnd, median_vnd, p75_vnd)
+    SELECT
+        sample.service,
+        sample.city,
+        COUNT(*),
+        ROUND(percentile_cont(0.25) WITHIN GROUP (ORDER BY sample.price_vnd))::INT,
+        ROUND(percentile_cont(0.5) WITHIN GROUP (ORDER BY sample.price_vnd))::INT,
+        ROUND(percentile_cont(0.75) WITHIN GROUP (ORDER BY sample.price_vnd))::INT
+    FROM (
+        -- Reference rates declared by handymen, located in their service city
+        SELECT hs.service, hsl.city, hs.rate_vnd AS price_vnd
+        FROM handyman_service hs
+        LEFT JOIN handyman_service_location hsl ON hsl.handyman_id = hs.handyman_id
+        WHERE hs.rate_vnd IS NOT NULL
+        UNION ALL
+        -- Prices customers actually agreed on, located in the task city
+        SELECT ctr.service, ctr.location_city, tq.price_vnd
+        FROM task_quote tq
+        JOIN customer_task_request ctr ON ctr.id = tq.task_id
+        WHERE tq.status = 'ACCEPTED'
+    ) AS sample
+    GROUP BY GROUPING SETS ((sample.service, sample.city), (sample.service))
+    -- Prices without a city only count toward the distribution over all cities
+    HAVING COUNT(*) >= min_sample_count
+        AND (GROUPING(sample.city) = 1 OR sample.city IS NOT NULL);
+
+    GET DIAGNOSTICS refreshed = ROW_COUNT;
+    RETURN refreshed;
+END;
+$$ LANGUAGE plpgsql;
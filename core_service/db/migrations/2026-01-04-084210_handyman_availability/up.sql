@@ -0,0 +1,22 @@
+-- Working hours and days off of handymen. Indexed in search service for search by availability.
+
+-- Each time of the daily or weekly recurrence schedule starts a working slot of `slot_minutes`.
+CREATE TABLE handyman_working_hours (
+    handyman_id BIGINT PRIMARY KEY,
+    schedule BIGINT NOT NULL REFERENCES schedule(id),
+    slot_minutes INT NOT NULL CHECK (slot_minutes > 0 AND slot_minutes <= 1440),
+    created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+    updated_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC')
+);
+
+SELECT diesel_manage_updated_at('handyman_working_hours');
+
+-- Dates the handyman does not work, whatever the working hours.
+CREATE TABLE handyman_blackout (
+    handyman_id BIGINT NOT NULL,
+    date DATE NOT NULL,
+    note TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+
+    PRIMARY KEY (handyman_id, date)
+);
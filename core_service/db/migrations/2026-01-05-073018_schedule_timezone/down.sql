@@ -0,0 +1,4 @@
+ALTER TABLE schedule_fixed_time
+    ALTER COLUMN time TYPE TIMESTAMP USING time AT TIME ZONE 'Asia/Ho_Chi_Minh';
+
+ALTER TABLE schedule DROP COLUMN timezone;
@@ -0,0 +1,10 @@
+-- Schedules carry the IANA time zone their times are local to. Schedules created so far were
+-- all entered in Việt Nam.
+
+ALTER TABLE schedule ADD COLUMN timezone TEXT NOT NULL DEFAULT 'Asia/Ho_Chi_Minh';
+ALTER TABLE schedule ALTER COLUMN timezone DROP DEFAULT;
+
+-- A fixed time is an instant. Recurrence times stay wall clock times of the schedule time zone,
+-- so they keep their local time across daylight saving time transitions.
+ALTER TABLE schedule_fixed_time
+    ALTER COLUMN time TYPE TIMESTAMPTZ USING time AT TIME ZONE 'Asia/Ho_Chi_Minh';
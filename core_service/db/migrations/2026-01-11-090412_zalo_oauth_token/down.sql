@@ -0,0 +1 @@
+DROP TABLE zalo_oauth_token;
@@ -0,0 +1,13 @@
+-- OAuth tokens of Zalo apps, shared by all core service replicas.
+-- Zalo rotates the refresh token on every refresh, so only the latest one is kept.
+
+CREATE TABLE zalo_oauth_token (
+    app_id TEXT PRIMARY KEY,
+    refresh_token TEXT NOT NULL,
+    access_token TEXT NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC'),
+    updated_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP AT TIME ZONE 'UTC')
+);
+
+SELECT diesel_manage_updated_at('zalo_oauth_token');
@@ -0,0 +1,4 @@
+DROP INDEX task_quote_task_id_handyman_id_active_idx;
+
+ALTER TABLE task_quote ADD CONSTRAINT task_quote_task_id_handyman_id_key
+    UNIQUE (task_id, handyman_id);
@@ -0,0 +1,7 @@
+-- A handyman can quote a task again after withdrawing, so only one quote per handyman
+-- may be pending or accepted at a time.
+
+ALTER TABLE task_quote DROP CONSTRAINT task_quote_task_id_handyman_id_key;
+
+CREATE UNIQUE INDEX task_quote_task_id_handyman_id_active_idx ON task_quote(task_id, handyman_id)
+    WHERE status IN ('PENDING', 'ACCEPTED');
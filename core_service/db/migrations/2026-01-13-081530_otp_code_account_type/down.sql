@@ -0,0 +1,6 @@
+DELETE FROM otp_code WHERE account_type <> 'CUSTOMER';
+
+ALTER TABLE otp_code DROP CONSTRAINT otp_code_pkey;
+ALTER TABLE otp_code ADD PRIMARY KEY (purpose, e164_phone_number);
+
+ALTER TABLE otp_code DROP COLUMN account_type;
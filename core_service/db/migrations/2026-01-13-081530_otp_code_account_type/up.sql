@@ -0,0 +1,8 @@
+-- Customer and handyman accounts may share a phone number,
+-- so their pending codes are kept apart.
+
+ALTER TABLE otp_code ADD COLUMN account_type TEXT NOT NULL DEFAULT 'CUSTOMER';
+ALTER TABLE otp_code ALTER COLUMN account_type DROP DEFAULT;
+
+ALTER TABLE otp_code DROP CONSTRAINT otp_code_pkey;
+ALTER TABLE otp_code ADD PRIMARY KEY (purpose, account_type, e164_phone_number);
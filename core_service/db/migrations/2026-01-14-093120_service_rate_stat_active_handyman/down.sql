@@ -0,0 +1,41 @@
+DROP FUNCTION refresh_service_rate_stat(INT, BIGINT[]);
+
+-- Replace every distribution, groups having less than `min_sample_count` prices are left out.
+-- Returns the number of distributions written.
+CREATE FUNCTION refresh_service_rate_stat(min_sample_count INT)
+RETURNS INT AS $$
+DECLARE
+    refreshed INT;
+BEGIN
+    DELETE FROM service_rate_stat;
+
+    INSERT INTO service_rate_stat (service, city, sample_count, p25_vnd, median_vnd, p75_vnd)
+    SELECT
+        sample.service,
+        sample.city,
+        COUNT(*),
+        ROUND(percentile_cont(0.25) WITHIN GROUP (ORDER BY sample.price_vnd))::INT,
+        ROUND(percentile_cont(0.5) WITHIN GROUP (ORDER BY sample.price_vnd))::INT,
+        ROUND(percentile_cont(0.75) WITHIN GROUP (ORDER BY sample.price_vnd))::INT
+    FROM (
+        -- Reference rates declared by handymen, located in their service city
+        SELECT hs.service, hsl.city, hs.rate_vnd AS price_vnd
+        FROM handyman_service hs
+        LEFT JOIN handyman_service_location hsl ON hsl.handyman_id = hs.handyman_id
+        WHERE hs.rate_vnd IS NOT NULL
+        UNION ALL
+        -- Prices customers actually agreed on, located in the task city
+        SELECT ctr.service, ctr.location_city, tq.price_vnd
+        FROM task_quote tq
+        JOIN customer_task_request ctr ON ctr.id = tq.task_id
+        WHERE tq.status = 'ACCEPTED'
+    ) AS sample
+    GROUP BY GROUPING SETS ((sample.service, sample.city), (sample.service))
+    -- Prices without a city only count toward the distribution over all cities
+    HAVING COUNT(*) >= min_sample_count
+        AND (GROUPING(sample.city) = 1 OR sample.city IS NOT NULL);
+
+    GET DIAGNOSTICS refreshed = ROW_COUNT;
+    RETURN refreshed;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,170 @@
+use crate::{
+    CustomerTaskRequest,
+    schema::{customer_task_photo, customer_task_request},
+    unexpected_status_error,
+};
+use actor_auth::ActorAuth;
+use chrono::NaiveDateTime;
+use db_utils::AsyncPgConnection;
+use diesel::{dsl, prelude::*};
+use diesel_async::RunQueryDsl;
+use entity_type::{CustomerId, CustomerTaskPhotoId, CustomerTaskRequestId, CustomerTaskStatus};
+use error::{
+    Error, Result,
+    error_details::{PreconditionFailure, precondition_failure::Violation},
+};
+
+pub const MAX_PHOTOS_PER_TASK: i64 = 10;
+
+/// Photos can be changed until a handyman starts working on the task
+const PHOTO_EDITABLE_STATUSES: [CustomerTaskStatus; 3] = [
+    CustomerTaskStatus::Draft,
+    CustomerTaskStatus::Open,
+    CustomerTaskStatus::Assigned,
+];
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = customer_task_photo)]
+pub struct CustomerTaskPhoto {
+    pub id: CustomerTaskPhotoId,
+    pub task_id: CustomerTaskRequestId,
+    /// Key of the image in the blob store
+    pub blob_key: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl CustomerTaskPhoto {
+    /// Customer attaches a photo, already written to the blob store, to their task.
+    pub async fn create(
+        actor_auth: &ActorAuth,
+        task_id: CustomerTaskRequestId,
+        blob_key: &str,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        // Lock the task so that concurrent uploads can't exceed the limit
+        customer_task_request::table
+            .find(task_id)
+            .select(customer_task_request::id)
+            .for_update()
+            .execute(conn)
+            .await?;
+        Self::require_addable(actor_auth, task_id, conn).await?;
+
+        let result = diesel::insert_into(customer_task_photo::table)
+            .values((
+                customer_task_photo::task_id.eq(task_id),
+                customer_task_photo::blob_key.eq(blob_key),
+            ))
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Check the customer can attach one more photo to their task,
+    /// so that uploads which would be rejected aren't written to the blob store.
+    pub async fn require_addable(
+        actor_auth: &ActorAuth,
+        task_id: CustomerTaskRequestId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<()> {
+        let (customer_id, status) = customer_task_request::table
+            .filter(customer_task_request::id.eq(task_id))
+            .select((
+                customer_task_request::customer_id,
+                customer_task_request::status,
+            ))
+            .first::<(CustomerId, CustomerTaskStatus)>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Customer task request not found"))?;
+        actor_auth.require_customer_access(customer_id)?;
+        require_photo_editable(status)?;
+
+        let photo_count = customer_task_photo::table
+            .filter(customer_task_photo::task_id.eq(task_id))
+            .select(dsl::count_star())
+            .first::<i64>(conn)
+            .await?;
+        if photo_count >= MAX_PHOTOS_PER_TASK {
+            return Err(Error::failed_precondition_with(
+                format!("A task has at most {MAX_PHOTOS_PER_TASK} photos"),
+                Some(PreconditionFailure {
+                    violations: vec![Violation {
+                        r#type: "TOO_MANY_PHOTOS".into(),
+                        subject: "task_photos".into(),
+                        description: format!("{photo_count}"),
+                    }],
+                }),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Photos of the tasks the actor can read, grouped by task and the oldest first.
+    /// This API requires god or admin or any session actor.
+    pub async fn load_by_tasks(
+        actor_auth: &ActorAuth,
+        task_ids: &[CustomerTaskRequestId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let readable_task_ids = CustomerTaskRequest::load_by_ids(actor_auth, task_ids, conn)
+            .await?
+            .into_iter()
+            .map(|t| t.id)
+            .collect::<Vec<_>>();
+
+        let result = customer_task_photo::table
+            .filter(customer_task_photo::task_id.eq_any(readable_task_ids))
+            .select(Self::as_select())
+            .order((
+                customer_task_photo::task_id,
+                customer_task_photo::created_at,
+                customer_task_photo::id,
+            ))
+            .load::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Customer removes a photo from their task.
+    /// The caller is responsible for removing the image from the blob store.
+    pub async fn delete(
+        actor_auth: &ActorAuth,
+        photo_id: CustomerTaskPhotoId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        let (customer_id, status) = customer_task_photo::table
+            .inner_join(customer_task_request::table)
+            .filter(customer_task_photo::id.eq(photo_id))
+            .select((
+                customer_task_request::customer_id,
+                customer_task_request::status,
+            ))
+            .first::<(CustomerId, CustomerTaskStatus)>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Task photo not found"))?;
+        actor_auth.require_customer_access(customer_id)?;
+        require_photo_editable(status)?;
+
+        let result = diesel::delete(customer_task_photo::table.find(photo_id))
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Task photo not found"))?;
+
+        Ok(result)
+    }
+}
+
+fn require_photo_editable(status: CustomerTaskStatus) -> Result<()> {
+    if !PHOTO_EDITABLE_STATUSES.contains(&status) {
+        return Err(unexpected_status_error("task_status", status));
+    }
+    Ok(())
+}
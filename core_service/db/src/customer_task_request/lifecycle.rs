@@ -0,0 +1,229 @@
+use crate::{CustomerTaskRequest, TaskQuote, schema::customer_task_request};
+use actor_auth::ActorAuth;
+use chrono::{NaiveDateTime, Utc};
+use db_utils::AsyncPgConnection;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use entity_type::{CustomerId, CustomerTaskRequestId, CustomerTaskStatus, HandymanId};
+use error::{
+    Error, Result,
+    error_details::{PreconditionFailure, precondition_failure::Violation},
+};
+
+/// Allowed transitions of a customer task request.
+///
+/// ```text
+/// DRAFT -> OPEN -> ASSIGNED -> IN_PROGRESS -> COMPLETED
+///   |        |        |
+///   +--------+--------+-> CANCELLED
+///            |
+///            +-> EXPIRED
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CustomerTaskTransition {
+    /// Customer publishes a draft task.
+    Open,
+    /// Customer picks a handyman for an open task.
+    Assign(HandymanId),
+    /// Assigned handyman starts working on the task.
+    Start,
+    /// Assigned handyman finishes the task.
+    Complete,
+    /// Customer cancels the task before work is started.
+    Cancel,
+    /// System expires an open task that nobody took.
+    Expire,
+}
+
+impl CustomerTaskTransition {
+    pub fn target_status(&self) -> CustomerTaskStatus {
+        match self {
+            CustomerTaskTransition::Open => CustomerTaskStatus::Open,
+            CustomerTaskTransition::Assign(_) => CustomerTaskStatus::Assigned,
+            CustomerTaskTransition::Start => CustomerTaskStatus::InProgress,
+            CustomerTaskTransition::Complete => CustomerTaskStatus::Completed,
+            CustomerTaskTransition::Cancel => CustomerTaskStatus::Cancelled,
+            CustomerTaskTransition::Expire => CustomerTaskStatus::Expired,
+        }
+    }
+
+    pub fn allowed_from(&self) -> &'static [CustomerTaskStatus] {
+        match self {
+            CustomerTaskTransition::Open => &[CustomerTaskStatus::Draft],
+            CustomerTaskTransition::Assign(_) => &[CustomerTaskStatus::Open],
+            CustomerTaskTransition::Start => &[CustomerTaskStatus::Assigned],
+            CustomerTaskTransition::Complete => &[CustomerTaskStatus::InProgress],
+            CustomerTaskTransition::Cancel => &[
+                CustomerTaskStatus::Draft,
+                CustomerTaskStatus::Open,
+                CustomerTaskStatus::Assigned,
+            ],
+            CustomerTaskTransition::Expire => &[CustomerTaskStatus::Open],
+        }
+    }
+
+    /// Check whether the actor is allowed to perform the transition on the task.
+    fn require_access(&self, actor_auth: &ActorAuth, task: &CustomerTaskRequest) -> Result<()> {
+        match self {
+            CustomerTaskTransition::Open
+            | CustomerTaskTransition::Assign(_)
+            | CustomerTaskTransition::Cancel => {
+                actor_auth.require_customer_access(task.customer_id)
+            }
+            CustomerTaskTransition::Start | CustomerTaskTransition::Complete => {
+                let handyman_id = task
+                    .assigned_handyman_id
+                    .ok_or_else(|| Error::permission_denied("Task is not assigned"))?;
+                actor_auth.require_handyman_access(handyman_id)
+            }
+            CustomerTaskTransition::Expire => actor_auth.require_god_or_admin(),
+        }
+    }
+
+    fn changeset(&self, now: NaiveDateTime) -> StatusChangeset {
+        let mut changeset = StatusChangeset {
+            status: self.target_status(),
+            assigned_handyman_id: None,
+            opened_at: None,
+            assigned_at: None,
+            started_at: None,
+            completed_at: None,
+            cancelled_at: None,
+            expired_at: None,
+        };
+        match self {
+            CustomerTaskTransition::Open => changeset.opened_at = Some(now),
+            CustomerTaskTransition::Assign(handyman_id) => {
+                changeset.assigned_handyman_id = Some(*handyman_id);
+                changeset.assigned_at = Some(now);
+            }
+            CustomerTaskTransition::Start => changeset.started_at = Some(now),
+            CustomerTaskTransition::Complete => changeset.completed_at = Some(now),
+            CustomerTaskTransition::Cancel => changeset.cancelled_at = Some(now),
+            CustomerTaskTransition::Expire => changeset.expired_at = Some(now),
+        }
+        changeset
+    }
+}
+
+impl CustomerTaskRequest {
+    /// Move a task to the next lifecycle status.
+    /// The task row is locked for the rest of the transaction.
+    pub async fn transition(
+        actor_auth: &ActorAuth,
+        id: CustomerTaskRequestId,
+        transition: CustomerTaskTransition,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        let task = customer_task_request::table
+            .filter(customer_task_request::id.eq(id))
+            .select(Self::as_select())
+            .for_update()
+            .first::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Customer task request not found"))?;
+
+        transition.require_access(actor_auth, &task)?;
+
+        if !transition.allowed_from().contains(&task.status) {
+            return Err(Error::failed_precondition_with(
+                format!(
+                    "Can't move task from {:?} to {:?}",
+                    task.status,
+                    transition.target_status()
+                ),
+                Some(PreconditionFailure {
+                    violations: vec![Violation {
+                        r#type: "INVALID_STATUS_TRANSITION".into(),
+                        subject: "status".into(),
+                        description: format!("{:?}", task.status),
+                    }],
+                }),
+            ));
+        }
+
+        let result = diesel::update(customer_task_request::table.find(id))
+            .set(transition.changeset(Utc::now().naive_utc()))
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await?;
+
+        match transition {
+            CustomerTaskTransition::Assign(handyman_id) => {
+                TaskQuote::close_pending(&[id], Some(handyman_id), conn).await?
+            }
+            CustomerTaskTransition::Cancel => TaskQuote::close_pending(&[id], None, conn).await?,
+            _ => {}
+        }
+
+        Ok(result)
+    }
+
+    /// Expire open tasks which were published before `opened_before`.
+    pub async fn expire_stale(
+        actor_auth: &ActorAuth,
+        opened_before: NaiveDateTime,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        actor_auth.require_god_or_admin()?;
+
+        let result = diesel::update(
+            customer_task_request::table.filter(
+                customer_task_request::status
+                    .eq(CustomerTaskStatus::Open)
+                    .and(customer_task_request::opened_at.lt(opened_before)),
+            ),
+        )
+        .set(CustomerTaskTransition::Expire.changeset(Utc::now().naive_utc()))
+        .returning(Self::as_returning())
+        .get_results::<Self>(conn)
+        .await?;
+
+        let expired_ids = result.iter().map(|t| t.id).collect::<Vec<_>>();
+        TaskQuote::close_pending(&expired_ids, None, conn).await?;
+
+        Ok(result)
+    }
+
+    /// Cancel open tasks of a customer whose account is suspended or deleted,
+    /// nobody can accept a quote on them anymore.
+    pub async fn cancel_open_by_customer(
+        actor_auth: &ActorAuth,
+        customer_id: CustomerId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        actor_auth.require_god_or_admin()?;
+
+        let result = diesel::update(
+            customer_task_request::table.filter(
+                customer_task_request::customer_id
+                    .eq(customer_id)
+                    .and(customer_task_request::status.eq(CustomerTaskStatus::Open)),
+            ),
+        )
+        .set(CustomerTaskTransition::Cancel.changeset(Utc::now().naive_utc()))
+        .returning(Self::as_returning())
+        .get_results::<Self>(conn)
+        .await?;
+
+        let cancelled_ids = result.iter().map(|t| t.id).collect::<Vec<_>>();
+        TaskQuote::close_pending(&cancelled_ids, None, conn).await?;
+
+        Ok(result)
+    }
+}
+
+/// `None` fields are left unchanged.
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = customer_task_request)]
+struct StatusChangeset {
+    status: CustomerTaskStatus,
+    assigned_handyman_id: Option<HandymanId>,
+    opened_at: Option<NaiveDateTime>,
+    assigned_at: Option<NaiveDateTime>,
+    started_at: Option<NaiveDateTime>,
+    completed_at: Option<NaiveDateTime>,
+    cancelled_at: Option<NaiveDateTime>,
+    expired_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,5 @@
+mod model;
+pub use model::*;
+
+mod lifecycle;
+pub use lifecycle::*;
@@ -0,0 +1,196 @@
+use crate::{
+    CatalogService, NewLocation, NewSchedule, Schedule,
+    schema::{customer_task_request, task_quote},
+};
+use actor_auth::{ActorAuth, ActorType};
+use chrono::{NaiveDateTime, Utc};
+use db_utils::AsyncPgConnection;
+use diesel::{dsl, prelude::*};
+use diesel_async::RunQueryDsl;
+use entity_type::{CustomerId, CustomerTaskRequestId, CustomerTaskStatus, HandymanId, ScheduleId};
+use error::Result;
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = customer_task_request)]
+pub struct CustomerTaskRequest {
+    pub id: CustomerTaskRequestId,
+    pub customer_id: CustomerId,
+    /// Code of a [`CatalogService`]
+    pub service: String,
+    pub title: String,
+    pub note: Option<String>,
+    pub schedule: ScheduleId,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub status: CustomerTaskStatus,
+    pub assigned_handyman_id: Option<HandymanId>,
+    pub opened_at: Option<NaiveDateTime>,
+    pub assigned_at: Option<NaiveDateTime>,
+    pub started_at: Option<NaiveDateTime>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub cancelled_at: Option<NaiveDateTime>,
+    pub expired_at: Option<NaiveDateTime>,
+    pub location_city: Option<String>,
+    pub location_address_line1: Option<String>,
+    pub location_formatted_address: Option<String>,
+    pub location_lon: Option<f64>,
+    pub location_lat: Option<f64>,
+}
+
+impl CustomerTaskRequest {
+    /// `(lon, lat)` of the task location. `None` for tasks created before location was persisted.
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        self.location_lon.zip(self.location_lat)
+    }
+
+    pub async fn create(
+        actor_auth: &ActorAuth,
+        NewCustomerTaskRequest {
+            customer_id,
+            service,
+            title,
+            note,
+            schedule,
+            location,
+            draft,
+        }: NewCustomerTaskRequest,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<(Self, Schedule)> {
+        actor_auth.require_customer_access(customer_id)?;
+        CatalogService::require_active(&[&service], conn).await?;
+        let schedule = Schedule::create(actor_auth, schedule, conn).await?;
+
+        let (status, opened_at) = if draft {
+            (CustomerTaskStatus::Draft, None)
+        } else {
+            (CustomerTaskStatus::Open, Some(Utc::now().naive_utc()))
+        };
+        let new_request = CustomerTaskRequestInsertable {
+            customer_id,
+            service,
+            title,
+            note,
+            schedule: schedule.base.id,
+            status,
+            opened_at,
+            location_city: location.city,
+            location_address_line1: location.address_line1,
+            location_formatted_address: location.formatted_address,
+            location_lon: location.lon,
+            location_lat: location.lat,
+        };
+
+        let result = diesel::insert_into(customer_task_request::table)
+            .values(new_request)
+            .get_result::<Self>(conn)
+            .await?;
+
+        Ok((result, schedule))
+    }
+
+    /// Customers can only read their own tasks. Handymen can read open tasks in order to respond,
+    /// and the tasks they quoted or are assigned to.
+    pub async fn load_by_ids(
+        actor_auth: &ActorAuth,
+        ids: &[CustomerTaskRequestId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let mut query = customer_task_request::table
+            .filter(customer_task_request::id.eq_any(ids))
+            .select(Self::as_select())
+            .into_boxed();
+
+        match actor_auth.session_actor() {
+            Some(ActorType::Customer(customer)) => {
+                query = query.filter(customer_task_request::customer_id.eq(customer.customer_id));
+            }
+            Some(ActorType::Handyman(handyman)) => {
+                let quoted = task_quote::table.filter(
+                    task_quote::task_id
+                        .eq(customer_task_request::id)
+                        .and(task_quote::handyman_id.eq(handyman.handyman_id)),
+                );
+                query = query.filter(
+                    customer_task_request::status
+                        .eq(CustomerTaskStatus::Open)
+                        .or(customer_task_request::assigned_handyman_id.eq(handyman.handyman_id))
+                        .or(dsl::exists(quoted)),
+                );
+            }
+            Some(ActorType::Admin(_)) | None => {}
+        }
+
+        let result = query.load::<Self>(conn).await?;
+        Ok(result)
+    }
+
+    /// Tasks of a customer, newest first
+    pub async fn get_by_customer(
+        actor_auth: &ActorAuth,
+        customer_id: CustomerId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        actor_auth.require_customer_access(customer_id)?;
+
+        let result = customer_task_request::table
+            .filter(customer_task_request::customer_id.eq(customer_id))
+            .select(Self::as_select())
+            .order(customer_task_request::created_at.desc())
+            .load::<Self>(conn)
+            .await?;
+        Ok(result)
+    }
+
+    /// Erase the addresses of every task of a deleted customer, the tasks are kept
+    /// for the handymen who quoted or worked on them.
+    pub async fn anonymize_locations_by_customer(
+        actor_auth: &ActorAuth,
+        customer_id: CustomerId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<usize> {
+        actor_auth.require_god_or_admin()?;
+
+        let result = diesel::update(
+            customer_task_request::table.filter(customer_task_request::customer_id.eq(customer_id)),
+        )
+        .set((
+            customer_task_request::location_city.eq(None::<String>),
+            customer_task_request::location_address_line1.eq(None::<String>),
+            customer_task_request::location_formatted_address.eq(None::<String>),
+            customer_task_request::location_lon.eq(None::<f64>),
+            customer_task_request::location_lat.eq(None::<f64>),
+        ))
+        .execute(conn)
+        .await?;
+        Ok(result)
+    }
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = customer_task_request)]
+struct CustomerTaskRequestInsertable {
+    customer_id: CustomerId,
+    service: String,
+    title: String,
+    note: Option<String>,
+    schedule: ScheduleId,
+    status: CustomerTaskStatus,
+    opened_at: Option<NaiveDateTime>,
+    location_city: String,
+    location_address_line1: String,
+    location_formatted_address: String,
+    location_lon: f64,
+    location_lat: f64,
+}
+
+pub struct NewCustomerTaskRequest {
+    pub customer_id: CustomerId,
+    /// Code of an active [`CatalogService`]
+    pub service: String,
+    pub title: String,
+    pub note: Option<String>,
+    pub schedule: NewSchedule,
+    pub location: NewLocation,
+    /// Save the task as draft instead of publishing it right away
+    pub draft: bool,
+}
@@ -0,0 +1,283 @@
+use crate::{
+    DEFAULT_SCHEDULE_TIMEZONE, NewSchedule, NewScheduleVariant, Schedule, ScheduleBase,
+    ScheduleVariant,
+    schema::{handyman_blackout, handyman_working_hours},
+};
+use actor_auth::ActorAuth;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use chrono_tz::Tz;
+use db_utils::AsyncPgConnection;
+use diesel::{prelude::*, upsert::excluded};
+use diesel_async::RunQueryDsl;
+use entity_type::{HandymanId, ScheduleId, Weekday};
+use error::{
+    Error, Result,
+    error_details::{BadRequest, bad_request::FieldViolation},
+};
+
+pub const MINUTES_PER_DAY: i32 = 24 * 60;
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = handyman_working_hours)]
+pub struct HandymanWorkingHours {
+    pub handyman_id: HandymanId,
+    /// Daily or weekly recurrence, each time starts a working slot
+    pub schedule: ScheduleId,
+    pub slot_minutes: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl HandymanWorkingHours {
+    /// Set the working hours of a handyman, replacing the existing ones.
+    pub async fn set(
+        actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        NewHandymanWorkingHours {
+            schedule,
+            slot_minutes,
+        }: NewHandymanWorkingHours,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_handyman_access(handyman_id)?;
+        if matches!(schedule.variant, NewScheduleVariant::FixedTime(_)) {
+            return Err(invalid_working_hours_error(
+                "Working hours must recur daily or weekly",
+                "schedule",
+                "FIXED_TIME",
+            ));
+        }
+        if !(1..=MINUTES_PER_DAY).contains(&slot_minutes) {
+            return Err(invalid_working_hours_error(
+                format!("Slot must last between 1 and {MINUTES_PER_DAY} minutes"),
+                "slot_minutes",
+                "OUT_OF_RANGE",
+            ));
+        }
+
+        let replaced_schedule = handyman_working_hours::table
+            .find(handyman_id)
+            .select(handyman_working_hours::schedule)
+            .for_update()
+            .first::<ScheduleId>(conn)
+            .await
+            .optional()?;
+        let schedule = Schedule::create(actor_auth, schedule, conn).await?;
+
+        let result = diesel::insert_into(handyman_working_hours::table)
+            .values((
+                handyman_working_hours::handyman_id.eq(handyman_id),
+                handyman_working_hours::schedule.eq(schedule.base.id),
+                handyman_working_hours::slot_minutes.eq(slot_minutes),
+            ))
+            .on_conflict(handyman_working_hours::handyman_id)
+            .do_update()
+            .set((
+                handyman_working_hours::schedule.eq(excluded(handyman_working_hours::schedule)),
+                handyman_working_hours::slot_minutes
+                    .eq(excluded(handyman_working_hours::slot_minutes)),
+            ))
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await?;
+
+        if let Some(replaced_schedule) = replaced_schedule {
+            ScheduleBase::delete(replaced_schedule, conn).await?;
+        }
+
+        Ok(result)
+    }
+
+    /// This API requires god or admin or any session actor.
+    pub async fn get_by_handyman(
+        _actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Option<Self>> {
+        let result = handyman_working_hours::table
+            .find(handyman_id)
+            .select(Self::as_select())
+            .first::<Self>(conn)
+            .await
+            .optional()?;
+
+        Ok(result)
+    }
+
+    /// Weekly working slots of the handyman along with the time zone they are wall clock times of,
+    /// empty slots in the default time zone without working hours.
+    /// Slots crossing midnight are split, overlapping slots are merged.
+    pub async fn load_working_slots(
+        actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<(Tz, Vec<WorkingSlot>)> {
+        let Some(working_hours) = Self::get_by_handyman(actor_auth, handyman_id, conn).await?
+        else {
+            return Ok((DEFAULT_SCHEDULE_TIMEZONE, Vec::new()));
+        };
+        let schedule = Schedule::load_by_ids(actor_auth, &[working_hours.schedule], conn)
+            .await?
+            .pop()
+            .ok_or_else(|| Error::internal("Working hours must have a schedule"))?;
+
+        Ok((schedule.timezone, working_hours.working_slots(&schedule)))
+    }
+
+    /// Expand the schedule of the working hours into weekly working slots, in wall clock time of
+    /// the schedule time zone.
+    pub fn working_slots(&self, schedule: &Schedule) -> Vec<WorkingSlot> {
+        let slot_starts: Vec<(chrono::Weekday, NaiveTime)> = match &schedule.variant {
+            ScheduleVariant::FixedTime(_) => Vec::new(),
+            ScheduleVariant::DailyRecurrence(v) => {
+                std::iter::successors(Some(chrono::Weekday::Mon), |d| Some(d.succ()))
+                    .take(7)
+                    .flat_map(|weekday| v.times.iter().map(move |time| (weekday, *time)))
+                    .collect()
+            }
+            ScheduleVariant::WeeklyRecurrence(v) => v
+                .weekday_times
+                .iter()
+                .flat_map(|w| {
+                    let weekday = chrono::Weekday::from(w.weekday);
+                    w.times.iter().map(move |time| (weekday, *time))
+                })
+                .collect(),
+        };
+
+        let mut ranges = Vec::with_capacity(slot_starts.len());
+        for (weekday, time) in slot_starts {
+            let start_minute = (time.num_seconds_from_midnight() / 60) as i32;
+            let end_minute = start_minute + self.slot_minutes;
+            ranges.push((weekday, start_minute, end_minute.min(MINUTES_PER_DAY)));
+            if end_minute > MINUTES_PER_DAY {
+                ranges.push((weekday.succ(), 0, end_minute - MINUTES_PER_DAY));
+            }
+        }
+        ranges.sort_by_key(|(weekday, start_minute, _)| {
+            (weekday.num_days_from_monday(), *start_minute)
+        });
+
+        let mut slots: Vec<WorkingSlot> = Vec::with_capacity(ranges.len());
+        for (weekday, start_minute, end_minute) in ranges {
+            let weekday = Weekday::from(weekday);
+            match slots.last_mut() {
+                Some(last) if last.weekday == weekday && start_minute <= last.end_minute => {
+                    last.end_minute = last.end_minute.max(end_minute);
+                }
+                _ => slots.push(WorkingSlot {
+                    weekday,
+                    start_minute,
+                    end_minute,
+                }),
+            }
+        }
+        slots
+    }
+}
+
+#[derive(Debug)]
+pub struct NewHandymanWorkingHours {
+    pub schedule: NewSchedule,
+    pub slot_minutes: i32,
+}
+
+/// Weekly working slot covering minutes `[start_minute, end_minute)` since midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkingSlot {
+    pub weekday: Weekday,
+    pub start_minute: i32,
+    pub end_minute: i32,
+}
+
+fn invalid_working_hours_error(
+    message: impl Into<String>,
+    field: &str,
+    description: &str,
+) -> Error {
+    Error::invalid_argument_with(
+        message,
+        Some(BadRequest {
+            field_violations: vec![FieldViolation {
+                field: field.into(),
+                description: description.into(),
+            }],
+        }),
+    )
+}
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = handyman_blackout)]
+pub struct HandymanBlackout {
+    pub handyman_id: HandymanId,
+    pub date: NaiveDate,
+    pub note: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl HandymanBlackout {
+    /// Mark a date as day off of a handyman, the note of an existing day off is replaced.
+    pub async fn upsert(
+        actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        NewHandymanBlackout { date, note }: NewHandymanBlackout<'_>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        let result = diesel::insert_into(handyman_blackout::table)
+            .values((
+                handyman_blackout::handyman_id.eq(handyman_id),
+                handyman_blackout::date.eq(date),
+                handyman_blackout::note.eq(note),
+            ))
+            .on_conflict((handyman_blackout::handyman_id, handyman_blackout::date))
+            .do_update()
+            .set(handyman_blackout::note.eq(excluded(handyman_blackout::note)))
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn delete(
+        actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        date: NaiveDate,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Option<Self>> {
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        let result = diesel::delete(handyman_blackout::table.find((handyman_id, date)))
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await
+            .optional()?;
+
+        Ok(result)
+    }
+
+    /// Days off of a handyman, in date order.
+    /// This API requires god or admin or any session actor.
+    pub async fn get_by_handyman(
+        _actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let result = handyman_blackout::table
+            .filter(handyman_blackout::handyman_id.eq(handyman_id))
+            .select(Self::as_select())
+            .order(handyman_blackout::date)
+            .load::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+}
+
+#[derive(Debug)]
+pub struct NewHandymanBlackout<'a> {
+    pub date: NaiveDate,
+    pub note: Option<&'a str>,
+}
@@ -0,0 +1,268 @@
+use crate::{
+    schema::{customer_task_request, handyman_review},
+    unexpected_status_error,
+};
+use actor_auth::ActorAuth;
+use chrono::NaiveDateTime;
+use db_utils::AsyncPgConnection;
+use diesel::{dsl, prelude::*};
+use diesel_async::RunQueryDsl;
+use entity_type::{
+    CustomerId, CustomerTaskRequestId, CustomerTaskStatus, HandymanId, HandymanReviewId,
+};
+use error::{
+    Error, Result,
+    error_details::{BadRequest, bad_request::FieldViolation},
+};
+
+pub const MIN_RATING: i16 = 1;
+pub const MAX_RATING: i16 = 5;
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = handyman_review)]
+pub struct HandymanReview {
+    pub id: HandymanReviewId,
+    pub task_id: CustomerTaskRequestId,
+    pub customer_id: CustomerId,
+    pub handyman_id: HandymanId,
+    pub rating: i16,
+    pub comment: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl HandymanReview {
+    /// Customer reviews the handyman who completed their task.
+    pub async fn create(
+        actor_auth: &ActorAuth,
+        NewHandymanReview {
+            task_id,
+            rating,
+            comment,
+        }: NewHandymanReview<'_>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        if !(MIN_RATING..=MAX_RATING).contains(&rating) {
+            return Err(Error::invalid_argument_with(
+                format!("Rating must be between {MIN_RATING} and {MAX_RATING}"),
+                Some(BadRequest {
+                    field_violations: vec![FieldViolation {
+                        field: "rating".into(),
+                        description: "OUT_OF_RANGE".into(),
+                    }],
+                }),
+            ));
+        }
+
+        let (customer_id, status, assigned_handyman_id) = customer_task_request::table
+            .filter(customer_task_request::id.eq(task_id))
+            .select((
+                customer_task_request::customer_id,
+                customer_task_request::status,
+                customer_task_request::assigned_handyman_id,
+            ))
+            .for_share()
+            .first::<(CustomerId, CustomerTaskStatus, Option<HandymanId>)>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Customer task request not found"))?;
+        actor_auth.require_customer_access(customer_id)?;
+
+        if status != CustomerTaskStatus::Completed {
+            return Err(unexpected_status_error("task_status", status));
+        }
+        let handyman_id = assigned_handyman_id
+            .ok_or_else(|| Error::internal("Completed task must have an assigned handyman"))?;
+
+        let result = diesel::insert_into(handyman_review::table)
+            .values((
+                handyman_review::task_id.eq(task_id),
+                handyman_review::customer_id.eq(customer_id),
+                handyman_review::handyman_id.eq(handyman_id),
+                handyman_review::rating.eq(rating),
+                handyman_review::comment.eq(comment),
+            ))
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// This API requires god or admin or any session actor.
+    pub async fn load_by_ids(
+        _actor_auth: &ActorAuth,
+        ids: &[HandymanReviewId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let result = handyman_review::table
+            .filter(handyman_review::id.eq_any(ids))
+            .select(Self::as_select())
+            .load::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Reviews of a handyman, newest first.
+    /// This API requires god or admin or any session actor.
+    pub async fn get_by_handyman(
+        _actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let result = handyman_review::table
+            .filter(handyman_review::handyman_id.eq(handyman_id))
+            .select(Self::as_select())
+            .order((handyman_review::created_at.desc(), handyman_review::id))
+            .load::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Aggregated rating of a handyman.
+    /// This API requires god or admin or any session actor.
+    pub async fn rating_summary(
+        _actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<HandymanRatingSummary> {
+        let (rating_sum, review_count) = handyman_review::table
+            .filter(handyman_review::handyman_id.eq(handyman_id))
+            .select((dsl::sum(handyman_review::rating), dsl::count_star()))
+            .first::<(Option<i64>, i64)>(conn)
+            .await?;
+
+        Ok(HandymanRatingSummary {
+            rating_sum: rating_sum.unwrap_or(0),
+            review_count,
+        })
+    }
+
+    /// Aggregated ratings of handymen, handymen without any review are left out.
+    /// This API requires god or admin or any session actor.
+    pub async fn rating_summaries(
+        _actor_auth: &ActorAuth,
+        handyman_ids: &[HandymanId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<(HandymanId, HandymanRatingSummary)>> {
+        let result = handyman_review::table
+            .filter(handyman_review::handyman_id.eq_any(handyman_ids))
+            .group_by(handyman_review::handyman_id)
+            .select((
+                handyman_review::handyman_id,
+                dsl::sum(handyman_review::rating),
+                dsl::count_star(),
+            ))
+            .load::<(HandymanId, Option<i64>, i64)>(conn)
+            .await?;
+
+        Ok(result
+            .into_iter()
+            .map(|(handyman_id, rating_sum, review_count)| {
+                (
+                    handyman_id,
+                    HandymanRatingSummary {
+                        rating_sum: rating_sum.unwrap_or(0),
+                        review_count,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// Aggregated rating of a handyman together with the reviews not yet reflected in search index.
+    /// The reviews are read before the summary, so the summary covers all of them
+    /// and they can be marked as indexed once the summary is indexed.
+    /// This API requires god or admin or any session actor.
+    pub async fn rating_snapshot(
+        actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<HandymanRatingSnapshot> {
+        let unindexed_review_ids = handyman_review::table
+            .filter(handyman_review::handyman_id.eq(handyman_id))
+            .filter(handyman_review::rating_indexed.eq(false))
+            .select(handyman_review::id)
+            .load::<HandymanReviewId>(conn)
+            .await?;
+        let summary = Self::rating_summary(actor_auth, handyman_id, conn).await?;
+
+        Ok(HandymanRatingSnapshot {
+            summary,
+            unindexed_review_ids,
+        })
+    }
+
+    /// Mark reviews as reflected in search index.
+    /// This API requires god or admin.
+    pub async fn mark_rating_indexed(
+        actor_auth: &ActorAuth,
+        review_ids: &[HandymanReviewId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<()> {
+        actor_auth.require_god_or_admin()?;
+
+        diesel::update(handyman_review::table.filter(handyman_review::id.eq_any(review_ids)))
+            .set(handyman_review::rating_indexed.eq(true))
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Handymen having reviews not yet reflected in search index.
+    /// This API requires god or admin.
+    pub async fn rating_unindexed_handyman_ids(
+        actor_auth: &ActorAuth,
+        limit: i64,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<HandymanId>> {
+        actor_auth.require_god_or_admin()?;
+
+        let result = handyman_review::table
+            .filter(handyman_review::rating_indexed.eq(false))
+            .select(handyman_review::handyman_id)
+            .distinct()
+            .limit(limit)
+            .load::<HandymanId>(conn)
+            .await?;
+
+        Ok(result)
+    }
+}
+
+/// The default is the summary of a handyman without any review
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HandymanRatingSummary {
+    pub rating_sum: i64,
+    pub review_count: i64,
+}
+
+impl HandymanRatingSummary {
+    /// Average rating in stars, `None` if the handyman has no review.
+    pub fn avg_rating(&self) -> Option<f64> {
+        (self.review_count > 0).then(|| self.rating_sum as f64 / self.review_count as f64)
+    }
+
+    /// Average rating scaled by 100 and rounded, e.g. 4.25 stars is 425.
+    /// This is the format of `avg_rating_score` in search index.
+    pub fn avg_rating_score(&self) -> Option<i16> {
+        (self.review_count > 0)
+            .then(|| ((self.rating_sum * 100 + self.review_count / 2) / self.review_count) as i16)
+    }
+}
+
+#[derive(Debug)]
+pub struct HandymanRatingSnapshot {
+    pub summary: HandymanRatingSummary,
+    pub unindexed_review_ids: Vec<HandymanReviewId>,
+}
+
+#[derive(Debug)]
+pub struct NewHandymanReview<'a> {
+    pub task_id: CustomerTaskRequestId,
+    pub rating: i16,
+    pub comment: Option<&'a str>,
+}
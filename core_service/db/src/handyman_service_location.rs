@@ -0,0 +1,95 @@
+use crate::{NewLocation, schema::handyman_service_location};
+use actor_auth::ActorAuth;
+use chrono::NaiveDateTime;
+use db_utils::AsyncPgConnection;
+use diesel::{prelude::*, upsert::excluded};
+use diesel_async::RunQueryDsl;
+use entity_type::HandymanId;
+use error::Result;
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = handyman_service_location)]
+pub struct HandymanServiceLocation {
+    pub handyman_id: HandymanId,
+    pub city: String,
+    pub address_line1: String,
+    pub formatted_address: String,
+    pub lon: f64,
+    pub lat: f64,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl HandymanServiceLocation {
+    /// Set the service location of a handyman, replacing the existing one.
+    pub async fn upsert(
+        actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        NewLocation {
+            city,
+            address_line1,
+            formatted_address,
+            lon,
+            lat,
+        }: NewLocation,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        let result = diesel::insert_into(handyman_service_location::table)
+            .values((
+                handyman_service_location::handyman_id.eq(handyman_id),
+                handyman_service_location::city.eq(city),
+                handyman_service_location::address_line1.eq(address_line1),
+                handyman_service_location::formatted_address.eq(formatted_address),
+                handyman_service_location::lon.eq(lon),
+                handyman_service_location::lat.eq(lat),
+            ))
+            .on_conflict(handyman_service_location::handyman_id)
+            .do_update()
+            .set((
+                handyman_service_location::city.eq(excluded(handyman_service_location::city)),
+                handyman_service_location::address_line1
+                    .eq(excluded(handyman_service_location::address_line1)),
+                handyman_service_location::formatted_address
+                    .eq(excluded(handyman_service_location::formatted_address)),
+                handyman_service_location::lon.eq(excluded(handyman_service_location::lon)),
+                handyman_service_location::lat.eq(excluded(handyman_service_location::lat)),
+            ))
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// This API requires god or admin or any session actor.
+    pub async fn get_by_handyman(
+        _actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Option<Self>> {
+        let result = handyman_service_location::table
+            .find(handyman_id)
+            .select(Self::as_select())
+            .first::<Self>(conn)
+            .await
+            .optional()?;
+
+        Ok(result)
+    }
+
+    /// Remove the service location of a deleted handyman.
+    pub async fn delete_by_handyman(
+        actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<()> {
+        actor_auth.require_god_or_admin()?;
+
+        diesel::delete(handyman_service_location::table.find(handyman_id))
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
+}
@@ -1,21 +1,22 @@
-use crate::schema::handyman_service;
+use crate::{
+    CatalogService,
+    schema::{catalog_service, handyman_service},
+};
 use actor_auth::ActorAuth;
 use chrono::NaiveDateTime;
 use db_utils::AsyncPgConnection;
 use diesel::{dsl::exists, prelude::*};
 use diesel_async::RunQueryDsl;
-use entity_type::{
-    HandymanAccessGuardId, HandymanId, HandymanServiceId, ServiceLayer1, ServiceLayer2,
-};
+use entity_type::{HandymanAccessGuardId, HandymanId, HandymanServiceId};
 use error::{Error, Result};
-use std::ops::Deref;
 
 #[derive(Debug, Queryable, Selectable)]
 #[diesel(table_name = handyman_service)]
 pub struct HandymanService {
     pub id: HandymanServiceId,
     pub handyman_id: HandymanId,
-    pub service: ServiceLayer2,
+    /// Code of a [`CatalogService`]
+    pub service: String,
     pub note: Option<String>,
     pub rate_vnd: Option<i32>,
     pub created_at: NaiveDateTime,
@@ -29,6 +30,14 @@ impl HandymanService {
         conn: &mut AsyncPgConnection,
     ) -> Result<Vec<Self>> {
         actor_auth.require_handyman_access(handyman_id)?;
+        CatalogService::require_active(
+            &new_records
+                .iter()
+                .map(|record| record.service)
+                .collect::<Vec<_>>(),
+            conn,
+        )
+        .await?;
 
         let results = diesel::insert_into(handyman_service::table)
             .values(
@@ -45,7 +54,7 @@ impl HandymanService {
         Ok(results)
     }
 
-    /// Returns list of service belonging to a handyman.
+    /// Returns list of service belonging to a handyman, along with the code of their group.
     /// This API requires god or admin or any session actor.
     pub async fn get_by_handyman(
         _actor_auth: &ActorAuth,
@@ -53,10 +62,11 @@ impl HandymanService {
         conn: &mut AsyncPgConnection,
     ) -> Result<HandymanServiceList> {
         let result = handyman_service::table
+            .inner_join(catalog_service::table)
             .filter(handyman_service::handyman_id.eq(handyman_id))
-            .select(Self::as_select())
-            .order(handyman_service::service)
-            .load::<Self>(conn)
+            .select((catalog_service::group_code, Self::as_select()))
+            .order((catalog_service::group_code, handyman_service::service))
+            .load::<(String, Self)>(conn)
             .await?;
 
         Ok(HandymanServiceList(result))
@@ -111,9 +121,27 @@ impl HandymanService {
         Ok(records)
     }
 
+    /// Remove every service of a deleted handyman.
+    pub async fn delete_by_handyman(
+        actor_auth: &ActorAuth,
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        actor_auth.require_god_or_admin()?;
+
+        let records = diesel::delete(
+            handyman_service::table.filter(handyman_service::handyman_id.eq(handyman_id)),
+        )
+        .returning(Self::as_returning())
+        .get_results::<Self>(conn)
+        .await?;
+
+        Ok(records)
+    }
+
     pub async fn handyman_service_exists(
         handyman_id: HandymanId,
-        service: ServiceLayer2,
+        service: &str,
         conn: &mut AsyncPgConnection,
     ) -> Result<bool> {
         diesel::select(exists(
@@ -132,7 +160,8 @@ impl HandymanService {
 #[derive(Debug, Insertable)]
 #[diesel(table_name = handyman_service)]
 pub struct NewHandymanService<'a> {
-    pub service: ServiceLayer2,
+    /// Code of an active [`CatalogService`]
+    pub service: &'a str,
     pub note: Option<&'a str>,
     pub rate_vnd: Option<i32>,
 }
@@ -144,26 +173,31 @@ pub struct HandymanServiceChangeset<'a> {
     pub rate_vnd: Option<Option<i32>>,
 }
 
-pub struct HandymanServiceList(Vec<HandymanService>);
+/// Services of a handyman sorted by the code of their group
+pub struct HandymanServiceList(Vec<(String, HandymanService)>);
 
-impl Deref for HandymanServiceList {
-    type Target = Vec<HandymanService>;
+impl HandymanServiceList {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    pub fn iter(&self) -> impl Iterator<Item = &HandymanService> {
+        self.0.iter().map(|(_, service)| service)
     }
-}
 
-impl HandymanServiceList {
     pub fn into_group(self) -> Vec<HandymanServiceGroup> {
-        db_utils::group_by(self.0.into_iter().map(|e| (e.service.layer1(), e)))
+        db_utils::group_by(self.0)
             .into_iter()
-            .map(|(group, services)| HandymanServiceGroup { group, services })
+            .map(|(group_code, services)| HandymanServiceGroup {
+                group_code,
+                services,
+            })
             .collect()
     }
 }
 
 pub struct HandymanServiceGroup {
-    pub group: ServiceLayer1,
+    /// Code of a [`crate::CatalogServiceGroup`]
+    pub group_code: String,
     pub services: Vec<HandymanService>,
 }
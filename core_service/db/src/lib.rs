@@ -3,11 +3,41 @@ pub mod schema;
 mod migrations;
 pub use migrations::*;
 
+mod location;
+pub use location::*;
+
+mod service_catalog;
+pub use service_catalog::*;
+
+mod service_rate_stat;
+pub use service_rate_stat::*;
+
 mod handymand_service;
 pub use handymand_service::*;
 
+mod handyman_service_location;
+pub use handyman_service_location::*;
+
+mod handyman_availability;
+pub use handyman_availability::*;
+
 mod schedule;
 pub use schedule::*;
 
 mod customer_task_request;
 pub use customer_task_request::*;
+
+mod task_quote;
+pub use task_quote::*;
+
+mod customer_task_photo;
+pub use customer_task_photo::*;
+
+mod handyman_review;
+pub use handyman_review::*;
+
+mod otp_code;
+pub use otp_code::*;
+
+mod zalo_oauth_token;
+pub use zalo_oauth_token::*;
@@ -0,0 +1,9 @@
+/// Address and coordinates of a place, flattened into `<prefix>_*` columns of the owning table.
+#[derive(Debug)]
+pub struct NewLocation {
+    pub city: String,
+    pub address_line1: String,
+    pub formatted_address: String,
+    pub lon: f64,
+    pub lat: f64,
+}
@@ -0,0 +1,149 @@
+//! Postgres-backed [`OtpStore`], so that pending codes survive restarts
+//! and are shared among replicas.
+
+use crate::schema::otp_code;
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use db_utils::{AsyncPgConnection, PgConnectionPool, with_mutable_db};
+use diesel::{prelude::*, upsert::excluded};
+use diesel_async::RunQueryDsl;
+use error::Result;
+use otp_store::{OtpKey, OtpPolicy, OtpRecord, OtpStore, OtpVerification};
+use scoped_futures::ScopedFutureExt;
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = otp_code)]
+struct OtpCodeRow {
+    code: Option<String>,
+    expires_at: NaiveDateTime,
+    failed_attempts: i32,
+    last_sent_at: NaiveDateTime,
+    locked_until: Option<NaiveDateTime>,
+}
+
+impl From<OtpCodeRow> for OtpRecord {
+    fn from(
+        OtpCodeRow {
+            code,
+            expires_at,
+            failed_attempts,
+            last_sent_at,
+            locked_until,
+        }: OtpCodeRow,
+    ) -> Self {
+        Self {
+            code,
+            expires_at,
+            failed_attempts,
+            last_sent_at,
+            locked_until,
+        }
+    }
+}
+
+pub struct PgOtpStore {
+    db_connection_pool: PgConnectionPool,
+    policy: OtpPolicy,
+}
+
+impl PgOtpStore {
+    pub fn new(db_connection_pool: PgConnectionPool, policy: OtpPolicy) -> Self {
+        Self {
+            db_connection_pool,
+            policy,
+        }
+    }
+
+    async fn lock_record(key: &OtpKey, conn: &mut AsyncPgConnection) -> Result<Option<OtpRecord>> {
+        let row = otp_code::table
+            .filter(otp_code::purpose.eq(key.purpose.as_str()))
+            .filter(otp_code::account_type.eq(key.account_type))
+            .filter(otp_code::e164_phone_number.eq(&key.e164_phone_number_str))
+            .select(OtpCodeRow::as_select())
+            .for_update()
+            .first::<OtpCodeRow>(conn)
+            .await
+            .optional()?;
+
+        Ok(row.map(OtpRecord::from))
+    }
+
+    async fn save_record(
+        key: &OtpKey,
+        record: &OtpRecord,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<()> {
+        diesel::insert_into(otp_code::table)
+            .values((
+                otp_code::purpose.eq(key.purpose.as_str()),
+                otp_code::account_type.eq(key.account_type),
+                otp_code::e164_phone_number.eq(&key.e164_phone_number_str),
+                otp_code::code.eq(&record.code),
+                otp_code::expires_at.eq(record.expires_at),
+                otp_code::failed_attempts.eq(record.failed_attempts),
+                otp_code::last_sent_at.eq(record.last_sent_at),
+                otp_code::locked_until.eq(record.locked_until),
+            ))
+            .on_conflict((
+                otp_code::purpose,
+                otp_code::account_type,
+                otp_code::e164_phone_number,
+            ))
+            .do_update()
+            .set((
+                otp_code::code.eq(excluded(otp_code::code)),
+                otp_code::expires_at.eq(excluded(otp_code::expires_at)),
+                otp_code::failed_attempts.eq(excluded(otp_code::failed_attempts)),
+                otp_code::last_sent_at.eq(excluded(otp_code::last_sent_at)),
+                otp_code::locked_until.eq(excluded(otp_code::locked_until)),
+            ))
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OtpStore for PgOtpStore {
+    fn policy(&self) -> &OtpPolicy {
+        &self.policy
+    }
+
+    async fn issue(&self, key: &OtpKey, code: &str) -> Result<()> {
+        let now = Utc::now().naive_utc();
+        let policy = &self.policy;
+
+        with_mutable_db(&self.db_connection_pool, |conn| {
+            async move {
+                let existing = Self::lock_record(key, conn).await?;
+                let record = OtpRecord::issue(existing.as_ref(), code, now, policy)?;
+                Self::save_record(key, &record, conn).await
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    async fn verify(&self, key: &OtpKey, code: &str) -> Result<()> {
+        let now = Utc::now().naive_utc();
+        let policy = &self.policy;
+
+        // Failed attempts must be committed, so the verification outcome is
+        // turned into an error only after the transaction.
+        let verification = with_mutable_db(&self.db_connection_pool, |conn| {
+            async move {
+                let Some(mut record) = Self::lock_record(key, conn).await? else {
+                    return Ok(OtpVerification::Expired);
+                };
+                let verification = record.verify(code, now, policy);
+                Self::save_record(key, &record, conn).await?;
+                Ok(verification)
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        verification.into_result(now)
+    }
+}
@@ -27,6 +27,18 @@ impl ScheduleDailyRecurrence {
             .await
             .map_err(Error::from)
     }
+
+    pub(crate) async fn load_by_ids(
+        ids: &[ScheduleId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        schedule_daily_recurrence::table
+            .filter(schedule_daily_recurrence::id.eq_any(ids))
+            .select(Self::as_select())
+            .load::<Self>(conn)
+            .await
+            .map_err(Error::from)
+    }
 }
 
 #[derive(Debug, Insertable)]
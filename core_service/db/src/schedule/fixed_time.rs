@@ -1,5 +1,5 @@
 use crate::schema::schedule_fixed_time;
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use db_utils::AsyncPgConnection;
 use diesel::prelude::*;
 use diesel_async::RunQueryDsl;
@@ -11,7 +11,7 @@ use error::{Error, Result};
 pub struct ScheduleFixedTime {
     pub id: ScheduleId,
     pub schedule_type: ScheduleType,
-    pub time: NaiveDateTime,
+    pub time: DateTime<Utc>,
     pub updated_at: NaiveDateTime,
 }
 
@@ -27,10 +27,22 @@ impl ScheduleFixedTime {
             .await
             .map_err(Error::from)
     }
+
+    pub(crate) async fn load_by_ids(
+        ids: &[ScheduleId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        schedule_fixed_time::table
+            .filter(schedule_fixed_time::id.eq_any(ids))
+            .select(Self::as_select())
+            .load::<Self>(conn)
+            .await
+            .map_err(Error::from)
+    }
 }
 
 #[derive(Debug, Insertable)]
 #[diesel(table_name = schedule_fixed_time)]
 pub struct NewFixedTimeSchedule {
-    pub time: NaiveDateTime,
+    pub time: DateTime<Utc>,
 }
@@ -9,3 +9,6 @@ pub use daily_recurrence::*;
 
 mod weekly_recurrence;
 pub use weekly_recurrence::*;
+
+mod occurrence;
+pub use occurrence::*;
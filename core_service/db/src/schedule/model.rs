@@ -4,11 +4,13 @@ use crate::{
 };
 use actor_auth::ActorAuth;
 use chrono::NaiveDateTime;
+use chrono_tz::Tz;
 use db_utils::AsyncPgConnection;
 use diesel::prelude::*;
 use diesel_async::RunQueryDsl;
 use entity_type::{ScheduleId, ScheduleType};
 use error::{Error, Result};
+use std::collections::HashMap;
 
 #[derive(Debug, Queryable, Selectable)]
 #[diesel(table_name = schedule)]
@@ -16,19 +18,33 @@ pub struct ScheduleBase {
     pub id: ScheduleId,
     pub schedule_type: ScheduleType,
     pub created_at: NaiveDateTime,
+    /// IANA time zone name, see [`Schedule::timezone`]
+    pub timezone: String,
 }
 
 impl ScheduleBase {
     pub(crate) async fn create(
         schedule_type: ScheduleType,
+        timezone: Tz,
         conn: &mut AsyncPgConnection,
     ) -> Result<Self> {
         diesel::insert_into(schedule::table)
-            .values(schedule::schedule_type.eq(schedule_type))
+            .values((
+                schedule::schedule_type.eq(schedule_type),
+                schedule::timezone.eq(timezone.name()),
+            ))
             .get_result::<Self>(conn)
             .await
             .map_err(Error::from)
     }
+
+    /// Delete a schedule no longer referenced, its time rule is deleted by cascade.
+    pub(crate) async fn delete(id: ScheduleId, conn: &mut AsyncPgConnection) -> Result<()> {
+        diesel::delete(schedule::table.find(id))
+            .execute(conn)
+            .await?;
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -41,6 +57,8 @@ pub enum ScheduleVariant {
 #[derive(Debug)]
 pub struct Schedule {
     pub base: ScheduleBase,
+    /// Time zone the recurrence times are wall clock times of, parsed from `base.timezone`
+    pub timezone: Tz,
     pub variant: ScheduleVariant,
 }
 
@@ -48,16 +66,17 @@ impl Schedule {
     /// Create a new schedule. Requires authenticated session.
     pub async fn create(
         _actor_auth: &ActorAuth,
-        new: NewScheduleVariant,
+        NewSchedule { timezone, variant }: NewSchedule,
         conn: &mut AsyncPgConnection,
     ) -> Result<Self> {
-        let base = ScheduleBase::create(new.schedule_type(), conn).await?;
+        let base = ScheduleBase::create(variant.schedule_type(), timezone, conn).await?;
 
-        let result = match new {
+        let result = match variant {
             NewScheduleVariant::FixedTime(fixed_time) => {
                 let variant = ScheduleFixedTime::create(base.id, fixed_time, conn).await?;
                 Schedule {
                     base,
+                    timezone,
                     variant: ScheduleVariant::FixedTime(variant),
                 }
             }
@@ -66,6 +85,7 @@ impl Schedule {
                     ScheduleDailyRecurrence::create(base.id, daily_recurrence, conn).await?;
                 Schedule {
                     base,
+                    timezone,
                     variant: ScheduleVariant::DailyRecurrence(variant),
                 }
             }
@@ -74,6 +94,7 @@ impl Schedule {
                     ScheduleWeeklyRecurrence::create(base.id, weekly_recurrence, conn).await?;
                 Schedule {
                     base,
+                    timezone,
                     variant: ScheduleVariant::WeeklyRecurrence(variant),
                 }
             }
@@ -81,6 +102,76 @@ impl Schedule {
 
         Ok(result)
     }
+
+    /// Load schedules with their time rule. Missing ids are omitted from the result.
+    pub async fn load_by_ids(
+        _actor_auth: &ActorAuth,
+        ids: &[ScheduleId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let bases = schedule::table
+            .filter(schedule::id.eq_any(ids))
+            .select(ScheduleBase::as_select())
+            .load::<ScheduleBase>(conn)
+            .await?;
+
+        let ids_of = |schedule_type: ScheduleType| {
+            bases
+                .iter()
+                .filter(|b| b.schedule_type == schedule_type)
+                .map(|b| b.id)
+                .collect::<Vec<_>>()
+        };
+        let fixed_time_ids = ids_of(ScheduleType::FixedTime);
+        let daily_recurrence_ids = ids_of(ScheduleType::DailyRecurrence);
+        let weekly_recurrence_ids = ids_of(ScheduleType::WeeklyRecurrence);
+
+        let mut variants = HashMap::with_capacity(bases.len());
+        if !fixed_time_ids.is_empty() {
+            for v in ScheduleFixedTime::load_by_ids(&fixed_time_ids, conn).await? {
+                variants.insert(v.id, ScheduleVariant::FixedTime(v));
+            }
+        }
+        if !daily_recurrence_ids.is_empty() {
+            for v in ScheduleDailyRecurrence::load_by_ids(&daily_recurrence_ids, conn).await? {
+                variants.insert(v.id, ScheduleVariant::DailyRecurrence(v));
+            }
+        }
+        if !weekly_recurrence_ids.is_empty() {
+            for v in ScheduleWeeklyRecurrence::load_by_ids(&weekly_recurrence_ids, conn).await? {
+                variants.insert(v.schedule_id, ScheduleVariant::WeeklyRecurrence(v));
+            }
+        }
+
+        bases
+            .into_iter()
+            .map(|base| {
+                let variant = variants.remove(&base.id).ok_or_else(|| {
+                    Error::internal(format!("Schedule {:?} is missing its time rule", base.id))
+                })?;
+                let timezone = base.timezone.parse::<Tz>().map_err(|_| {
+                    Error::internal(format!(
+                        "Schedule {:?} has unknown time zone {}",
+                        base.id, base.timezone
+                    ))
+                })?;
+                Ok(Schedule {
+                    base,
+                    timezone,
+                    variant,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Time zone of schedules entered without one, all customers were in Việt Nam at first.
+pub const DEFAULT_SCHEDULE_TIMEZONE: Tz = Tz::Asia__Ho_Chi_Minh;
+
+#[derive(Debug)]
+pub struct NewSchedule {
+    pub timezone: Tz,
+    pub variant: NewScheduleVariant,
 }
 
 #[derive(Debug)]
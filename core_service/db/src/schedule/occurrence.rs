@@ -0,0 +1,393 @@
+use crate::{Schedule, ScheduleVariant};
+use chrono::{
+    DateTime, Datelike, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta,
+    TimeZone, Utc,
+};
+use chrono_tz::Tz;
+use entity_type::Weekday;
+use std::collections::VecDeque;
+
+impl Schedule {
+    /// Occurrences at or after `from`, in time order. Recurrences never end.
+    pub fn occurrences_from(&self, from: DateTime<Utc>) -> Occurrences<'_> {
+        let (pending, done) = match &self.variant {
+            ScheduleVariant::FixedTime(v) => (
+                (v.time >= from)
+                    .then(|| v.time.with_timezone(&self.timezone))
+                    .into_iter()
+                    .collect(),
+                true,
+            ),
+            ScheduleVariant::DailyRecurrence(v) => (VecDeque::new(), v.times.is_empty()),
+            ScheduleVariant::WeeklyRecurrence(v) => (
+                VecDeque::new(),
+                v.weekday_times.iter().all(|w| w.times.is_empty()),
+            ),
+        };
+        // Start a day early, a skipped time of the day before may be shifted past `from`
+        let first_day = from.with_timezone(&self.timezone).date_naive();
+
+        Occurrences {
+            schedule: self,
+            from,
+            day: first_day.pred_opt().or(Some(first_day)),
+            pending,
+            done,
+        }
+    }
+
+    /// At most `limit` occurrences at or after `from`.
+    pub fn next_occurrences(&self, from: DateTime<Utc>, limit: usize) -> Vec<DateTime<Tz>> {
+        self.occurrences_from(from).take(limit).collect()
+    }
+
+    /// Occurrences within `[start, end)`.
+    pub fn occurrences_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<DateTime<Tz>> {
+        self.occurrences_from(start)
+            .take_while(|t| *t < end)
+            .collect()
+    }
+
+    /// First pair of occurrences, one of each schedule, starting within `[start, end)` whose
+    /// time slots intersect. Each occurrence of a schedule lasts the duration given with it.
+    pub fn first_overlap(
+        &self,
+        duration: TimeDelta,
+        other: &Schedule,
+        other_duration: TimeDelta,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Option<(DateTime<Tz>, DateTime<Tz>)> {
+        let mut occurrences = self.occurrences_from(start).take_while(|t| *t < end);
+        let mut other_occurrences = other.occurrences_from(start).take_while(|t| *t < end);
+        let mut current = occurrences.next()?;
+        let mut other_current = other_occurrences.next()?;
+
+        loop {
+            if current < other_current + other_duration && other_current < current + duration {
+                return Some((current, other_current));
+            }
+            // The slot ending first can't intersect any later slot of the other schedule
+            if current + duration <= other_current {
+                current = occurrences.next()?;
+            } else {
+                other_current = other_occurrences.next()?;
+            }
+        }
+    }
+}
+
+impl ScheduleVariant {
+    /// Wall clock times the recurrence occurs at on the date.
+    fn recurrence_times_on(&self, date: NaiveDate) -> Vec<NaiveTime> {
+        match self {
+            ScheduleVariant::FixedTime(_) => Vec::new(),
+            ScheduleVariant::DailyRecurrence(v) => v.times.clone(),
+            ScheduleVariant::WeeklyRecurrence(v) => {
+                let weekday = Weekday::from(date.weekday());
+                v.weekday_times
+                    .iter()
+                    .filter(|w| w.weekday == weekday)
+                    .flat_map(|w| w.times.iter().copied())
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Resolve a wall clock time of the time zone. A time repeated when clocks fall back resolves to
+/// its first occurrence. A time skipped when clocks spring forward is read with the offset before
+/// the transition, i.e. shifted forward by the length of the gap.
+pub fn resolve_local_time(timezone: Tz, local: NaiveDateTime) -> DateTime<Tz> {
+    match timezone.from_local_datetime(&local) {
+        LocalResult::Single(t) | LocalResult::Ambiguous(t, _) => t,
+        LocalResult::None => {
+            let offset_before = timezone
+                .offset_from_utc_datetime(&(local - TimeDelta::days(1)))
+                .fix();
+            timezone.from_utc_datetime(&(local - offset_before))
+        }
+    }
+}
+
+/// Iterator over occurrences of a schedule, recurrences are expanded day by day.
+pub struct Occurrences<'a> {
+    schedule: &'a Schedule,
+    from: DateTime<Utc>,
+    /// Next day to expand, None past the last representable date
+    day: Option<NaiveDate>,
+    pending: VecDeque<DateTime<Tz>>,
+    done: bool,
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(time) = self.pending.pop_front() {
+                return Some(time);
+            }
+            if self.done {
+                return None;
+            }
+
+            let day = self.day?;
+            let mut times = self
+                .schedule
+                .variant
+                .recurrence_times_on(day)
+                .into_iter()
+                .map(|time| resolve_local_time(self.schedule.timezone, day.and_time(time)))
+                .filter(|time| *time >= self.from)
+                .collect::<Vec<_>>();
+            // Shifted skipped times may be out of order
+            times.sort_unstable();
+            times.dedup();
+            self.pending.extend(times);
+            self.day = day.succ_opt();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        DEFAULT_SCHEDULE_TIMEZONE, ScheduleBase, ScheduleDailyRecurrence, ScheduleFixedTime,
+        ScheduleWeeklyRecurrence, WeekdayTime,
+    };
+    use entity_type::{ScheduleId, ScheduleType};
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    /// Time in Asia/Ho_Chi_Minh on a date of the week of Monday 2026-01-05
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        DEFAULT_SCHEDULE_TIMEZONE
+            .from_local_datetime(
+                &NaiveDate::from_ymd_opt(2026, 1, day)
+                    .unwrap()
+                    .and_time(time(hour, minute)),
+            )
+            .unwrap()
+            .to_utc()
+    }
+
+    fn rfc3339(times: Vec<DateTime<Tz>>) -> Vec<String> {
+        times.into_iter().map(|t| t.to_rfc3339()).collect()
+    }
+
+    fn schedule(timezone: Tz, variant: ScheduleVariant) -> Schedule {
+        let (id, schedule_type) = match &variant {
+            ScheduleVariant::FixedTime(v) => (v.id, v.schedule_type),
+            ScheduleVariant::DailyRecurrence(v) => (v.id, v.schedule_type),
+            ScheduleVariant::WeeklyRecurrence(v) => (v.schedule_id, ScheduleType::WeeklyRecurrence),
+        };
+        Schedule {
+            base: ScheduleBase {
+                id,
+                schedule_type,
+                created_at: NaiveDateTime::default(),
+                timezone: timezone.name().into(),
+            },
+            timezone,
+            variant,
+        }
+    }
+
+    fn fixed_time(time: DateTime<Utc>) -> Schedule {
+        schedule(
+            DEFAULT_SCHEDULE_TIMEZONE,
+            ScheduleVariant::FixedTime(ScheduleFixedTime {
+                id: ScheduleId(1),
+                schedule_type: ScheduleType::FixedTime,
+                time,
+                updated_at: NaiveDateTime::default(),
+            }),
+        )
+    }
+
+    fn daily(timezone: Tz, times: Vec<NaiveTime>) -> Schedule {
+        schedule(
+            timezone,
+            ScheduleVariant::DailyRecurrence(ScheduleDailyRecurrence {
+                id: ScheduleId(2),
+                schedule_type: ScheduleType::DailyRecurrence,
+                times,
+                updated_at: NaiveDateTime::default(),
+            }),
+        )
+    }
+
+    fn weekly(weekday_times: Vec<(Weekday, Vec<NaiveTime>)>) -> Schedule {
+        schedule(
+            DEFAULT_SCHEDULE_TIMEZONE,
+            ScheduleVariant::WeeklyRecurrence(ScheduleWeeklyRecurrence {
+                schedule_id: ScheduleId(3),
+                weekday_times: weekday_times
+                    .into_iter()
+                    .enumerate()
+                    .map(|(id, (weekday, times))| WeekdayTime {
+                        id: id as i64,
+                        schedule_id: ScheduleId(3),
+                        schedule_type: ScheduleType::WeeklyRecurrence,
+                        weekday,
+                        times,
+                        updated_at: NaiveDateTime::default(),
+                    })
+                    .collect(),
+            }),
+        )
+    }
+
+    #[test]
+    fn test_fixed_time_occurs_once() {
+        let schedule = fixed_time(at(6, 9, 30));
+
+        assert_eq!(schedule.next_occurrences(at(5, 0, 0), 3), [at(6, 9, 30)]);
+        assert_eq!(schedule.next_occurrences(at(6, 9, 30), 3), [at(6, 9, 30)]);
+        assert!(schedule.next_occurrences(at(6, 9, 31), 3).is_empty());
+    }
+
+    #[test]
+    fn test_daily_recurrence_in_time_order() {
+        let schedule = daily(
+            DEFAULT_SCHEDULE_TIMEZONE,
+            vec![time(17, 0), time(8, 0), time(8, 0)],
+        );
+
+        assert_eq!(
+            schedule.next_occurrences(at(5, 12, 0), 4),
+            [at(5, 17, 0), at(6, 8, 0), at(6, 17, 0), at(7, 8, 0)]
+        );
+    }
+
+    #[test]
+    fn test_weekly_recurrence_between() {
+        let schedule = weekly(vec![
+            (Weekday::Sat, vec![time(10, 0)]),
+            (Weekday::Mon, vec![time(8, 0)]),
+        ]);
+
+        assert_eq!(
+            schedule.occurrences_between(at(5, 8, 0), at(12, 8, 0)),
+            [at(5, 8, 0), at(10, 10, 0)]
+        );
+        assert_eq!(schedule.next_occurrences(at(10, 10, 1), 1), [at(12, 8, 0)]);
+    }
+
+    #[test]
+    fn test_occurrences_in_schedule_timezone() {
+        let schedule = daily(DEFAULT_SCHEDULE_TIMEZONE, vec![time(8, 0)]);
+        // 2026-01-05 00:30 UTC is 07:30 in Hồ Chí Minh
+        let from = "2026-01-05T00:30:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        assert_eq!(
+            rfc3339(schedule.next_occurrences(from, 1)),
+            ["2026-01-05T08:00:00+07:00"]
+        );
+    }
+
+    #[test]
+    fn test_recurrence_keeps_wall_clock_time_across_dst() {
+        let schedule = daily(Tz::America__New_York, vec![time(9, 0)]);
+        let from = "2026-03-07T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        // Clocks spring forward on 2026-03-08
+        assert_eq!(
+            rfc3339(schedule.next_occurrences(from, 3)),
+            [
+                "2026-03-07T09:00:00-05:00",
+                "2026-03-08T09:00:00-04:00",
+                "2026-03-09T09:00:00-04:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skipped_and_repeated_times() {
+        // 02:30 doesn't exist on 2026-03-08 in New York
+        let schedule = daily(Tz::America__New_York, vec![time(2, 30)]);
+        let from = "2026-03-07T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            rfc3339(schedule.next_occurrences(from, 2)),
+            ["2026-03-08T03:30:00-04:00", "2026-03-09T02:30:00-04:00"]
+        );
+
+        // 01:30 happens twice on 2026-11-01 in New York
+        let schedule = daily(Tz::America__New_York, vec![time(1, 30)]);
+        let from = "2026-11-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            rfc3339(schedule.next_occurrences(from, 2)),
+            ["2026-11-01T01:30:00-04:00", "2026-11-02T01:30:00-05:00"]
+        );
+    }
+
+    #[test]
+    fn test_recurrence_without_times_never_occurs() {
+        assert!(
+            daily(DEFAULT_SCHEDULE_TIMEZONE, Vec::new())
+                .next_occurrences(at(5, 0, 0), 1)
+                .is_empty()
+        );
+        assert!(
+            weekly(vec![(Weekday::Mon, Vec::new())])
+                .next_occurrences(at(5, 0, 0), 1)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_first_overlap() {
+        let hour = TimeDelta::hours(1);
+        let handyman = weekly(vec![(Weekday::Wed, vec![time(14, 0)])]);
+
+        // Overlapping slots, starting at different times
+        let task = daily(DEFAULT_SCHEDULE_TIMEZONE, vec![time(14, 30)]);
+        let (handyman_start, task_start) = handyman
+            .first_overlap(hour, &task, hour, at(5, 0, 0), at(12, 0, 0))
+            .unwrap();
+        assert_eq!(handyman_start, at(7, 14, 0));
+        assert_eq!(task_start, at(7, 14, 30));
+
+        // Adjacent slots don't overlap
+        let task = fixed_time(at(7, 15, 0));
+        assert!(
+            handyman
+                .first_overlap(hour, &task, hour, at(5, 0, 0), at(12, 0, 0))
+                .is_none()
+        );
+        assert!(
+            handyman
+                .first_overlap(
+                    TimeDelta::minutes(61),
+                    &task,
+                    hour,
+                    at(5, 0, 0),
+                    at(12, 0, 0)
+                )
+                .is_some()
+        );
+
+        // 14:00 in Hồ Chí Minh is 08:00 in Paris in winter
+        let task = daily(Tz::Europe__Paris, vec![time(8, 0)]);
+        let (_, task_start) = handyman
+            .first_overlap(hour, &task, hour, at(5, 0, 0), at(12, 0, 0))
+            .unwrap();
+        assert_eq!(task_start.to_rfc3339(), "2026-01-07T08:00:00+01:00");
+
+        // Occurrences past the end of the range are ignored
+        let task = daily(DEFAULT_SCHEDULE_TIMEZONE, vec![time(14, 0)]);
+        assert!(
+            handyman
+                .first_overlap(hour, &task, hour, at(5, 0, 0), at(7, 14, 0))
+                .is_none()
+        );
+    }
+}
@@ -49,6 +49,31 @@ impl ScheduleWeeklyRecurrence {
             weekday_times,
         })
     }
+
+    pub(crate) async fn load_by_ids(
+        ids: &[ScheduleId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let weekday_times = schedule_weekly_recurrence::table
+            .filter(schedule_weekly_recurrence::schedule_id.eq_any(ids))
+            .select(WeekdayTime::as_select())
+            .order((
+                schedule_weekly_recurrence::schedule_id,
+                schedule_weekly_recurrence::id,
+            ))
+            .load::<WeekdayTime>(conn)
+            .await?;
+
+        let result = db_utils::group_by(weekday_times.into_iter().map(|w| (w.schedule_id, w)))
+            .into_iter()
+            .map(|(schedule_id, weekday_times)| ScheduleWeeklyRecurrence {
+                schedule_id,
+                weekday_times,
+            })
+            .collect();
+
+        Ok(result)
+    }
 }
 
 #[derive(Debug, Insertable)]
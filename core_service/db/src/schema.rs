@@ -1,15 +1,88 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    catalog_service (code) {
+        code -> Text,
+        group_code -> Text,
+        name_vi -> Text,
+        name_en -> Text,
+        icon -> Nullable<Text>,
+        recommended_rate_vnd -> Nullable<Int4>,
+        display_order -> Int4,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    catalog_service_group (code) {
+        code -> Text,
+        name_vi -> Text,
+        name_en -> Text,
+        icon -> Nullable<Text>,
+        display_order -> Int4,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    customer_task_photo (id) {
+        id -> Int8,
+        task_id -> Int8,
+        blob_key -> Text,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     customer_task_request (id) {
         id -> Int8,
         customer_id -> Int8,
-        service -> entity_type::ServiceLayer2Mapping,
+        service -> Text,
         title -> Text,
         note -> Nullable<Text>,
         schedule -> Int8,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        status -> entity_type::CustomerTaskStatusMapping,
+        assigned_handyman_id -> Nullable<Int8>,
+        opened_at -> Nullable<Timestamp>,
+        assigned_at -> Nullable<Timestamp>,
+        started_at -> Nullable<Timestamp>,
+        completed_at -> Nullable<Timestamp>,
+        cancelled_at -> Nullable<Timestamp>,
+        expired_at -> Nullable<Timestamp>,
+        location_city -> Nullable<Text>,
+        location_address_line1 -> Nullable<Text>,
+        location_formatted_address -> Nullable<Text>,
+        location_lon -> Nullable<Float8>,
+        location_lat -> Nullable<Float8>,
+    }
+}
+
+diesel::table! {
+    handyman_blackout (handyman_id, date) {
+        handyman_id -> Int8,
+        date -> Date,
+        note -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    handyman_review (id) {
+        id -> Int8,
+        task_id -> Int8,
+        customer_id -> Int8,
+        handyman_id -> Int8,
+        rating -> Int2,
+        comment -> Nullable<Text>,
+        rating_indexed -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
@@ -17,18 +90,57 @@ diesel::table! {
     handyman_service (id) {
         id -> Int8,
         handyman_id -> Int8,
-        service -> entity_type::ServiceLayer2Mapping,
+        service -> Text,
         note -> Nullable<Text>,
         rate_vnd -> Nullable<Int4>,
         created_at -> Timestamp,
     }
 }
 
+diesel::table! {
+    handyman_service_location (handyman_id) {
+        handyman_id -> Int8,
+        city -> Text,
+        address_line1 -> Text,
+        formatted_address -> Text,
+        lon -> Float8,
+        lat -> Float8,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    handyman_working_hours (handyman_id) {
+        handyman_id -> Int8,
+        schedule -> Int8,
+        slot_minutes -> Int4,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    otp_code (purpose, account_type, e164_phone_number) {
+        purpose -> Text,
+        e164_phone_number -> Text,
+        code -> Nullable<Text>,
+        expires_at -> Timestamp,
+        failed_attempts -> Int4,
+        last_sent_at -> Timestamp,
+        locked_until -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        account_type -> entity_type::AccountTypeMapping,
+    }
+}
+
 diesel::table! {
     schedule (id) {
         id -> Int8,
         schedule_type -> entity_type::ScheduleTypeMapping,
         created_at -> Timestamp,
+        timezone -> Text,
     }
 }
 
@@ -45,7 +157,7 @@ diesel::table! {
     schedule_fixed_time (id) {
         id -> Int8,
         schedule_type -> entity_type::ScheduleTypeMapping,
-        time -> Timestamp,
+        time -> Timestamptz,
         updated_at -> Timestamp,
     }
 }
@@ -61,13 +173,70 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    service_rate_stat (id) {
+        id -> Int8,
+        service -> Text,
+        city -> Nullable<Text>,
+        sample_count -> Int4,
+        p25_vnd -> Int4,
+        median_vnd -> Int4,
+        p75_vnd -> Int4,
+        refreshed_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    task_quote (id) {
+        id -> Int8,
+        task_id -> Int8,
+        handyman_id -> Int8,
+        price_vnd -> Int4,
+        eta -> Timestamp,
+        message -> Nullable<Text>,
+        status -> entity_type::TaskQuoteStatusMapping,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    zalo_oauth_token (app_id) {
+        app_id -> Text,
+        refresh_token -> Text,
+        access_token -> Text,
+        expires_at -> Timestamptz,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::joinable!(catalog_service -> catalog_service_group (group_code));
+diesel::joinable!(customer_task_request -> catalog_service (service));
 diesel::joinable!(customer_task_request -> schedule (schedule));
+diesel::joinable!(customer_task_photo -> customer_task_request (task_id));
+diesel::joinable!(handyman_service -> catalog_service (service));
+diesel::joinable!(handyman_review -> customer_task_request (task_id));
+diesel::joinable!(handyman_working_hours -> schedule (schedule));
+diesel::joinable!(service_rate_stat -> catalog_service (service));
+diesel::joinable!(task_quote -> customer_task_request (task_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    catalog_service,
+    catalog_service_group,
+    customer_task_photo,
     customer_task_request,
+    handyman_blackout,
+    handyman_review,
     handyman_service,
+    handyman_service_location,
+    handyman_working_hours,
+    otp_code,
     schedule,
     schedule_daily_recurrence,
     schedule_fixed_time,
     schedule_weekly_recurrence,
+    service_rate_stat,
+    task_quote,
+    zalo_oauth_token,
 );
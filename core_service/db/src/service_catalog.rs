@@ -0,0 +1,264 @@
+use crate::schema::{catalog_service, catalog_service_group};
+use actor_auth::ActorAuth;
+use chrono::NaiveDateTime;
+use db_utils::AsyncPgConnection;
+use diesel::{prelude::*, upsert::excluded};
+use diesel_async::RunQueryDsl;
+use error::{
+    Error, Result,
+    error_details::{BadRequest, bad_request::FieldViolation},
+};
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = catalog_service_group)]
+pub struct CatalogServiceGroup {
+    /// Stable identifier, the values of [`entity_type::ServiceLayer1`] are seeded
+    pub code: String,
+    pub name_vi: String,
+    pub name_en: String,
+    pub icon: Option<String>,
+    pub display_order: i32,
+    pub is_active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl CatalogServiceGroup {
+    /// Returns groups shown in the catalog, in display order.
+    /// The catalog is public, this API requires no actor.
+    pub async fn list_active(conn: &mut AsyncPgConnection) -> Result<Vec<Self>> {
+        let result = catalog_service_group::table
+            .filter(catalog_service_group::is_active.eq(true))
+            .select(Self::as_select())
+            .order((
+                catalog_service_group::display_order,
+                catalog_service_group::code,
+            ))
+            .load::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Inactive groups are included so that existing rows keep resolving.
+    pub async fn load_by_codes(
+        codes: &[String],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let result = catalog_service_group::table
+            .filter(catalog_service_group::code.eq_any(codes))
+            .select(Self::as_select())
+            .load::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Staff adds a group or edits the group having the same code.
+    pub async fn upsert(
+        actor_auth: &ActorAuth,
+        new_record: NewCatalogServiceGroup<'_>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_god_or_admin()?;
+        validate_catalog_code(new_record.code)?;
+
+        let result = diesel::insert_into(catalog_service_group::table)
+            .values(&new_record)
+            .on_conflict(catalog_service_group::code)
+            .do_update()
+            .set((
+                catalog_service_group::name_vi.eq(excluded(catalog_service_group::name_vi)),
+                catalog_service_group::name_en.eq(excluded(catalog_service_group::name_en)),
+                catalog_service_group::icon.eq(excluded(catalog_service_group::icon)),
+                catalog_service_group::display_order
+                    .eq(excluded(catalog_service_group::display_order)),
+                catalog_service_group::is_active.eq(excluded(catalog_service_group::is_active)),
+            ))
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = catalog_service_group)]
+pub struct NewCatalogServiceGroup<'a> {
+    pub code: &'a str,
+    pub name_vi: &'a str,
+    pub name_en: &'a str,
+    pub icon: Option<&'a str>,
+    pub display_order: i32,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = catalog_service)]
+pub struct CatalogService {
+    /// Stable identifier referenced by tasks and handyman services,
+    /// the values of [`entity_type::ServiceLayer2`] are seeded
+    pub code: String,
+    pub group_code: String,
+    pub name_vi: String,
+    pub name_en: String,
+    pub icon: Option<String>,
+    pub recommended_rate_vnd: Option<i32>,
+    pub display_order: i32,
+    pub is_active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl CatalogService {
+    /// Returns services of the groups shown in the catalog, in display order.
+    /// The catalog is public, this API requires no actor.
+    pub async fn list_active_by_group_codes(
+        group_codes: &[String],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let result = catalog_service::table
+            .filter(
+                catalog_service::group_code
+                    .eq_any(group_codes)
+                    .and(catalog_service::is_active.eq(true)),
+            )
+            .select(Self::as_select())
+            .order((catalog_service::display_order, catalog_service::code))
+            .load::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Inactive services are included so that existing rows keep resolving.
+    pub async fn load_by_codes(
+        codes: &[String],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let result = catalog_service::table
+            .filter(catalog_service::code.eq_any(codes))
+            .select(Self::as_select())
+            .load::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// New tasks and handyman services can only refer to services shown in the catalog.
+    pub async fn require_active(codes: &[&str], conn: &mut AsyncPgConnection) -> Result<()> {
+        let active_codes = catalog_service::table
+            .filter(
+                catalog_service::code
+                    .eq_any(codes)
+                    .and(catalog_service::is_active.eq(true)),
+            )
+            .select(catalog_service::code)
+            .load::<String>(conn)
+            .await?;
+
+        if let Some(unknown) = codes
+            .iter()
+            .find(|code| !active_codes.iter().any(|active| active == *code))
+        {
+            return Err(Error::invalid_argument_with(
+                format!("Service {unknown} is not in the catalog"),
+                Some(BadRequest {
+                    field_violations: vec![FieldViolation {
+                        field: "service_code".into(),
+                        description: "UNKNOWN_SERVICE".into(),
+                    }],
+                }),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Staff adds a service or edits the service having the same code.
+    pub async fn upsert(
+        actor_auth: &ActorAuth,
+        new_record: NewCatalogService<'_>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_god_or_admin()?;
+        validate_catalog_code(new_record.code)?;
+        if new_record
+            .recommended_rate_vnd
+            .is_some_and(|rate| rate <= 0)
+        {
+            return Err(Error::invalid_argument_with(
+                "Recommended rate must be positive",
+                Some(BadRequest {
+                    field_violations: vec![FieldViolation {
+                        field: "recommended_rate_vnd".into(),
+                        description: "NOT_POSITIVE".into(),
+                    }],
+                }),
+            ));
+        }
+
+        let group_exists = diesel::select(diesel::dsl::exists(
+            catalog_service_group::table
+                .filter(catalog_service_group::code.eq(new_record.group_code)),
+        ))
+        .get_result::<bool>(conn)
+        .await?;
+        if !group_exists {
+            return Err(Error::not_found("Service group not found"));
+        }
+
+        let result = diesel::insert_into(catalog_service::table)
+            .values(&new_record)
+            .on_conflict(catalog_service::code)
+            .do_update()
+            .set((
+                catalog_service::group_code.eq(excluded(catalog_service::group_code)),
+                catalog_service::name_vi.eq(excluded(catalog_service::name_vi)),
+                catalog_service::name_en.eq(excluded(catalog_service::name_en)),
+                catalog_service::icon.eq(excluded(catalog_service::icon)),
+                catalog_service::recommended_rate_vnd
+                    .eq(excluded(catalog_service::recommended_rate_vnd)),
+                catalog_service::display_order.eq(excluded(catalog_service::display_order)),
+                catalog_service::is_active.eq(excluded(catalog_service::is_active)),
+            ))
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = catalog_service)]
+pub struct NewCatalogService<'a> {
+    pub code: &'a str,
+    pub group_code: &'a str,
+    pub name_vi: &'a str,
+    pub name_en: &'a str,
+    pub icon: Option<&'a str>,
+    pub recommended_rate_vnd: Option<i32>,
+    pub display_order: i32,
+    pub is_active: bool,
+}
+
+/// Codes are SCREAMING_SNAKE_CASE like the values of the enums predating the catalog.
+fn validate_catalog_code(code: &str) -> Result<()> {
+    let valid = code.starts_with(|c: char| c.is_ascii_uppercase())
+        && code
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+    if !valid {
+        return Err(Error::invalid_argument_with(
+            "Catalog code must be SCREAMING_SNAKE_CASE",
+            Some(BadRequest {
+                field_violations: vec![FieldViolation {
+                    field: "code".into(),
+                    description: "INVALID_FORMAT".into(),
+                }],
+            }),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use crate::schema::service_rate_stat;
+use actor_auth::ActorAuth;
+use chrono::NaiveDateTime;
+use db_utils::{AsyncPgConnection, try_advisory_xact_lock};
+use diesel::{
+    define_sql_function,
+    dsl::max,
+    prelude::*,
+    sql_types::{Array, Int4, Int8},
+};
+use diesel_async::RunQueryDsl;
+use entity_type::HandymanId;
+use error::{Error, Result};
+
+/// Distributions built from fewer prices are too noisy to be shown.
+pub const MIN_RATE_SAMPLE_COUNT: i32 = 3;
+
+/// Advisory lock held by a refresh, so that replicas don't refresh at the same time
+const REFRESH_LOCK_KEY: &str = "service_rate_stat:refresh";
+
+define_sql_function! {
+    #[sql_name = "refresh_service_rate_stat"]
+    /// Custom function made available in core service database by the `service_rate_stat` migration
+    fn refresh_service_rate_stat(min_sample_count: Int4, excluded_handyman_ids: Array<Int8>) -> Int4
+}
+
+/// Price distribution of a service, from handyman rates and accepted quotes.
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = service_rate_stat)]
+pub struct ServiceRateStat {
+    pub service: String,
+    /// None for the distribution over all cities
+    pub city: Option<String>,
+    pub sample_count: i32,
+    pub p25_vnd: i32,
+    pub median_vnd: i32,
+    pub p75_vnd: i32,
+    pub refreshed_at: NaiveDateTime,
+}
+
+impl ServiceRateStat {
+    /// Returns distributions of the services, ordered by service then city.
+    /// Prices are public, this API requires no actor.
+    pub async fn load_by_services(
+        services: &[String],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let result = service_rate_stat::table
+            .filter(service_rate_stat::service.eq_any(services))
+            .select(Self::as_select())
+            .order((
+                service_rate_stat::service,
+                service_rate_stat::city.asc().nulls_first(),
+            ))
+            .load::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Recompute every distribution from current prices, returns the number of distributions.
+    /// Rates of the excluded handymen are left out, e.g. suspended or deleted ones.
+    /// This API requires god or admin.
+    pub async fn refresh(
+        actor_auth: &ActorAuth,
+        excluded_handyman_ids: &[HandymanId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<i32> {
+        actor_auth.require_god_or_admin()?;
+        if !try_advisory_xact_lock(REFRESH_LOCK_KEY, conn).await? {
+            return Err(Error::aborted(
+                "Service rate distributions are being refreshed",
+            ));
+        }
+
+        Self::refresh_locked(excluded_handyman_ids, conn).await
+    }
+
+    /// Same as [`Self::refresh`] but skipped, returning `None`, when another transaction is
+    /// refreshing or the distributions were refreshed after `stale_before`.
+    pub async fn refresh_if_stale(
+        actor_auth: &ActorAuth,
+        stale_before: NaiveDateTime,
+        excluded_handyman_ids: &[HandymanId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Option<i32>> {
+        actor_auth.require_god_or_admin()?;
+        if !try_advisory_xact_lock(REFRESH_LOCK_KEY, conn).await? {
+            return Ok(None);
+        }
+        let refreshed_at = service_rate_stat::table
+            .select(max(service_rate_stat::refreshed_at))
+            .get_result::<Option<NaiveDateTime>>(conn)
+            .await?;
+        if refreshed_at.is_some_and(|t| t >= stale_before) {
+            return Ok(None);
+        }
+
+        Self::refresh_locked(excluded_handyman_ids, conn)
+            .await
+            .map(Some)
+    }
+
+    async fn refresh_locked(
+        excluded_handyman_ids: &[HandymanId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<i32> {
+        let result = diesel::select(refresh_service_rate_stat(
+            MIN_RATE_SAMPLE_COUNT,
+            excluded_handyman_ids,
+        ))
+        .get_result::<i32>(conn)
+        .await?;
+
+        Ok(result)
+    }
+
+    /// Picks the distribution of the city among distributions of a service,
+    /// falls back to the distribution over all cities.
+    pub fn pick<'a>(stats: &'a [Self], city: Option<&str>) -> Option<&'a Self> {
+        city.and_then(|city| stats.iter().find(|s| s.city.as_deref() == Some(city)))
+            .or_else(|| stats.iter().find(|s| s.city.is_none()))
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{
+    CustomerTaskRequest, CustomerTaskTransition,
+    schema::{customer_task_request, task_quote},
+};
+use actor_auth::{ActorAuth, ActorType};
+use chrono::NaiveDateTime;
+use db_utils::AsyncPgConnection;
+use diesel::prelude::*;
+use diesel_async::RunQueryDsl;
+use entity_type::{
+    CustomerTaskRequestId, CustomerTaskStatus, HandymanAccessGuardId, HandymanId, TaskQuoteId,
+    TaskQuoteStatus,
+};
+use error::{
+    Error, Result,
+    error_details::{
+        BadRequest, PreconditionFailure, bad_request::FieldViolation,
+        precondition_failure::Violation,
+    },
+};
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = task_quote)]
+pub struct TaskQuote {
+    pub id: TaskQuoteId,
+    pub task_id: CustomerTaskRequestId,
+    pub handyman_id: HandymanId,
+    pub price_vnd: i32,
+    pub eta: NaiveDateTime,
+    pub message: Option<String>,
+    pub status: TaskQuoteStatus,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl TaskQuote {
+    /// Handyman quotes an open task.
+    pub async fn submit(
+        actor_auth: &ActorAuth,
+        new: NewTaskQuote<'_>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_handyman_access(new.handyman_id)?;
+        new.validate()?;
+
+        // Lock the task so it can't be assigned while the quote is being submitted.
+        let task_status = customer_task_request::table
+            .filter(customer_task_request::id.eq(new.task_id))
+            .select(customer_task_request::status)
+            .for_share()
+            .first::<CustomerTaskStatus>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Customer task request not found"))?;
+        if task_status != CustomerTaskStatus::Open {
+            return Err(unexpected_status_error("task_status", task_status));
+        }
+
+        let result = diesel::insert_into(task_quote::table)
+            .values(new)
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Handyman withdraws a pending quote.
+    pub async fn withdraw(
+        actor_auth: &ActorAuth,
+        HandymanAccessGuardId {
+            handyman_id,
+            entity_id,
+        }: HandymanAccessGuardId<TaskQuoteId>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        let quote = Self::lock(entity_id, conn).await?;
+        if quote.handyman_id != handyman_id {
+            return Err(Error::not_found("Task quote not found"));
+        }
+        if quote.status != TaskQuoteStatus::Pending {
+            return Err(unexpected_status_error("quote_status", quote.status));
+        }
+
+        let result = diesel::update(task_quote::table.find(entity_id))
+            .set(task_quote::status.eq(TaskQuoteStatus::Withdrawn))
+            .returning(Self::as_returning())
+            .get_result::<Self>(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Customer accepts a pending quote, which assigns the task to the quoting handyman
+    /// and rejects all other pending quotes of the task.
+    pub async fn accept(
+        actor_auth: &ActorAuth,
+        id: TaskQuoteId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<(Self, CustomerTaskRequest)> {
+        // Lock the task before the quote, in the same order as the task transitions closing
+        // pending quotes, so that accepting can't deadlock with cancelling the task.
+        let task_id = task_quote::table
+            .find(id)
+            .select(task_quote::task_id)
+            .first::<CustomerTaskRequestId>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Task quote not found"))?;
+        customer_task_request::table
+            .find(task_id)
+            .select(customer_task_request::id)
+            .for_update()
+            .execute(conn)
+            .await?;
+
+        let quote = Self::lock(id, conn).await?;
+        if quote.status != TaskQuoteStatus::Pending {
+            return Err(unexpected_status_error("quote_status", quote.status));
+        }
+
+        // Customer access is checked by the task transition
+        let task = CustomerTaskRequest::transition(
+            actor_auth,
+            quote.task_id,
+            CustomerTaskTransition::Assign(quote.handyman_id),
+            conn,
+        )
+        .await?;
+
+        let quote = task_quote::table
+            .find(id)
+            .select(Self::as_select())
+            .first::<Self>(conn)
+            .await?;
+
+        Ok((quote, task))
+    }
+
+    /// Same access rule as [`Self::load_by_tasks`].
+    pub async fn load_by_ids(
+        actor_auth: &ActorAuth,
+        ids: &[TaskQuoteId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let mut query = task_quote::table
+            .inner_join(customer_task_request::table)
+            .filter(task_quote::id.eq_any(ids))
+            .select(Self::as_select())
+            .into_boxed();
+
+        match actor_auth.session_actor() {
+            Some(ActorType::Customer(customer)) => {
+                query = query.filter(customer_task_request::customer_id.eq(customer.customer_id));
+            }
+            Some(ActorType::Handyman(handyman)) => {
+                query = query.filter(task_quote::handyman_id.eq(handyman.handyman_id));
+            }
+            Some(ActorType::Admin(_)) | None => {}
+        }
+
+        let result = query.load::<Self>(conn).await?;
+        Ok(result)
+    }
+
+    /// Quotes of tasks, grouped by task and the cheapest first.
+    /// Customer who owns the task can see all quotes, a handyman can only see their own quote.
+    pub async fn load_by_tasks(
+        actor_auth: &ActorAuth,
+        task_ids: &[CustomerTaskRequestId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let mut query = task_quote::table
+            .inner_join(customer_task_request::table)
+            .filter(task_quote::task_id.eq_any(task_ids))
+            .select(Self::as_select())
+            .order((
+                task_quote::task_id,
+                task_quote::price_vnd,
+                task_quote::created_at,
+            ))
+            .into_boxed();
+
+        match actor_auth.session_actor() {
+            Some(ActorType::Customer(customer)) => {
+                query = query.filter(customer_task_request::customer_id.eq(customer.customer_id));
+            }
+            Some(ActorType::Handyman(handyman)) => {
+                query = query.filter(task_quote::handyman_id.eq(handyman.handyman_id));
+            }
+            Some(ActorType::Admin(_)) | None => {}
+        }
+
+        let result = query.load::<Self>(conn).await?;
+        Ok(result)
+    }
+
+    /// Close pending quotes of tasks which are no longer open.
+    /// Quote of `accepted_handyman_id` (if any) is accepted, the others are rejected.
+    pub(crate) async fn close_pending(
+        task_ids: &[CustomerTaskRequestId],
+        accepted_handyman_id: Option<HandymanId>,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<()> {
+        let pending = || {
+            task_quote::table.filter(
+                task_quote::task_id
+                    .eq_any(task_ids)
+                    .and(task_quote::status.eq(TaskQuoteStatus::Pending)),
+            )
+        };
+
+        if let Some(handyman_id) = accepted_handyman_id {
+            diesel::update(pending().filter(task_quote::handyman_id.eq(handyman_id)))
+                .set(task_quote::status.eq(TaskQuoteStatus::Accepted))
+                .execute(conn)
+                .await?;
+        }
+
+        diesel::update(pending())
+            .set(task_quote::status.eq(TaskQuoteStatus::Rejected))
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn lock(id: TaskQuoteId, conn: &mut AsyncPgConnection) -> Result<Self> {
+        task_quote::table
+            .find(id)
+            .select(Self::as_select())
+            .for_update()
+            .first::<Self>(conn)
+            .await
+            .optional()?
+            .ok_or_else(|| Error::not_found("Task quote not found"))
+    }
+}
+
+pub(crate) fn unexpected_status_error(subject: &str, status: impl std::fmt::Debug) -> Error {
+    Error::failed_precondition_with(
+        format!("Unexpected {subject} {status:?}"),
+        Some(PreconditionFailure {
+            violations: vec![Violation {
+                r#type: "UNEXPECTED_STATUS".into(),
+                subject: subject.into(),
+                description: format!("{status:?}"),
+            }],
+        }),
+    )
+}
+
+#[derive(Debug, Clone, Copy, Insertable)]
+#[diesel(table_name = task_quote)]
+pub struct NewTaskQuote<'a> {
+    pub task_id: CustomerTaskRequestId,
+    pub handyman_id: HandymanId,
+    pub price_vnd: i32,
+    pub eta: NaiveDateTime,
+    pub message: Option<&'a str>,
+}
+
+impl NewTaskQuote<'_> {
+    fn validate(&self) -> Result<()> {
+        if self.price_vnd <= 0 {
+            return Err(Error::invalid_argument_with(
+                "Quoted price must be positive",
+                Some(BadRequest {
+                    field_violations: vec![FieldViolation {
+                        field: "price_vnd".into(),
+                        description: "NOT_POSITIVE".into(),
+                    }],
+                }),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+//! Postgres-backed [`ZaloTokenStore`], so that the rotated refresh token survives restarts
+//! and is shared among replicas.
+
+use crate::schema::zalo_oauth_token;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use db_utils::{PgConnectionPool, advisory_xact_lock};
+use diesel::{prelude::*, upsert::excluded};
+use diesel_async::RunQueryDsl;
+use error::Result;
+use scoped_futures::ScopedFutureExt;
+use sms_sender::{ZaloToken, ZaloTokenRefresh, ZaloTokenStore};
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = zalo_oauth_token)]
+struct ZaloOauthTokenRow {
+    refresh_token: String,
+    access_token: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl From<ZaloOauthTokenRow> for ZaloToken {
+    fn from(
+        ZaloOauthTokenRow {
+            refresh_token,
+            access_token,
+            expires_at,
+        }: ZaloOauthTokenRow,
+    ) -> Self {
+        Self {
+            refresh_token,
+            access_token,
+            expires_at,
+        }
+    }
+}
+
+pub struct PgZaloTokenStore {
+    db_connection_pool: PgConnectionPool,
+}
+
+impl PgZaloTokenStore {
+    pub fn new(db_connection_pool: PgConnectionPool) -> Self {
+        Self { db_connection_pool }
+    }
+}
+
+#[async_trait]
+impl ZaloTokenStore for PgZaloTokenStore {
+    async fn refresh(&self, app_id: &str, refresh: ZaloTokenRefresh<'_>) -> Result<ZaloToken> {
+        // Read committed rather than repeatable read, so that the token stored by
+        // the previous lock holder is visible once the lock is acquired.
+        self.db_connection_pool
+            .get()
+            .await?
+            .build_transaction()
+            .read_write()
+            .run(|conn| {
+                async move {
+                    // The row may not exist yet, so lock the app id rather than the row
+                    advisory_xact_lock(&format!("zalo_oauth_token:{app_id}"), conn).await?;
+                    let stored = zalo_oauth_token::table
+                        .find(app_id)
+                        .select(ZaloOauthTokenRow::as_select())
+                        .first::<ZaloOauthTokenRow>(conn)
+                        .await
+                        .optional()?;
+
+                    let token = refresh(stored.map(ZaloToken::from)).await?;
+
+                    diesel::insert_into(zalo_oauth_token::table)
+                        .values((
+                            zalo_oauth_token::app_id.eq(app_id),
+                            zalo_oauth_token::refresh_token.eq(&token.refresh_token),
+                            zalo_oauth_token::access_token.eq(&token.access_token),
+                            zalo_oauth_token::expires_at.eq(token.expires_at),
+                        ))
+                        .on_conflict(zalo_oauth_token::app_id)
+                        .do_update()
+                        .set((
+                            zalo_oauth_token::refresh_token
+                                .eq(excluded(zalo_oauth_token::refresh_token)),
+                            zalo_oauth_token::access_token
+                                .eq(excluded(zalo_oauth_token::access_token)),
+                            zalo_oauth_token::expires_at.eq(excluded(zalo_oauth_token::expires_at)),
+                        ))
+                        .execute(conn)
+                        .await?;
+
+                    Ok(token)
+                }
+                .scope_boxed()
+            })
+            .await
+    }
+}
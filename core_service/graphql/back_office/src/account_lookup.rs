@@ -0,0 +1,83 @@
+use account_service_server::{
+    FindCustomerAccountByPhoneNumberRequest, FindHandymanAccountByPhoneNumberRequest,
+};
+use async_graphql::{Context, ID, Object};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{Customer, CustomerTaskRequest, GlobalId, Handyman};
+use db_utils::with_readonly_db;
+use error::Result;
+use scoped_futures::ScopedFutureExt;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct AccountLookupQuery;
+
+#[Object]
+impl AccountLookupQuery {
+    /// Customer registered with the phone number, null if there is none
+    #[tracing::instrument(skip(self, ctx))]
+    async fn customer_by_phone_number(
+        &self,
+        ctx: &Context<'_>,
+        phone_number: String,
+    ) -> Result<Option<Customer>> {
+        let e164_phone_number_str = typesafe::normalize_phone_number_str(&phone_number)?;
+        let context = ctx.data::<RequestContext>()?;
+
+        let customer = context
+            .account_service_client
+            .find_customer_account_by_phone_number(FindCustomerAccountByPhoneNumberRequest {
+                actor_auth: context.actor_auth().await?,
+                e164_phone_number_str,
+            })
+            .await?
+            .customer;
+
+        Ok(customer.map(|account| Customer::new_with(account.id, Arc::new(account))))
+    }
+
+    /// Handyman registered with the phone number, null if there is none
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_by_phone_number(
+        &self,
+        ctx: &Context<'_>,
+        phone_number: String,
+    ) -> Result<Option<Handyman>> {
+        let e164_phone_number_str = typesafe::normalize_phone_number_str(&phone_number)?;
+        let context = ctx.data::<RequestContext>()?;
+
+        let handyman = context
+            .account_service_client
+            .find_handyman_account_by_phone_number(FindHandymanAccountByPhoneNumberRequest {
+                actor_auth: context.actor_auth().await?,
+                e164_phone_number_str,
+            })
+            .await?
+            .handyman;
+
+        Ok(handyman.map(|account| Handyman::new_with(account.id, Arc::new(account))))
+    }
+
+    /// Task requests of the customer in every status, newest first
+    #[tracing::instrument(skip(self, ctx))]
+    async fn customer_task_requests(
+        &self,
+        ctx: &Context<'_>,
+        customer_id: ID,
+    ) -> Result<Vec<CustomerTaskRequest>> {
+        let customer_id = Customer::from_global_id(&customer_id)?.inner_id();
+        let context = ctx.data::<RequestContext>()?;
+        let actor_auth = context.actor_auth().await?;
+
+        let tasks = with_readonly_db(&context.db_connection_pool, |conn| {
+            db::CustomerTaskRequest::get_by_customer(&actor_auth, customer_id, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(tasks
+            .into_iter()
+            .map(|task| CustomerTaskRequest::new_with(task.id, Arc::new(task)))
+            .collect())
+    }
+}
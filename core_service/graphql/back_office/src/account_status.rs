@@ -0,0 +1,212 @@
+use account_service_server::{
+    DeleteAccountRequest, DeleteAccountResponse, LoadHandymanProfileByIdsRequest,
+    UpdateAccountStatusRequest,
+};
+use actor_auth::ActorKey;
+use async_graphql::{Context, ID, InputObject, Object, SimpleObject};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{ActorType, Node, parse_any_global_id};
+use db_utils::with_readonly_db;
+use entity_type::{AccountStatus, HandymanId};
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use search_service_db as sea_db;
+use search_service_server::{HandymanIndexRequest, HandymanIndexType};
+
+#[derive(Default)]
+pub struct AccountStatusMutation;
+
+#[Object]
+impl AccountStatusMutation {
+    /// Suspend or reinstate a customer or handyman account.
+    /// Suspended accounts are signed out of every device and can't sign in,
+    /// open tasks of suspended customers are cancelled,
+    /// suspended handymen are hidden from search until reinstated.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn update_account_status(
+        &self,
+        ctx: &Context<'_>,
+        input: UpdateAccountStatusInput,
+    ) -> Result<UpdateAccountStatusPayload> {
+        let UpdateAccountStatusInput { account_id, status } = input;
+        let actor_key = parse_account_id(&account_id)?;
+        let context = ctx.data::<RequestContext>()?;
+
+        let account = context
+            .account_service_client
+            .update_account_status(UpdateAccountStatusRequest {
+                actor_auth: context.actor_auth().await?,
+                actor_key,
+                status,
+            })
+            .await?
+            .account;
+
+        match (status, actor_key) {
+            (AccountStatus::Active, ActorKey::Handyman(handyman_id)) => {
+                rebuild_handyman_index(context, handyman_id).await?;
+            }
+            (AccountStatus::Active, _) => {}
+            (AccountStatus::Suspended | AccountStatus::Deleted, _) => {
+                context.withdraw_account(actor_key, status).await?;
+            }
+        }
+
+        Ok(UpdateAccountStatusPayload {
+            account: account.into(),
+        })
+    }
+
+    /// Delete a customer or handyman account on behalf of its owner,
+    /// personal data is anonymized and the phone number can be registered again.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn delete_account(
+        &self,
+        ctx: &Context<'_>,
+        input: DeleteAccountInput,
+    ) -> Result<DeleteAccountPayload> {
+        let DeleteAccountInput { account_id } = input;
+        let actor_key = parse_account_id(&account_id)?;
+        let context = ctx.data::<RequestContext>()?;
+
+        let DeleteAccountResponse {
+            account,
+            avatar_key,
+        } = context
+            .account_service_client
+            .delete_account(DeleteAccountRequest {
+                actor_auth: context.actor_auth().await?,
+                actor_key,
+                password: None,
+            })
+            .await?;
+        if let Some(avatar_key) = avatar_key {
+            context.delete_blob_quietly(&avatar_key).await;
+        }
+
+        context
+            .withdraw_account(actor_key, AccountStatus::Deleted)
+            .await?;
+
+        Ok(DeleteAccountPayload {
+            account: account.into(),
+        })
+    }
+}
+
+/// Only customer and handyman accounts have a status
+fn parse_account_id(id: &ID) -> Result<ActorKey> {
+    match parse_any_global_id(id)? {
+        Some(Node::Customer(customer)) => Ok(ActorKey::Customer(customer.inner_id())),
+        Some(Node::Handyman(handyman)) => Ok(ActorKey::Handyman(handyman.inner_id())),
+        _ => Err(Error::invalid_argument(
+            "Expect the ID of a customer or a handyman",
+        )),
+    }
+}
+
+/// Index a reinstated handyman again from the profile, services, location, reviews
+/// and availability
+async fn rebuild_handyman_index(context: &RequestContext, handyman_id: HandymanId) -> Result<()> {
+    let actor_auth = &context.actor_auth().await?;
+    let profile = context
+        .account_service_client
+        .load_handyman_profile_by_ids(LoadHandymanProfileByIdsRequest {
+            actor_auth: context.actor_auth().await?,
+            account_ids: vec![handyman_id],
+        })
+        .await?
+        .profiles
+        .pop();
+
+    let (services, location, rating_summary, (timezone, working_slots), blackouts) =
+        with_readonly_db(&context.db_connection_pool, |conn| {
+            async move {
+                let services =
+                    db::HandymanService::get_by_handyman(actor_auth, handyman_id, conn).await?;
+                let location =
+                    db::HandymanServiceLocation::get_by_handyman(actor_auth, handyman_id, conn)
+                        .await?;
+                let rating_summary =
+                    db::HandymanReview::rating_summary(actor_auth, handyman_id, conn).await?;
+                let working_slots =
+                    db::HandymanWorkingHours::load_working_slots(actor_auth, handyman_id, conn)
+                        .await?;
+                let blackouts =
+                    db::HandymanBlackout::get_by_handyman(actor_auth, handyman_id, conn).await?;
+                Ok((services, location, rating_summary, working_slots, blackouts))
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+    let mut index_types = vec![HandymanIndexType::SetAvgRatingScore {
+        avg_rating_score: rating_summary.avg_rating_score(),
+        review_count: rating_summary.review_count as i32,
+    }];
+    if let Some(profile) = profile {
+        index_types.push(HandymanIndexType::SetFullName(profile.full_name()));
+    }
+    if !services.is_empty() {
+        index_types.push(HandymanIndexType::AddSkills(
+            services.iter().map(|s| s.service.clone()).collect(),
+        ));
+    }
+    if let Some(location) = location {
+        index_types.push(HandymanIndexType::SetLocation {
+            lon: location.lon,
+            lat: location.lat,
+        });
+    }
+    if !working_slots.is_empty() || !blackouts.is_empty() {
+        index_types.push(HandymanIndexType::SetAvailability(
+            sea_db::HandymanAvailability {
+                timezone,
+                working_slots: working_slots
+                    .into_iter()
+                    .map(|slot| sea_db::HandymanWorkingSlot {
+                        weekday: slot.weekday,
+                        start_minute: slot.start_minute,
+                        end_minute: slot.end_minute,
+                    })
+                    .collect(),
+                blackout_dates: blackouts.into_iter().map(|b| b.date).collect(),
+            },
+        ));
+    }
+
+    for index_type in index_types {
+        context
+            .search_service_client
+            .handyman_index(HandymanIndexRequest {
+                handyman_id,
+                index_type,
+            })
+            .await?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, InputObject)]
+struct UpdateAccountStatusInput {
+    /// ID of a customer or a handyman
+    account_id: ID,
+    status: AccountStatus,
+}
+
+#[derive(SimpleObject)]
+struct UpdateAccountStatusPayload {
+    account: ActorType,
+}
+
+#[derive(Debug, InputObject)]
+struct DeleteAccountInput {
+    /// ID of a customer or a handyman
+    account_id: ID,
+}
+
+#[derive(SimpleObject)]
+struct DeleteAccountPayload {
+    account: ActorType,
+}
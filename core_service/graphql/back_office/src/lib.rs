@@ -0,0 +1,20 @@
+//! GraphQL schema of the back-office used by staff for moderation and support.
+//! Requests are authenticated by a staff credential and resolve nodes as god.
+
+mod query;
+pub use query::*;
+
+mod mutation;
+pub use mutation::*;
+
+mod account_lookup;
+pub(crate) use account_lookup::*;
+
+mod moderation;
+pub(crate) use moderation::*;
+
+mod account_status;
+pub(crate) use account_status::*;
+
+mod service_catalog;
+pub(crate) use service_catalog::*;
@@ -0,0 +1,180 @@
+use account_service_server::{CustomerUpdateProfileRequest, HandymanUpdateProfileRequest};
+use async_graphql::{Context, ID, InputObject, Object, SimpleObject};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{
+    Customer, CustomerProfile, GlobalId, Handyman, HandymanProfile, HandymanService,
+};
+use db_utils::with_mutable_db;
+use entity_type::HandymanAccessGuardId;
+use error::Result;
+use scoped_futures::ScopedFutureExt;
+use search_service_server::HandymanIndexType;
+use std::sync::Arc;
+
+/// Replacement of names hidden by staff, names can't be empty.
+pub const HIDDEN_NAME: &str = "***";
+
+#[derive(Default)]
+pub struct ModerationMutation;
+
+#[Object]
+impl ModerationMutation {
+    /// Correct the nick name of a customer, or hide it when `nickName` is null.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn moderate_customer_nick_name(
+        &self,
+        ctx: &Context<'_>,
+        input: ModerateCustomerNickNameInput,
+    ) -> Result<ModerateCustomerNickNamePayload> {
+        let ModerateCustomerNickNameInput {
+            customer_id,
+            nick_name,
+        } = input;
+        let customer_id = Customer::from_global_id(&customer_id)?.inner_id();
+        let context = ctx.data::<RequestContext>()?;
+
+        let profile = context
+            .account_service_client
+            .customer_update_profile(CustomerUpdateProfileRequest {
+                actor_auth: context.actor_auth().await?,
+                customer_id,
+                nick_name: Some(nick_name.unwrap_or_else(|| HIDDEN_NAME.into())),
+            })
+            .await?
+            .profile;
+
+        Ok(ModerateCustomerNickNamePayload {
+            profile: CustomerProfile::new_with(profile.customer_id, Arc::new(profile)),
+        })
+    }
+
+    /// Correct the name of a handyman, or hide it when `name` is null.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn moderate_handyman_name(
+        &self,
+        ctx: &Context<'_>,
+        input: ModerateHandymanNameInput,
+    ) -> Result<ModerateHandymanNamePayload> {
+        let ModerateHandymanNameInput { handyman_id, name } = input;
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+        let HandymanNameInput {
+            first_name,
+            last_name,
+        } = name.unwrap_or_else(|| HandymanNameInput {
+            first_name: HIDDEN_NAME.into(),
+            last_name: HIDDEN_NAME.into(),
+        });
+        let context = ctx.data::<RequestContext>()?;
+
+        let profile = context
+            .account_service_client
+            .handyman_update_profile(HandymanUpdateProfileRequest {
+                actor_auth: context.actor_auth().await?,
+                handyman_id,
+                first_name: Some(first_name),
+                last_name: Some(last_name),
+                bio: None,
+                years_of_experience: None,
+                avatar_key: None,
+            })
+            .await?
+            .profile;
+
+        context
+            .index_handyman_if_active(
+                handyman_id,
+                HandymanIndexType::SetFullName(profile.full_name()),
+            )
+            .await?;
+
+        context
+            .handyman_loaders
+            .profile_by_id_loader
+            .feed_one(profile.handyman_id, Arc::new(profile))
+            .await;
+
+        Ok(ModerateHandymanNamePayload {
+            profile: HandymanProfile::new(handyman_id),
+        })
+    }
+
+    /// Correct the note of a handyman service, or hide it when `note` is null.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn moderate_handyman_service_note(
+        &self,
+        ctx: &Context<'_>,
+        input: ModerateHandymanServiceNoteInput,
+    ) -> Result<ModerateHandymanServiceNotePayload> {
+        let ModerateHandymanServiceNoteInput {
+            handyman_id,
+            service_id,
+            note,
+        } = input;
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+        let service_id = HandymanService::from_global_id(&service_id)?.id;
+        let context = ctx.data::<RequestContext>()?;
+        let actor_auth = context.actor_auth().await?;
+
+        let updated = with_mutable_db(&context.db_connection_pool, |conn| {
+            db::HandymanService::update(
+                &actor_auth,
+                HandymanAccessGuardId {
+                    handyman_id,
+                    entity_id: service_id,
+                },
+                db::HandymanServiceChangeset {
+                    note: Some(note.as_deref()),
+                    rate_vnd: None,
+                },
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(ModerateHandymanServiceNotePayload {
+            service: HandymanService::new(Arc::new(updated)),
+        })
+    }
+}
+
+#[derive(Debug, InputObject)]
+struct ModerateCustomerNickNameInput {
+    customer_id: ID,
+    nick_name: Option<String>,
+}
+
+#[derive(SimpleObject)]
+struct ModerateCustomerNickNamePayload {
+    profile: CustomerProfile,
+}
+
+#[derive(Debug, InputObject)]
+struct ModerateHandymanNameInput {
+    handyman_id: ID,
+    name: Option<HandymanNameInput>,
+}
+
+#[derive(Debug, InputObject)]
+struct HandymanNameInput {
+    first_name: String,
+    last_name: String,
+}
+
+#[derive(SimpleObject)]
+struct ModerateHandymanNamePayload {
+    profile: HandymanProfile,
+}
+
+#[derive(Debug, InputObject)]
+struct ModerateHandymanServiceNoteInput {
+    handyman_id: ID,
+    service_id: ID,
+    note: Option<String>,
+}
+
+#[derive(SimpleObject)]
+struct ModerateHandymanServiceNotePayload {
+    service: HandymanService,
+}
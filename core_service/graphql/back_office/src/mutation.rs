@@ -0,0 +1,9 @@
+use crate::*;
+use async_graphql::MergedObject;
+
+#[derive(MergedObject, Default)]
+pub struct BackOfficeMutation(
+    ModerationMutation,
+    AccountStatusMutation,
+    ServiceCatalogMutation,
+);
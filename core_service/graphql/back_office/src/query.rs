@@ -0,0 +1,5 @@
+use crate::*;
+use async_graphql::MergedObject;
+
+#[derive(MergedObject, Default)]
+pub struct BackOfficeQuery(AccountLookupQuery);
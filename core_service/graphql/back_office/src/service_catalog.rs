@@ -0,0 +1,168 @@
+use account_service_server::LoadInactiveHandymanIdsRequest;
+use async_graphql::{Context, InputObject, Object, SimpleObject};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{Service, ServiceGroup};
+use db_utils::with_mutable_db;
+use error::Result;
+use scoped_futures::ScopedFutureExt;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct ServiceCatalogMutation;
+
+#[Object]
+impl ServiceCatalogMutation {
+    /// Add a group to the service catalog, or edit the group having the same code.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn upsert_service_group(
+        &self,
+        ctx: &Context<'_>,
+        input: UpsertServiceGroupInput,
+    ) -> Result<UpsertServiceGroupPayload> {
+        let UpsertServiceGroupInput {
+            code,
+            name_vi,
+            name_en,
+            icon,
+            display_order,
+            is_active,
+        } = input;
+        let context = ctx.data::<RequestContext>()?;
+        let actor_auth = context.actor_auth().await?;
+
+        let group = with_mutable_db(&context.db_connection_pool, |conn| {
+            db::CatalogServiceGroup::upsert(
+                &actor_auth,
+                db::NewCatalogServiceGroup {
+                    code: &code,
+                    name_vi: &name_vi,
+                    name_en: &name_en,
+                    icon: icon.as_deref(),
+                    display_order,
+                    is_active,
+                },
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(UpsertServiceGroupPayload {
+            group: ServiceGroup::new_with(group.code.clone(), Arc::new(group)),
+        })
+    }
+
+    /// Add a service to the catalog, or edit the service having the same code.
+    /// Deactivate a service instead of removing it, existing tasks and handymen still refer to it.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn upsert_service(
+        &self,
+        ctx: &Context<'_>,
+        input: UpsertServiceInput,
+    ) -> Result<UpsertServicePayload> {
+        let UpsertServiceInput {
+            code,
+            group_code,
+            name_vi,
+            name_en,
+            icon,
+            recommended_rate_vnd,
+            display_order,
+            is_active,
+        } = input;
+        let context = ctx.data::<RequestContext>()?;
+        let actor_auth = context.actor_auth().await?;
+
+        let service = with_mutable_db(&context.db_connection_pool, |conn| {
+            db::CatalogService::upsert(
+                &actor_auth,
+                db::NewCatalogService {
+                    code: &code,
+                    group_code: &group_code,
+                    name_vi: &name_vi,
+                    name_en: &name_en,
+                    icon: icon.as_deref(),
+                    recommended_rate_vnd,
+                    display_order,
+                    is_active,
+                },
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(UpsertServicePayload {
+            service: Service::new_with(service.code.clone(), Arc::new(service)),
+        })
+    }
+
+    /// Recompute price distributions of services now instead of waiting for the periodic refresh.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn refresh_service_rate_stats(
+        &self,
+        ctx: &Context<'_>,
+    ) -> Result<RefreshServiceRateStatsPayload> {
+        let context = ctx.data::<RequestContext>()?;
+        let excluded_handyman_ids = context
+            .account_service_client
+            .load_inactive_handyman_ids(LoadInactiveHandymanIdsRequest {
+                actor_auth: context.actor_auth().await?,
+            })
+            .await?
+            .handyman_ids;
+        let actor_auth = context.actor_auth().await?;
+
+        let distribution_count = with_mutable_db(&context.db_connection_pool, |conn| {
+            db::ServiceRateStat::refresh(&actor_auth, &excluded_handyman_ids, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(RefreshServiceRateStatsPayload { distribution_count })
+    }
+}
+
+#[derive(Debug, InputObject)]
+struct UpsertServiceGroupInput {
+    /// SCREAMING_SNAKE_CASE, can't be changed once created
+    code: String,
+    name_vi: String,
+    name_en: String,
+    icon: Option<String>,
+    #[graphql(default)]
+    display_order: i32,
+    #[graphql(default = true)]
+    is_active: bool,
+}
+
+#[derive(SimpleObject)]
+struct UpsertServiceGroupPayload {
+    group: ServiceGroup,
+}
+
+#[derive(Debug, InputObject)]
+struct UpsertServiceInput {
+    /// SCREAMING_SNAKE_CASE, can't be changed once created
+    code: String,
+    group_code: String,
+    name_vi: String,
+    name_en: String,
+    icon: Option<String>,
+    recommended_rate_vnd: Option<i32>,
+    #[graphql(default)]
+    display_order: i32,
+    #[graphql(default = true)]
+    is_active: bool,
+}
+
+#[derive(SimpleObject)]
+struct UpsertServicePayload {
+    service: Service,
+}
+
+#[derive(SimpleObject)]
+struct RefreshServiceRateStatsPayload {
+    /// Number of distributions over services and cities having enough prices
+    distribution_count: i32,
+}
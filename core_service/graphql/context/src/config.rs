@@ -1,4 +1,5 @@
 use cookie::SameSite;
+use rate_limiter::RateLimit;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -22,3 +23,28 @@ pub struct CookieConfig {
     pub same_site: SameSite,
     pub cookie_domain: String,
 }
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// Request rate limits of APIs open to unauthenticated users
+pub struct RateLimitConfig {
+    /// `userAccountStartRegistration`, each accepted request sends an OTP SMS
+    pub start_registration: ApiRateLimit,
+    /// `userSignInWithPassword`
+    pub sign_in_with_password: ApiRateLimit,
+    /// `userPasswordResetStart`, each accepted request may send an OTP SMS
+    pub start_password_reset: ApiRateLimit,
+    /// `userPhoneNumberChangeStart`, each accepted request sends an OTP SMS
+    pub start_phone_number_change: ApiRateLimit,
+    /// `userSignInWithOtpStart`, each accepted request may send an OTP SMS
+    pub start_sign_in_with_otp: ApiRateLimit,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiRateLimit {
+    /// Limit of requests from the same remote IP address
+    pub per_remote_addr: RateLimit,
+    /// Limit of requests for the same phone number and account type
+    pub per_phone_number: RateLimit,
+}
@@ -1,28 +1,37 @@
-use crate::{CookieConfig, EnvironmentConfig, Features};
+use crate::{CookieConfig, EnvironmentConfig, Features, RateLimitConfig};
 use account_service_server::AccountService;
-use actor_auth::Session;
+use actor_auth::{ActorAuth, ActorKey, Session};
+use blob_store::{BlobKey, BlobStore};
+use chrono::Duration;
+use core_service_db as db;
 use core_service_graphql_loader::{
-    CacheConfig, CustomerLoaders, HandymanLoaders, SyncSessionContext,
+    CacheConfig, ContextActor, CustomerLoaders, HandymanLoaders, ServiceLoaders, SessionLoaders,
+    SyncSessionContext, TaskLoaders,
 };
-use db_utils::PgConnectionPool;
-use error::{
-    Error, Result,
-    error_details::{BadRequest, bad_request::FieldViolation},
-};
-use moka::future::Cache;
+use db_utils::{PgConnectionPool, with_mutable_db};
+use entity_type::{AccountStatus, AccountType, HandymanId};
+use error::{Error, Result};
+use otp_store::{OtpKey, OtpPurpose, OtpStore};
 use random_util::Random;
-use search_service_server::SearchService;
+use rate_limiter::RateLimiter;
+use scoped_futures::ScopedFutureExt;
+use search_service_server::{
+    HandymanIndexDeleteRequest, HandymanIndexRequest, HandymanIndexType, SearchService,
+    TaskIndexDeleteRequest,
+};
 use sms_sender::SmsSender;
 use std::{net::SocketAddr, ops::Deref, sync::Arc};
 use tokio::sync::RwLock;
 
-/// OTP code for account registration having TTL is 15 mins
-pub const OTP_CODE_TTL_SECONDS: u64 = 60 * 15;
+/// Signed urls of private blobs, e.g. task photos, expire after this period
+const SIGNED_BLOB_URL_TTL_MINUTES: i64 = 15;
 
 pub struct ContextInternal {
     /// User session extracted from graphql request
     /// Can be mutable when user login, logout, reset password, etc.
     pub session_context: Arc<RwLock<Option<Arc<Session>>>>,
+    /// Who the request acts on behalf of, see [`RequestContext::actor_auth`]
+    pub context_actor: ContextActor,
     /// Connection pool to database
     pub db_connection_pool: PgConnectionPool,
     /// Server feature flags
@@ -34,15 +43,24 @@ pub struct ContextInternal {
     pub account_service_client: AccountService,
     pub search_service_client: SearchService,
     pub sms_sender: Arc<dyn SmsSender>,
-    /// Cache [e164_phone_number_str - 6 digits verification code]
-    phone_pending_registration_cache: Arc<Cache<String, String>>,
+    /// Pending OTP codes sent to phone numbers
+    otp_store: Arc<dyn OtpStore>,
+    /// Avatars and task photos
+    pub blob_store: Arc<dyn BlobStore>,
+    pub rate_limit_config: RateLimitConfig,
+    /// Request counters shared among requests, see [`RequestContext::check_rate_limit`]
+    pub(crate) rate_limiter: Arc<RateLimiter>,
     pub random: Random,
     pub customer_loaders: CustomerLoaders,
     pub handyman_loaders: HandymanLoaders,
+    pub task_loaders: TaskLoaders,
+    pub service_loaders: ServiceLoaders,
+    pub session_loaders: SessionLoaders,
 }
 
 pub struct NewContextParams {
     pub session_context: Option<Session>,
+    pub context_actor: ContextActor,
     pub db_connection_pool: PgConnectionPool,
     pub features: Features,
     pub environment_config: Arc<EnvironmentConfig>,
@@ -51,7 +69,10 @@ pub struct NewContextParams {
     pub account_service_client: AccountService,
     pub search_service_client: SearchService,
     pub sms_sender: Arc<dyn SmsSender>,
-    pub phone_pending_registration_cache: Arc<Cache<String, String>>,
+    pub otp_store: Arc<dyn OtpStore>,
+    pub blob_store: Arc<dyn BlobStore>,
+    pub rate_limit_config: RateLimitConfig,
+    pub rate_limiter: Arc<RateLimiter>,
     pub loader_cache_config: CacheConfig,
 }
 
@@ -59,6 +80,7 @@ impl ContextInternal {
     pub fn new(
         NewContextParams {
             session_context,
+            context_actor,
             db_connection_pool,
             features,
             environment_config,
@@ -67,7 +89,10 @@ impl ContextInternal {
             account_service_client,
             search_service_client,
             sms_sender,
-            phone_pending_registration_cache,
+            otp_store,
+            blob_store,
+            rate_limit_config,
+            rate_limiter,
             loader_cache_config,
         }: NewContextParams,
     ) -> Self {
@@ -79,19 +104,35 @@ impl ContextInternal {
             remote_addr,
             customer_loaders: CustomerLoaders::new(
                 account_service_client.clone(),
-                SyncSessionContext::new(session_context.clone()),
+                SyncSessionContext::new(session_context.clone(), context_actor),
                 loader_cache_config,
             ),
             handyman_loaders: HandymanLoaders::new(
+                db_connection_pool.clone(),
+                account_service_client.clone(),
+                SyncSessionContext::new(session_context.clone(), context_actor),
+                loader_cache_config,
+            ),
+            task_loaders: TaskLoaders::new(
+                db_connection_pool.clone(),
+                SyncSessionContext::new(session_context.clone(), context_actor),
+                loader_cache_config,
+            ),
+            service_loaders: ServiceLoaders::new(db_connection_pool.clone(), loader_cache_config),
+            session_loaders: SessionLoaders::new(
                 account_service_client.clone(),
-                SyncSessionContext::new(session_context.clone()),
+                SyncSessionContext::new(session_context.clone(), context_actor),
                 loader_cache_config,
             ),
             session_context,
+            context_actor,
             db_connection_pool,
             account_service_client,
             search_service_client,
-            phone_pending_registration_cache: phone_pending_registration_cache.clone(),
+            otp_store,
+            blob_store,
+            rate_limit_config,
+            rate_limiter,
             sms_sender,
             random: Random::default(),
         }
@@ -124,49 +165,181 @@ impl RequestContext {
             .ok_or_else(|| Error::unauthenticated("User is not authenticated"))
     }
 
-    /// Generate OTP code, insert to cache and return the OTP code
-    pub async fn pending_registration_phone_cache(
+    /// Authority which shared nodes are resolved with.
+    /// Back-office requests act as god, others act as the user session.
+    pub async fn actor_auth(&self) -> Result<ActorAuth> {
+        match self.context_actor {
+            ContextActor::Session => Ok(self.try_session_context().await?.as_actor_auth()),
+            ContextActor::God => Ok(ActorAuth::God),
+        }
+    }
+
+    /// Generate OTP code, store it for the phone number of the account type and return the OTP code
+    pub async fn otp_issue(
         &self,
+        purpose: OtpPurpose,
+        account_type: AccountType,
         e164_phone_number_str: String,
     ) -> Result<OtpCode> {
         static OTP_CODE_LENGTH: u8 = 6;
 
         let code = self.random.gen_numeric_string(OTP_CODE_LENGTH).await?;
-        self.phone_pending_registration_cache
-            .insert(e164_phone_number_str, code.clone())
-            .await;
+        self.otp_store
+            .issue(
+                &OtpKey {
+                    purpose,
+                    account_type,
+                    e164_phone_number_str,
+                },
+                &code,
+            )
+            .await?;
 
+        let policy = self.otp_store.policy();
         Ok(OtpCode {
             code,
             digits: OTP_CODE_LENGTH,
-            ttl_seconds: OTP_CODE_TTL_SECONDS,
+            ttl_seconds: policy.ttl.num_seconds() as u64,
+            resend_cooldown_seconds: policy.resend_cooldown.num_seconds() as u64,
         })
     }
 
-    /// Generate OTP code, insert to cache and return the OTP code
-    pub async fn pending_registration_phone_validate(
+    /// Public url of a blob stored under the key
+    pub fn blob_url(&self, key: &str) -> Result<String> {
+        Ok(self.blob_store.url(&BlobKey::parse(key)?))
+    }
+
+    /// Short-lived url of a private blob stored under the key,
+    /// only handed to actors who can read the entity owning the blob
+    pub fn signed_blob_url(&self, key: &str) -> Result<String> {
+        self.blob_store.signed_url(
+            &BlobKey::parse(key)?,
+            Duration::minutes(SIGNED_BLOB_URL_TTL_MINUTES),
+        )
+    }
+
+    /// Remove a blob which is no longer referenced. Failures are only logged,
+    /// an orphan blob is harmless while failing the request after the database is updated isn't.
+    pub async fn delete_blob_quietly(&self, key: &str) {
+        let result = match BlobKey::parse(key) {
+            Ok(key) => self.blob_store.delete(&key).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            tracing::warn!(error = ?e, key, "Cannot delete blob");
+        }
+    }
+
+    /// Update the search index of the handyman. Suspended handymen are removed from the index
+    /// until they are reinstated, so their index is left as is.
+    pub async fn index_handyman_if_active(
         &self,
-        e164_phone_number_str: &str,
-        otp_code: &str,
+        handyman_id: HandymanId,
+        index_type: HandymanIndexType,
     ) -> Result<()> {
-        let code = self
-            .phone_pending_registration_cache
-            .get(e164_phone_number_str)
-            .await;
+        let account = self
+            .handyman_loaders
+            .account_by_id_loader
+            .load_one(handyman_id)
+            .await?;
+        if account.is_some_and(|a| a.status == AccountStatus::Active) {
+            self.search_service_client
+                .handyman_index(HandymanIndexRequest {
+                    handyman_id,
+                    index_type,
+                })
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Withdraw a suspended or deleted account from the marketplace.
+    /// Open tasks of a customer are cancelled and removed from the task index, a handyman is
+    /// removed from the handyman index. Deleted accounts also lose the addresses of their tasks,
+    /// or their services and service location.
+    pub async fn withdraw_account(&self, actor_key: ActorKey, status: AccountStatus) -> Result<()> {
+        let deleted = status == AccountStatus::Deleted;
+        match actor_key {
+            ActorKey::Customer(customer_id) => {
+                let cancelled = with_mutable_db(&self.db_connection_pool, |conn| {
+                    async move {
+                        let cancelled = db::CustomerTaskRequest::cancel_open_by_customer(
+                            &ActorAuth::God,
+                            customer_id,
+                            conn,
+                        )
+                        .await?;
+                        if deleted {
+                            db::CustomerTaskRequest::anonymize_locations_by_customer(
+                                &ActorAuth::God,
+                                customer_id,
+                                conn,
+                            )
+                            .await?;
+                        }
+                        Ok(cancelled)
+                    }
+                    .scope_boxed()
+                })
+                .await?;
 
-        if Some(otp_code) == code.as_deref() {
-            return Ok(());
+                if !cancelled.is_empty() {
+                    self.search_service_client
+                        .task_index_delete(TaskIndexDeleteRequest {
+                            task_ids: cancelled.iter().map(|t| t.id).collect(),
+                        })
+                        .await?;
+                }
+            }
+            ActorKey::Handyman(handyman_id) => {
+                if deleted {
+                    with_mutable_db(&self.db_connection_pool, |conn| {
+                        async move {
+                            db::HandymanService::delete_by_handyman(
+                                &ActorAuth::God,
+                                handyman_id,
+                                conn,
+                            )
+                            .await?;
+                            db::HandymanServiceLocation::delete_by_handyman(
+                                &ActorAuth::God,
+                                handyman_id,
+                                conn,
+                            )
+                            .await
+                        }
+                        .scope_boxed()
+                    })
+                    .await?;
+                }
+
+                self.search_service_client
+                    .handyman_index_delete(HandymanIndexDeleteRequest { handyman_id })
+                    .await?;
+            }
+            ActorKey::Admin(_) => {}
         }
+        Ok(())
+    }
 
-        Err(Error::invalid_argument_with(
-            "OTP verification failed",
-            Some(BadRequest {
-                field_violations: vec![FieldViolation {
-                    field: "OTP_VERIFICATION".into(),
-                    description: "FAILED".into(),
-                }],
-            }),
-        ))
+    /// Verify OTP code sent to the phone number of the account type, the code is consumed on success
+    pub async fn otp_verify(
+        &self,
+        purpose: OtpPurpose,
+        account_type: AccountType,
+        e164_phone_number_str: &str,
+        otp_code: &str,
+    ) -> Result<()> {
+        self.otp_store
+            .verify(
+                &OtpKey {
+                    purpose,
+                    account_type,
+                    e164_phone_number_str: e164_phone_number_str.into(),
+                },
+                otp_code,
+            )
+            .await
     }
 }
 
@@ -175,4 +348,5 @@ pub struct OtpCode {
     pub code: String,
     pub digits: u8,
     pub ttl_seconds: u64,
+    pub resend_cooldown_seconds: u64,
 }
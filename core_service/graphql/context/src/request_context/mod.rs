@@ -2,3 +2,6 @@ mod context;
 pub use context::*;
 
 mod session_handler;
+
+mod rate_limit;
+pub use rate_limit::*;
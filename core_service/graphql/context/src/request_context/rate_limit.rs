@@ -0,0 +1,62 @@
+use crate::{ApiRateLimit, RequestContext};
+use entity_type::AccountType;
+use error::Result;
+
+#[derive(Debug, Clone, Copy)]
+/// APIs guarded by [`crate::RateLimitConfig`]
+pub enum RateLimitedApi {
+    StartRegistration,
+    SignInWithPassword,
+    StartPasswordReset,
+    StartPhoneNumberChange,
+    StartSignInWithOtp,
+}
+
+impl RateLimitedApi {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateLimitedApi::StartRegistration => "START_REGISTRATION",
+            RateLimitedApi::SignInWithPassword => "SIGN_IN_WITH_PASSWORD",
+            RateLimitedApi::StartPasswordReset => "START_PASSWORD_RESET",
+            RateLimitedApi::StartPhoneNumberChange => "START_PHONE_NUMBER_CHANGE",
+            RateLimitedApi::StartSignInWithOtp => "START_SIGN_IN_WITH_OTP",
+        }
+    }
+
+    fn limit(&self, context: &RequestContext) -> ApiRateLimit {
+        match self {
+            RateLimitedApi::StartRegistration => context.rate_limit_config.start_registration,
+            RateLimitedApi::SignInWithPassword => context.rate_limit_config.sign_in_with_password,
+            RateLimitedApi::StartPasswordReset => context.rate_limit_config.start_password_reset,
+            RateLimitedApi::StartPhoneNumberChange => {
+                context.rate_limit_config.start_phone_number_change
+            }
+            RateLimitedApi::StartSignInWithOtp => context.rate_limit_config.start_sign_in_with_otp,
+        }
+    }
+}
+
+impl RequestContext {
+    /// Count a request to the API, reject it if either the remote address
+    /// or the phone number of the account type has exceeded its limit.
+    pub fn check_rate_limit(
+        &self,
+        api: RateLimitedApi,
+        account_type: AccountType,
+        e164_phone_number_str: &str,
+    ) -> Result<()> {
+        let limit = api.limit(self);
+        let subject = api.as_str();
+
+        // Port is excluded since every connection of a client has a different one
+        let remote_addr_key = format!("{subject}:{}", self.remote_addr.ip());
+        self.rate_limiter
+            .acquire(&remote_addr_key, &limit.per_remote_addr)
+            .into_result(subject, "TOO_MANY_REQUESTS_FROM_REMOTE_ADDR")?;
+
+        let phone_number_key = format!("{subject}:{account_type:?}:{e164_phone_number_str}");
+        self.rate_limiter
+            .acquire(&phone_number_key, &limit.per_phone_number)
+            .into_result(subject, "TOO_MANY_REQUESTS_FOR_PHONE_NUMBER")
+    }
+}
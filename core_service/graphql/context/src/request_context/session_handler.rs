@@ -30,6 +30,14 @@ impl RequestContext {
         Ok(new_session_ctx)
     }
 
+    /// Clear
+    /// - http session
+    /// - context session
+    pub async fn clear_session(&self, ctx: &Context<'_>) {
+        self.session_context.write().await.take();
+        self.clear_session_cookies(ctx);
+    }
+
     pub fn set_session_cookies(&self, ctx: &Context<'_>, token: SessionAndCsrfToken) {
         let jwt_cookie = Cookie::build((ACCESS_TOKEN_COOKIE_KEY, token.session_token))
             .secure(self.cookie_config.use_https)
@@ -1,23 +1,37 @@
-use actor_auth::Session;
+use actor_auth::{ActorAuth, Session};
 use error::{Error, Result};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 #[derive(Clone)]
-pub struct SyncSessionContext(Arc<RwLock<Option<Arc<Session>>>>);
+pub struct SyncSessionContext {
+    session: Arc<RwLock<Option<Arc<Session>>>>,
+    context_actor: ContextActor,
+}
 
 impl SyncSessionContext {
-    pub fn new(inner: Arc<RwLock<Option<Arc<Session>>>>) -> Self {
-        Self(inner)
+    pub fn new(session: Arc<RwLock<Option<Arc<Session>>>>, context_actor: ContextActor) -> Self {
+        Self {
+            session,
+            context_actor,
+        }
     }
 
     pub async fn try_session_context(&self) -> Result<Arc<Session>> {
-        let session_context = self.0.read().await;
+        let session_context = self.session.read().await;
         session_context
             .as_ref()
             .map(Arc::clone)
             .ok_or_else(|| Error::unauthenticated("Unauthenticated"))
     }
+
+    /// Authority which data is loaded with, see [`ContextActor`]
+    pub async fn actor_auth(&self) -> Result<ActorAuth> {
+        match self.context_actor {
+            ContextActor::Session => Ok(self.try_session_context().await?.as_actor_auth()),
+            ContextActor::God => Ok(ActorAuth::God),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -25,3 +39,12 @@ pub enum CacheConfig {
     Cache,
     NoCache,
 }
+
+/// Who a request context acts on behalf of
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextActor {
+    /// The user session extracted from the request, requests without one are unauthenticated
+    Session,
+    /// Staff requests which passed the back-office credential check
+    God,
+}
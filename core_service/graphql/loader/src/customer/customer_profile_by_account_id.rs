@@ -20,11 +20,11 @@ impl Loader<CustomerId> for CustomerProfileByIdLoaderInner {
         &self,
         keys: &[CustomerId],
     ) -> Result<HashMap<CustomerId, Arc<acc_db::CustomerProfile>>> {
-        let session_ctx = self.session_ctx.try_session_context().await?;
+        let actor_auth = self.session_ctx.actor_auth().await?;
         let batch = self
             .account_service_client
             .load_customer_profile_by_ids(LoadCustomerProfileByIdsRequest {
-                actor_auth: session_ctx.as_actor_auth(),
+                actor_auth,
                 account_ids: keys.to_vec(),
             })
             .await?;
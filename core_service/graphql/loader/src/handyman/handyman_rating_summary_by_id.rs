@@ -0,0 +1,68 @@
+use crate::{CacheConfig, SyncSessionContext};
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use core_service_db as db;
+use db_utils::{PgConnectionPool, with_readonly_db};
+use entity_type::HandymanId;
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use std::{collections::HashMap, ops::Deref};
+
+#[doc(hidden)]
+pub struct HandymanRatingSummaryByIdLoaderInner {
+    db_connection_pool: PgConnectionPool,
+    session_ctx: SyncSessionContext,
+}
+
+impl Loader<HandymanId> for HandymanRatingSummaryByIdLoaderInner {
+    type Error = Error;
+    type Value = db::HandymanRatingSummary;
+
+    async fn load(
+        &self,
+        keys: &[HandymanId],
+    ) -> Result<HashMap<HandymanId, db::HandymanRatingSummary>> {
+        let actor_auth = self.session_ctx.actor_auth().await?;
+        let batch = with_readonly_db(&self.db_connection_pool, |conn| {
+            db::HandymanReview::rating_summaries(&actor_auth, keys, conn).scope_boxed()
+        })
+        .await?;
+        Ok(batch.into_iter().collect())
+    }
+}
+
+/// Handymen without any review are missing from the result,
+/// their summary is [`db::HandymanRatingSummary::default`].
+pub struct HandymanRatingSummaryByIdLoader(
+    DataLoader<HandymanRatingSummaryByIdLoaderInner, HashMapCache>,
+);
+
+impl Deref for HandymanRatingSummaryByIdLoader {
+    type Target = DataLoader<HandymanRatingSummaryByIdLoaderInner, HashMapCache>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl HandymanRatingSummaryByIdLoader {
+    pub fn new(
+        db_connection_pool: PgConnectionPool,
+        session_ctx: SyncSessionContext,
+        cache_config: CacheConfig,
+    ) -> Self {
+        let loader = DataLoader::with_cache(
+            HandymanRatingSummaryByIdLoaderInner {
+                db_connection_pool,
+                session_ctx,
+            },
+            tokio::spawn,
+            HashMapCache::new(),
+        );
+
+        if matches!(cache_config, CacheConfig::NoCache) {
+            loader.enable_all_cache(false);
+        }
+
+        Self(loader)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{CacheConfig, SyncSessionContext};
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use core_service_db as db;
+use db_utils::{PgConnectionPool, with_readonly_db};
+use entity_type::HandymanReviewId;
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use std::{collections::HashMap, ops::Deref, sync::Arc};
+
+#[doc(hidden)]
+pub struct HandymanReviewByIdLoaderInner {
+    db_connection_pool: PgConnectionPool,
+    session_ctx: SyncSessionContext,
+}
+
+impl Loader<HandymanReviewId> for HandymanReviewByIdLoaderInner {
+    type Error = Error;
+    type Value = Arc<db::HandymanReview>;
+
+    async fn load(
+        &self,
+        keys: &[HandymanReviewId],
+    ) -> Result<HashMap<HandymanReviewId, Arc<db::HandymanReview>>> {
+        let actor_auth = self.session_ctx.actor_auth().await?;
+        let batch = with_readonly_db(&self.db_connection_pool, |conn| {
+            db::HandymanReview::load_by_ids(&actor_auth, keys, conn).scope_boxed()
+        })
+        .await?;
+        Ok(batch.into_iter().map(|r| (r.id, Arc::new(r))).collect())
+    }
+}
+
+pub struct HandymanReviewByIdLoader(DataLoader<HandymanReviewByIdLoaderInner, HashMapCache>);
+
+impl Deref for HandymanReviewByIdLoader {
+    type Target = DataLoader<HandymanReviewByIdLoaderInner, HashMapCache>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl HandymanReviewByIdLoader {
+    pub fn new(
+        db_connection_pool: PgConnectionPool,
+        session_ctx: SyncSessionContext,
+        cache_config: CacheConfig,
+    ) -> Self {
+        let loader = DataLoader::with_cache(
+            HandymanReviewByIdLoaderInner {
+                db_connection_pool,
+                session_ctx,
+            },
+            tokio::spawn,
+            HashMapCache::new(),
+        );
+
+        if matches!(cache_config, CacheConfig::NoCache) {
+            loader.enable_all_cache(false);
+        }
+
+        Self(loader)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{CacheConfig, SyncSessionContext};
 use account_service_server::AccountService;
+use db_utils::PgConnectionPool;
 
 mod handyman_account_by_id;
 pub use handyman_account_by_id::*;
@@ -7,13 +8,22 @@ pub use handyman_account_by_id::*;
 mod handyman_profile_by_account_id;
 pub use handyman_profile_by_account_id::*;
 
+mod handyman_rating_summary_by_id;
+pub use handyman_rating_summary_by_id::*;
+
+mod handyman_review_by_id;
+pub use handyman_review_by_id::*;
+
 pub struct HandymanLoaders {
     pub account_by_id_loader: HandymanAccountByIdLoader,
     pub profile_by_id_loader: HandymanProfileByIdLoader,
+    pub rating_summary_by_id_loader: HandymanRatingSummaryByIdLoader,
+    pub review_by_id_loader: HandymanReviewByIdLoader,
 }
 
 impl HandymanLoaders {
     pub fn new(
+        db_connection_pool: PgConnectionPool,
         account_service_client: AccountService,
         session_ctx: SyncSessionContext,
         cache_config: CacheConfig,
@@ -26,6 +36,16 @@ impl HandymanLoaders {
             ),
             profile_by_id_loader: HandymanProfileByIdLoader::new(
                 account_service_client,
+                session_ctx.clone(),
+                cache_config,
+            ),
+            rating_summary_by_id_loader: HandymanRatingSummaryByIdLoader::new(
+                db_connection_pool.clone(),
+                session_ctx.clone(),
+                cache_config,
+            ),
+            review_by_id_loader: HandymanReviewByIdLoader::new(
+                db_connection_pool,
                 session_ctx,
                 cache_config,
             ),
@@ -6,3 +6,12 @@ pub use customer::*;
 
 mod handyman;
 pub use handyman::*;
+
+mod task;
+pub use task::*;
+
+mod service;
+pub use service::*;
+
+mod session;
+pub use session::*;
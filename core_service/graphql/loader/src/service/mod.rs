@@ -0,0 +1,37 @@
+use crate::CacheConfig;
+use db_utils::PgConnectionPool;
+
+mod service_by_code;
+pub use service_by_code::*;
+
+mod service_group_by_code;
+pub use service_group_by_code::*;
+
+mod rate_stats_by_service_code;
+pub use rate_stats_by_service_code::*;
+
+/// Loaders of the service catalog, which is public and loaded without any actor
+pub struct ServiceLoaders {
+    pub service_by_code_loader: ServiceByCodeLoader,
+    pub service_group_by_code_loader: ServiceGroupByCodeLoader,
+    pub rate_stats_by_service_code_loader: RateStatsByServiceCodeLoader,
+}
+
+impl ServiceLoaders {
+    pub fn new(db_connection_pool: PgConnectionPool, cache_config: CacheConfig) -> Self {
+        Self {
+            service_by_code_loader: ServiceByCodeLoader::new(
+                db_connection_pool.clone(),
+                cache_config,
+            ),
+            service_group_by_code_loader: ServiceGroupByCodeLoader::new(
+                db_connection_pool.clone(),
+                cache_config,
+            ),
+            rate_stats_by_service_code_loader: RateStatsByServiceCodeLoader::new(
+                db_connection_pool,
+                cache_config,
+            ),
+        }
+    }
+}
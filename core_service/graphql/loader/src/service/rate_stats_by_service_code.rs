@@ -0,0 +1,62 @@
+use crate::CacheConfig;
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use core_service_db as db;
+use db_utils::{PgConnectionPool, with_readonly_db};
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use std::{collections::HashMap, ops::Deref, sync::Arc};
+
+#[doc(hidden)]
+pub struct RateStatsByServiceCodeLoaderInner {
+    db_connection_pool: PgConnectionPool,
+}
+
+impl Loader<String> for RateStatsByServiceCodeLoaderInner {
+    type Error = Error;
+    type Value = Arc<Vec<db::ServiceRateStat>>;
+
+    /// Services without any distribution are missing from the result
+    async fn load(
+        &self,
+        keys: &[String],
+    ) -> Result<HashMap<String, Arc<Vec<db::ServiceRateStat>>>> {
+        let batch = with_readonly_db(&self.db_connection_pool, |conn| {
+            db::ServiceRateStat::load_by_services(keys, conn).scope_boxed()
+        })
+        .await?;
+        Ok(
+            db_utils::group_by(batch.into_iter().map(|s| (s.service.clone(), s)))
+                .into_iter()
+                .map(|(service, stats)| (service, Arc::new(stats)))
+                .collect(),
+        )
+    }
+}
+
+pub struct RateStatsByServiceCodeLoader(
+    DataLoader<RateStatsByServiceCodeLoaderInner, HashMapCache>,
+);
+
+impl Deref for RateStatsByServiceCodeLoader {
+    type Target = DataLoader<RateStatsByServiceCodeLoaderInner, HashMapCache>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl RateStatsByServiceCodeLoader {
+    pub fn new(db_connection_pool: PgConnectionPool, cache_config: CacheConfig) -> Self {
+        let loader = DataLoader::with_cache(
+            RateStatsByServiceCodeLoaderInner { db_connection_pool },
+            tokio::spawn,
+            HashMapCache::new(),
+        );
+
+        if matches!(cache_config, CacheConfig::NoCache) {
+            loader.enable_all_cache(false);
+        }
+
+        Self(loader)
+    }
+}
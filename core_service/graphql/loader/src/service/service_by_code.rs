@@ -0,0 +1,54 @@
+use crate::CacheConfig;
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use core_service_db as db;
+use db_utils::{PgConnectionPool, with_readonly_db};
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use std::{collections::HashMap, ops::Deref, sync::Arc};
+
+#[doc(hidden)]
+pub struct ServiceByCodeLoaderInner {
+    db_connection_pool: PgConnectionPool,
+}
+
+impl Loader<String> for ServiceByCodeLoaderInner {
+    type Error = Error;
+    type Value = Arc<db::CatalogService>;
+
+    async fn load(&self, keys: &[String]) -> Result<HashMap<String, Arc<db::CatalogService>>> {
+        let batch = with_readonly_db(&self.db_connection_pool, |conn| {
+            db::CatalogService::load_by_codes(keys, conn).scope_boxed()
+        })
+        .await?;
+        Ok(batch
+            .into_iter()
+            .map(|s| (s.code.clone(), Arc::new(s)))
+            .collect())
+    }
+}
+
+pub struct ServiceByCodeLoader(DataLoader<ServiceByCodeLoaderInner, HashMapCache>);
+
+impl Deref for ServiceByCodeLoader {
+    type Target = DataLoader<ServiceByCodeLoaderInner, HashMapCache>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl ServiceByCodeLoader {
+    pub fn new(db_connection_pool: PgConnectionPool, cache_config: CacheConfig) -> Self {
+        let loader = DataLoader::with_cache(
+            ServiceByCodeLoaderInner { db_connection_pool },
+            tokio::spawn,
+            HashMapCache::new(),
+        );
+
+        if matches!(cache_config, CacheConfig::NoCache) {
+            loader.enable_all_cache(false);
+        }
+
+        Self(loader)
+    }
+}
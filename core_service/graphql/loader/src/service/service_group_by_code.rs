@@ -0,0 +1,54 @@
+use crate::CacheConfig;
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use core_service_db as db;
+use db_utils::{PgConnectionPool, with_readonly_db};
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use std::{collections::HashMap, ops::Deref, sync::Arc};
+
+#[doc(hidden)]
+pub struct ServiceGroupByCodeLoaderInner {
+    db_connection_pool: PgConnectionPool,
+}
+
+impl Loader<String> for ServiceGroupByCodeLoaderInner {
+    type Error = Error;
+    type Value = Arc<db::CatalogServiceGroup>;
+
+    async fn load(&self, keys: &[String]) -> Result<HashMap<String, Arc<db::CatalogServiceGroup>>> {
+        let batch = with_readonly_db(&self.db_connection_pool, |conn| {
+            db::CatalogServiceGroup::load_by_codes(keys, conn).scope_boxed()
+        })
+        .await?;
+        Ok(batch
+            .into_iter()
+            .map(|s| (s.code.clone(), Arc::new(s)))
+            .collect())
+    }
+}
+
+pub struct ServiceGroupByCodeLoader(DataLoader<ServiceGroupByCodeLoaderInner, HashMapCache>);
+
+impl Deref for ServiceGroupByCodeLoader {
+    type Target = DataLoader<ServiceGroupByCodeLoaderInner, HashMapCache>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl ServiceGroupByCodeLoader {
+    pub fn new(db_connection_pool: PgConnectionPool, cache_config: CacheConfig) -> Self {
+        let loader = DataLoader::with_cache(
+            ServiceGroupByCodeLoaderInner { db_connection_pool },
+            tokio::spawn,
+            HashMapCache::new(),
+        );
+
+        if matches!(cache_config, CacheConfig::NoCache) {
+            loader.enable_all_cache(false);
+        }
+
+        Self(loader)
+    }
+}
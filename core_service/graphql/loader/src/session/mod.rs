@@ -0,0 +1,25 @@
+use crate::{CacheConfig, SyncSessionContext};
+use account_service_server::AccountService;
+
+mod user_session_by_id;
+pub use user_session_by_id::*;
+
+pub struct SessionLoaders {
+    pub user_session_by_id_loader: UserSessionByIdLoader,
+}
+
+impl SessionLoaders {
+    pub fn new(
+        account_service_client: AccountService,
+        session_ctx: SyncSessionContext,
+        cache_config: CacheConfig,
+    ) -> Self {
+        Self {
+            user_session_by_id_loader: UserSessionByIdLoader::new(
+                account_service_client,
+                session_ctx,
+                cache_config,
+            ),
+        }
+    }
+}
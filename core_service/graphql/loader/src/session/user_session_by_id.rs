@@ -0,0 +1,70 @@
+use crate::{CacheConfig, SyncSessionContext};
+use account_service_db as acc_db;
+use account_service_server::{AccountService, LoadUserSessionByIdsRequest};
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use entity_type::UserSessionId;
+use error::{Error, Result};
+use std::{collections::HashMap, ops::Deref, sync::Arc};
+
+#[doc(hidden)]
+pub struct UserSessionByIdLoaderInner {
+    account_service_client: AccountService,
+    session_ctx: SyncSessionContext,
+}
+
+impl Loader<UserSessionId> for UserSessionByIdLoaderInner {
+    type Error = Error;
+    type Value = Arc<acc_db::UserSession>;
+
+    async fn load(
+        &self,
+        keys: &[UserSessionId],
+    ) -> Result<HashMap<UserSessionId, Arc<acc_db::UserSession>>> {
+        let actor_auth = self.session_ctx.actor_auth().await?;
+        let batch = self
+            .account_service_client
+            .load_user_session_by_ids(LoadUserSessionByIdsRequest {
+                actor_auth,
+                session_ids: keys.to_vec(),
+            })
+            .await?;
+        Ok(batch
+            .sessions
+            .into_iter()
+            .map(|s| (s.id, Arc::new(s)))
+            .collect())
+    }
+}
+
+pub struct UserSessionByIdLoader(DataLoader<UserSessionByIdLoaderInner, HashMapCache>);
+
+impl Deref for UserSessionByIdLoader {
+    type Target = DataLoader<UserSessionByIdLoaderInner, HashMapCache>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl UserSessionByIdLoader {
+    pub fn new(
+        account_service_client: AccountService,
+        session_ctx: SyncSessionContext,
+        cache_config: CacheConfig,
+    ) -> Self {
+        let loader = DataLoader::with_cache(
+            UserSessionByIdLoaderInner {
+                account_service_client,
+                session_ctx,
+            },
+            tokio::spawn,
+            HashMapCache::new(),
+        );
+
+        if matches!(cache_config, CacheConfig::NoCache) {
+            loader.enable_all_cache(false);
+        }
+
+        Self(loader)
+    }
+}
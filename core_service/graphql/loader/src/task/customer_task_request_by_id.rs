@@ -0,0 +1,66 @@
+use crate::{CacheConfig, SyncSessionContext};
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use core_service_db as db;
+use db_utils::{PgConnectionPool, with_readonly_db};
+use entity_type::CustomerTaskRequestId;
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use std::{collections::HashMap, ops::Deref, sync::Arc};
+
+#[doc(hidden)]
+pub struct CustomerTaskRequestByIdLoaderInner {
+    db_connection_pool: PgConnectionPool,
+    session_ctx: SyncSessionContext,
+}
+
+impl Loader<CustomerTaskRequestId> for CustomerTaskRequestByIdLoaderInner {
+    type Error = Error;
+    type Value = Arc<db::CustomerTaskRequest>;
+
+    async fn load(
+        &self,
+        keys: &[CustomerTaskRequestId],
+    ) -> Result<HashMap<CustomerTaskRequestId, Arc<db::CustomerTaskRequest>>> {
+        let actor_auth = self.session_ctx.actor_auth().await?;
+        let batch = with_readonly_db(&self.db_connection_pool, |conn| {
+            db::CustomerTaskRequest::load_by_ids(&actor_auth, keys, conn).scope_boxed()
+        })
+        .await?;
+        Ok(batch.into_iter().map(|t| (t.id, Arc::new(t))).collect())
+    }
+}
+
+pub struct CustomerTaskRequestByIdLoader(
+    DataLoader<CustomerTaskRequestByIdLoaderInner, HashMapCache>,
+);
+
+impl Deref for CustomerTaskRequestByIdLoader {
+    type Target = DataLoader<CustomerTaskRequestByIdLoaderInner, HashMapCache>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl CustomerTaskRequestByIdLoader {
+    pub fn new(
+        db_connection_pool: PgConnectionPool,
+        session_ctx: SyncSessionContext,
+        cache_config: CacheConfig,
+    ) -> Self {
+        let loader = DataLoader::with_cache(
+            CustomerTaskRequestByIdLoaderInner {
+                db_connection_pool,
+                session_ctx,
+            },
+            tokio::spawn,
+            HashMapCache::new(),
+        );
+
+        if matches!(cache_config, CacheConfig::NoCache) {
+            loader.enable_all_cache(false);
+        }
+
+        Self(loader)
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{CacheConfig, SyncSessionContext};
+use db_utils::PgConnectionPool;
+
+mod customer_task_request_by_id;
+pub use customer_task_request_by_id::*;
+
+mod schedule_by_id;
+pub use schedule_by_id::*;
+
+mod task_photos_by_task_id;
+pub use task_photos_by_task_id::*;
+
+mod task_quote_by_id;
+pub use task_quote_by_id::*;
+
+mod task_quotes_by_task_id;
+pub use task_quotes_by_task_id::*;
+
+pub struct TaskLoaders {
+    pub customer_task_request_by_id_loader: CustomerTaskRequestByIdLoader,
+    pub schedule_by_id_loader: ScheduleByIdLoader,
+    pub task_quote_by_id_loader: TaskQuoteByIdLoader,
+    pub quotes_by_task_id_loader: TaskQuotesByTaskIdLoader,
+    pub photos_by_task_id_loader: TaskPhotosByTaskIdLoader,
+}
+
+impl TaskLoaders {
+    pub fn new(
+        db_connection_pool: PgConnectionPool,
+        session_ctx: SyncSessionContext,
+        cache_config: CacheConfig,
+    ) -> Self {
+        Self {
+            customer_task_request_by_id_loader: CustomerTaskRequestByIdLoader::new(
+                db_connection_pool.clone(),
+                session_ctx.clone(),
+                cache_config,
+            ),
+            schedule_by_id_loader: ScheduleByIdLoader::new(
+                db_connection_pool.clone(),
+                session_ctx.clone(),
+                cache_config,
+            ),
+            task_quote_by_id_loader: TaskQuoteByIdLoader::new(
+                db_connection_pool.clone(),
+                session_ctx.clone(),
+                cache_config,
+            ),
+            quotes_by_task_id_loader: TaskQuotesByTaskIdLoader::new(
+                db_connection_pool.clone(),
+                session_ctx.clone(),
+                cache_config,
+            ),
+            photos_by_task_id_loader: TaskPhotosByTaskIdLoader::new(
+                db_connection_pool,
+                session_ctx,
+                cache_config,
+            ),
+        }
+    }
+}
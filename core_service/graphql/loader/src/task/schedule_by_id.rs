@@ -0,0 +1,64 @@
+use crate::{CacheConfig, SyncSessionContext};
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use core_service_db as db;
+use db_utils::{PgConnectionPool, with_readonly_db};
+use entity_type::ScheduleId;
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use std::{collections::HashMap, ops::Deref, sync::Arc};
+
+#[doc(hidden)]
+pub struct ScheduleByIdLoaderInner {
+    db_connection_pool: PgConnectionPool,
+    session_ctx: SyncSessionContext,
+}
+
+impl Loader<ScheduleId> for ScheduleByIdLoaderInner {
+    type Error = Error;
+    type Value = Arc<db::Schedule>;
+
+    async fn load(&self, keys: &[ScheduleId]) -> Result<HashMap<ScheduleId, Arc<db::Schedule>>> {
+        let actor_auth = self.session_ctx.actor_auth().await?;
+        let batch = with_readonly_db(&self.db_connection_pool, |conn| {
+            db::Schedule::load_by_ids(&actor_auth, keys, conn).scope_boxed()
+        })
+        .await?;
+        Ok(batch
+            .into_iter()
+            .map(|s| (s.base.id, Arc::new(s)))
+            .collect())
+    }
+}
+
+pub struct ScheduleByIdLoader(DataLoader<ScheduleByIdLoaderInner, HashMapCache>);
+
+impl Deref for ScheduleByIdLoader {
+    type Target = DataLoader<ScheduleByIdLoaderInner, HashMapCache>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl ScheduleByIdLoader {
+    pub fn new(
+        db_connection_pool: PgConnectionPool,
+        session_ctx: SyncSessionContext,
+        cache_config: CacheConfig,
+    ) -> Self {
+        let loader = DataLoader::with_cache(
+            ScheduleByIdLoaderInner {
+                db_connection_pool,
+                session_ctx,
+            },
+            tokio::spawn,
+            HashMapCache::new(),
+        );
+
+        if matches!(cache_config, CacheConfig::NoCache) {
+            loader.enable_all_cache(false);
+        }
+
+        Self(loader)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{CacheConfig, SyncSessionContext};
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use core_service_db as db;
+use db_utils::{PgConnectionPool, with_readonly_db};
+use entity_type::CustomerTaskRequestId;
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use std::{collections::HashMap, ops::Deref, sync::Arc};
+
+#[doc(hidden)]
+pub struct TaskPhotosByTaskIdLoaderInner {
+    db_connection_pool: PgConnectionPool,
+    session_ctx: SyncSessionContext,
+}
+
+impl Loader<CustomerTaskRequestId> for TaskPhotosByTaskIdLoaderInner {
+    type Error = Error;
+    type Value = Vec<Arc<db::CustomerTaskPhoto>>;
+
+    /// Tasks without any photo the actor can see are missing from the result
+    async fn load(
+        &self,
+        keys: &[CustomerTaskRequestId],
+    ) -> Result<HashMap<CustomerTaskRequestId, Vec<Arc<db::CustomerTaskPhoto>>>> {
+        let actor_auth = self.session_ctx.actor_auth().await?;
+        let batch = with_readonly_db(&self.db_connection_pool, |conn| {
+            db::CustomerTaskPhoto::load_by_tasks(&actor_auth, keys, conn).scope_boxed()
+        })
+        .await?;
+        Ok(
+            db_utils::group_by(batch.into_iter().map(|v| (v.task_id, Arc::new(v))))
+                .into_iter()
+                .collect(),
+        )
+    }
+}
+
+pub struct TaskPhotosByTaskIdLoader(DataLoader<TaskPhotosByTaskIdLoaderInner, HashMapCache>);
+
+impl Deref for TaskPhotosByTaskIdLoader {
+    type Target = DataLoader<TaskPhotosByTaskIdLoaderInner, HashMapCache>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TaskPhotosByTaskIdLoader {
+    pub fn new(
+        db_connection_pool: PgConnectionPool,
+        session_ctx: SyncSessionContext,
+        cache_config: CacheConfig,
+    ) -> Self {
+        let loader = DataLoader::with_cache(
+            TaskPhotosByTaskIdLoaderInner {
+                db_connection_pool,
+                session_ctx,
+            },
+            tokio::spawn,
+            HashMapCache::new(),
+        );
+
+        if matches!(cache_config, CacheConfig::NoCache) {
+            loader.enable_all_cache(false);
+        }
+
+        Self(loader)
+    }
+}
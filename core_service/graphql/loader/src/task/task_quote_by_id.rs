@@ -0,0 +1,61 @@
+use crate::{CacheConfig, SyncSessionContext};
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use core_service_db as db;
+use db_utils::{PgConnectionPool, with_readonly_db};
+use entity_type::TaskQuoteId;
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use std::{collections::HashMap, ops::Deref, sync::Arc};
+
+#[doc(hidden)]
+pub struct TaskQuoteByIdLoaderInner {
+    db_connection_pool: PgConnectionPool,
+    session_ctx: SyncSessionContext,
+}
+
+impl Loader<TaskQuoteId> for TaskQuoteByIdLoaderInner {
+    type Error = Error;
+    type Value = Arc<db::TaskQuote>;
+
+    async fn load(&self, keys: &[TaskQuoteId]) -> Result<HashMap<TaskQuoteId, Arc<db::TaskQuote>>> {
+        let actor_auth = self.session_ctx.actor_auth().await?;
+        let batch = with_readonly_db(&self.db_connection_pool, |conn| {
+            db::TaskQuote::load_by_ids(&actor_auth, keys, conn).scope_boxed()
+        })
+        .await?;
+        Ok(batch.into_iter().map(|q| (q.id, Arc::new(q))).collect())
+    }
+}
+
+pub struct TaskQuoteByIdLoader(DataLoader<TaskQuoteByIdLoaderInner, HashMapCache>);
+
+impl Deref for TaskQuoteByIdLoader {
+    type Target = DataLoader<TaskQuoteByIdLoaderInner, HashMapCache>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl TaskQuoteByIdLoader {
+    pub fn new(
+        db_connection_pool: PgConnectionPool,
+        session_ctx: SyncSessionContext,
+        cache_config: CacheConfig,
+    ) -> Self {
+        let loader = DataLoader::with_cache(
+            TaskQuoteByIdLoaderInner {
+                db_connection_pool,
+                session_ctx,
+            },
+            tokio::spawn,
+            HashMapCache::new(),
+        );
+
+        if matches!(cache_config, CacheConfig::NoCache) {
+            loader.enable_all_cache(false);
+        }
+
+        Self(loader)
+    }
+}
@@ -0,0 +1,246 @@
+use account_service_server::{
+    AccountExistsRequest, ChangePasswordRequest, ChangePasswordResponse, ChangePhoneNumberRequest,
+    DeleteAccountRequest, DeleteAccountResponse, PersonExistsRequest,
+};
+use async_graphql::{Context, InputObject, Object, SimpleObject};
+use core_service_graphql_context::{RateLimitedApi, RequestContext};
+use core_service_graphql_types::ActorType;
+use entity_type::{AccountStatus, AccountType};
+use error::{Error, Result};
+use otp_store::OtpPurpose;
+use sms_sender::{MessageType, OtpVerificationForPhoneNumberChange, SendSmsInput};
+
+#[derive(Default)]
+pub struct AccountUpdateMutation;
+
+#[Object]
+impl AccountUpdateMutation {
+    /// Change password of the current account, the current password is required.
+    /// Every other session of the account is revoked.
+    #[tracing::instrument(skip_all)]
+    async fn user_change_password(
+        &self,
+        ctx: &Context<'_>,
+        input: UserChangePasswordInput,
+    ) -> Result<UserChangePasswordPayload> {
+        let UserChangePasswordInput {
+            old_password,
+            new_password,
+        } = input;
+        let context = ctx.data::<RequestContext>()?;
+        let req_session = context.try_session_context().await?;
+
+        let ChangePasswordResponse {
+            account,
+            initiate_session,
+        } = context
+            .account_service_client
+            .change_password(ChangePasswordRequest {
+                actor_auth: req_session.as_actor_auth(),
+                old_password,
+                new_password,
+            })
+            .await?;
+        context.set_session(ctx, initiate_session).await?;
+
+        Ok(UserChangePasswordPayload {
+            account: account.into(),
+        })
+    }
+
+    /// Send an OTP code to the new phone number of the current account
+    #[tracing::instrument(skip(self, ctx))]
+    async fn user_phone_number_change_start(
+        &self,
+        ctx: &Context<'_>,
+        input: UserPhoneNumberChangeStartInput,
+    ) -> Result<UserPhoneNumberChangeStartPayload> {
+        let UserPhoneNumberChangeStartInput { phone_number } = input;
+        let phone_number = typesafe::phone_number_from_str(&phone_number)?;
+        let e164_phone_number_str = typesafe::phone_number_to_e164_format(&phone_number);
+        let context = ctx.data::<RequestContext>()?;
+        let req_session = context.try_session_context().await?;
+        let account_type = req_session.actor_type.account_type();
+        context.check_rate_limit(
+            RateLimitedApi::StartPhoneNumberChange,
+            account_type,
+            &e164_phone_number_str,
+        )?;
+
+        // Customer and handyman accounts of a person share the phone number
+        let exists = match account_type {
+            AccountType::Customer | AccountType::Handyman => {
+                context
+                    .account_service_client
+                    .person_exists(PersonExistsRequest {
+                        e164_phone_number_str: e164_phone_number_str.clone(),
+                    })
+                    .await?
+                    .exists
+            }
+            AccountType::Admin => {
+                context
+                    .account_service_client
+                    .account_exists(AccountExistsRequest {
+                        e164_phone_number_str: e164_phone_number_str.clone(),
+                        account_type,
+                    })
+                    .await?
+                    .exists
+            }
+        };
+        if exists {
+            return Err(Error::already_exists("Phone number already exist"));
+        }
+
+        let otp_code = context
+            .otp_issue(
+                OtpPurpose::PhoneNumberChange,
+                account_type,
+                e164_phone_number_str.clone(),
+            )
+            .await?;
+
+        context
+            .sms_sender
+            .send(SendSmsInput {
+                to: phone_number,
+                message: MessageType::OtpVerificationForPhoneNumberChange(
+                    OtpVerificationForPhoneNumberChange {
+                        code: otp_code.code,
+                    },
+                ),
+            })
+            .await?;
+
+        Ok(UserPhoneNumberChangeStartPayload {
+            digits: otp_code.digits,
+            ttl_seconds: otp_code.ttl_seconds,
+            resend_cooldown_seconds: otp_code.resend_cooldown_seconds,
+            e164_phone_number_str,
+        })
+    }
+
+    /// Verify the OTP code sent to the new phone number and move the current account to it
+    #[tracing::instrument(skip(self, ctx))]
+    async fn user_phone_number_change_finish(
+        &self,
+        ctx: &Context<'_>,
+        input: UserPhoneNumberChangeFinishInput,
+    ) -> Result<UserPhoneNumberChangeFinishPayload> {
+        let UserPhoneNumberChangeFinishInput {
+            phone_number,
+            otp_code,
+        } = input;
+        let e164_phone_number_str = typesafe::normalize_phone_number_str(&phone_number)?;
+        let context = ctx.data::<RequestContext>()?;
+        let req_session = context.try_session_context().await?;
+        context
+            .otp_verify(
+                OtpPurpose::PhoneNumberChange,
+                req_session.actor_type.account_type(),
+                &e164_phone_number_str,
+                &otp_code,
+            )
+            .await?;
+
+        let account = context
+            .account_service_client
+            .change_phone_number(ChangePhoneNumberRequest {
+                actor_auth: req_session.as_actor_auth(),
+                e164_phone_number_str,
+            })
+            .await?
+            .account;
+
+        Ok(UserPhoneNumberChangeFinishPayload {
+            account: account.into(),
+        })
+    }
+
+    /// Delete the current account and sign out of every device, the current password is required.
+    /// Personal data is anonymized and the phone number can be registered again.
+    #[tracing::instrument(skip_all)]
+    async fn user_delete_account(
+        &self,
+        ctx: &Context<'_>,
+        input: UserDeleteAccountInput,
+    ) -> Result<UserDeleteAccountPayload> {
+        let UserDeleteAccountInput { password } = input;
+        let context = ctx.data::<RequestContext>()?;
+        let req_session = context.try_session_context().await?;
+        let actor_key = req_session.actor_type.actor_key();
+
+        let DeleteAccountResponse {
+            account,
+            avatar_key,
+        } = context
+            .account_service_client
+            .delete_account(DeleteAccountRequest {
+                actor_auth: req_session.as_actor_auth(),
+                actor_key,
+                password: Some(password),
+            })
+            .await?;
+        context.clear_session(ctx).await;
+        if let Some(avatar_key) = avatar_key {
+            context.delete_blob_quietly(&avatar_key).await;
+        }
+
+        context
+            .withdraw_account(actor_key, AccountStatus::Deleted)
+            .await?;
+
+        Ok(UserDeleteAccountPayload {
+            account: account.into(),
+        })
+    }
+}
+
+#[derive(InputObject)]
+struct UserChangePasswordInput {
+    old_password: String,
+    new_password: String,
+}
+
+#[derive(SimpleObject)]
+struct UserChangePasswordPayload {
+    account: ActorType,
+}
+
+#[derive(Debug, InputObject)]
+struct UserPhoneNumberChangeStartInput {
+    phone_number: String,
+}
+
+#[derive(SimpleObject)]
+/// An OTP code has been sent to the new phone number (zalo) for verification.
+struct UserPhoneNumberChangeStartPayload {
+    digits: u8,
+    ttl_seconds: u64,
+    /// Seconds to wait before another code can be sent to the phone number
+    resend_cooldown_seconds: u64,
+    /// The standard phone number format captured by backend
+    e164_phone_number_str: String,
+}
+
+#[derive(Debug, InputObject)]
+struct UserPhoneNumberChangeFinishInput {
+    phone_number: String,
+    otp_code: String,
+}
+
+#[derive(SimpleObject)]
+struct UserPhoneNumberChangeFinishPayload {
+    account: ActorType,
+}
+
+#[derive(InputObject)]
+struct UserDeleteAccountInput {
+    password: String,
+}
+
+#[derive(SimpleObject)]
+struct UserDeleteAccountPayload {
+    account: ActorType,
+}
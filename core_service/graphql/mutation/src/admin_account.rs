@@ -0,0 +1,53 @@
+use account_service_server::AdminCreateAccountRequest;
+use async_graphql::{Context, InputObject, Object, SimpleObject};
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::Admin;
+use error::Result;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct AdminAccountMutation;
+
+#[Object]
+impl AdminAccountMutation {
+    /// Create another admin account, requires an admin session
+    #[tracing::instrument(skip_all, fields(phone_number = input.phone_number.as_str()))]
+    async fn admin_create_account(
+        &self,
+        ctx: &Context<'_>,
+        input: AdminCreateAccountInput,
+    ) -> Result<AdminCreateAccountPayload> {
+        let AdminCreateAccountInput {
+            phone_number,
+            password,
+        } = input;
+        let e164_phone_number_str = typesafe::normalize_phone_number_str(&phone_number)?;
+        let context = ctx.data::<RequestContext>()?;
+        let req_session = context.try_session_context().await?;
+
+        let account = context
+            .account_service_client
+            .admin_create_account(AdminCreateAccountRequest {
+                actor_auth: req_session.as_actor_auth(),
+                e164_phone_number_str,
+                password,
+            })
+            .await?
+            .account;
+
+        Ok(AdminCreateAccountPayload {
+            admin: Admin::new_with(account.id, Arc::new(account)),
+        })
+    }
+}
+
+#[derive(InputObject)]
+struct AdminCreateAccountInput {
+    phone_number: String,
+    password: String,
+}
+
+#[derive(SimpleObject)]
+struct AdminCreateAccountPayload {
+    admin: Admin,
+}
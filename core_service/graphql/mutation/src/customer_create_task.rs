@@ -1,8 +1,13 @@
+use crate::sync_task_search_index;
 use async_graphql::{Context, InputObject, Object, SimpleObject};
+use core_service_db as db;
 use core_service_graphql_context::RequestContext;
-use core_service_graphql_types::ScheduleInput;
+use core_service_graphql_types::{CustomerTaskRequest, ScheduleInput, service_code_input};
+use db_utils::with_mutable_db;
 use entity_type::ServiceLayer2;
 use error::Result;
+use scoped_futures::ScopedFutureExt;
+use std::sync::Arc;
 
 #[derive(Default)]
 pub struct CustomerCreateTaskMutation;
@@ -15,26 +20,74 @@ impl CustomerCreateTaskMutation {
         ctx: &Context<'_>,
         input: CustomerCreateTaskInput,
     ) -> Result<CustomerCreateTaskPayload> {
+        let CustomerCreateTaskInput {
+            service_code,
+            service,
+            title,
+            note,
+            schedule,
+            draft,
+        } = input;
+        let service_code = service_code_input(service_code.as_deref(), service)?.to_string();
+        typesafe::require_trimmed_and_not_empty_str(&title, "title")?;
+        let location = db::NewLocation::try_from(schedule.location)?;
+        let schedule = db::NewSchedule::try_from(schedule.time)?;
+        let note = Some(note).filter(|n| !n.trim().is_empty());
+
         let context = ctx.data::<RequestContext>()?;
         let session_ctx = context.try_session_context().await?;
-        let _actor_auth = session_ctx.as_actor_auth();
+        let actor_auth = session_ctx.as_actor_auth();
+        let customer_id = session_ctx.actor_type.try_customer()?.customer_id;
+
+        let (task, schedule) = with_mutable_db(&context.db_connection_pool, |conn| {
+            db::CustomerTaskRequest::create(
+                &actor_auth,
+                db::NewCustomerTaskRequest {
+                    customer_id,
+                    service: service_code,
+                    title,
+                    note,
+                    schedule,
+                    location,
+                    draft: draft.unwrap_or(false),
+                },
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+
+        sync_task_search_index(context, &task).await?;
+
+        context
+            .task_loaders
+            .schedule_by_id_loader
+            .feed_one(schedule.base.id, Arc::new(schedule))
+            .await;
 
-        Ok(CustomerCreateTaskPayload { foo: true })
+        Ok(CustomerCreateTaskPayload {
+            task: CustomerTaskRequest::new_with(task.id, Arc::new(task)),
+        })
     }
 }
 
 #[derive(Debug, InputObject)]
 struct CustomerCreateTaskInput {
-    service: ServiceLayer2,
+    /// Code of an active service of the catalog, required unless `service` is set
+    service_code: Option<String>,
+    #[graphql(deprecation = "Use serviceCode")]
+    service: Option<ServiceLayer2>,
     /// Plain text title
     title: String,
     /// Markdown note
     note: String,
     /// Schedule for the task
     schedule: ScheduleInput,
+    /// Save the task as draft instead of publishing it right away. Default to false.
+    draft: Option<bool>,
 }
 
 #[derive(SimpleObject)]
 struct CustomerCreateTaskPayload {
-    foo: bool,
+    task: CustomerTaskRequest,
 }
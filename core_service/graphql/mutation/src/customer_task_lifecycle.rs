@@ -0,0 +1,123 @@
+use async_graphql::{Context, ID, InputObject, Object, SimpleObject};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{CustomerTaskRequest, GlobalId};
+use db_utils::with_mutable_db;
+use entity_type::CustomerTaskStatus;
+use error::Result;
+use scoped_futures::ScopedFutureExt;
+use search_service_db as sea_db;
+use search_service_server::{TaskIndexDeleteRequest, TaskIndexRequest};
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct CustomerTaskLifecycleMutation;
+
+#[Object]
+impl CustomerTaskLifecycleMutation {
+    /// Publish a draft task so handymen can see it.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn customer_task_open(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerTaskTransitionInput,
+    ) -> Result<CustomerTaskTransitionPayload> {
+        transition_task(ctx, &input.task_id, db::CustomerTaskTransition::Open).await
+    }
+
+    /// Cancel a task which is not started yet.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn customer_task_cancel(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerTaskTransitionInput,
+    ) -> Result<CustomerTaskTransitionPayload> {
+        transition_task(ctx, &input.task_id, db::CustomerTaskTransition::Cancel).await
+    }
+
+    /// Assigned handyman starts working on the task.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_task_start(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerTaskTransitionInput,
+    ) -> Result<CustomerTaskTransitionPayload> {
+        transition_task(ctx, &input.task_id, db::CustomerTaskTransition::Start).await
+    }
+
+    /// Assigned handyman finishes the task.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_task_complete(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerTaskTransitionInput,
+    ) -> Result<CustomerTaskTransitionPayload> {
+        transition_task(ctx, &input.task_id, db::CustomerTaskTransition::Complete).await
+    }
+}
+
+async fn transition_task(
+    ctx: &Context<'_>,
+    task_id: &ID,
+    transition: db::CustomerTaskTransition,
+) -> Result<CustomerTaskTransitionPayload> {
+    let task_id = CustomerTaskRequest::from_global_id(task_id)?.inner_id();
+
+    let context = ctx.data::<RequestContext>()?;
+    let session_ctx = context.try_session_context().await?;
+    let actor_auth = session_ctx.as_actor_auth();
+
+    let task = with_mutable_db(&context.db_connection_pool, |conn| {
+        db::CustomerTaskRequest::transition(&actor_auth, task_id, transition, conn).scope_boxed()
+    })
+    .await?;
+
+    sync_task_search_index(context, &task).await?;
+
+    Ok(CustomerTaskTransitionPayload {
+        task: CustomerTaskRequest::new_with(task.id, Arc::new(task)),
+    })
+}
+
+/// Only open tasks are searchable by handymen, so the task is indexed when it's opened
+/// and removed from the index once it moves to any other status.
+pub(crate) async fn sync_task_search_index(
+    context: &RequestContext,
+    task: &db::CustomerTaskRequest,
+) -> Result<()> {
+    match (task.status, task.coordinates(), task.opened_at) {
+        (CustomerTaskStatus::Open, Some((lon, lat)), Some(opened_at)) => {
+            context
+                .search_service_client
+                .task_index(TaskIndexRequest {
+                    task: sea_db::NewTaskSearch {
+                        task_id: task.id,
+                        service: task.service.clone(),
+                        lon,
+                        lat,
+                        opened_at,
+                    },
+                })
+                .await?;
+        }
+        _ => {
+            context
+                .search_service_client
+                .task_index_delete(TaskIndexDeleteRequest {
+                    task_ids: vec![task.id],
+                })
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, InputObject)]
+struct CustomerTaskTransitionInput {
+    task_id: ID,
+}
+
+#[derive(SimpleObject)]
+struct CustomerTaskTransitionPayload {
+    task: CustomerTaskRequest,
+}
@@ -0,0 +1,105 @@
+use crate::store_uploaded_image;
+use async_graphql::{Context, ID, InputObject, Object, SimpleObject, Upload};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{CustomerTaskRequest, GlobalId, TaskPhoto};
+use db_utils::{with_mutable_db, with_readonly_db};
+use error::Result;
+use scoped_futures::ScopedFutureExt;
+use std::sync::Arc;
+
+/// Task photos are private, they are only served through signed urls
+const TASK_PHOTO_BLOB_PREFIX: &str = "private/task-photos";
+
+#[derive(Default)]
+pub struct CustomerTaskPhotoMutation;
+
+#[Object]
+impl CustomerTaskPhotoMutation {
+    /// Customer attaches a JPEG, PNG or WebP photo of at most 5 MB to their task.
+    /// Photos can be changed until the task is started, a task has at most 10 photos.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn customer_add_task_photo(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerAddTaskPhotoInput,
+    ) -> Result<CustomerAddTaskPhotoPayload> {
+        let CustomerAddTaskPhotoInput { task_id, file } = input;
+        let task_id = CustomerTaskRequest::from_global_id(&task_id)?.inner_id();
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let actor_auth = session_ctx.as_actor_auth();
+
+        with_readonly_db(&context.db_connection_pool, |conn| {
+            db::CustomerTaskPhoto::require_addable(&actor_auth, task_id, conn).scope_boxed()
+        })
+        .await?;
+
+        // Checked again when the photo is saved, in case of concurrent changes of the task
+        let key = store_uploaded_image(ctx, &file, TASK_PHOTO_BLOB_PREFIX).await?;
+        let blob_key = key.as_str();
+        let result = with_mutable_db(&context.db_connection_pool, |conn| {
+            db::CustomerTaskPhoto::create(&actor_auth, task_id, blob_key, conn).scope_boxed()
+        })
+        .await;
+        let photo = match result {
+            Ok(photo) => photo,
+            Err(e) => {
+                context.delete_blob_quietly(blob_key).await;
+                return Err(e);
+            }
+        };
+
+        Ok(CustomerAddTaskPhotoPayload {
+            photo: TaskPhoto::new(Arc::new(photo)),
+        })
+    }
+
+    /// Customer removes a photo from their task.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn customer_remove_task_photo(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerRemoveTaskPhotoInput,
+    ) -> Result<CustomerRemoveTaskPhotoPayload> {
+        let CustomerRemoveTaskPhotoInput { photo_id } = input;
+        let photo_id = TaskPhoto::from_global_id(&photo_id)?.id;
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let actor_auth = session_ctx.as_actor_auth();
+
+        let photo = with_mutable_db(&context.db_connection_pool, |conn| {
+            db::CustomerTaskPhoto::delete(&actor_auth, photo_id, conn).scope_boxed()
+        })
+        .await?;
+        context.delete_blob_quietly(&photo.blob_key).await;
+
+        let task_id = photo.task_id;
+        Ok(CustomerRemoveTaskPhotoPayload {
+            removed_photo_id: TaskPhoto::new(Arc::new(photo)).as_global_id()?,
+            task: CustomerTaskRequest::new(task_id),
+        })
+    }
+}
+
+#[derive(Debug, InputObject)]
+struct CustomerAddTaskPhotoInput {
+    task_id: ID,
+    file: Upload,
+}
+
+#[derive(SimpleObject)]
+struct CustomerAddTaskPhotoPayload {
+    photo: TaskPhoto,
+}
+
+#[derive(Debug, InputObject)]
+struct CustomerRemoveTaskPhotoInput {
+    photo_id: ID,
+}
+
+#[derive(SimpleObject)]
+struct CustomerRemoveTaskPhotoPayload {
+    removed_photo_id: ID,
+    task: CustomerTaskRequest,
+}
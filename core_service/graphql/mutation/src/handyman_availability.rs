@@ -0,0 +1,206 @@
+use async_graphql::{Context, ID, InputObject, Object, SimpleObject};
+use chrono::NaiveDate;
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{GlobalId, Handyman, HandymanProfile, ScheduleTimeInput};
+use db_utils::{with_mutable_db, with_readonly_db};
+use entity_type::HandymanId;
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use search_service_db as sea_db;
+use search_service_server::{HandymanIndexRequest, HandymanIndexType};
+
+#[derive(Default)]
+pub struct HandymanAvailabilityMutation;
+
+#[Object]
+impl HandymanAvailabilityMutation {
+    /// Set the weekly working hours of the handyman, used by search by availability.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_profile_set_working_hours(
+        &self,
+        ctx: &Context<'_>,
+        input: HandymanProfileSetWorkingHoursInput,
+    ) -> Result<HandymanProfileSetWorkingHoursPayload> {
+        let HandymanProfileSetWorkingHoursInput {
+            handyman_id,
+            time,
+            slot_minutes,
+        } = input;
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+        let schedule = db::NewSchedule::try_from(time)?;
+
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let actor_auth = session_ctx.as_actor_auth();
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        with_mutable_db(&context.db_connection_pool, |conn| {
+            db::HandymanWorkingHours::set(
+                &actor_auth,
+                handyman_id,
+                db::NewHandymanWorkingHours {
+                    schedule,
+                    slot_minutes,
+                },
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+
+        sync_handyman_availability_index(context, handyman_id).await?;
+
+        Ok(HandymanProfileSetWorkingHoursPayload {
+            profile: HandymanProfile::new(handyman_id),
+        })
+    }
+
+    /// Mark a date as day off, the handyman is not available that date whatever the working hours.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_profile_add_blackout(
+        &self,
+        ctx: &Context<'_>,
+        input: HandymanProfileAddBlackoutInput,
+    ) -> Result<HandymanProfileAddBlackoutPayload> {
+        let HandymanProfileAddBlackoutInput {
+            handyman_id,
+            date,
+            note,
+        } = input;
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+        let note = note.filter(|n| !n.trim().is_empty());
+
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let actor_auth = session_ctx.as_actor_auth();
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        with_mutable_db(&context.db_connection_pool, |conn| {
+            db::HandymanBlackout::upsert(
+                &actor_auth,
+                handyman_id,
+                db::NewHandymanBlackout {
+                    date,
+                    note: note.as_deref(),
+                },
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+
+        sync_handyman_availability_index(context, handyman_id).await?;
+
+        Ok(HandymanProfileAddBlackoutPayload {
+            profile: HandymanProfile::new(handyman_id),
+        })
+    }
+
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_profile_remove_blackout(
+        &self,
+        ctx: &Context<'_>,
+        input: HandymanProfileRemoveBlackoutInput,
+    ) -> Result<HandymanProfileRemoveBlackoutPayload> {
+        let HandymanProfileRemoveBlackoutInput { handyman_id, date } = input;
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let actor_auth = session_ctx.as_actor_auth();
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        with_mutable_db(&context.db_connection_pool, |conn| {
+            db::HandymanBlackout::delete(&actor_auth, handyman_id, date, conn).scope_boxed()
+        })
+        .await?
+        .ok_or_else(|| Error::not_found("Blackout not found"))?;
+
+        sync_handyman_availability_index(context, handyman_id).await?;
+
+        Ok(HandymanProfileRemoveBlackoutPayload {
+            profile: HandymanProfile::new(handyman_id),
+            removed_date: date,
+        })
+    }
+}
+
+/// Index the working slots and days off of the handyman again, replacing the indexed ones.
+pub(crate) async fn sync_handyman_availability_index(
+    context: &RequestContext,
+    handyman_id: HandymanId,
+) -> Result<()> {
+    let actor_auth = &context.actor_auth().await?;
+    let ((timezone, working_slots), blackouts) =
+        with_readonly_db(&context.db_connection_pool, |conn| {
+            async move {
+                let working_slots =
+                    db::HandymanWorkingHours::load_working_slots(actor_auth, handyman_id, conn)
+                        .await?;
+                let blackouts =
+                    db::HandymanBlackout::get_by_handyman(actor_auth, handyman_id, conn).await?;
+                Ok((working_slots, blackouts))
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+    context
+        .search_service_client
+        .handyman_index(HandymanIndexRequest {
+            handyman_id,
+            index_type: HandymanIndexType::SetAvailability(sea_db::HandymanAvailability {
+                timezone,
+                working_slots: working_slots
+                    .into_iter()
+                    .map(|slot| sea_db::HandymanWorkingSlot {
+                        weekday: slot.weekday,
+                        start_minute: slot.start_minute,
+                        end_minute: slot.end_minute,
+                    })
+                    .collect(),
+                blackout_dates: blackouts.into_iter().map(|b| b.date).collect(),
+            }),
+        })
+        .await?;
+    Ok(())
+}
+
+#[derive(Debug, InputObject)]
+struct HandymanProfileSetWorkingHoursInput {
+    handyman_id: ID,
+    /// Daily or weekly recurrence, each time starts a working slot. Fixed time is rejected.
+    time: ScheduleTimeInput,
+    /// Length of each working slot, slots may cross midnight
+    slot_minutes: i32,
+}
+
+#[derive(SimpleObject)]
+struct HandymanProfileSetWorkingHoursPayload {
+    profile: HandymanProfile,
+}
+
+#[derive(Debug, InputObject)]
+struct HandymanProfileAddBlackoutInput {
+    handyman_id: ID,
+    date: NaiveDate,
+    note: Option<String>,
+}
+
+#[derive(SimpleObject)]
+struct HandymanProfileAddBlackoutPayload {
+    profile: HandymanProfile,
+}
+
+#[derive(Debug, InputObject)]
+struct HandymanProfileRemoveBlackoutInput {
+    handyman_id: ID,
+    date: NaiveDate,
+}
+
+#[derive(SimpleObject)]
+struct HandymanProfileRemoveBlackoutPayload {
+    profile: HandymanProfile,
+    removed_date: NaiveDate,
+}
@@ -0,0 +1,137 @@
+use account_service_server::{AccountService, LoadHandymanAccountByIdsRequest};
+use actor_auth::ActorAuth;
+use async_graphql::{Context, ID, InputObject, Object, SimpleObject};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{CustomerTaskRequest, GlobalId, HandymanReview};
+use db_utils::{PgConnectionPool, with_mutable_db, with_readonly_db};
+use entity_type::{AccountStatus, HandymanId};
+use error::Result;
+use scoped_futures::ScopedFutureExt;
+use search_service_server::{HandymanIndexRequest, HandymanIndexType, SearchService};
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct HandymanReviewMutation;
+
+#[Object]
+impl HandymanReviewMutation {
+    /// Customer rates the handyman who completed their task. One review per task.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn customer_task_review(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerTaskReviewInput,
+    ) -> Result<CustomerTaskReviewPayload> {
+        let CustomerTaskReviewInput {
+            task_id,
+            rating,
+            comment,
+        } = input;
+        let task_id = CustomerTaskRequest::from_global_id(&task_id)?.inner_id();
+        let comment = comment.as_deref().filter(|c| !c.trim().is_empty());
+
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let actor_auth = session_ctx.as_actor_auth();
+
+        let review = with_mutable_db(&context.db_connection_pool, |conn| {
+            async move {
+                db::HandymanReview::create(
+                    &actor_auth,
+                    db::NewHandymanReview {
+                        task_id,
+                        rating,
+                        comment,
+                    },
+                    conn,
+                )
+                .await
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        // The review is saved anyway, unindexed ratings are retried by a background job
+        if let Err(e) = sync_handyman_rating_index(
+            &context.db_connection_pool,
+            &context.account_service_client,
+            &context.search_service_client,
+            review.handyman_id,
+        )
+        .await
+        {
+            tracing::error!(
+                "Failed to index rating of handyman {} {e:?}",
+                review.handyman_id.0
+            );
+        }
+
+        Ok(CustomerTaskReviewPayload {
+            review: HandymanReview::new_with(review.id, Arc::new(review)),
+        })
+    }
+}
+
+/// Index the average rating of the handyman computed from the committed reviews,
+/// then mark the reviews it covers as indexed. No transaction is held during the index request,
+/// search index ignores a rating computed from fewer reviews than the indexed one.
+pub async fn sync_handyman_rating_index(
+    db_connection_pool: &PgConnectionPool,
+    account_service_client: &AccountService,
+    search_service_client: &SearchService,
+    handyman_id: HandymanId,
+) -> Result<()> {
+    let snapshot = with_readonly_db(db_connection_pool, |conn| {
+        db::HandymanReview::rating_snapshot(&ActorAuth::God, handyman_id, conn).scope_boxed()
+    })
+    .await?;
+
+    // Suspended handymen are removed from the index, reinstating them indexes the rating again
+    let handyman = account_service_client
+        .load_handyman_account_by_ids(LoadHandymanAccountByIdsRequest {
+            actor_auth: ActorAuth::God,
+            account_ids: vec![handyman_id],
+        })
+        .await?
+        .handymans
+        .pop();
+    if handyman.is_some_and(|h| h.status == AccountStatus::Active) {
+        search_service_client
+            .handyman_index(HandymanIndexRequest {
+                handyman_id,
+                index_type: HandymanIndexType::SetAvgRatingScore {
+                    avg_rating_score: snapshot.summary.avg_rating_score(),
+                    review_count: snapshot.summary.review_count as i32,
+                },
+            })
+            .await?;
+    }
+
+    if !snapshot.unindexed_review_ids.is_empty() {
+        with_mutable_db(db_connection_pool, |conn| {
+            db::HandymanReview::mark_rating_indexed(
+                &ActorAuth::God,
+                &snapshot.unindexed_review_ids,
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, InputObject)]
+struct CustomerTaskReviewInput {
+    task_id: ID,
+    /// Rating in stars, from 1 to 5
+    rating: i16,
+    comment: Option<String>,
+}
+
+#[derive(SimpleObject)]
+struct CustomerTaskReviewPayload {
+    review: HandymanReview,
+}
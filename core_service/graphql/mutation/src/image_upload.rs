@@ -0,0 +1,43 @@
+use async_graphql::{Context, Upload};
+use blob_store::{BlobKey, require_image_size, validate_image};
+use core_service_graphql_context::RequestContext;
+use error::{Error, Result};
+use std::io::Read;
+
+/// Validate an uploaded image and write it to the blob store under the prefix, e.g. `avatars`.
+/// The caller should delete the returned blob if it fails to reference it.
+pub(crate) async fn store_uploaded_image(
+    ctx: &Context<'_>,
+    upload: &Upload,
+    prefix: &str,
+) -> Result<BlobKey> {
+    let context = ctx.data::<RequestContext>()?;
+    let value = upload
+        .value(ctx)
+        .map_err(|e| Error::invalid_argument(format!("Cannot read uploaded file {e:?}")))?;
+    let size = value
+        .size()
+        .map_err(|e| Error::internal(format!("Cannot read uploaded file size {e:?}")))?;
+    require_image_size(size)?;
+
+    let content_type = value.content_type.clone();
+    // Uploads are buffered in a temporary file, read it off the async runtime
+    let data = tokio::task::spawn_blocking(move || {
+        let mut data = Vec::with_capacity(size as usize);
+        value
+            .into_read()
+            .read_to_end(&mut data)
+            .map_err(|e| Error::internal(format!("Cannot read uploaded file {e:?}")))?;
+        Ok::<_, Error>(data)
+    })
+    .await
+    .map_err(|e| Error::internal(format!("Tokio join handle error {e:?}")))??;
+    let image_type = validate_image(&data, content_type.as_deref())?;
+
+    let key = BlobKey::new_image(prefix, image_type);
+    context
+        .blob_store
+        .put(&key, image_type.content_type(), data)
+        .await?;
+    Ok(key)
+}
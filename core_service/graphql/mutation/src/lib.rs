@@ -7,5 +7,39 @@ pub(crate) use signup_and_auth::*;
 mod oboarding_handyman;
 pub(crate) use oboarding_handyman::*;
 
+mod handyman_availability;
+pub(crate) use handyman_availability::*;
+
 mod customer_create_task;
 pub(crate) use customer_create_task::*;
+
+mod customer_task_lifecycle;
+pub(crate) use customer_task_lifecycle::*;
+
+mod task_quote;
+pub(crate) use task_quote::*;
+
+mod handyman_review;
+pub(crate) use handyman_review::*;
+pub use handyman_review::sync_handyman_rating_index;
+
+mod user_session;
+pub(crate) use user_session::*;
+
+mod password_reset;
+pub(crate) use password_reset::*;
+
+mod account_update;
+pub(crate) use account_update::*;
+
+mod admin_account;
+pub(crate) use admin_account::*;
+
+mod profile_update;
+pub(crate) use profile_update::*;
+
+mod image_upload;
+pub(crate) use image_upload::*;
+
+mod customer_task_photo;
+pub(crate) use customer_task_photo::*;
@@ -5,5 +5,15 @@ use async_graphql::MergedObject;
 pub struct Mutation(
     SignUpAndAuthMutation,
     OnboardingHandymanMutation,
+    HandymanAvailabilityMutation,
     CustomerCreateTaskMutation,
+    CustomerTaskLifecycleMutation,
+    TaskQuoteMutation,
+    HandymanReviewMutation,
+    UserSessionMutation,
+    PasswordResetMutation,
+    AccountUpdateMutation,
+    AdminAccountMutation,
+    ProfileUpdateMutation,
+    CustomerTaskPhotoMutation,
 );
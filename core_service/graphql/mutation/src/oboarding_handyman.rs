@@ -1,7 +1,10 @@
 use async_graphql::{Context, ID, InputObject, Object, SimpleObject};
 use core_service_db as db;
 use core_service_graphql_context::RequestContext;
-use core_service_graphql_types::{GlobalId, Handyman, HandymanProfile, HandymanService, SetValue};
+use core_service_graphql_types::{
+    GlobalId, Handyman, HandymanProfile, HandymanService, LocationInput, SetValue,
+    service_code_input,
+};
 use db_utils::with_mutable_db;
 use entity_type::{HandymanAccessGuardId, ServiceLayer2};
 use error::{Error, Result};
@@ -33,8 +36,8 @@ impl OnboardingHandymanMutation {
 
         let new_records = services
             .iter()
-            .map(db::NewHandymanService::from)
-            .collect::<Vec<_>>();
+            .map(db::NewHandymanService::try_from)
+            .collect::<Result<Vec<_>>>()?;
         let added_services = with_mutable_db(&context.db_connection_pool, |conn| {
             db::HandymanService::create_many(&actor_auth, handyman_id, &new_records, conn)
                 .scope_boxed()
@@ -46,7 +49,7 @@ impl OnboardingHandymanMutation {
             .handyman_index(HandymanIndexRequest {
                 handyman_id,
                 index_type: HandymanIndexType::AddSkills(
-                    added_services.iter().map(|s| s.service).collect(),
+                    added_services.into_iter().map(|s| s.service).collect(),
                 ),
             })
             .await?;
@@ -127,7 +130,7 @@ impl OnboardingHandymanMutation {
                     let should_remove_service_index =
                         !db::HandymanService::handyman_service_exists(
                             handyman_id,
-                            deleted.service,
+                            &deleted.service,
                             conn,
                         )
                         .await?;
@@ -152,6 +155,47 @@ impl OnboardingHandymanMutation {
             removed_service_id: service_id,
         })
     }
+
+    /// Set the location where the handyman offers their services, used by geo search.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_profile_set_service_location(
+        &self,
+        ctx: &Context<'_>,
+        input: HandymanProfileSetServiceLocationInput,
+    ) -> Result<HandymanProfileSetServiceLocationPayload> {
+        let HandymanProfileSetServiceLocationInput {
+            handyman_id,
+            location,
+        } = input;
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+        let location = db::NewLocation::try_from(location)?;
+
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let actor_auth = session_ctx.as_actor_auth();
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        let location = with_mutable_db(&context.db_connection_pool, |conn| {
+            db::HandymanServiceLocation::upsert(&actor_auth, handyman_id, location, conn)
+                .scope_boxed()
+        })
+        .await?;
+
+        context
+            .search_service_client
+            .handyman_index(HandymanIndexRequest {
+                handyman_id,
+                index_type: HandymanIndexType::SetLocation {
+                    lon: location.lon,
+                    lat: location.lat,
+                },
+            })
+            .await?;
+
+        Ok(HandymanProfileSetServiceLocationPayload {
+            profile: HandymanProfile::new(handyman_id),
+        })
+    }
 }
 
 #[derive(Debug, InputObject)]
@@ -162,24 +206,30 @@ struct HandymanProfileAddServicesInput {
 
 #[derive(Debug, InputObject)]
 struct NewHandymanService {
-    service: ServiceLayer2,
+    /// Code of an active service of the catalog, required unless `service` is set
+    service_code: Option<String>,
+    #[graphql(deprecation = "Use serviceCode")]
+    service: Option<ServiceLayer2>,
     note: Option<String>,
     rate_vnd: Option<u32>,
 }
 
-impl<'a> From<&'a NewHandymanService> for db::NewHandymanService<'a> {
-    fn from(
+impl<'a> TryFrom<&'a NewHandymanService> for db::NewHandymanService<'a> {
+    type Error = Error;
+
+    fn try_from(
         NewHandymanService {
+            service_code,
             service,
             note,
             rate_vnd,
         }: &'a NewHandymanService,
-    ) -> Self {
-        db::NewHandymanService {
-            service: *service,
+    ) -> Result<Self> {
+        Ok(db::NewHandymanService {
+            service: service_code_input(service_code.as_deref(), *service)?,
             note: note.as_deref(),
             rate_vnd: rate_vnd.map(|r| r.try_into().unwrap_or(i32::MAX)),
-        }
+        })
     }
 }
 
@@ -226,3 +276,14 @@ struct HandymanProfileRemoveServicePayload {
     profile: HandymanProfile,
     removed_service_id: ID,
 }
+
+#[derive(Debug, InputObject)]
+struct HandymanProfileSetServiceLocationInput {
+    handyman_id: ID,
+    location: LocationInput,
+}
+
+#[derive(SimpleObject)]
+struct HandymanProfileSetServiceLocationPayload {
+    profile: HandymanProfile,
+}
@@ -0,0 +1,163 @@
+use crate::admin_account_type_error;
+use account_service_server::{AccountExistsRequest, ResetPasswordRequest};
+use async_graphql::{Context, InputObject, Object, SimpleObject};
+use core_service_graphql_context::{RateLimitedApi, RequestContext};
+use core_service_graphql_types::Session;
+use entity_type::AccountType;
+use error::Result;
+use otp_store::OtpPurpose;
+use sms_sender::{MessageType, OtpVerificationForPasswordReset, SendSmsInput};
+
+#[derive(Default)]
+pub struct PasswordResetMutation;
+
+#[Object]
+impl PasswordResetMutation {
+    /// Send an OTP code to the phone number for resetting the password.
+    /// The payload is the same whether or not the account exists,
+    /// so that phone numbers of accounts can't be enumerated.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn user_password_reset_start(
+        &self,
+        ctx: &Context<'_>,
+        input: UserPasswordResetStartInput,
+    ) -> Result<UserPasswordResetStartPayload> {
+        let UserPasswordResetStartInput {
+            phone_number,
+            account_type,
+        } = input;
+        reject_admin_password_reset(account_type)?;
+        let phone_number = typesafe::phone_number_from_str(&phone_number)?;
+        let e164_phone_number_str = typesafe::phone_number_to_e164_format(&phone_number);
+        let context = ctx.data::<RequestContext>()?;
+        context.check_rate_limit(
+            RateLimitedApi::StartPasswordReset,
+            account_type,
+            &e164_phone_number_str,
+        )?;
+
+        let exists = context
+            .account_service_client
+            .account_exists(AccountExistsRequest {
+                e164_phone_number_str: e164_phone_number_str.clone(),
+                account_type,
+            })
+            .await?
+            .exists;
+
+        // Issued even without an account, so that the resend cooldown doesn't tell it exists.
+        // Codes are kept per account type, the code of another account of the phone number stays.
+        let otp_code = context
+            .otp_issue(
+                OtpPurpose::PasswordReset,
+                account_type,
+                e164_phone_number_str.clone(),
+            )
+            .await?;
+
+        if exists {
+            context
+                .sms_sender
+                .send(SendSmsInput {
+                    to: phone_number,
+                    message: MessageType::OtpVerificationForPasswordReset(
+                        OtpVerificationForPasswordReset {
+                            code: otp_code.code,
+                        },
+                    ),
+                })
+                .await?;
+        }
+
+        Ok(UserPasswordResetStartPayload {
+            digits: otp_code.digits,
+            ttl_seconds: otp_code.ttl_seconds,
+            resend_cooldown_seconds: otp_code.resend_cooldown_seconds,
+            e164_phone_number_str,
+        })
+    }
+
+    /// Verify the OTP code and set the new password,
+    /// every other session of the account is revoked.
+    #[tracing::instrument(skip_all, fields(phone_number = input.phone_number.as_str()))]
+    async fn user_password_reset_finish(
+        &self,
+        ctx: &Context<'_>,
+        input: UserPasswordResetFinishInput,
+    ) -> Result<UserPasswordResetFinishPayload> {
+        let UserPasswordResetFinishInput {
+            phone_number,
+            account_type,
+            otp_code,
+            new_password,
+        } = input;
+        reject_admin_password_reset(account_type)?;
+        let e164_phone_number_str = typesafe::normalize_phone_number_str(&phone_number)?;
+        // Check before the OTP code is consumed, so that it can be retried with another password
+        typesafe::check_password_safety(&new_password)?;
+        let context = ctx.data::<RequestContext>()?;
+        context
+            .otp_verify(
+                OtpPurpose::PasswordReset,
+                account_type,
+                &e164_phone_number_str,
+                &otp_code,
+            )
+            .await?;
+
+        let initiate_session = context
+            .account_service_client
+            .reset_password(ResetPasswordRequest {
+                e164_phone_number_str,
+                account_type,
+                password: new_password,
+            })
+            .await?
+            .initiate_session;
+
+        let session = context.set_session(ctx, initiate_session).await?;
+        Ok(UserPasswordResetFinishPayload {
+            session: Session::new(session),
+        })
+    }
+}
+
+/// Admin passwords are reset by other admins, owning the phone number doesn't grant admin access
+fn reject_admin_password_reset(account_type: AccountType) -> Result<()> {
+    match account_type {
+        AccountType::Admin => Err(admin_account_type_error(
+            "Admin accounts can't reset their password",
+        )),
+        AccountType::Customer | AccountType::Handyman => Ok(()),
+    }
+}
+
+#[derive(Debug, InputObject)]
+struct UserPasswordResetStartInput {
+    phone_number: String,
+    account_type: AccountType,
+}
+
+#[derive(SimpleObject)]
+/// An OTP code has been sent to user phone number (zalo) if the account exists.
+struct UserPasswordResetStartPayload {
+    digits: u8,
+    ttl_seconds: u64,
+    /// Seconds to wait before another code can be sent to the phone number
+    resend_cooldown_seconds: u64,
+    /// The standard phone number format captured by backend
+    e164_phone_number_str: String,
+}
+
+#[derive(InputObject)]
+struct UserPasswordResetFinishInput {
+    phone_number: String,
+    account_type: AccountType,
+    otp_code: String,
+    new_password: String,
+}
+
+#[derive(SimpleObject)]
+struct UserPasswordResetFinishPayload {
+    session: Session,
+}
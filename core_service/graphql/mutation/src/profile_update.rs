@@ -0,0 +1,228 @@
+use crate::store_uploaded_image;
+use account_service_server::{
+    CustomerUpdateProfileRequest, HandymanUpdateProfileRequest, HandymanUpdateProfileResponse,
+};
+use async_graphql::{Context, ID, InputObject, Object, SimpleObject, Upload};
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{
+    Customer, CustomerProfile, GlobalId, Handyman, HandymanProfile, SetValue,
+};
+use entity_type::HandymanId;
+use error::Result;
+use search_service_server::HandymanIndexType;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct ProfileUpdateMutation;
+
+#[Object]
+impl ProfileUpdateMutation {
+    #[tracing::instrument(skip(self, ctx))]
+    async fn customer_update_profile(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerUpdateProfileInput,
+    ) -> Result<CustomerUpdateProfilePayload> {
+        let CustomerUpdateProfileInput {
+            customer_id,
+            changeset: CustomerUpdateProfileChangeset { nick_name },
+        } = input;
+        let customer_id = Customer::from_global_id(&customer_id)?.inner_id();
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+
+        let profile = context
+            .account_service_client
+            .customer_update_profile(CustomerUpdateProfileRequest {
+                actor_auth: session_ctx.as_actor_auth(),
+                customer_id,
+                nick_name,
+            })
+            .await?
+            .profile;
+
+        Ok(CustomerUpdateProfilePayload {
+            profile: CustomerProfile::new_with(profile.customer_id, Arc::new(profile)),
+        })
+    }
+
+    /// Update the profile of a handyman, the search index follows name changes.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_update_profile(
+        &self,
+        ctx: &Context<'_>,
+        input: HandymanUpdateProfileInput,
+    ) -> Result<HandymanUpdateProfilePayload> {
+        let HandymanUpdateProfileInput {
+            handyman_id,
+            changeset:
+                HandymanUpdateProfileChangeset {
+                    first_name,
+                    last_name,
+                    bio,
+                    years_of_experience,
+                },
+        } = input;
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let is_name_changed = first_name.is_some() || last_name.is_some();
+
+        let profile = context
+            .account_service_client
+            .handyman_update_profile(HandymanUpdateProfileRequest {
+                actor_auth: session_ctx.as_actor_auth(),
+                handyman_id,
+                first_name,
+                last_name,
+                bio: bio.map(|s| s.value),
+                years_of_experience: years_of_experience.map(|s| s.value),
+                avatar_key: None,
+            })
+            .await?
+            .profile;
+
+        if is_name_changed {
+            context
+                .index_handyman_if_active(
+                    handyman_id,
+                    HandymanIndexType::SetFullName(profile.full_name()),
+                )
+                .await?;
+        }
+
+        context
+            .handyman_loaders
+            .profile_by_id_loader
+            .feed_one(profile.handyman_id, Arc::new(profile))
+            .await;
+
+        Ok(HandymanUpdateProfilePayload {
+            profile: HandymanProfile::new(handyman_id),
+        })
+    }
+
+    /// Upload a JPEG, PNG or WebP avatar of at most 5 MB, replacing the current avatar.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_upload_avatar(
+        &self,
+        ctx: &Context<'_>,
+        input: HandymanUploadAvatarInput,
+    ) -> Result<HandymanUpdateProfilePayload> {
+        let HandymanUploadAvatarInput { handyman_id, file } = input;
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        session_ctx
+            .as_actor_auth()
+            .require_handyman_access(handyman_id)?;
+
+        let key = store_uploaded_image(ctx, &file, AVATAR_BLOB_PREFIX).await?;
+        let result = set_handyman_avatar(context, handyman_id, Some(key.to_string())).await;
+        if result.is_err() {
+            context.delete_blob_quietly(key.as_str()).await;
+        }
+        result
+    }
+
+    /// Remove the avatar of a handyman, if any.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_remove_avatar(
+        &self,
+        ctx: &Context<'_>,
+        input: HandymanRemoveAvatarInput,
+    ) -> Result<HandymanUpdateProfilePayload> {
+        let HandymanRemoveAvatarInput { handyman_id } = input;
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+        let context = ctx.data::<RequestContext>()?;
+
+        set_handyman_avatar(context, handyman_id, None).await
+    }
+}
+
+const AVATAR_BLOB_PREFIX: &str = "avatars";
+
+/// Point the profile to the new avatar then delete the previous avatar from the blob store
+async fn set_handyman_avatar(
+    context: &RequestContext,
+    handyman_id: HandymanId,
+    avatar_key: Option<String>,
+) -> Result<HandymanUpdateProfilePayload> {
+    let session_ctx = context.try_session_context().await?;
+    let HandymanUpdateProfileResponse {
+        profile,
+        replaced_avatar_key,
+    } = context
+        .account_service_client
+        .handyman_update_profile(HandymanUpdateProfileRequest {
+            actor_auth: session_ctx.as_actor_auth(),
+            handyman_id,
+            first_name: None,
+            last_name: None,
+            bio: None,
+            years_of_experience: None,
+            avatar_key: Some(avatar_key),
+        })
+        .await?;
+
+    if let Some(replaced_avatar_key) = replaced_avatar_key {
+        context.delete_blob_quietly(&replaced_avatar_key).await;
+    }
+    context
+        .handyman_loaders
+        .profile_by_id_loader
+        .feed_one(profile.handyman_id, Arc::new(profile))
+        .await;
+
+    Ok(HandymanUpdateProfilePayload {
+        profile: HandymanProfile::new(handyman_id),
+    })
+}
+
+#[derive(Debug, InputObject)]
+struct CustomerUpdateProfileInput {
+    customer_id: ID,
+    changeset: CustomerUpdateProfileChangeset,
+}
+
+/// Omitted fields are left unchanged.
+#[derive(Debug, InputObject)]
+struct CustomerUpdateProfileChangeset {
+    nick_name: Option<String>,
+}
+
+#[derive(SimpleObject)]
+struct CustomerUpdateProfilePayload {
+    profile: CustomerProfile,
+}
+
+#[derive(Debug, InputObject)]
+struct HandymanUpdateProfileInput {
+    handyman_id: ID,
+    changeset: HandymanUpdateProfileChangeset,
+}
+
+/// Omitted fields are left unchanged, optional fields are cleared by setting their value to null.
+#[derive(Debug, InputObject)]
+struct HandymanUpdateProfileChangeset {
+    first_name: Option<String>,
+    last_name: Option<String>,
+    bio: Option<SetValue<String>>,
+    years_of_experience: Option<SetValue<i32>>,
+}
+
+#[derive(SimpleObject)]
+struct HandymanUpdateProfilePayload {
+    profile: HandymanProfile,
+}
+
+#[derive(Debug, InputObject)]
+struct HandymanUploadAvatarInput {
+    handyman_id: ID,
+    file: Upload,
+}
+
+#[derive(Debug, InputObject)]
+struct HandymanRemoveAvatarInput {
+    handyman_id: ID,
+}
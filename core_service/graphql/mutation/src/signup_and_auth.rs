@@ -1,15 +1,21 @@
 use account_service_server::{
-    AccountExistsRequest, CustomerCreateProfileRequest, CustomerRegisterRequest,
-    CustomerSignInWithPasswordRequest, HandymanCreateProfileRequest, HandymanRegisterRequest,
-    HandymanSigninWithPasswordRequest,
+    AccountExistsRequest, AdminSignInWithPasswordRequest, CustomerCreateProfileRequest,
+    CustomerRegisterRequest, CustomerSignInWithPasswordRequest, HandymanCreateProfileRequest,
+    HandymanRegisterRequest, HandymanSigninWithPasswordRequest, SignInWithOtpRequest,
 };
 use async_graphql::{Context, ID, InputObject, Object, SimpleObject, Union};
-use core_service_graphql_context::RequestContext;
+use core_service_graphql_context::{RateLimitedApi, RequestContext};
 use core_service_graphql_types::{Customer, GlobalId, Handyman, Session};
 use entity_type::AccountType;
-use error::Result;
+use error::{
+    Error, Result,
+    error_details::{BadRequest, bad_request::FieldViolation},
+};
+use otp_store::OtpPurpose;
 use search_service_server::{HandymanIndexRequest, HandymanIndexType};
-use sms_sender::{MessageType, OtpVerificationForRegistration, SendSmsInput};
+use sms_sender::{
+    MessageType, OtpVerificationForRegistration, OtpVerificationForSignIn, SendSmsInput,
+};
 use std::sync::Arc;
 
 #[derive(Default)]
@@ -27,9 +33,15 @@ impl SignUpAndAuthMutation {
             phone_number,
             account_type,
         } = input;
+        reject_admin_registration(account_type)?;
         let phone_number = typesafe::phone_number_from_str(&phone_number)?;
         let e164_phone_number_str = typesafe::phone_number_to_e164_format(&phone_number);
         let context = ctx.data::<RequestContext>()?;
+        context.check_rate_limit(
+            RateLimitedApi::StartRegistration,
+            account_type,
+            &e164_phone_number_str,
+        )?;
 
         let exists = context
             .account_service_client
@@ -49,7 +61,11 @@ impl SignUpAndAuthMutation {
         }
 
         let otp_code = context
-            .pending_registration_phone_cache(e164_phone_number_str.clone())
+            .otp_issue(
+                OtpPurpose::Registration,
+                account_type,
+                e164_phone_number_str.clone(),
+            )
             .await?;
 
         context
@@ -68,6 +84,7 @@ impl SignUpAndAuthMutation {
             case: StartRegistrationCase::OtpCode(StartRegistrationCaseOtpCode {
                 digits: otp_code.digits,
                 ttl_seconds: otp_code.ttl_seconds,
+                resend_cooldown_seconds: otp_code.resend_cooldown_seconds,
                 e164_phone_number_str,
             }),
         })
@@ -90,7 +107,12 @@ impl SignUpAndAuthMutation {
 
         let context = ctx.data::<RequestContext>()?;
         context
-            .pending_registration_phone_validate(&e164_phone_number_str, &otp_code)
+            .otp_verify(
+                OtpPurpose::Registration,
+                account_type,
+                &e164_phone_number_str,
+                &otp_code,
+            )
             .await?;
 
         let initiate_session = match account_type {
@@ -114,6 +136,7 @@ impl SignUpAndAuthMutation {
                     .await?
                     .initiate_session
             }
+            AccountType::Admin => return Err(admin_account_type_error(ADMIN_REGISTRATION_MESSAGE)),
         };
 
         let session = context.set_session(ctx, initiate_session).await?;
@@ -135,6 +158,11 @@ impl SignUpAndAuthMutation {
         } = input;
         let e164_phone_number_str = typesafe::normalize_phone_number_str(&phone_number)?;
         let context = ctx.data::<RequestContext>()?;
+        context.check_rate_limit(
+            RateLimitedApi::SignInWithPassword,
+            account_type,
+            &e164_phone_number_str,
+        )?;
 
         let initiate_session = match account_type {
             AccountType::Customer => {
@@ -157,6 +185,16 @@ impl SignUpAndAuthMutation {
                     .await?
                     .initiate_session
             }
+            AccountType::Admin => {
+                context
+                    .account_service_client
+                    .admin_sign_in_with_password(AdminSignInWithPasswordRequest {
+                        e164_phone_number_str,
+                        password,
+                    })
+                    .await?
+                    .initiate_session
+            }
         };
 
         let session = context.set_session(ctx, initiate_session).await?;
@@ -165,6 +203,104 @@ impl SignUpAndAuthMutation {
         })
     }
 
+    /// Send an OTP code to the phone number for signing in without a password.
+    /// The payload is the same whether or not the account exists,
+    /// so that phone numbers of accounts can't be enumerated.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn user_sign_in_with_otp_start(
+        &self,
+        ctx: &Context<'_>,
+        input: UserSignInWithOtpStartInput,
+    ) -> Result<UserSignInWithOtpStartPayload> {
+        let UserSignInWithOtpStartInput {
+            phone_number,
+            account_type,
+        } = input;
+        reject_admin_sign_in_with_otp(account_type)?;
+        let phone_number = typesafe::phone_number_from_str(&phone_number)?;
+        let e164_phone_number_str = typesafe::phone_number_to_e164_format(&phone_number);
+        let context = ctx.data::<RequestContext>()?;
+        context.check_rate_limit(
+            RateLimitedApi::StartSignInWithOtp,
+            account_type,
+            &e164_phone_number_str,
+        )?;
+
+        let exists = context
+            .account_service_client
+            .account_exists(AccountExistsRequest {
+                e164_phone_number_str: e164_phone_number_str.clone(),
+                account_type,
+            })
+            .await?
+            .exists;
+
+        let otp_code = context
+            .otp_issue(
+                OtpPurpose::SignIn,
+                account_type,
+                e164_phone_number_str.clone(),
+            )
+            .await?;
+
+        if exists {
+            context
+                .sms_sender
+                .send(SendSmsInput {
+                    to: phone_number,
+                    message: MessageType::OtpVerificationForSignIn(OtpVerificationForSignIn {
+                        code: otp_code.code,
+                    }),
+                })
+                .await?;
+        }
+
+        Ok(UserSignInWithOtpStartPayload {
+            digits: otp_code.digits,
+            ttl_seconds: otp_code.ttl_seconds,
+            resend_cooldown_seconds: otp_code.resend_cooldown_seconds,
+            e164_phone_number_str,
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(phone_number = input.phone_number.as_str()))]
+    async fn user_sign_in_with_otp_finish(
+        &self,
+        ctx: &Context<'_>,
+        input: UserSignInWithOtpFinishInput,
+    ) -> Result<UserSignInWithOtpFinishPayload> {
+        let UserSignInWithOtpFinishInput {
+            phone_number,
+            otp_code,
+            account_type,
+        } = input;
+        reject_admin_sign_in_with_otp(account_type)?;
+        let e164_phone_number_str = typesafe::normalize_phone_number_str(&phone_number)?;
+        let context = ctx.data::<RequestContext>()?;
+        context
+            .otp_verify(
+                OtpPurpose::SignIn,
+                account_type,
+                &e164_phone_number_str,
+                &otp_code,
+            )
+            .await?;
+
+        let initiate_session = context
+            .account_service_client
+            .sign_in_with_otp(SignInWithOtpRequest {
+                e164_phone_number_str,
+                account_type,
+            })
+            .await?
+            .initiate_session;
+
+        let session = context.set_session(ctx, initiate_session).await?;
+        Ok(UserSignInWithOtpFinishPayload {
+            session: Session::new(session),
+        })
+    }
+
     #[tracing::instrument(skip(self, ctx))]
     async fn customer_create_profile(
         &self,
@@ -246,6 +382,38 @@ impl SignUpAndAuthMutation {
     }
 }
 
+const ADMIN_REGISTRATION_MESSAGE: &str = "Admin accounts can't be registered";
+
+/// Admin accounts are created by god or other admins rather than registered
+fn reject_admin_registration(account_type: AccountType) -> Result<()> {
+    match account_type {
+        AccountType::Admin => Err(admin_account_type_error(ADMIN_REGISTRATION_MESSAGE)),
+        AccountType::Customer | AccountType::Handyman => Ok(()),
+    }
+}
+
+/// Admins sign in with their password only, owning the phone number doesn't grant admin access
+fn reject_admin_sign_in_with_otp(account_type: AccountType) -> Result<()> {
+    match account_type {
+        AccountType::Admin => Err(admin_account_type_error(
+            "Admin accounts can't sign in with OTP",
+        )),
+        AccountType::Customer | AccountType::Handyman => Ok(()),
+    }
+}
+
+pub(crate) fn admin_account_type_error(message: &str) -> Error {
+    Error::invalid_argument_with(
+        message,
+        Some(BadRequest {
+            field_violations: vec![FieldViolation {
+                field: "account_type".into(),
+                description: "ADMIN".into(),
+            }],
+        }),
+    )
+}
+
 #[derive(Debug, InputObject)]
 struct UserAccountStartRegistrationInput {
     phone_number: String,
@@ -277,6 +445,8 @@ struct StartRegistrationCaseAccountExist {
 struct StartRegistrationCaseOtpCode {
     digits: u8,
     ttl_seconds: u64,
+    /// Seconds to wait before another code can be sent to the phone number
+    resend_cooldown_seconds: u64,
     /// The standard phone number format captured by backend
     e164_phone_number_str: String,
 }
@@ -306,6 +476,35 @@ struct UserSignInWithPasswordPayload {
     session: Session,
 }
 
+#[derive(Debug, InputObject)]
+struct UserSignInWithOtpStartInput {
+    phone_number: String,
+    account_type: AccountType,
+}
+
+#[derive(SimpleObject)]
+/// An OTP code has been sent to user phone number (zalo) if the account exists.
+struct UserSignInWithOtpStartPayload {
+    digits: u8,
+    ttl_seconds: u64,
+    /// Seconds to wait before another code can be sent to the phone number
+    resend_cooldown_seconds: u64,
+    /// The standard phone number format captured by backend
+    e164_phone_number_str: String,
+}
+
+#[derive(Debug, InputObject)]
+struct UserSignInWithOtpFinishInput {
+    phone_number: String,
+    otp_code: String,
+    account_type: AccountType,
+}
+
+#[derive(SimpleObject)]
+struct UserSignInWithOtpFinishPayload {
+    session: Session,
+}
+
 #[derive(Debug, InputObject)]
 struct CustomerCreateProfileInput {
     /// Requires customer_id to allow admin control.
@@ -0,0 +1,158 @@
+use crate::sync_task_search_index;
+use async_graphql::{Context, ID, InputObject, Object, SimpleObject};
+use chrono::{DateTime, FixedOffset};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{CustomerTaskRequest, GlobalId, Handyman, TaskQuote};
+use db_utils::with_mutable_db;
+use entity_type::HandymanAccessGuardId;
+use error::Result;
+use scoped_futures::ScopedFutureExt;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct TaskQuoteMutation;
+
+#[Object]
+impl TaskQuoteMutation {
+    /// Handyman quotes a price and ETA on an open task.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_task_quote_submit(
+        &self,
+        ctx: &Context<'_>,
+        input: HandymanTaskQuoteSubmitInput,
+    ) -> Result<HandymanTaskQuotePayload> {
+        let HandymanTaskQuoteSubmitInput {
+            task_id,
+            handyman_id,
+            price_vnd,
+            eta,
+            message,
+        } = input;
+        let task_id = CustomerTaskRequest::from_global_id(&task_id)?.inner_id();
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+        let message = message.as_deref().filter(|m| !m.trim().is_empty());
+
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let actor_auth = session_ctx.as_actor_auth();
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        let quote = with_mutable_db(&context.db_connection_pool, |conn| {
+            db::TaskQuote::submit(
+                &actor_auth,
+                db::NewTaskQuote {
+                    task_id,
+                    handyman_id,
+                    price_vnd,
+                    eta: eta.naive_utc(),
+                    message,
+                },
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(HandymanTaskQuotePayload {
+            quote: TaskQuote::new_with(quote.id, Arc::new(quote)),
+        })
+    }
+
+    /// Handyman withdraws a pending quote.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_task_quote_withdraw(
+        &self,
+        ctx: &Context<'_>,
+        input: HandymanTaskQuoteWithdrawInput,
+    ) -> Result<HandymanTaskQuotePayload> {
+        let HandymanTaskQuoteWithdrawInput {
+            handyman_id,
+            quote_id,
+        } = input;
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+        let quote_id = TaskQuote::from_global_id(&quote_id)?.inner_id();
+
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let actor_auth = session_ctx.as_actor_auth();
+        actor_auth.require_handyman_access(handyman_id)?;
+
+        let quote = with_mutable_db(&context.db_connection_pool, |conn| {
+            db::TaskQuote::withdraw(
+                &actor_auth,
+                HandymanAccessGuardId {
+                    handyman_id,
+                    entity_id: quote_id,
+                },
+                conn,
+            )
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(HandymanTaskQuotePayload {
+            quote: TaskQuote::new_with(quote.id, Arc::new(quote)),
+        })
+    }
+
+    /// Customer accepts a quote. The task is assigned to the quoting handyman
+    /// and the other pending quotes are rejected.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn customer_task_quote_accept(
+        &self,
+        ctx: &Context<'_>,
+        input: CustomerTaskQuoteAcceptInput,
+    ) -> Result<CustomerTaskQuoteAcceptPayload> {
+        let quote_id = TaskQuote::from_global_id(&input.quote_id)?.inner_id();
+
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let actor_auth = session_ctx.as_actor_auth();
+
+        let (quote, task) = with_mutable_db(&context.db_connection_pool, |conn| {
+            db::TaskQuote::accept(&actor_auth, quote_id, conn).scope_boxed()
+        })
+        .await?;
+
+        sync_task_search_index(context, &task).await?;
+
+        Ok(CustomerTaskQuoteAcceptPayload {
+            quote: TaskQuote::new_with(quote.id, Arc::new(quote)),
+            task: CustomerTaskRequest::new_with(task.id, Arc::new(task)),
+        })
+    }
+}
+
+#[derive(Debug, InputObject)]
+struct HandymanTaskQuoteSubmitInput {
+    task_id: ID,
+    handyman_id: ID,
+    /// Quoted price in VND
+    price_vnd: i32,
+    /// Estimated time the handyman can get the task done
+    eta: DateTime<FixedOffset>,
+    message: Option<String>,
+}
+
+#[derive(Debug, InputObject)]
+struct HandymanTaskQuoteWithdrawInput {
+    handyman_id: ID,
+    quote_id: ID,
+}
+
+#[derive(SimpleObject)]
+struct HandymanTaskQuotePayload {
+    quote: TaskQuote,
+}
+
+#[derive(Debug, InputObject)]
+struct CustomerTaskQuoteAcceptInput {
+    quote_id: ID,
+}
+
+#[derive(SimpleObject)]
+struct CustomerTaskQuoteAcceptPayload {
+    quote: TaskQuote,
+    task: CustomerTaskRequest,
+}
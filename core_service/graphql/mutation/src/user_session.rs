@@ -0,0 +1,164 @@
+use account_service_server::{
+    RenewSessionRequest, RevokeAllSessionsRequest, RevokeSessionRequest, SignOutRequest,
+    SwitchActiveRoleRequest,
+};
+use async_graphql::{Context, ID, InputObject, Object, SimpleObject};
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{GlobalId, Session, UserSession};
+use error::Result;
+use std::sync::Arc;
+
+#[derive(Default)]
+pub struct UserSessionMutation;
+
+#[Object]
+impl UserSessionMutation {
+    /// Revoke the current session and clear session cookies.
+    /// Signing out without a session only clears the cookies.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn user_sign_out(&self, ctx: &Context<'_>) -> Result<UserSignOutPayload> {
+        let context = ctx.data::<RequestContext>()?;
+        let Ok(req_session) = context.try_session_context().await else {
+            context.clear_session_cookies(ctx);
+            return Ok(UserSignOutPayload { user_session: None });
+        };
+
+        let session = context
+            .account_service_client
+            .sign_out(SignOutRequest {
+                actor_auth: req_session.as_actor_auth(),
+            })
+            .await?
+            .session;
+        context.clear_session(ctx).await;
+
+        Ok(UserSignOutPayload {
+            user_session: Some(UserSession::new_with(session.id, Arc::new(session))),
+        })
+    }
+
+    /// Issue a new session token extending the current session,
+    /// the previous session token is no longer valid.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn user_renew_session(&self, ctx: &Context<'_>) -> Result<UserRenewSessionPayload> {
+        let context = ctx.data::<RequestContext>()?;
+        let req_session = context.try_session_context().await?;
+
+        let renew_session = context
+            .account_service_client
+            .renew_session(RenewSessionRequest {
+                actor_auth: req_session.as_actor_auth(),
+            })
+            .await?
+            .renew_session;
+
+        let session = context.set_session(ctx, renew_session).await?;
+        Ok(UserRenewSessionPayload {
+            session: Session::new(session),
+        })
+    }
+
+    /// Revoke a session of the current account, e.g. to sign out a lost device
+    #[tracing::instrument(skip(self, ctx))]
+    async fn user_revoke_session(
+        &self,
+        ctx: &Context<'_>,
+        input: UserRevokeSessionInput,
+    ) -> Result<UserRevokeSessionPayload> {
+        let UserRevokeSessionInput { session_id } = input;
+        let session_id = UserSession::from_global_id(&session_id)?.inner_id();
+        let context = ctx.data::<RequestContext>()?;
+        let req_session = context.try_session_context().await?;
+
+        let session = context
+            .account_service_client
+            .revoke_session(RevokeSessionRequest {
+                actor_auth: req_session.as_actor_auth(),
+                session_id,
+            })
+            .await?
+            .session;
+        if session_id == req_session.session_id {
+            context.clear_session(ctx).await;
+        }
+
+        Ok(UserRevokeSessionPayload {
+            user_session: UserSession::new_with(session.id, Arc::new(session)),
+        })
+    }
+
+    /// Revoke every session of the current account, including the current one
+    #[tracing::instrument(skip(self, ctx))]
+    async fn user_revoke_all_sessions(
+        &self,
+        ctx: &Context<'_>,
+    ) -> Result<UserRevokeAllSessionsPayload> {
+        let context = ctx.data::<RequestContext>()?;
+        let req_session = context.try_session_context().await?;
+
+        let revoked_count = context
+            .account_service_client
+            .revoke_all_sessions(RevokeAllSessionsRequest {
+                actor_auth: req_session.as_actor_auth(),
+            })
+            .await?
+            .revoked_count;
+        context.clear_session(ctx).await;
+
+        Ok(UserRevokeAllSessionsPayload {
+            revoked_count: revoked_count as u64,
+        })
+    }
+
+    /// Switch between the customer and the handyman account registered with the same phone number,
+    /// the current session is replaced by a session of the other account.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn switch_active_role(&self, ctx: &Context<'_>) -> Result<SwitchActiveRolePayload> {
+        let context = ctx.data::<RequestContext>()?;
+        let req_session = context.try_session_context().await?;
+
+        let initiate_session = context
+            .account_service_client
+            .switch_active_role(SwitchActiveRoleRequest {
+                actor_auth: req_session.as_actor_auth(),
+            })
+            .await?
+            .initiate_session;
+
+        let session = context.set_session(ctx, initiate_session).await?;
+        Ok(SwitchActiveRolePayload {
+            session: Session::new(session),
+        })
+    }
+}
+
+#[derive(SimpleObject)]
+struct UserSignOutPayload {
+    /// The revoked session, `null` if the request has no session
+    user_session: Option<UserSession>,
+}
+
+#[derive(SimpleObject)]
+struct UserRenewSessionPayload {
+    session: Session,
+}
+
+#[derive(Debug, InputObject)]
+struct UserRevokeSessionInput {
+    session_id: ID,
+}
+
+#[derive(SimpleObject)]
+struct UserRevokeSessionPayload {
+    user_session: UserSession,
+}
+
+#[derive(SimpleObject)]
+struct UserRevokeAllSessionsPayload {
+    revoked_count: u64,
+}
+
+#[derive(SimpleObject)]
+struct SwitchActiveRolePayload {
+    session: Session,
+}
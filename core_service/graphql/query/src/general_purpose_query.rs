@@ -12,7 +12,9 @@ impl GeneralPurposeQuery {
         let node = parse_any_global_id(&id)?;
         match node {
             // Custom non-loadable nodes
-            Some(Node::Session(_) | Node::HandymanService(_)) => Err(Error::invalid_argument(
+            Some(
+                Node::Session(_) | Node::HandymanService(_) | Node::Admin(_) | Node::TaskPhoto(_),
+            ) => Err(Error::invalid_argument(
                 "Entity unsuported with \"node\" query",
             )),
             loadable_node => Ok(loadable_node),
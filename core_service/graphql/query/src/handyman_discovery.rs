@@ -1,6 +1,8 @@
+use crate::DistanceWithinInput;
 use async_graphql::{Context, ID, InputObject, Object};
+use chrono::{DateTime, FixedOffset};
 use core_service_graphql_context::RequestContext;
-use core_service_graphql_types::{GlobalId, Handyman, PagingOffsetPayload};
+use core_service_graphql_types::{GlobalId, Handyman, HandymanSearchPayload};
 use entity_type::ServiceLayer2;
 use error::{Error, Result};
 use paging::{PagingOffsetConfig, PagingOffsetInput};
@@ -18,7 +20,7 @@ impl HandymanDiscoveryQuery {
         ctx: &Context<'_>,
         filter: HandymanSearchFilter,
         paging_config: PagingOffsetInput,
-    ) -> Result<PagingOffsetPayload<Handyman>> {
+    ) -> Result<HandymanSearchPayload> {
         let context = ctx.data::<RequestContext>()?;
         context.try_session_context().await?;
 
@@ -31,18 +33,40 @@ impl HandymanDiscoveryQuery {
             .await?
             .result;
 
-        Ok(PagingOffsetPayload {
-            paging_info: data.paging_info,
-            items: data.items.into_iter().map(Handyman::new).collect(),
-        })
+        Ok(into_handyman_search_payload(data))
+    }
+}
+
+pub(crate) fn into_handyman_search_payload(
+    paging::PagingOffsetPayload { paging_info, items }: paging::PagingOffsetPayload<
+        sea_db::HandymanSearchResult,
+    >,
+) -> HandymanSearchPayload {
+    let distance_meters = items.iter().map(|r| r.distance_meters).collect();
+    HandymanSearchPayload {
+        paging_info,
+        items: items
+            .into_iter()
+            .map(|r| Handyman::new(r.handyman_id))
+            .collect(),
+        distance_meters,
     }
 }
 
 #[derive(Debug, InputObject)]
 pub struct HandymanSearchFilter {
+    /// Codes of services of the catalog
+    pub service_codes: Option<Vec<String>>,
+    #[graphql(deprecation = "Use serviceCodes")]
     pub services: Option<Vec<ServiceLayer2>>,
     pub name: Option<String>,
     pub ids: Option<Vec<ID>>,
+    /// Only handymen whose service location is within the distance, ordered by distance
+    /// after ranking.
+    pub distance_within: Option<DistanceWithinInput>,
+    /// Only handymen whose working hours cover the time and who have no day off on its date,
+    /// both in the time zone of their working hours. Handymen without working hours are excluded.
+    pub available_at: Option<DateTime<FixedOffset>>,
 }
 
 impl TryFrom<HandymanSearchFilter> for sea_db::HandymanSearchFilter {
@@ -50,9 +74,12 @@ impl TryFrom<HandymanSearchFilter> for sea_db::HandymanSearchFilter {
 
     fn try_from(
         HandymanSearchFilter {
+            service_codes,
             services,
             name,
             ids,
+            distance_within,
+            available_at,
         }: HandymanSearchFilter,
     ) -> Result<Self> {
         let handyman_ids = if let Some(ids) = ids {
@@ -68,8 +95,11 @@ impl TryFrom<HandymanSearchFilter> for sea_db::HandymanSearchFilter {
         let result = Self {
             handyman_ids,
             name,
-            skills: services,
-            distance_within: None,
+            skills: service_codes.or_else(|| {
+                services.map(|services| services.iter().map(|s| s.code().to_string()).collect())
+            }),
+            distance_within: distance_within.map(Into::into),
+            available_at: available_at.map(|t| t.to_utc()),
         };
         Ok(result)
     }
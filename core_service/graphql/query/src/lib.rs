@@ -12,3 +12,6 @@ pub(crate) use service::*;
 
 mod handyman_discovery;
 pub(crate) use handyman_discovery::*;
+
+mod task_discovery;
+pub(crate) use task_discovery::*;
@@ -7,4 +7,5 @@ pub struct Query(
     SessionQuery,
     ServiceQuery,
     HandymanDiscoveryQuery,
+    TaskDiscoveryQuery,
 );
@@ -1,15 +1,41 @@
-use async_graphql::Object;
-use core_service_graphql_types::ServiceGroup;
-use entity_type::ServiceLayer1;
-use strum::IntoEnumIterator;
+use async_graphql::{Context, Object};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{Service, ServiceGroup};
+use db_utils::with_readonly_db;
+use error::Result;
+use scoped_futures::ScopedFutureExt;
+use std::sync::Arc;
 
 #[derive(Default)]
 pub struct ServiceQuery;
 
 #[Object]
 impl ServiceQuery {
-    #[tracing::instrument(skip(self))]
-    async fn service_groups(&self) -> Vec<ServiceGroup> {
-        ServiceLayer1::iter().map(ServiceGroup).collect()
+    /// Active groups of the service catalog, in display order
+    #[tracing::instrument(skip(self, ctx))]
+    async fn service_groups(&self, ctx: &Context<'_>) -> Result<Vec<ServiceGroup>> {
+        let context = ctx.data::<RequestContext>()?;
+        let groups = with_readonly_db(&context.db_connection_pool, |conn| {
+            db::CatalogServiceGroup::list_active(conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(groups
+            .into_iter()
+            .map(|g| ServiceGroup::new_with(g.code.clone(), Arc::new(g)))
+            .collect())
+    }
+
+    /// Look up a catalog service by code, inactive services included
+    #[tracing::instrument(skip(self, ctx))]
+    async fn service(&self, ctx: &Context<'_>, code: String) -> Result<Option<Service>> {
+        let context = ctx.data::<RequestContext>()?;
+        Ok(context
+            .service_loaders
+            .service_by_code_loader
+            .load_one(code)
+            .await?
+            .map(|s| Service::new_with(s.code.clone(), s)))
     }
 }
@@ -1,7 +1,9 @@
+use account_service_server::ActiveSessionsRequest;
 use async_graphql::{Context, Object};
 use core_service_graphql_context::RequestContext;
-use core_service_graphql_types::Session;
+use core_service_graphql_types::{Session, UserSession};
 use error::Result;
+use std::sync::Arc;
 
 #[derive(Default)]
 pub struct SessionQuery;
@@ -14,4 +16,24 @@ impl SessionQuery {
         let session_ctx = context.try_session_context().await.ok();
         Ok(session_ctx.map(Session::new))
     }
+
+    /// Active sessions of the current account, one per signed in device
+    #[tracing::instrument(skip(self, ctx))]
+    async fn user_sessions(&self, ctx: &Context<'_>) -> Result<Vec<UserSession>> {
+        let context = ctx.data::<RequestContext>()?;
+        let req_session = context.try_session_context().await?;
+
+        let sessions = context
+            .account_service_client
+            .active_sessions(ActiveSessionsRequest {
+                actor_auth: req_session.as_actor_auth(),
+            })
+            .await?
+            .sessions;
+
+        Ok(sessions
+            .into_iter()
+            .map(|s| UserSession::new_with(s.id, Arc::new(s)))
+            .collect())
+    }
 }
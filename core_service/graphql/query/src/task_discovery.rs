@@ -0,0 +1,122 @@
+use crate::into_handyman_search_payload;
+use async_graphql::{Context, ID, InputObject, Object};
+use core_service_graphql_context::RequestContext;
+use core_service_graphql_types::{
+    CustomerTaskRequest, GlobalId, Handyman, HandymanSearchPayload, PagingOffsetPayload,
+};
+use error::{Error, Result};
+use paging::{PagingOffsetConfig, PagingOffsetInput};
+use search_service_db as sea_db;
+use search_service_server::{HandymanTaskSearchRequest, TaskMatchHandymenRequest};
+
+#[derive(Default)]
+pub struct TaskDiscoveryQuery;
+
+#[Object]
+impl TaskDiscoveryQuery {
+    /// Open tasks near the given location which match the handyman's skills,
+    /// ordered by distance.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn handyman_nearby_tasks(
+        &self,
+        ctx: &Context<'_>,
+        handyman_id: ID,
+        distance_within: DistanceWithinInput,
+        paging_config: PagingOffsetInput,
+    ) -> Result<PagingOffsetPayload<CustomerTaskRequest>> {
+        let handyman_id = Handyman::from_global_id(&handyman_id)?.inner_id();
+
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        session_ctx
+            .as_actor_auth()
+            .require_handyman_access(handyman_id)?;
+
+        let data = context
+            .search_service_client
+            .handyman_task_search(HandymanTaskSearchRequest {
+                handyman_id,
+                distance_within: distance_within.into(),
+                paging_config: PagingOffsetConfig::try_from(paging_config)?,
+            })
+            .await?
+            .result;
+
+        Ok(PagingOffsetPayload {
+            paging_info: data.paging_info,
+            items: data
+                .items
+                .into_iter()
+                .map(CustomerTaskRequest::new)
+                .collect(),
+        })
+    }
+
+    /// Handymen who have the skill required by the task and are located within
+    /// `withinMeters` of the task location.
+    #[tracing::instrument(skip(self, ctx))]
+    async fn customer_task_matching_handymen(
+        &self,
+        ctx: &Context<'_>,
+        task_id: ID,
+        within_meters: f64,
+        paging_config: PagingOffsetInput,
+    ) -> Result<HandymanSearchPayload> {
+        let task_id = CustomerTaskRequest::from_global_id(&task_id)?.inner_id();
+
+        let context = ctx.data::<RequestContext>()?;
+        let session_ctx = context.try_session_context().await?;
+        let task = context
+            .task_loaders
+            .customer_task_request_by_id_loader
+            .load_one(task_id)
+            .await?
+            .ok_or_else(|| Error::not_found("Customer task request not found"))?;
+        session_ctx
+            .as_actor_auth()
+            .require_customer_access(task.customer_id)?;
+
+        let (lon, lat) = task
+            .coordinates()
+            .ok_or_else(|| Error::failed_precondition("Customer task request has no location"))?;
+
+        let data = context
+            .search_service_client
+            .task_match_handymen(TaskMatchHandymenRequest {
+                service: task.service.clone(),
+                distance_within: sea_db::DistanceWithinFilter {
+                    lon,
+                    lat,
+                    within_meters,
+                },
+                paging_config: PagingOffsetConfig::try_from(paging_config)?,
+            })
+            .await?
+            .result;
+
+        Ok(into_handyman_search_payload(data))
+    }
+}
+
+#[derive(Debug, InputObject)]
+pub struct DistanceWithinInput {
+    pub lon: f64,
+    pub lat: f64,
+    pub within_meters: f64,
+}
+
+impl From<DistanceWithinInput> for sea_db::DistanceWithinFilter {
+    fn from(
+        DistanceWithinInput {
+            lon,
+            lat,
+            within_meters,
+        }: DistanceWithinInput,
+    ) -> Self {
+        Self {
+            lon,
+            lat,
+            within_meters,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{CachedNode, GlobalId};
+use account_service_db as acc_db;
+use account_service_server::LoadAdminAccountByIdsRequest;
+use async_graphql::{Context, ID, Object};
+use core_service_graphql_context::RequestContext;
+use entity_type::AdminId;
+use error::{Error, Result};
+use std::sync::Arc;
+
+pub type Admin = CachedNode<AdminId, Arc<acc_db::AdminAccount>>;
+
+impl Admin {
+    /// Admins are rarely loaded, so they are loaded without a data loader
+    async fn load(id: AdminId, context: &RequestContext) -> Result<Arc<acc_db::AdminAccount>> {
+        context
+            .account_service_client
+            .load_admin_account_by_ids(LoadAdminAccountByIdsRequest {
+                actor_auth: context.actor_auth().await?,
+                account_ids: vec![id],
+            })
+            .await?
+            .admins
+            .pop()
+            .map(Arc::new)
+            .ok_or_else(|| Error::not_found("Admin not found"))
+    }
+
+    async fn get(&self, ctx: &Context<'_>) -> Result<&Arc<acc_db::AdminAccount>> {
+        let context = ctx.data::<RequestContext>()?;
+        self.get_or_load(|id| Self::load(*id, context)).await
+    }
+}
+
+#[Object]
+impl Admin {
+    pub async fn id(&self) -> Result<ID> {
+        self.as_global_id()
+    }
+
+    async fn phone_number(&self, ctx: &Context<'_>) -> Result<&str> {
+        Ok(&self.get(ctx).await?.phone_number)
+    }
+}
@@ -3,7 +3,7 @@ use account_service_db as acc_db;
 use account_service_server::LoadCustomerProfileByIdsRequest;
 use async_graphql::{Context, ID, Object};
 use core_service_graphql_context::RequestContext;
-use entity_type::CustomerId;
+use entity_type::{AccountStatus, CustomerId};
 use error::{Error, Result};
 use std::sync::Arc;
 
@@ -38,13 +38,16 @@ impl Customer {
         Ok(&self.get(ctx).await?.phone_number)
     }
 
+    async fn status(&self, ctx: &Context<'_>) -> Result<AccountStatus> {
+        Ok(self.get(ctx).await?.status)
+    }
+
     async fn profile(&self, ctx: &Context<'_>) -> Result<Option<CustomerProfile>> {
         let context = ctx.data::<RequestContext>()?;
-        let session_ctx = context.try_session_context().await?;
         let profile = context
             .account_service_client
             .load_customer_profile_by_ids(LoadCustomerProfileByIdsRequest {
-                actor_auth: session_ctx.as_actor_auth(),
+                actor_auth: context.actor_auth().await?,
                 account_ids: vec![self.inner_id()],
             })
             .await?
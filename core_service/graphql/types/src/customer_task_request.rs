@@ -0,0 +1,161 @@
+use crate::{
+    CachedNode, Customer, GeoPoint, GlobalId, Handyman, Location, Schedule, Service, TaskPhoto,
+    TaskQuote,
+};
+use async_graphql::{Context, ID, Object};
+use chrono::{DateTime, Utc};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use entity_type::{CustomerTaskRequestId, CustomerTaskStatus};
+use error::{Error, Result};
+use std::sync::Arc;
+
+pub type CustomerTaskRequest = CachedNode<CustomerTaskRequestId, Arc<db::CustomerTaskRequest>>;
+
+impl CustomerTaskRequest {
+    async fn load(
+        id: CustomerTaskRequestId,
+        context: &RequestContext,
+    ) -> Result<Arc<db::CustomerTaskRequest>> {
+        context
+            .task_loaders
+            .customer_task_request_by_id_loader
+            .load_one(id)
+            .await?
+            .ok_or_else(|| Error::not_found("Customer task request not found"))
+    }
+
+    async fn get(&self, ctx: &Context<'_>) -> Result<&Arc<db::CustomerTaskRequest>> {
+        let context = ctx.data::<RequestContext>()?;
+        self.get_or_load(|id| Self::load(*id, context)).await
+    }
+}
+
+#[Object]
+impl CustomerTaskRequest {
+    pub async fn id(&self) -> Result<ID> {
+        self.as_global_id()
+    }
+
+    async fn service(&self, ctx: &Context<'_>) -> Result<Service> {
+        Ok(Service::new(self.get(ctx).await?.service.clone()))
+    }
+
+    /// Plain text title
+    async fn title(&self, ctx: &Context<'_>) -> Result<&str> {
+        Ok(&self.get(ctx).await?.title)
+    }
+
+    /// Markdown note
+    async fn note(&self, ctx: &Context<'_>) -> Result<Option<&str>> {
+        Ok(self.get(ctx).await?.note.as_deref())
+    }
+
+    async fn schedule(&self, ctx: &Context<'_>) -> Result<Schedule> {
+        let context = ctx.data::<RequestContext>()?;
+        let schedule_id = self.get(ctx).await?.schedule;
+        context
+            .task_loaders
+            .schedule_by_id_loader
+            .load_one(schedule_id)
+            .await?
+            .map(Schedule)
+            .ok_or_else(|| Error::not_found("Schedule not found"))
+    }
+
+    /// Null for tasks created before location was persisted.
+    async fn location(&self, ctx: &Context<'_>) -> Result<Option<Location>> {
+        let task = self.get(ctx).await?;
+        let Some((lon, lat)) = task.coordinates() else {
+            return Ok(None);
+        };
+
+        Ok(Some(Location {
+            city: task.location_city.clone().unwrap_or_default(),
+            address_line1: task.location_address_line1.clone().unwrap_or_default(),
+            formatted_address: task.location_formatted_address.clone().unwrap_or_default(),
+            coordinates: GeoPoint { lon, lat },
+        }))
+    }
+
+    /// Customer who posted the task
+    async fn customer(&self, ctx: &Context<'_>) -> Result<Customer> {
+        Ok(Customer::new(self.get(ctx).await?.customer_id))
+    }
+
+    async fn status(&self, ctx: &Context<'_>) -> Result<CustomerTaskStatus> {
+        Ok(self.get(ctx).await?.status)
+    }
+
+    /// Handyman assigned to the task, if any
+    async fn assigned_handyman(&self, ctx: &Context<'_>) -> Result<Option<Handyman>> {
+        Ok(self.get(ctx).await?.assigned_handyman_id.map(Handyman::new))
+    }
+
+    async fn opened_at(&self, ctx: &Context<'_>) -> Result<Option<DateTime<Utc>>> {
+        Ok(self.get(ctx).await?.opened_at.map(|t| t.and_utc()))
+    }
+
+    async fn assigned_at(&self, ctx: &Context<'_>) -> Result<Option<DateTime<Utc>>> {
+        Ok(self.get(ctx).await?.assigned_at.map(|t| t.and_utc()))
+    }
+
+    async fn started_at(&self, ctx: &Context<'_>) -> Result<Option<DateTime<Utc>>> {
+        Ok(self.get(ctx).await?.started_at.map(|t| t.and_utc()))
+    }
+
+    async fn completed_at(&self, ctx: &Context<'_>) -> Result<Option<DateTime<Utc>>> {
+        Ok(self.get(ctx).await?.completed_at.map(|t| t.and_utc()))
+    }
+
+    async fn cancelled_at(&self, ctx: &Context<'_>) -> Result<Option<DateTime<Utc>>> {
+        Ok(self.get(ctx).await?.cancelled_at.map(|t| t.and_utc()))
+    }
+
+    async fn expired_at(&self, ctx: &Context<'_>) -> Result<Option<DateTime<Utc>>> {
+        Ok(self.get(ctx).await?.expired_at.map(|t| t.and_utc()))
+    }
+
+    /// Quotes of the task. The customer sees all quotes, a handyman only sees their own quote.
+    async fn quotes(&self, ctx: &Context<'_>) -> Result<Vec<TaskQuote>> {
+        // The loader checks the actor can read the task
+        self.get(ctx).await?;
+        let context = ctx.data::<RequestContext>()?;
+
+        let quotes = context
+            .task_loaders
+            .quotes_by_task_id_loader
+            .load_one(self.inner_id())
+            .await?
+            .unwrap_or_default();
+
+        Ok(quotes
+            .into_iter()
+            .map(|q| TaskQuote::new_with(q.id, q))
+            .collect())
+    }
+
+    /// Photos the customer attached to the task, oldest first
+    async fn photos(&self, ctx: &Context<'_>) -> Result<Vec<TaskPhoto>> {
+        // The loader checks the actor can read the task
+        self.get(ctx).await?;
+        let context = ctx.data::<RequestContext>()?;
+
+        let photos = context
+            .task_loaders
+            .photos_by_task_id_loader
+            .load_one(self.inner_id())
+            .await?
+            .unwrap_or_default();
+
+        Ok(photos.into_iter().map(TaskPhoto::new).collect())
+    }
+
+    async fn created_at(&self, ctx: &Context<'_>) -> Result<DateTime<Utc>> {
+        Ok(self.get(ctx).await?.created_at.and_utc())
+    }
+
+    async fn updated_at(&self, ctx: &Context<'_>) -> Result<DateTime<Utc>> {
+        Ok(self.get(ctx).await?.updated_at.and_utc())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::CustomerTaskRequest;
 use async_graphql::{InputObject, InputType, OutputType, SimpleObject};
 use paging::PagingOffsetInfo;
 
@@ -16,6 +17,10 @@ pub struct SetValue<T: InputType> {
 }
 
 #[derive(Debug, SimpleObject)]
+#[graphql(concrete(
+    name = "PagingOffsetPayloadCustomerTaskRequest",
+    params(CustomerTaskRequest)
+))]
 pub struct PagingOffsetPayload<T: OutputType> {
     pub paging_info: PagingOffsetInfo,
     pub items: Vec<T>,
@@ -1,10 +1,11 @@
 use crate::{CachedNode, GlobalId, HandymanProfile};
 use account_service_db as acc_db;
 use account_service_server::LoadHandymanProfileByIdsRequest;
-use async_graphql::{Context, ID, Object};
+use async_graphql::{Context, ID, Object, SimpleObject};
 use core_service_graphql_context::RequestContext;
-use entity_type::HandymanId;
+use entity_type::{AccountStatus, HandymanId};
 use error::{Error, Result};
+use paging::PagingOffsetInfo;
 use std::sync::Arc;
 
 pub type Handyman = CachedNode<HandymanId, Arc<acc_db::HandymanAccount>>;
@@ -38,13 +39,16 @@ impl Handyman {
         Ok(&self.get(ctx).await?.phone_number)
     }
 
+    async fn status(&self, ctx: &Context<'_>) -> Result<AccountStatus> {
+        Ok(self.get(ctx).await?.status)
+    }
+
     async fn profile(&self, ctx: &Context<'_>) -> Result<Option<HandymanProfile>> {
         let context = ctx.data::<RequestContext>()?;
-        let session_ctx = context.try_session_context().await?;
         let profile = context
             .account_service_client
             .load_handyman_profile_by_ids(LoadHandymanProfileByIdsRequest {
-                actor_auth: session_ctx.as_actor_auth(),
+                actor_auth: context.actor_auth().await?,
                 account_ids: vec![self.inner_id()],
             })
             .await?
@@ -68,3 +72,15 @@ impl Handyman {
         )))
     }
 }
+
+/// Page of handymen found by a search. Keeps the name and the items of the payload predating
+/// geo search, the distances are listed alongside the items.
+#[derive(Debug, SimpleObject)]
+#[graphql(name = "PagingOffsetPayload")]
+pub struct HandymanSearchPayload {
+    pub paging_info: PagingOffsetInfo,
+    pub items: Vec<Handyman>,
+    /// Distance in meters from the searched location to each item, in the order of the items.
+    /// Null if the search is not filtered by location.
+    pub distance_meters: Option<Vec<f64>>,
+}
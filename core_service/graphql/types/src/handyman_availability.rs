@@ -0,0 +1,25 @@
+use crate::Schedule;
+use async_graphql::SimpleObject;
+use chrono::NaiveDate;
+use core_service_db as db;
+
+/// Weekly working hours of a handyman
+#[derive(Debug, SimpleObject)]
+pub struct HandymanWorkingHours {
+    /// Daily or weekly recurrence, each time starts a working slot
+    pub schedule: Schedule,
+    pub slot_minutes: i32,
+}
+
+/// Date the handyman does not work, whatever the working hours
+#[derive(Debug, SimpleObject)]
+pub struct HandymanBlackout {
+    pub date: NaiveDate,
+    pub note: Option<String>,
+}
+
+impl From<db::HandymanBlackout> for HandymanBlackout {
+    fn from(db::HandymanBlackout { date, note, .. }: db::HandymanBlackout) -> Self {
+        Self { date, note }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{CachedNode, GlobalId, HandymanServiceGroup};
+use crate::{
+    CachedNode, GlobalId, HandymanBlackout, HandymanReview, HandymanServiceGroup,
+    HandymanWorkingHours, Location, Schedule,
+};
 use account_service_db as acc_db;
 use async_graphql::{Context, ID, Object};
 use core_service_db as db;
@@ -28,6 +31,18 @@ impl HandymanProfile {
         let context = ctx.data::<RequestContext>()?;
         self.get_or_load(|id| Self::load(*id, context)).await
     }
+
+    async fn rating_summary(&self, ctx: &Context<'_>) -> Result<db::HandymanRatingSummary> {
+        let context = ctx.data::<RequestContext>()?;
+        let handyman_id = self.get(ctx).await?.handyman_id;
+
+        Ok(context
+            .handyman_loaders
+            .rating_summary_by_id_loader
+            .load_one(handyman_id)
+            .await?
+            .unwrap_or_default())
+    }
 }
 
 #[Object]
@@ -44,10 +59,29 @@ impl HandymanProfile {
         Ok(&self.get(ctx).await?.last_name)
     }
 
+    /// Introduction written by the handyman
+    async fn bio(&self, ctx: &Context<'_>) -> Result<Option<&str>> {
+        Ok(self.get(ctx).await?.bio.as_deref())
+    }
+
+    async fn years_of_experience(&self, ctx: &Context<'_>) -> Result<Option<i32>> {
+        Ok(self.get(ctx).await?.years_of_experience)
+    }
+
+    /// Public url of the avatar image, null if the handyman has no avatar
+    async fn avatar_url(&self, ctx: &Context<'_>) -> Result<Option<String>> {
+        let context = ctx.data::<RequestContext>()?;
+        self.get(ctx)
+            .await?
+            .avatar_key
+            .as_deref()
+            .map(|key| context.blob_url(key))
+            .transpose()
+    }
+
     async fn services(&self, ctx: &Context<'_>) -> Result<Vec<HandymanServiceGroup>> {
         let context = ctx.data::<RequestContext>()?;
-        let session_ctx = context.try_session_context().await?;
-        let actor_auth = session_ctx.as_actor_auth();
+        let actor_auth = context.actor_auth().await?;
         let handyman_id = self.get(ctx).await?.handyman_id;
 
         let group = with_readonly_db(&context.db_connection_pool, |conn| {
@@ -58,4 +92,86 @@ impl HandymanProfile {
 
         Ok(group.into_iter().map(HandymanServiceGroup::from).collect())
     }
+
+    /// Location where the handyman offers their services
+    async fn service_location(&self, ctx: &Context<'_>) -> Result<Option<Location>> {
+        let context = ctx.data::<RequestContext>()?;
+        let actor_auth = context.actor_auth().await?;
+        let handyman_id = self.get(ctx).await?.handyman_id;
+
+        let location = with_readonly_db(&context.db_connection_pool, |conn| {
+            db::HandymanServiceLocation::get_by_handyman(&actor_auth, handyman_id, conn)
+                .scope_boxed()
+        })
+        .await?;
+
+        Ok(location.map(Location::from))
+    }
+
+    /// Null until the handyman sets their working hours
+    async fn working_hours(&self, ctx: &Context<'_>) -> Result<Option<HandymanWorkingHours>> {
+        let context = ctx.data::<RequestContext>()?;
+        let actor_auth = context.actor_auth().await?;
+        let handyman_id = self.get(ctx).await?.handyman_id;
+
+        let working_hours = with_readonly_db(&context.db_connection_pool, |conn| {
+            db::HandymanWorkingHours::get_by_handyman(&actor_auth, handyman_id, conn).scope_boxed()
+        })
+        .await?;
+        let Some(working_hours) = working_hours else {
+            return Ok(None);
+        };
+
+        let schedule = context
+            .task_loaders
+            .schedule_by_id_loader
+            .load_one(working_hours.schedule)
+            .await?
+            .map(Schedule)
+            .ok_or_else(|| Error::not_found("Schedule not found"))?;
+        Ok(Some(HandymanWorkingHours {
+            schedule,
+            slot_minutes: working_hours.slot_minutes,
+        }))
+    }
+
+    /// Days off, in date order
+    async fn blackouts(&self, ctx: &Context<'_>) -> Result<Vec<HandymanBlackout>> {
+        let context = ctx.data::<RequestContext>()?;
+        let actor_auth = context.actor_auth().await?;
+        let handyman_id = self.get(ctx).await?.handyman_id;
+
+        let blackouts = with_readonly_db(&context.db_connection_pool, |conn| {
+            db::HandymanBlackout::get_by_handyman(&actor_auth, handyman_id, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(blackouts.into_iter().map(HandymanBlackout::from).collect())
+    }
+
+    /// Reviews from customers, newest first
+    async fn reviews(&self, ctx: &Context<'_>) -> Result<Vec<HandymanReview>> {
+        let context = ctx.data::<RequestContext>()?;
+        let actor_auth = context.actor_auth().await?;
+        let handyman_id = self.get(ctx).await?.handyman_id;
+
+        let reviews = with_readonly_db(&context.db_connection_pool, |conn| {
+            db::HandymanReview::get_by_handyman(&actor_auth, handyman_id, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(reviews
+            .into_iter()
+            .map(|review| HandymanReview::new_with(review.id, Arc::new(review)))
+            .collect())
+    }
+
+    /// Average rating in stars, null if the handyman has no review yet
+    async fn avg_rating(&self, ctx: &Context<'_>) -> Result<Option<f64>> {
+        Ok(self.rating_summary(ctx).await?.avg_rating())
+    }
+
+    async fn review_count(&self, ctx: &Context<'_>) -> Result<i64> {
+        Ok(self.rating_summary(ctx).await?.review_count)
+    }
 }
@@ -0,0 +1,62 @@
+use crate::{CachedNode, Customer, CustomerTaskRequest, GlobalId, Handyman};
+use async_graphql::{Context, ID, Object};
+use chrono::{DateTime, Utc};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use entity_type::HandymanReviewId;
+use error::{Error, Result};
+use std::sync::Arc;
+
+pub type HandymanReview = CachedNode<HandymanReviewId, Arc<db::HandymanReview>>;
+
+impl HandymanReview {
+    async fn load(
+        id: HandymanReviewId,
+        context: &RequestContext,
+    ) -> Result<Arc<db::HandymanReview>> {
+        context
+            .handyman_loaders
+            .review_by_id_loader
+            .load_one(id)
+            .await?
+            .ok_or_else(|| Error::not_found("Handyman review not found"))
+    }
+
+    async fn get(&self, ctx: &Context<'_>) -> Result<&Arc<db::HandymanReview>> {
+        let context = ctx.data::<RequestContext>()?;
+        self.get_or_load(|id| Self::load(*id, context)).await
+    }
+}
+
+#[Object]
+impl HandymanReview {
+    pub async fn id(&self) -> Result<ID> {
+        self.as_global_id()
+    }
+
+    /// The completed task this review is about
+    async fn task(&self, ctx: &Context<'_>) -> Result<CustomerTaskRequest> {
+        Ok(CustomerTaskRequest::new(self.get(ctx).await?.task_id))
+    }
+
+    async fn customer(&self, ctx: &Context<'_>) -> Result<Customer> {
+        Ok(Customer::new(self.get(ctx).await?.customer_id))
+    }
+
+    async fn handyman(&self, ctx: &Context<'_>) -> Result<Handyman> {
+        Ok(Handyman::new(self.get(ctx).await?.handyman_id))
+    }
+
+    /// Rating in stars, from 1 to 5
+    async fn rating(&self, ctx: &Context<'_>) -> Result<i16> {
+        Ok(self.get(ctx).await?.rating)
+    }
+
+    async fn comment(&self, ctx: &Context<'_>) -> Result<Option<&str>> {
+        Ok(self.get(ctx).await?.comment.as_deref())
+    }
+
+    async fn created_at(&self, ctx: &Context<'_>) -> Result<DateTime<Utc>> {
+        Ok(self.get(ctx).await?.created_at.and_utc())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{GlobalId, Service};
+use crate::{GlobalId, Service, ServiceGroup};
 use async_graphql::{ID, Object, SimpleObject};
 use core_service_db as db;
 use entity_type::{HandymanServiceId, ServiceLayer1};
@@ -35,7 +35,7 @@ impl HandymanService {
     }
 
     async fn service(&self) -> Result<Service> {
-        Ok(Service(self.get()?.service))
+        Ok(Service::new(self.get()?.service.clone()))
     }
 
     async fn note(&self) -> Result<Option<&str>> {
@@ -49,14 +49,23 @@ impl HandymanService {
 
 #[derive(SimpleObject)]
 pub struct HandymanServiceGroup {
+    service_group: ServiceGroup,
+    /// `OTHER` for groups added to the catalog after `ServiceLayer1` was frozen
+    #[graphql(deprecation = "Use serviceGroup")]
     group: ServiceLayer1,
     services: Vec<HandymanService>,
 }
 
 impl From<db::HandymanServiceGroup> for HandymanServiceGroup {
-    fn from(db::HandymanServiceGroup { group, services }: db::HandymanServiceGroup) -> Self {
+    fn from(
+        db::HandymanServiceGroup {
+            group_code,
+            services,
+        }: db::HandymanServiceGroup,
+    ) -> Self {
         HandymanServiceGroup {
-            group,
+            group: ServiceLayer1::from_code(&group_code).unwrap_or(ServiceLayer1::Other),
+            service_group: ServiceGroup::new(group_code),
             services: services
                 .into_iter()
                 .map(|e| HandymanService::new(Arc::new(e)))
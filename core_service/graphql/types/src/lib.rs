@@ -19,6 +19,9 @@ pub use customer_profile::*;
 mod handyman;
 pub use handyman::*;
 
+mod admin;
+pub use admin::*;
+
 mod handyman_profile;
 pub use handyman_profile::*;
 
@@ -27,3 +30,21 @@ pub use handyman_service::*;
 
 mod schedule;
 pub use schedule::*;
+
+mod handyman_availability;
+pub use handyman_availability::*;
+
+mod customer_task_request;
+pub use customer_task_request::*;
+
+mod task_quote;
+pub use task_quote::*;
+
+mod task_photo;
+pub use task_photo::*;
+
+mod handyman_review;
+pub use handyman_review::*;
+
+mod user_session;
+pub use user_session::*;
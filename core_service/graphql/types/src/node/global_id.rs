@@ -95,6 +95,30 @@ impl GlobalId for HandymanService {
     const KEY: NodeKey = NodeKey::HandymanService;
 }
 
+impl GlobalId for CustomerTaskRequest {
+    const KEY: NodeKey = NodeKey::CustomerTaskRequest;
+}
+
+impl GlobalId for TaskQuote {
+    const KEY: NodeKey = NodeKey::TaskQuote;
+}
+
+impl GlobalId for HandymanReview {
+    const KEY: NodeKey = NodeKey::HandymanReview;
+}
+
+impl GlobalId for UserSession {
+    const KEY: NodeKey = NodeKey::UserSession;
+}
+
+impl GlobalId for Admin {
+    const KEY: NodeKey = NodeKey::Admin;
+}
+
+impl GlobalId for TaskPhoto {
+    const KEY: NodeKey = NodeKey::TaskPhoto;
+}
+
 pub fn parse_any_global_id(id: &ID) -> Result<Option<Node>> {
     let any_global_id = AnyGlobalId::from_global_id(id)?;
     let node = match any_global_id.key {
@@ -111,6 +135,9 @@ pub fn parse_any_global_id(id: &ID) -> Result<Option<Node>> {
         NodeKey::HandymanProfile => Node::HandymanProfile(
             HandymanProfile::from_any_global_id_inner(&any_global_id.inner)?,
         ),
+        NodeKey::CustomerTaskRequest => Node::CustomerTaskRequest(
+            CustomerTaskRequest::from_any_global_id_inner(&any_global_id.inner)?,
+        ),
         #[allow(unreachable_patterns)]
         _ => return Ok(None),
     };
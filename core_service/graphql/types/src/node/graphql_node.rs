@@ -13,6 +13,12 @@ pub enum NodeKey {
     CustomerProfile,
     HandymanProfile,
     HandymanService,
+    CustomerTaskRequest,
+    TaskQuote,
+    HandymanReview,
+    UserSession,
+    Admin,
+    TaskPhoto,
 }
 
 /// Identifies a global object uniquely.
@@ -26,4 +32,10 @@ pub enum Node {
     CustomerProfile(CustomerProfile),
     HandymanProfile(HandymanProfile),
     HandymanService(HandymanService),
+    CustomerTaskRequest(CustomerTaskRequest),
+    TaskQuote(TaskQuote),
+    HandymanReview(HandymanReview),
+    UserSession(UserSession),
+    Admin(Admin),
+    TaskPhoto(TaskPhoto),
 }
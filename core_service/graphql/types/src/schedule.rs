@@ -1,6 +1,13 @@
-use async_graphql::InputObject;
-use chrono::{NaiveDateTime, NaiveTime};
-use entity_type::Weekday;
+use async_graphql::{InputObject, Object, SimpleObject};
+use chrono::{DateTime, FixedOffset, NaiveTime, Utc};
+use chrono_tz::Tz;
+use core_service_db as db;
+use entity_type::{ScheduleType, Weekday};
+use error::{
+    Error, Result,
+    error_details::{BadRequest, bad_request::FieldViolation},
+};
+use std::sync::Arc;
 
 #[derive(Debug, InputObject)]
 /// Include location and time for a schedule
@@ -19,8 +26,89 @@ pub struct LocationInput {
 
 #[derive(Debug, InputObject)]
 pub struct GeoCoordinates {
-    pub lon: i64,
-    pub lat: i64,
+    pub lon: f64,
+    pub lat: f64,
+}
+
+impl TryFrom<LocationInput> for db::NewLocation {
+    type Error = Error;
+
+    fn try_from(
+        LocationInput {
+            city,
+            address_line1,
+            formatted_address,
+            corrdinates: GeoCoordinates { lon, lat },
+        }: LocationInput,
+    ) -> Result<Self> {
+        if !(-180.0..=180.0).contains(&lon) {
+            return Err(invalid_location_error(
+                "location.corrdinates.lon",
+                "INVALID_LON",
+            ));
+        }
+        if !(-90.0..=90.0).contains(&lat) {
+            return Err(invalid_location_error(
+                "location.corrdinates.lat",
+                "INVALID_LAT",
+            ));
+        }
+
+        Ok(Self {
+            city,
+            address_line1,
+            formatted_address,
+            lon,
+            lat,
+        })
+    }
+}
+
+fn invalid_location_error(field: &str, description: &str) -> Error {
+    Error::invalid_argument_with(
+        format!("Invalid location: {field} {description}"),
+        Some(BadRequest {
+            field_violations: vec![FieldViolation {
+                field: field.into(),
+                description: description.into(),
+            }],
+        }),
+    )
+}
+
+/// Output type of a location
+#[derive(Debug, SimpleObject)]
+pub struct Location {
+    pub city: String,
+    pub address_line1: String,
+    pub formatted_address: String,
+    pub coordinates: GeoPoint,
+}
+
+impl From<db::HandymanServiceLocation> for Location {
+    fn from(
+        db::HandymanServiceLocation {
+            city,
+            address_line1,
+            formatted_address,
+            lon,
+            lat,
+            ..
+        }: db::HandymanServiceLocation,
+    ) -> Self {
+        Self {
+            city,
+            address_line1,
+            formatted_address,
+            coordinates: GeoPoint { lon, lat },
+        }
+    }
+}
+
+#[derive(Debug, SimpleObject)]
+pub struct GeoPoint {
+    pub lon: f64,
+    pub lat: f64,
 }
 
 #[derive(Debug, InputObject)]
@@ -33,11 +121,14 @@ pub struct ScheduleTimeInput {
     pub daily_recurrence: Option<DailyRecurrence>,
     /// A rule that repeats based on the day of the week, often with a start/end date.
     pub weekly_recurrence: Option<WeeklyRecurrence>,
+    /// IANA time zone the recurrence times are wall clock times of, Asia/Ho_Chi_Minh by default.
+    pub timezone: Option<Tz>,
 }
 
 #[derive(Debug, InputObject)]
 pub struct FixedTime {
-    pub time: NaiveDateTime,
+    /// RFC 3339 date and time with offset
+    pub time: DateTime<FixedOffset>,
 }
 
 #[derive(Debug, InputObject)]
@@ -55,3 +146,174 @@ pub struct WeekdayTime {
     pub day: Weekday,
     pub times: Vec<NaiveTime>,
 }
+
+impl TryFrom<ScheduleTimeInput> for db::NewSchedule {
+    type Error = Error;
+
+    fn try_from(
+        ScheduleTimeInput {
+            fixed_time,
+            daily_recurrence,
+            weekly_recurrence,
+            timezone,
+        }: ScheduleTimeInput,
+    ) -> Result<Self> {
+        let variant = match (fixed_time, daily_recurrence, weekly_recurrence) {
+            (Some(FixedTime { time }), None, None) => {
+                db::NewScheduleVariant::FixedTime(db::NewFixedTimeSchedule {
+                    time: time.to_utc(),
+                })
+            }
+            (None, Some(DailyRecurrence { times }), None) => {
+                require_not_empty_times(&times, "daily_recurrence.times")?;
+                db::NewScheduleVariant::DailyRecurrence(db::NewDailyRecurrenceSchedule { times })
+            }
+            (None, None, Some(WeeklyRecurrence { times })) => {
+                if times.is_empty() {
+                    return Err(invalid_schedule_time_error(
+                        "weekly_recurrence.times",
+                        "EMPTY",
+                    ));
+                }
+                let weekday_times = times
+                    .into_iter()
+                    .map(|WeekdayTime { day, times }| {
+                        require_not_empty_times(&times, "weekly_recurrence.times.times")?;
+                        Ok(db::NewWeekdayTime {
+                            weekday: day,
+                            times,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                db::NewScheduleVariant::WeeklyRecurrence(db::NewWeeklyRecurrenceSchedule {
+                    weekday_times,
+                })
+            }
+            _ => {
+                return Err(invalid_schedule_time_error(
+                    "schedule.time",
+                    "REQUIRE_EXACTLY_ONE",
+                ));
+            }
+        };
+
+        Ok(db::NewSchedule {
+            timezone: timezone.unwrap_or(db::DEFAULT_SCHEDULE_TIMEZONE),
+            variant,
+        })
+    }
+}
+
+fn require_not_empty_times(times: &[NaiveTime], field: &str) -> Result<()> {
+    if times.is_empty() {
+        return Err(invalid_schedule_time_error(field, "EMPTY"));
+    }
+    Ok(())
+}
+
+fn invalid_schedule_time_error(field: &str, description: &str) -> Error {
+    Error::invalid_argument_with(
+        format!("Invalid schedule time: {field} {description}"),
+        Some(BadRequest {
+            field_violations: vec![FieldViolation {
+                field: field.into(),
+                description: description.into(),
+            }],
+        }),
+    )
+}
+
+/// Upper bound of `limit` of `Schedule.nextOccurrences`
+pub const MAX_NEXT_OCCURRENCES: i32 = 100;
+
+/// Output type of a schedule, the time rule is exposed via exactly one
+/// non-null field matching `scheduleType`.
+#[derive(Debug, Clone)]
+pub struct Schedule(pub Arc<db::Schedule>);
+
+#[Object]
+impl Schedule {
+    async fn schedule_type(&self) -> ScheduleType {
+        self.0.base.schedule_type
+    }
+
+    /// IANA time zone the recurrence times are wall clock times of
+    async fn timezone(&self) -> Tz {
+        self.0.timezone
+    }
+
+    /// The fixed time with the offset of the schedule time zone
+    async fn fixed_time(&self) -> Option<ScheduleFixedTime> {
+        match &self.0.variant {
+            db::ScheduleVariant::FixedTime(v) => Some(ScheduleFixedTime {
+                time: v.time.with_timezone(&self.0.timezone).fixed_offset(),
+            }),
+            _ => None,
+        }
+    }
+
+    async fn daily_recurrence(&self) -> Option<ScheduleDailyRecurrence> {
+        match &self.0.variant {
+            db::ScheduleVariant::DailyRecurrence(v) => Some(ScheduleDailyRecurrence {
+                times: v.times.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    async fn weekly_recurrence(&self) -> Option<ScheduleWeeklyRecurrence> {
+        match &self.0.variant {
+            db::ScheduleVariant::WeeklyRecurrence(v) => Some(ScheduleWeeklyRecurrence {
+                times: v
+                    .weekday_times
+                    .iter()
+                    .map(|w| ScheduleWeekdayTime {
+                        day: w.weekday,
+                        times: w.times.clone(),
+                    })
+                    .collect(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Upcoming occurrences from now in time order, at most `limit` of them.
+    /// Times have the offset of the schedule time zone, a fixed time in the past has none.
+    async fn next_occurrences(&self, limit: i32) -> Result<Vec<DateTime<FixedOffset>>> {
+        if !(1..=MAX_NEXT_OCCURRENCES).contains(&limit) {
+            return Err(Error::invalid_argument_with(
+                format!("Limit must be between 1 and {MAX_NEXT_OCCURRENCES}"),
+                Some(BadRequest {
+                    field_violations: vec![FieldViolation {
+                        field: "limit".into(),
+                        description: "OUT_OF_RANGE".into(),
+                    }],
+                }),
+            ));
+        }
+
+        let occurrences = self.0.next_occurrences(Utc::now(), limit as usize);
+        Ok(occurrences.iter().map(DateTime::fixed_offset).collect())
+    }
+}
+
+#[derive(Debug, SimpleObject)]
+pub struct ScheduleFixedTime {
+    pub time: DateTime<FixedOffset>,
+}
+
+#[derive(Debug, SimpleObject)]
+pub struct ScheduleDailyRecurrence {
+    pub times: Vec<NaiveTime>,
+}
+
+#[derive(Debug, SimpleObject)]
+pub struct ScheduleWeeklyRecurrence {
+    pub times: Vec<ScheduleWeekdayTime>,
+}
+
+#[derive(Debug, SimpleObject)]
+pub struct ScheduleWeekdayTime {
+    pub day: Weekday,
+    pub times: Vec<NaiveTime>,
+}
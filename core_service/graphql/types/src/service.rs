@@ -1,38 +1,192 @@
-use async_graphql::Object;
+use crate::CachedNode;
+use async_graphql::{Context, Object, SimpleObject};
+use chrono::{DateTime, Utc};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use db_utils::with_readonly_db;
 use entity_type::{ServiceLayer1, ServiceLayer2};
+use error::{Error, Result};
+use scoped_futures::ScopedFutureExt;
+use std::sync::Arc;
 
-pub struct ServiceGroup(pub ServiceLayer1);
+/// Group of the service catalog, keyed by its code.
+pub type ServiceGroup = CachedNode<String, Arc<db::CatalogServiceGroup>>;
+
+impl ServiceGroup {
+    async fn load(code: String, context: &RequestContext) -> Result<Arc<db::CatalogServiceGroup>> {
+        context
+            .service_loaders
+            .service_group_by_code_loader
+            .load_one(code)
+            .await?
+            .ok_or_else(|| Error::not_found("Service group not found"))
+    }
+
+    async fn get(&self, ctx: &Context<'_>) -> Result<&Arc<db::CatalogServiceGroup>> {
+        let context = ctx.data::<RequestContext>()?;
+        self.get_or_load(|code| Self::load(code.clone(), context))
+            .await
+    }
+}
 
 #[Object]
 impl ServiceGroup {
-    async fn group_type(&self) -> ServiceLayer1 {
-        self.0
+    async fn code(&self) -> &str {
+        &self.k
     }
 
-    async fn children(&self) -> Vec<Service> {
-        self.0.layer2().iter().map(|s| Service(*s)).collect()
+    /// Null for groups added to the catalog after `ServiceLayer1` was frozen
+    async fn group_type(&self) -> Option<ServiceLayer1> {
+        ServiceLayer1::from_code(&self.k)
+    }
+
+    async fn name_vi(&self, ctx: &Context<'_>) -> Result<&str> {
+        Ok(&self.get(ctx).await?.name_vi)
+    }
+
+    async fn name_en(&self, ctx: &Context<'_>) -> Result<&str> {
+        Ok(&self.get(ctx).await?.name_en)
+    }
+
+    async fn icon(&self, ctx: &Context<'_>) -> Result<Option<&str>> {
+        Ok(self.get(ctx).await?.icon.as_deref())
+    }
+
+    /// Active services of the group, in display order
+    async fn children(&self, ctx: &Context<'_>) -> Result<Vec<Service>> {
+        let context = ctx.data::<RequestContext>()?;
+        let group_codes = [self.k.clone()];
+        let services = with_readonly_db(&context.db_connection_pool, |conn| {
+            db::CatalogService::list_active_by_group_codes(&group_codes, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(services
+            .into_iter()
+            .map(|s| Service::new_with(s.code.clone(), Arc::new(s)))
+            .collect())
     }
 }
 
-pub struct Service(pub ServiceLayer2);
+/// Service of the catalog, keyed by its code.
+pub type Service = CachedNode<String, Arc<db::CatalogService>>;
+
+impl Service {
+    async fn load(code: String, context: &RequestContext) -> Result<Arc<db::CatalogService>> {
+        context
+            .service_loaders
+            .service_by_code_loader
+            .load_one(code)
+            .await?
+            .ok_or_else(|| Error::not_found("Service not found"))
+    }
+
+    async fn get(&self, ctx: &Context<'_>) -> Result<&Arc<db::CatalogService>> {
+        let context = ctx.data::<RequestContext>()?;
+        self.get_or_load(|code| Self::load(code.clone(), context))
+            .await
+    }
+
+    async fn rate_stat(
+        &self,
+        ctx: &Context<'_>,
+        city: Option<&str>,
+    ) -> Result<Option<ServiceRateDistribution>> {
+        let context = ctx.data::<RequestContext>()?;
+        let stats = context
+            .service_loaders
+            .rate_stats_by_service_code_loader
+            .load_one(self.k.clone())
+            .await?
+            .unwrap_or_default();
+        Ok(db::ServiceRateStat::pick(&stats, city).map(ServiceRateDistribution::from))
+    }
+}
 
 #[Object]
 impl Service {
-    async fn service_type(&self) -> ServiceLayer2 {
-        self.0
+    async fn code(&self) -> &str {
+        &self.k
+    }
+
+    /// Null for services added to the catalog after `ServiceLayer2` was frozen
+    async fn service_type(&self) -> Option<ServiceLayer2> {
+        ServiceLayer2::from_code(&self.k)
+    }
+
+    async fn name_vi(&self, ctx: &Context<'_>) -> Result<&str> {
+        Ok(&self.get(ctx).await?.name_vi)
+    }
+
+    async fn name_en(&self, ctx: &Context<'_>) -> Result<&str> {
+        Ok(&self.get(ctx).await?.name_en)
+    }
+
+    async fn icon(&self, ctx: &Context<'_>) -> Result<Option<&str>> {
+        Ok(self.get(ctx).await?.icon.as_deref())
     }
 
-    async fn service_group(&self) -> ServiceGroup {
-        ServiceGroup(self.0.layer1())
+    async fn service_group(&self, ctx: &Context<'_>) -> Result<ServiceGroup> {
+        Ok(ServiceGroup::new(self.get(ctx).await?.group_code.clone()))
     }
 
-    async fn recommended_rate_vnd(&self) -> Option<i32> {
-        match self.0 {
-            ServiceLayer2::AirConditionerFixing => None,
-            ServiceLayer2::AirConditionerCleaning => Some(250_000),
-            ServiceLayer2::WashingMachineFixing => None,
-            ServiceLayer2::WashingMachineCleaning => Some(450_000),
-            ServiceLayer2::Other => todo!(),
+    /// Median price in the city, or over all cities when the city has too few prices.
+    /// Falls back to the catalog reference rate while the service has too few prices.
+    async fn recommended_rate_vnd(
+        &self,
+        ctx: &Context<'_>,
+        city: Option<String>,
+    ) -> Result<Option<i32>> {
+        if let Some(stat) = self.rate_stat(ctx, city.as_deref()).await? {
+            return Ok(Some(stat.median_vnd));
         }
+        Ok(self.get(ctx).await?.recommended_rate_vnd)
     }
+
+    /// Prices handymen ask and customers accepted in the city,
+    /// or over all cities when the city has too few prices.
+    async fn rate_distribution(
+        &self,
+        ctx: &Context<'_>,
+        city: Option<String>,
+    ) -> Result<Option<ServiceRateDistribution>> {
+        self.rate_stat(ctx, city.as_deref()).await
+    }
+}
+
+/// Price distribution of a service, refreshed periodically
+#[derive(SimpleObject)]
+pub struct ServiceRateDistribution {
+    /// Null for the distribution over all cities
+    city: Option<String>,
+    /// Number of prices the distribution is computed from
+    sample_count: i32,
+    p25_vnd: i32,
+    median_vnd: i32,
+    p75_vnd: i32,
+    refreshed_at: DateTime<Utc>,
+}
+
+impl From<&db::ServiceRateStat> for ServiceRateDistribution {
+    fn from(stat: &db::ServiceRateStat) -> Self {
+        Self {
+            city: stat.city.clone(),
+            sample_count: stat.sample_count,
+            p25_vnd: stat.p25_vnd,
+            median_vnd: stat.median_vnd,
+            p75_vnd: stat.p75_vnd,
+            refreshed_at: stat.refreshed_at.and_utc(),
+        }
+    }
+}
+
+/// Catalog code of a service input, taken from the deprecated `ServiceLayer2` field
+/// when a client predating the catalog doesn't send the code.
+pub fn service_code_input(
+    service_code: Option<&str>,
+    service: Option<ServiceLayer2>,
+) -> Result<&str> {
+    service_code
+        .or_else(|| service.map(|s| s.code()))
+        .ok_or_else(|| Error::invalid_argument("Expect serviceCode"))
 }
@@ -1,7 +1,8 @@
-use crate::{Customer, GlobalId, Handyman};
+use crate::{Admin, Customer, GlobalId, Handyman};
+use account_service_server::UserAccount;
 use actor_auth::{ActorKey, Session as ActorSession};
 use async_graphql::{ID, Object, Union};
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Utc};
 use error::{Error, Result};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -34,12 +35,12 @@ impl Session {
         self.as_global_id()
     }
 
-    async fn iat(&self) -> Result<NaiveDateTime> {
-        Ok(self.get()?.iat)
+    async fn iat(&self) -> Result<DateTime<Utc>> {
+        Ok(self.get()?.iat.and_utc())
     }
 
-    async fn exp(&self) -> Result<NaiveDateTime> {
-        Ok(self.get()?.exp)
+    async fn exp(&self) -> Result<DateTime<Utc>> {
+        Ok(self.get()?.exp.and_utc())
     }
 
     async fn actor_type(&self) -> Result<ActorType> {
@@ -50,12 +51,30 @@ impl Session {
             actor_auth::ActorType::Handyman(actor) => {
                 Ok(ActorType::Handyman(Handyman::new(actor.handyman_id)))
             }
+            actor_auth::ActorType::Admin(actor) => Ok(ActorType::Admin(Admin::new(actor.admin_id))),
         }
     }
 }
 
 #[derive(Union)]
-enum ActorType {
+pub enum ActorType {
     Customer(Customer),
     Handyman(Handyman),
+    Admin(Admin),
+}
+
+impl From<UserAccount> for ActorType {
+    fn from(account: UserAccount) -> Self {
+        match account {
+            UserAccount::Customer(account) => {
+                ActorType::Customer(Customer::new_with(account.id, Arc::new(account)))
+            }
+            UserAccount::Handyman(account) => {
+                ActorType::Handyman(Handyman::new_with(account.id, Arc::new(account)))
+            }
+            UserAccount::Admin(account) => {
+                ActorType::Admin(Admin::new_with(account.id, Arc::new(account)))
+            }
+        }
+    }
 }
@@ -0,0 +1,53 @@
+use crate::{CustomerTaskRequest, GlobalId};
+use async_graphql::{Context, ID, Object};
+use chrono::{DateTime, Utc};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use entity_type::CustomerTaskPhotoId;
+use error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Photo a customer attached to their task
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskPhoto {
+    pub id: CustomerTaskPhotoId,
+    #[serde(skip, default = "Option::default")]
+    inner: Option<Arc<db::CustomerTaskPhoto>>,
+}
+
+impl TaskPhoto {
+    pub fn new(inner: Arc<db::CustomerTaskPhoto>) -> Self {
+        Self {
+            id: inner.id,
+            inner: Some(inner),
+        }
+    }
+
+    fn get(&self) -> Result<&db::CustomerTaskPhoto> {
+        self.inner
+            .as_deref()
+            .ok_or_else(|| Error::internal("TaskPhoto is initiated with non value"))
+    }
+}
+
+#[Object]
+impl TaskPhoto {
+    pub async fn id(&self) -> Result<ID> {
+        self.as_global_id()
+    }
+
+    async fn task(&self) -> Result<CustomerTaskRequest> {
+        Ok(CustomerTaskRequest::new(self.get()?.task_id))
+    }
+
+    /// Url of the image, it expires after a while, query the photo again for a new url
+    async fn url(&self, ctx: &Context<'_>) -> Result<String> {
+        let context = ctx.data::<RequestContext>()?;
+        context.signed_blob_url(&self.get()?.blob_key)
+    }
+
+    async fn created_at(&self) -> Result<DateTime<Utc>> {
+        Ok(self.get()?.created_at.and_utc())
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{CachedNode, CustomerTaskRequest, GlobalId, Handyman};
+use async_graphql::{Context, ID, Object};
+use chrono::{DateTime, Utc};
+use core_service_db as db;
+use core_service_graphql_context::RequestContext;
+use entity_type::{TaskQuoteId, TaskQuoteStatus};
+use error::{Error, Result};
+use std::sync::Arc;
+
+pub type TaskQuote = CachedNode<TaskQuoteId, Arc<db::TaskQuote>>;
+
+impl TaskQuote {
+    async fn load(id: TaskQuoteId, context: &RequestContext) -> Result<Arc<db::TaskQuote>> {
+        context
+            .task_loaders
+            .task_quote_by_id_loader
+            .load_one(id)
+            .await?
+            .ok_or_else(|| Error::not_found("Task quote not found"))
+    }
+
+    async fn get(&self, ctx: &Context<'_>) -> Result<&Arc<db::TaskQuote>> {
+        let context = ctx.data::<RequestContext>()?;
+        self.get_or_load(|id| Self::load(*id, context)).await
+    }
+}
+
+#[Object]
+impl TaskQuote {
+    pub async fn id(&self) -> Result<ID> {
+        self.as_global_id()
+    }
+
+    async fn task(&self, ctx: &Context<'_>) -> Result<CustomerTaskRequest> {
+        Ok(CustomerTaskRequest::new(self.get(ctx).await?.task_id))
+    }
+
+    async fn handyman(&self, ctx: &Context<'_>) -> Result<Handyman> {
+        Ok(Handyman::new(self.get(ctx).await?.handyman_id))
+    }
+
+    /// Quoted price in VND
+    async fn price_vnd(&self, ctx: &Context<'_>) -> Result<i32> {
+        Ok(self.get(ctx).await?.price_vnd)
+    }
+
+    /// Estimated time the handyman can get the task done
+    async fn eta(&self, ctx: &Context<'_>) -> Result<DateTime<Utc>> {
+        Ok(self.get(ctx).await?.eta.and_utc())
+    }
+
+    async fn message(&self, ctx: &Context<'_>) -> Result<Option<&str>> {
+        Ok(self.get(ctx).await?.message.as_deref())
+    }
+
+    async fn status(&self, ctx: &Context<'_>) -> Result<TaskQuoteStatus> {
+        Ok(self.get(ctx).await?.status)
+    }
+
+    async fn created_at(&self, ctx: &Context<'_>) -> Result<DateTime<Utc>> {
+        Ok(self.get(ctx).await?.created_at.and_utc())
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{CachedNode, GlobalId};
+use account_service_db as acc_db;
+use async_graphql::{Context, ID, Object};
+use chrono::{DateTime, Utc};
+use core_service_graphql_context::RequestContext;
+use entity_type::UserSessionId;
+use error::{Error, Result};
+use std::sync::Arc;
+
+/// Server side session of a signed in device, unlike [`crate::Session`]
+/// which describes the session of the current request.
+pub type UserSession = CachedNode<UserSessionId, Arc<acc_db::UserSession>>;
+
+impl UserSession {
+    async fn load(id: UserSessionId, context: &RequestContext) -> Result<Arc<acc_db::UserSession>> {
+        context
+            .session_loaders
+            .user_session_by_id_loader
+            .load_one(id)
+            .await?
+            .ok_or_else(|| Error::not_found("Session not found"))
+    }
+
+    async fn get(&self, ctx: &Context<'_>) -> Result<&Arc<acc_db::UserSession>> {
+        let context = ctx.data::<RequestContext>()?;
+        self.get_or_load(|id| Self::load(*id, context)).await
+    }
+}
+
+#[Object]
+impl UserSession {
+    pub async fn id(&self) -> Result<ID> {
+        self.as_global_id()
+    }
+
+    /// When the device signed in
+    async fn created_at(&self, ctx: &Context<'_>) -> Result<DateTime<Utc>> {
+        Ok(self.get(ctx).await?.created_at.and_utc())
+    }
+
+    /// Expiry of the latest session token, extended on renewal
+    async fn expires_at(&self, ctx: &Context<'_>) -> Result<DateTime<Utc>> {
+        Ok(self.get(ctx).await?.expires_at.and_utc())
+    }
+
+    async fn revoked_at(&self, ctx: &Context<'_>) -> Result<Option<DateTime<Utc>>> {
+        Ok(self.get(ctx).await?.revoked_at.map(|t| t.and_utc()))
+    }
+
+    /// The session of the current request
+    async fn is_current(&self, ctx: &Context<'_>) -> Result<bool> {
+        let context = ctx.data::<RequestContext>()?;
+        let current = context.try_session_context().await.ok();
+        Ok(current.is_some_and(|s| s.session_id == self.inner_id()))
+    }
+}
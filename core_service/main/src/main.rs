@@ -1,17 +1,19 @@
 use account_service_main as acc_main;
+use blob_store::{BlobStore, LocalBlobStore, LocalBlobStoreConfig, S3BlobStore, S3BlobStoreConfig};
 use clap::Parser;
 use core_service_db as db;
 use core_service_graphql_context::{
-    CookieConfig as CookieConfigInner, EnvironmentConfig, Features, OTP_CODE_TTL_SECONDS,
+    CookieConfig as CookieConfigInner, EnvironmentConfig, Features, RateLimitConfig,
 };
 use core_service_server::{
     Server,
-    config_types::{HttpConfig, SameSiteConfig},
+    config_types::{BackOfficeConfig, HttpConfig, SameSiteConfig},
 };
-use moka::future::CacheBuilder;
+use db_utils::PgConnectionPool;
+use otp_store::OtpPolicy;
 use search_service_main as sea_main;
 use serde::Deserialize;
-use sms_sender::TerminalSmsSender;
+use sms_sender::{SmsSender, TerminalSmsSender, ZaloSmsSender, ZaloSmsSenderConfig};
 use std::{net::SocketAddr, sync::Arc};
 use tokio::{net::TcpListener, runtime::Builder};
 
@@ -109,9 +111,57 @@ impl From<CookieConfig> for CookieConfigInner {
     }
 }
 
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+/// Which [`SmsSender`] implementation the server uses.
+enum SmsSenderConfig {
+    Terminal,
+    Zalo(Box<ZaloSmsSenderConfig>),
+}
+
+impl SmsSenderConfig {
+    fn into_sms_sender(self, db_connection_pool: &PgConnectionPool) -> Arc<dyn SmsSender> {
+        match self {
+            SmsSenderConfig::Terminal => Arc::new(TerminalSmsSender),
+            SmsSenderConfig::Zalo(config) => Arc::new(
+                ZaloSmsSender::new(
+                    *config,
+                    Arc::new(db::PgZaloTokenStore::new(db_connection_pool.clone())),
+                )
+                .expect("Failed to create Zalo SMS sender"),
+            ),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+/// Which [`BlobStore`] implementation the server uses.
+enum BlobStoreConfig {
+    Local(LocalBlobStoreConfig),
+    S3(Box<S3BlobStoreConfig>),
+}
+
+impl BlobStoreConfig {
+    fn into_blob_store(self) -> Arc<dyn BlobStore> {
+        match self {
+            BlobStoreConfig::Local(config) => Arc::new(LocalBlobStore::new(config)),
+            BlobStoreConfig::S3(config) => {
+                Arc::new(S3BlobStore::new(*config).expect("Failed to create S3 blob store"))
+            }
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 struct ServerConfig {
+    /// Staff credentials of the back-office API
+    pub back_office: BackOfficeConfig,
+
+    /// Object storage of avatars and task photos
+    pub blob_store: BlobStoreConfig,
+
     /// Config for setting session cookies
     pub cookie_config: CookieConfig,
 
@@ -126,6 +176,12 @@ struct ServerConfig {
 
     /// Secret for signing / verifying session JWT token
     pub jwt_secret: String,
+
+    /// Request rate limits of unauthenticated APIs
+    pub rate_limit: RateLimitConfig,
+
+    /// SMS provider for OTP messages
+    pub sms_sender: SmsSenderConfig,
 }
 
 async fn start_server() {
@@ -187,15 +243,14 @@ async fn start_server() {
         },
         account_service_client,
         search_service_client,
-        // TODO (MVP): implement zalo SMS sender
-        sms_sender: Arc::new(TerminalSmsSender),
-        // TODO: replace with Redis cache. For MVP, temporary in-memory cache
-        // with max 10_000 entries per 15 mins of TTL should be sufficient.
-        phone_pending_registration_cache: Arc::new(
-            CacheBuilder::new(10_000)
-                .time_to_live(std::time::Duration::from_secs(OTP_CODE_TTL_SECONDS))
-                .build(),
-        ),
+        sms_sender: config.sms_sender.into_sms_sender(&db_connection_pool),
+        otp_store: Arc::new(db::PgOtpStore::new(
+            db_connection_pool.clone(),
+            OtpPolicy::default(),
+        )),
+        blob_store: config.blob_store.into_blob_store(),
+        rate_limit_config: config.rate_limit,
+        back_office_config: Arc::new(config.back_office),
     }
     .serve(server_socket)
     .await
@@ -0,0 +1,144 @@
+use crate::{
+    AppState, BackOfficeSchema, CreateSchemaOption,
+    config_types::{BackOfficeConfig, StaffCredential},
+    create_back_office_schema, create_request_context, maybe_log_batch_response_err,
+};
+use async_graphql::{
+    BatchRequest,
+    parser::{parse_query, types::OperationType},
+};
+use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use axum::{
+    Extension,
+    extract::{ConnectInfo, Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use core_service_graphql_loader::ContextActor;
+use headers::{Authorization, HeaderMapExt, authorization::Bearer};
+use std::{net::SocketAddr, sync::Arc};
+use subtle::ConstantTimeEq;
+use tracing::{Instrument, Span, info_span};
+
+/// Staff member authenticated by [`require_staff_credential`]
+#[derive(Debug, Clone)]
+pub(crate) struct StaffIdentity(pub String);
+
+/// Middleware that rejects requests without a valid staff bearer token,
+/// the [`StaffIdentity`] of the token is added to the request extensions.
+pub async fn require_staff_credential(
+    State(config): State<Arc<BackOfficeConfig>>,
+    ConnectInfo(remote_addr): ConnectInfo<SocketAddr>,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    let staff = req
+        .headers()
+        .typed_get::<Authorization<Bearer>>()
+        .and_then(|auth| find_staff(&config, auth.token()));
+
+    let Some(staff) = staff else {
+        tracing::warn!(%remote_addr, "Rejected back-office request without staff credential");
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    req.extensions_mut()
+        .insert(StaffIdentity(staff.name.clone()));
+
+    next.run(req).await
+}
+
+/// Compare against every token in constant time, so that tokens can't be guessed by timing.
+fn find_staff<'a>(config: &'a BackOfficeConfig, token: &str) -> Option<&'a StaffCredential> {
+    config.staff.iter().fold(None, |found, staff| {
+        let is_match = bool::from(staff.token.as_bytes().ct_eq(token.as_bytes()));
+        if is_match { Some(staff) } else { found }
+    })
+}
+
+/// Middleware that creates the back-office graphql schema acting as god,
+/// it must run after [`require_staff_credential`].
+pub async fn create_back_office_schema_extension(
+    State(app_state): State<AppState>,
+    ConnectInfo(remote_addr): ConnectInfo<SocketAddr>,
+    Extension(StaffIdentity(staff)): Extension<StaffIdentity>,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    let request_context = create_request_context(app_state, None, ContextActor::God, remote_addr);
+
+    let ip_address = remote_addr.ip().to_string();
+    let span = info_span!("Back-office GraphQL request", staff, ip_address);
+    let schema = create_back_office_schema(CreateSchemaOption::WithContext(request_context));
+    req.extensions_mut().insert(span);
+    req.extensions_mut().insert(schema);
+
+    next.run(req).await
+}
+
+/// axum handler that execute back-office graphql request
+pub async fn back_office_graphql_handler(
+    Extension(schema): Extension<BackOfficeSchema>,
+    Extension(span): Extension<Span>,
+    req: GraphQLRequest,
+) -> Response {
+    let batch_request = req.into_inner();
+    span.in_scope(|| log_mutations(&batch_request));
+
+    let graphql_response =
+        GraphQLResponse::from(schema.execute(batch_request).instrument(span.clone()).await);
+    span.in_scope(|| maybe_log_batch_response_err(&graphql_response));
+
+    graphql_response.into_response()
+}
+
+/// Audit log of back-office mutations, the request span identifies the staff member.
+/// Requests that can't be parsed are rejected by the schema without changing anything.
+fn log_mutations(batch_request: &BatchRequest) {
+    for request in batch_request.iter() {
+        let Ok(document) = parse_query(&request.query) else {
+            continue;
+        };
+        let is_mutation = document
+            .operations
+            .iter()
+            .any(|(_, operation)| operation.node.ty == OperationType::Mutation);
+        if is_mutation {
+            tracing::info!(
+                operation_name = request.operation_name.as_deref(),
+                query = request.query.as_str(),
+                variables = %request.variables,
+                "Back-office mutation"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_staff() {
+        let config = BackOfficeConfig {
+            staff: vec![
+                StaffCredential {
+                    name: "first-staff".into(),
+                    token: "first-token".into(),
+                },
+                StaffCredential {
+                    name: "second-staff".into(),
+                    token: "second-token".into(),
+                },
+            ],
+        };
+        let find_name = |token| find_staff(&config, token).map(|s| s.name.as_str());
+        assert_eq!(find_name("first-token"), Some("first-staff"));
+        assert_eq!(find_name("second-token"), Some("second-staff"));
+        assert_eq!(find_name("second-toke"), None);
+        assert_eq!(find_name(""), None);
+
+        let empty = BackOfficeConfig { staff: vec![] };
+        assert!(find_staff(&empty, "").is_none());
+    }
+}
@@ -0,0 +1,151 @@
+use account_service_server::{AccountService, LoadInactiveHandymanIdsRequest};
+use actor_auth::ActorAuth;
+use chrono::{Duration, Utc};
+use core_service_db as db;
+use core_service_graphql_mutation::sync_handyman_rating_index;
+use db_utils::{PgConnectionPool, with_mutable_db, with_readonly_db};
+use scoped_futures::ScopedFutureExt;
+use search_service_server::{SearchService, TaskIndexDeleteRequest};
+
+/// Open tasks nobody took within this period are expired.
+pub const TASK_OPEN_TTL_DAYS: i64 = 14;
+
+const TASK_EXPIRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+const HANDYMAN_RATING_INDEX_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+const HANDYMAN_RATING_INDEX_BATCH_SIZE: i64 = 100;
+
+const SERVICE_RATE_REFRESH_INTERVAL: std::time::Duration =
+    std::time::Duration::from_secs(6 * 60 * 60);
+
+/// Periodically expire stale open customer tasks and remove them from the task search index.
+pub(crate) fn spawn_task_expiry_job(
+    db_connection_pool: PgConnectionPool,
+    search_service_client: SearchService,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(TASK_EXPIRY_INTERVAL);
+        loop {
+            interval.tick().await;
+            let opened_before = Utc::now().naive_utc() - Duration::days(TASK_OPEN_TTL_DAYS);
+            let result = with_mutable_db(&db_connection_pool, |conn| {
+                db::CustomerTaskRequest::expire_stale(&ActorAuth::God, opened_before, conn)
+                    .scope_boxed()
+            })
+            .await;
+
+            let expired = match result {
+                Ok(expired) if !expired.is_empty() => {
+                    tracing::info!("Expired {} stale customer tasks", expired.len());
+                    expired
+                }
+                Ok(_) => continue,
+                Err(e) => {
+                    tracing::error!("Failed to expire stale customer tasks {e:?}");
+                    continue;
+                }
+            };
+
+            let result = search_service_client
+                .task_index_delete(TaskIndexDeleteRequest {
+                    task_ids: expired.iter().map(|t| t.id).collect(),
+                })
+                .await;
+            if let Err(e) = result {
+                tracing::error!("Failed to remove expired customer tasks from search index {e:?}");
+            }
+        }
+    });
+}
+
+/// Periodically index ratings of handymen whose reviews are not reflected in search index yet,
+/// e.g. because the index request failed when the review was created.
+pub(crate) fn spawn_handyman_rating_index_job(
+    db_connection_pool: PgConnectionPool,
+    account_service_client: AccountService,
+    search_service_client: SearchService,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(HANDYMAN_RATING_INDEX_INTERVAL);
+        loop {
+            interval.tick().await;
+            let result = with_readonly_db(&db_connection_pool, |conn| {
+                db::HandymanReview::rating_unindexed_handyman_ids(
+                    &ActorAuth::God,
+                    HANDYMAN_RATING_INDEX_BATCH_SIZE,
+                    conn,
+                )
+                .scope_boxed()
+            })
+            .await;
+
+            let handyman_ids = match result {
+                Ok(handyman_ids) => handyman_ids,
+                Err(e) => {
+                    tracing::error!("Failed to load handymen with unindexed ratings {e:?}");
+                    continue;
+                }
+            };
+
+            for handyman_id in handyman_ids {
+                let result = sync_handyman_rating_index(
+                    &db_connection_pool,
+                    &account_service_client,
+                    &search_service_client,
+                    handyman_id,
+                )
+                .await;
+                if let Err(e) = result {
+                    tracing::error!("Failed to index rating of handyman {} {e:?}", handyman_id.0);
+                }
+            }
+        }
+    });
+}
+
+/// Periodically recompute price distributions of services from handyman rates and accepted quotes.
+/// Replicas skip the refresh when another one refreshed within the interval.
+pub(crate) fn spawn_service_rate_refresh_job(
+    db_connection_pool: PgConnectionPool,
+    account_service_client: AccountService,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SERVICE_RATE_REFRESH_INTERVAL);
+        loop {
+            interval.tick().await;
+            let result = account_service_client
+                .load_inactive_handyman_ids(LoadInactiveHandymanIdsRequest {
+                    actor_auth: ActorAuth::God,
+                })
+                .await;
+            let excluded_handyman_ids = match result {
+                Ok(response) => response.handyman_ids,
+                Err(e) => {
+                    tracing::error!("Failed to load inactive handymen {e:?}");
+                    continue;
+                }
+            };
+
+            let stale_before = Utc::now().naive_utc()
+                - Duration::from_std(SERVICE_RATE_REFRESH_INTERVAL).unwrap_or_default();
+            let result = with_mutable_db(&db_connection_pool, |conn| {
+                db::ServiceRateStat::refresh_if_stale(
+                    &ActorAuth::God,
+                    stale_before,
+                    &excluded_handyman_ids,
+                    conn,
+                )
+                .scope_boxed()
+            })
+            .await;
+
+            match result {
+                Ok(Some(refreshed)) => {
+                    tracing::info!("Refreshed {refreshed} service rate distributions")
+                }
+                Ok(None) => {}
+                Err(e) => tracing::error!("Failed to refresh service rate distributions {e:?}"),
+            }
+        }
+    });
+}
@@ -0,0 +1,64 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use blob_store::{BlobKey, BlobStore};
+use chrono::Utc;
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// Blob keys are never reused, a blob is replaced by writing a new key
+const BLOB_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// Signature of a url of a private blob, see [`BlobStore::signed_url`]
+#[derive(Debug, Deserialize)]
+pub struct SignedBlobQuery {
+    expires: Option<i64>,
+    signature: Option<String>,
+}
+
+/// axum handler that serves blobs from the blob store, e.g. avatars and task photos.
+/// Private blobs are only served through unexpired signed urls.
+pub async fn get_blob(
+    State(blob_store): State<Arc<dyn BlobStore>>,
+    Path(key): Path<String>,
+    Query(SignedBlobQuery { expires, signature }): Query<SignedBlobQuery>,
+) -> Response {
+    let Ok(key) = BlobKey::parse(&key) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let Some(image_type) = key.image_type() else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let cache_control = if key.is_private() {
+        let (Some(expires_at), Some(signature)) = (expires, signature) else {
+            return StatusCode::NOT_FOUND.into_response();
+        };
+        if !blob_store.verify_signed_url(&key, expires_at, &signature) {
+            return StatusCode::NOT_FOUND.into_response();
+        }
+        // Not shared, and not cached beyond the expiry of the url
+        let max_age = (expires_at - Utc::now().timestamp()).max(0);
+        format!("private, max-age={max_age}")
+    } else {
+        BLOB_CACHE_CONTROL.into()
+    };
+
+    match blob_store.get(&key).await {
+        Ok(Some(data)) => (
+            [
+                (header::CONTENT_TYPE, image_type.content_type().to_string()),
+                (header::CACHE_CONTROL, cache_control),
+            ],
+            data,
+        )
+            .into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!(error = ?e, %key, "Cannot read blob");
+            StatusCode::SERVICE_UNAVAILABLE.into_response()
+        }
+    }
+}
@@ -26,3 +26,20 @@ pub struct HttpConfig {
     pub cookie_config: Arc<CookieConfig>,
     pub cors_origins: Vec<String>,
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackOfficeConfig {
+    /// Staff allowed to use the back-office GraphQL API,
+    /// the API rejects every request if the list is empty.
+    pub staff: Vec<StaffCredential>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaffCredential {
+    /// Identifies the staff member in the audit log of back-office mutations, e.g. an email
+    pub name: String,
+    /// Bearer token handed to the staff member
+    pub token: String,
+}
@@ -1,16 +1,20 @@
 use crate::{create_schema, extract_session, make_user_context_span};
 use account_service_server::AccountService;
+use actor_auth::Session;
 use axum::{
     extract::{ConnectInfo, Request, State},
     middleware::Next,
     response::Response,
 };
+use blob_store::BlobStore;
 use core_service_graphql_context::{
-    ContextInternal, CookieConfig, EnvironmentConfig, Features, NewContextParams, RequestContext,
+    ContextInternal, CookieConfig, EnvironmentConfig, Features, NewContextParams, RateLimitConfig,
+    RequestContext,
 };
-use core_service_graphql_loader::CacheConfig;
+use core_service_graphql_loader::{CacheConfig, ContextActor};
 use db_utils::PgConnectionPool;
-use moka::future::Cache;
+use otp_store::OtpStore;
+use rate_limiter::RateLimiter;
 use search_service_server::SearchService;
 use sms_sender::SmsSender;
 use std::{net::SocketAddr, sync::Arc};
@@ -26,7 +30,10 @@ pub struct AppState {
     pub loader_cache_config: CacheConfig,
     pub account_service_client: AccountService,
     pub search_service_client: SearchService,
-    pub phone_pending_registration_cache: Arc<Cache<String, String>>,
+    pub otp_store: Arc<dyn OtpStore>,
+    pub blob_store: Arc<dyn BlobStore>,
+    pub rate_limit_config: RateLimitConfig,
+    pub rate_limiter: Arc<RateLimiter>,
 }
 
 /// Middleware that extracts user session, creates graphql schema and binds schema to axum request extensions.
@@ -39,8 +46,30 @@ pub async fn create_graphql_schema_extension(
     let header_map = req.headers();
     let session_context = extract_session(header_map, &app_state.account_service_client).await;
 
-    let request_context = RequestContext::new(ContextInternal::new(NewContextParams {
+    let request_context = create_request_context(
+        app_state,
         session_context,
+        ContextActor::Session,
+        remote_addr,
+    );
+
+    let user_context_span = make_user_context_span(&request_context).await;
+    let schema = create_schema(crate::CreateSchemaOption::WithContext(request_context));
+    req.extensions_mut().insert(user_context_span);
+    req.extensions_mut().insert(schema);
+
+    next.run(req).await
+}
+
+pub(crate) fn create_request_context(
+    app_state: AppState,
+    session_context: Option<Session>,
+    context_actor: ContextActor,
+    remote_addr: SocketAddr,
+) -> RequestContext {
+    RequestContext::new(ContextInternal::new(NewContextParams {
+        session_context,
+        context_actor,
         db_connection_pool: app_state.db_pool,
         features: app_state.features,
         environment_config: app_state.environment_config,
@@ -49,14 +78,10 @@ pub async fn create_graphql_schema_extension(
         account_service_client: app_state.account_service_client,
         search_service_client: app_state.search_service_client,
         sms_sender: app_state.sms_sender,
-        phone_pending_registration_cache: app_state.phone_pending_registration_cache,
+        otp_store: app_state.otp_store,
+        blob_store: app_state.blob_store,
+        rate_limit_config: app_state.rate_limit_config,
+        rate_limiter: app_state.rate_limiter,
         loader_cache_config: app_state.loader_cache_config,
-    }));
-
-    let user_context_span = make_user_context_span(&request_context).await;
-    let schema = create_schema(crate::CreateSchemaOption::WithContext(request_context));
-    req.extensions_mut().insert(user_context_span);
-    req.extensions_mut().insert(schema);
-
-    next.run(req).await
+    }))
 }
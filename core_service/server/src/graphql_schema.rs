@@ -1,4 +1,5 @@
 use async_graphql::{EmptySubscription, Schema};
+use core_service_graphql_back_office::{BackOfficeMutation, BackOfficeQuery};
 use core_service_graphql_context::RequestContext;
 use core_service_graphql_mutation::Mutation;
 use core_service_graphql_query::Query;
@@ -23,3 +24,20 @@ pub fn create_schema(option: CreateSchemaOption) -> ServiceSchema {
         CreateSchemaOption::WithContext(context) => builder.data(context).finish(),
     }
 }
+
+/// Back-office GraphQL schema for staff
+pub type BackOfficeSchema = Schema<BackOfficeQuery, BackOfficeMutation, EmptySubscription>;
+
+/// Create an instance of [BackOfficeSchema]
+pub fn create_back_office_schema(option: CreateSchemaOption) -> BackOfficeSchema {
+    let builder = BackOfficeSchema::build(
+        BackOfficeQuery::default(),
+        BackOfficeMutation::default(),
+        EmptySubscription,
+    )
+    .register_output_type::<Node>();
+    match option {
+        CreateSchemaOption::NoContext => builder.finish(),
+        CreateSchemaOption::WithContext(context) => builder.data(context).finish(),
+    }
+}
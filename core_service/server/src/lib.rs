@@ -6,14 +6,24 @@ pub use server::*;
 mod graphql_schema;
 pub use graphql_schema::*;
 
+mod back_office;
+pub(crate) use back_office::*;
+
 mod create_context;
 pub(crate) use create_context::*;
 
 mod extract_session;
 pub(crate) use extract_session::*;
 
+mod blob;
+pub(crate) use blob::*;
+
 mod health_check;
 pub(crate) use health_check::*;
 
 mod tracing_span;
 pub(crate) use tracing_span::*;
+
+mod background_jobs;
+pub use background_jobs::TASK_OPEN_TTL_DAYS;
+pub(crate) use background_jobs::*;
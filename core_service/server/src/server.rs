@@ -1,6 +1,9 @@
 use crate::{
-    AppState, ServiceSchema, config_types::HttpConfig, create_graphql_schema_extension,
-    health_check,
+    AppState, ServiceSchema, back_office_graphql_handler,
+    config_types::{BackOfficeConfig, HttpConfig},
+    create_back_office_schema_extension, create_graphql_schema_extension, get_blob, health_check,
+    require_staff_credential, spawn_handyman_rating_index_job, spawn_service_rate_refresh_job,
+    spawn_task_expiry_job,
 };
 use account_service_server::AccountService;
 use async_graphql::http::{
@@ -15,23 +18,29 @@ use axum::{
     response::{Html, IntoResponse, Response},
     routing::{get, post},
 };
-use core_service_graphql_context::{EnvironmentConfig, Features};
+use blob_store::{BlobStore, MAX_IMAGE_BYTES};
+use core_service_graphql_context::{EnvironmentConfig, Features, RateLimitConfig};
 use core_service_graphql_loader::CacheConfig;
 use db_utils::PgConnectionPool;
 use error::{Error, Result};
-use moka::future::Cache;
+use otp_store::OtpStore;
+use rate_limiter::RateLimiter;
 use search_service_server::SearchService;
 use service_http::ACCESS_TOKEN_COOKIE_KEY;
 use sms_sender::SmsSender;
-use std::{net::SocketAddr, sync::Arc};
+use std::{convert::Infallible, net::SocketAddr, sync::Arc};
 use tokio::net::TcpListener;
 use tower::ServiceBuilder;
 use tower_http::{
     cors::{AllowOrigin, CorsLayer},
+    limit::RequestBodyLimitLayer,
     trace::TraceLayer,
 };
 use tracing::{Instrument, Span};
 
+/// Room for an uploaded image and the rest of the multipart request
+const GRAPHQL_BODY_LIMIT_BYTES: usize = MAX_IMAGE_BYTES as usize + 1024 * 1024;
+
 pub struct Server {
     pub db_connection_pool: PgConnectionPool,
     pub environment_config: Arc<EnvironmentConfig>,
@@ -40,11 +49,17 @@ pub struct Server {
     pub account_service_client: AccountService,
     pub search_service_client: SearchService,
     pub sms_sender: Arc<dyn SmsSender>,
-    pub phone_pending_registration_cache: Arc<Cache<String, String>>,
+    pub otp_store: Arc<dyn OtpStore>,
+    pub blob_store: Arc<dyn BlobStore>,
+    pub rate_limit_config: RateLimitConfig,
+    pub back_office_config: Arc<BackOfficeConfig>,
 }
 
 impl Server {
     pub async fn serve(&self, server_socket: TcpListener) -> Result<()> {
+        // Shared by graphql, subscription and back-office endpoints
+        let rate_limiter = Arc::new(RateLimiter::new());
+
         // Create middleware stacks
         let middleware = ServiceBuilder::new()
             .layer(TraceLayer::new_for_http())
@@ -52,9 +67,14 @@ impl Server {
 
         let graphql_path = "/graphql";
         let subscriptions_path = "/subscriptions";
+        let back_office_graphql_path = "/back-office/graphql";
 
         let app = Router::new()
             .route("/health", get(health_check))
+            .route(
+                "/blobs/{*key}",
+                get(get_blob).with_state(Arc::clone(&self.blob_store)),
+            )
             .route(
                 "/graphiql",
                 get(async || Html(graphiql_source(graphql_path, Some(subscriptions_path)))),
@@ -73,21 +93,48 @@ impl Server {
                 post(graphql_handler)
                     // axum middleware are executed from bottom to top.
                     // See <https://docs.rs/axum/0.7.5/axum/middleware/index.html#ordering>
-                    .layer(middleware::from_fn_with_state(
-                        self.create_app_state(CacheConfig::Cache),
+                    .layer::<_, Infallible>(middleware::from_fn_with_state(
+                        self.create_app_state(CacheConfig::Cache, rate_limiter.clone()),
                         create_graphql_schema_extension,
-                    )),
+                    ))
+                    .layer(RequestBodyLimitLayer::new(GRAPHQL_BODY_LIMIT_BYTES)),
             )
             .route(
                 subscriptions_path,
                 get(graphql_subscriptions).layer(middleware::from_fn_with_state(
-                    self.create_app_state(CacheConfig::NoCache),
+                    self.create_app_state(CacheConfig::NoCache, rate_limiter.clone()),
                     create_graphql_schema_extension,
                 )),
             )
+            .route(
+                back_office_graphql_path,
+                post(back_office_graphql_handler)
+                    .layer(middleware::from_fn_with_state(
+                        self.create_app_state(CacheConfig::Cache, rate_limiter),
+                        create_back_office_schema_extension,
+                    ))
+                    .layer(middleware::from_fn_with_state(
+                        Arc::clone(&self.back_office_config),
+                        require_staff_credential,
+                    )),
+            )
             .layer(middleware)
             .into_make_service_with_connect_info::<SocketAddr>();
 
+        spawn_task_expiry_job(
+            self.db_connection_pool.clone(),
+            self.search_service_client.clone(),
+        );
+        spawn_handyman_rating_index_job(
+            self.db_connection_pool.clone(),
+            self.account_service_client.clone(),
+            self.search_service_client.clone(),
+        );
+        spawn_service_rate_refresh_job(
+            self.db_connection_pool.clone(),
+            self.account_service_client.clone(),
+        );
+
         tracing::info!("Server listening on {}", server_socket.local_addr()?.port());
         axum::serve(server_socket, app)
             .await
@@ -96,7 +143,11 @@ impl Server {
         Ok(())
     }
 
-    fn create_app_state(&self, loader_cache_config: CacheConfig) -> AppState {
+    fn create_app_state(
+        &self,
+        loader_cache_config: CacheConfig,
+        rate_limiter: Arc<RateLimiter>,
+    ) -> AppState {
         AppState {
             db_pool: self.db_connection_pool.clone(),
             features: self.features,
@@ -105,7 +156,10 @@ impl Server {
             sms_sender: self.sms_sender.clone(),
             account_service_client: self.account_service_client.clone(),
             search_service_client: self.search_service_client.clone(),
-            phone_pending_registration_cache: self.phone_pending_registration_cache.clone(),
+            otp_store: self.otp_store.clone(),
+            blob_store: self.blob_store.clone(),
+            rate_limit_config: self.rate_limit_config,
+            rate_limiter,
             loader_cache_config,
         }
     }
@@ -134,6 +188,7 @@ impl Server {
             .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
             .allow_headers([
                 header::CONTENT_TYPE,
+                header::AUTHORIZATION,
                 HeaderName::from_static(ACCESS_TOKEN_COOKIE_KEY),
                 HeaderName::from_static(header::REFERER.as_str()),
             ])
@@ -174,7 +229,7 @@ pub async fn graphql_subscriptions(
 }
 
 /// If any of the batch response contains an error, logs an error event.
-fn maybe_log_batch_response_err(response: &GraphQLResponse) {
+pub(crate) fn maybe_log_batch_response_err(response: &GraphQLResponse) {
     match &response.0 {
         async_graphql::BatchResponse::Single(response) => maybe_log_response_err(response),
         async_graphql::BatchResponse::Batch(responses) => {
@@ -2,8 +2,10 @@ mod graphql;
 
 use account_service_server::SESSION_TOKEN_TTL_DAYS;
 use chrono::Duration;
+use core_service_graphql_context::{ApiRateLimit, RateLimitConfig};
 use error::{ErrorVariant, Result};
-use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+use rate_limiter::RateLimit;
+use test_service_orchestration::{ServiceEnvironment, ServiceParams, core_service};
 
 #[tokio::test]
 async fn customer_registration() -> Result<()> {
@@ -245,3 +247,300 @@ async fn handyman_registration() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn registration_otp_attempt_limit() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let client =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+
+    let phone_number = "+84334445555";
+    let password = "12345678";
+
+    let otp_case = graphql::customer_account_start_registration(&client, phone_number)
+        .await
+        .data
+        .unwrap()
+        .user_account_start_registration
+        .case
+        .try_otp_case()?;
+    assert_eq!(otp_case.resend_cooldown_seconds, 60);
+    let sms = core_service.sms_receiver.receive_sms().await.pop().unwrap();
+    let otp_code = sms.message.try_otp_verification_for_registration()?.code;
+
+    // Test resending code during cooldown
+    let response = graphql::customer_account_start_registration(&client, phone_number).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::ResourceExhausted((None, None)),
+        Some(serde_json::json!({
+            "quotaFailure": {
+                "violations": [{
+                    "subject": "OTP_RESEND",
+                    "description": "COOLDOWN",
+                }]
+            },
+            "retryInfo": {
+                "retryDelay": { "seconds": 60, "nanos": 0 }
+            },
+        })),
+    );
+    assert!(core_service.sms_receiver.receive_sms().await.is_empty());
+
+    // Test wrong codes lock the phone number out
+    let wrong_code = if otp_code == "000000" {
+        "111111"
+    } else {
+        "000000"
+    };
+    for _ in 0..4 {
+        let response = graphql::customer_account_finish_registration(
+            &client,
+            graphql::UserAccountFinishRegistrationInput {
+                phone_number,
+                password,
+                otp_code: wrong_code,
+            },
+        )
+        .await;
+        graphql::assert_error_response(
+            response,
+            ErrorVariant::InvalidArgument(None),
+            Some(serde_json::json!({
+                "fieldViolations": [{
+                    "field": "OTP_VERIFICATION",
+                    "description": "FAILED",
+                }]
+            })),
+        );
+    }
+    for code in [wrong_code, otp_code.as_str()] {
+        let response = graphql::customer_account_finish_registration(
+            &client,
+            graphql::UserAccountFinishRegistrationInput {
+                phone_number,
+                password,
+                otp_code: code,
+            },
+        )
+        .await;
+        graphql::assert_error_response(
+            response,
+            ErrorVariant::ResourceExhausted((None, None)),
+            Some(serde_json::json!({
+                "quotaFailure": {
+                    "violations": [{
+                        "subject": "OTP_VERIFICATION",
+                        "description": "TOO_MANY_ATTEMPTS",
+                    }]
+                },
+                "retryInfo": {
+                    "retryDelay": { "seconds": 900, "nanos": 0 }
+                },
+            })),
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limit_unauthenticated_apis() -> Result<()> {
+    let limit = ApiRateLimit {
+        per_remote_addr: RateLimit {
+            max_requests: 3,
+            window_seconds: 3600,
+        },
+        per_phone_number: RateLimit {
+            max_requests: 2,
+            window_seconds: 3600,
+        },
+    };
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams {
+        core_service: core_service::CoreServiceParams {
+            rate_limit_config: RateLimitConfig {
+                start_registration: limit,
+                sign_in_with_password: limit,
+                start_password_reset: limit,
+                start_phone_number_change: limit,
+                start_sign_in_with_otp: limit,
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+    .init()
+    .await?;
+    let client =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+
+    let rate_limited_details = |subject: &str, description: &str| {
+        serde_json::json!({
+            "quotaFailure": {
+                "violations": [{
+                    "subject": subject,
+                    "description": description,
+                }]
+            },
+            "retryInfo": {
+                "retryDelay": { "seconds": 3600, "nanos": 0 }
+            },
+        })
+    };
+
+    // Test the phone number limit, the resend cooldown still applies within the limit
+    graphql::customer_account_start_registration(&client, "+84334445555")
+        .await
+        .data
+        .unwrap()
+        .user_account_start_registration
+        .case
+        .try_otp_case()?;
+    let response = graphql::customer_account_start_registration(&client, "+84334445555").await;
+    assert_eq!(
+        graphql::extract_error_extensions(response).get("code"),
+        Some(&serde_json::json!("RESOURCE_EXHAUSTED"))
+    );
+    let response = graphql::customer_account_start_registration(&client, "+84334445555").await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::ResourceExhausted((None, None)),
+        Some(rate_limited_details(
+            "START_REGISTRATION",
+            "TOO_MANY_REQUESTS_FOR_PHONE_NUMBER",
+        )),
+    );
+    assert_eq!(core_service.sms_receiver.receive_sms().await.len(), 1);
+
+    // Test the remote address limit covers other phone numbers
+    let response = graphql::customer_account_start_registration(&client, "+84334446666").await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::ResourceExhausted((None, None)),
+        Some(rate_limited_details(
+            "START_REGISTRATION",
+            "TOO_MANY_REQUESTS_FROM_REMOTE_ADDR",
+        )),
+    );
+    assert!(core_service.sms_receiver.receive_sms().await.is_empty());
+
+    // Test sign in attempts are limited separately from registration
+    for _ in 0..2 {
+        let response = graphql::user_sign_in_with_password(
+            &client,
+            graphql::user_sign_in_with_password::UserSignInWithPasswordInput {
+                phone_number: "+84334445555".into(),
+                password: "wrong-password".into(),
+                account_type: graphql::user_sign_in_with_password::AccountType::CUSTOMER,
+            },
+        )
+        .await;
+        graphql::assert_error_response(
+            response,
+            ErrorVariant::Unauthenticated(None),
+            Some(serde_json::json!(null)),
+        );
+    }
+    let response = graphql::user_sign_in_with_password(
+        &client,
+        graphql::user_sign_in_with_password::UserSignInWithPasswordInput {
+            phone_number: "+84334445555".into(),
+            password: "wrong-password".into(),
+            account_type: graphql::user_sign_in_with_password::AccountType::CUSTOMER,
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::ResourceExhausted((None, None)),
+        Some(rate_limited_details(
+            "SIGN_IN_WITH_PASSWORD",
+            "TOO_MANY_REQUESTS_FOR_PHONE_NUMBER",
+        )),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn handyman_sign_in_with_otp() -> Result<()> {
+    use graphql::user_sign_in_with_otp_finish::{AccountType, UserSignInWithOtpFinishInput};
+    use graphql::user_sign_in_with_otp_start::UserSignInWithOtpStartInput;
+
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let phone_number = "+84334445555";
+
+    let handyman_id = graphql::sign_up_handyman(
+        &graphql::GraphqlClient::new(graphql_url.clone()),
+        &core_service.sms_receiver,
+        phone_number,
+    )
+    .await?;
+    let client = graphql::GraphqlClient::new(graphql_url);
+    let start_input = |phone_number: &str| UserSignInWithOtpStartInput {
+        phone_number: phone_number.into(),
+        account_type: graphql::user_sign_in_with_otp_start::AccountType::HANDYMAN,
+    };
+    let finish_input = |phone_number: &str, otp_code: &str| UserSignInWithOtpFinishInput {
+        phone_number: phone_number.into(),
+        otp_code: otp_code.into(),
+        account_type: AccountType::HANDYMAN,
+    };
+
+    // Test no code is sent for phone numbers without an account
+    let payload = graphql::user_sign_in_with_otp_start(&client, start_input("+84334446666"))
+        .await
+        .data
+        .unwrap()
+        .user_sign_in_with_otp_start;
+    assert_eq!(payload.e164_phone_number_str, "+84334446666");
+    assert!(core_service.sms_receiver.receive_sms().await.is_empty());
+
+    graphql::user_sign_in_with_otp_start(&client, start_input(phone_number))
+        .await
+        .data
+        .unwrap();
+    let sms = core_service.sms_receiver.receive_sms().await.pop().unwrap();
+    let otp_code = sms.message.try_otp_verification_for_sign_in()?.code;
+
+    graphql::user_sign_in_with_otp_finish(&client, finish_input(phone_number, &otp_code))
+        .await
+        .data
+        .unwrap();
+    let session = graphql::session(&client)
+        .await
+        .data
+        .unwrap()
+        .session
+        .unwrap();
+    assert_eq!(session.actor_type.try_handyman()?.id, handyman_id);
+
+    // Test the code can't be reused
+    let response =
+        graphql::user_sign_in_with_otp_finish(&client, finish_input(phone_number, &otp_code)).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "OTP_VERIFICATION",
+                "description": "EXPIRED",
+            }]
+        })),
+    );
+
+    Ok(())
+}
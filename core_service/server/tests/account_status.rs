@@ -0,0 +1,308 @@
+mod graphql;
+
+use error::{ErrorVariant, Result};
+use graphql::handyman_profile_add_services::{HandymanProfileAddServicesInput, NewHandymanService};
+use graphql::handyman_search::{HandymanSearchFilter, PagingOffsetInput};
+use graphql::update_account_status::AccountStatus;
+use graphql::user_sign_in_with_password::{AccountType, UserSignInWithPasswordInput};
+use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+
+fn sign_in_input(phone_number: &str, account_type: AccountType) -> UserSignInWithPasswordInput {
+    UserSignInWithPasswordInput {
+        phone_number: phone_number.into(),
+        password: "12345678".into(),
+        account_type,
+    }
+}
+
+fn search_air_conditioner_fixing() -> (HandymanSearchFilter, PagingOffsetInput) {
+    (
+        HandymanSearchFilter {
+            service_codes: Some(vec!["AIR_CONDITIONER_FIXING".into()]),
+            services: None,
+            name: None,
+            ids: None,
+            distance_within: None,
+            available_at: None,
+        },
+        PagingOffsetInput {
+            page: 1,
+            page_size: 12,
+        },
+    )
+}
+
+#[tokio::test]
+async fn suspend_and_reinstate_accounts() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let staff = graphql::back_office_client(format!(
+        "http://{}/back-office/graphql",
+        core_service.service_host
+    ));
+    let customer_phone_number = "+84334445555";
+    let handyman_phone_number = "+84334446666";
+
+    let customer = graphql::GraphqlClient::new(graphql_url.clone());
+    let customer_id =
+        graphql::sign_up_customer(&customer, &core_service.sms_receiver, customer_phone_number)
+            .await?;
+    let task_id = graphql::customer_create_task(&customer, graphql::new_task_input())
+        .await
+        .data
+        .unwrap()
+        .customer_create_task
+        .task
+        .id;
+
+    let handyman = graphql::GraphqlClient::new(graphql_url.clone());
+    let handyman_id =
+        graphql::sign_up_handyman(&handyman, &core_service.sms_receiver, handyman_phone_number)
+            .await?;
+    graphql::handyman_profile_add_services(
+        &handyman,
+        HandymanProfileAddServicesInput {
+            handyman_id: handyman_id.clone(),
+            services: vec![NewHandymanService {
+                service_code: Some("AIR_CONDITIONER_FIXING".into()),
+                service: None,
+                note: None,
+                rate_vnd: None,
+            }],
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    let (filter, paging_config) = search_air_conditioner_fixing();
+    let result = graphql::handyman_search(&customer, filter, paging_config)
+        .await
+        .data
+        .unwrap()
+        .handyman_search;
+    assert_eq!(result.paging_info.total_count, 1);
+
+    // Test suspending signs the customer out and rejects signing in again
+    let account = graphql::update_account_status(&staff, &customer_id, AccountStatus::SUSPENDED)
+        .await
+        .data
+        .unwrap()
+        .update_account_status
+        .account;
+    let graphql::update_account_status::BackOfficeAccountFragment::Customer(account) = account
+    else {
+        panic!("Expect a customer account, found {account:?}");
+    };
+    assert!(matches!(account.status, AccountStatus::SUSPENDED));
+    assert!(
+        graphql::session(&customer)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_none()
+    );
+
+    let response = graphql::user_sign_in_with_password(
+        &customer,
+        sign_in_input(customer_phone_number, AccountType::CUSTOMER),
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::PermissionDenied(None),
+        Some(serde_json::json!({
+            "errorInfo": {
+                "reason": "SUSPENDED",
+                "domain": "ACCOUNT_STATUS",
+                "metadata": {},
+            }
+        })),
+    );
+
+    // Test reinstating allows the customer to sign in again
+    graphql::update_account_status(&staff, &customer_id, AccountStatus::ACTIVE)
+        .await
+        .data
+        .unwrap();
+    graphql::user_sign_in_with_password(
+        &customer,
+        sign_in_input(customer_phone_number, AccountType::CUSTOMER),
+    )
+    .await
+    .data
+    .unwrap();
+
+    // Test open tasks of the suspended customer were cancelled
+    let task = graphql::customer_task_request_node(&customer, &task_id)
+        .await
+        .data
+        .unwrap()
+        .node
+        .unwrap()
+        .try_customer_task_request()?;
+    assert!(matches!(
+        task.status,
+        graphql::customer_task_request_node::CustomerTaskStatus::CANCELLED
+    ));
+
+    // Test suspended handymen are hidden from search until reinstated
+    graphql::update_account_status(&staff, &handyman_id, AccountStatus::SUSPENDED)
+        .await
+        .data
+        .unwrap();
+    let (filter, paging_config) = search_air_conditioner_fixing();
+    let result = graphql::handyman_search(&customer, filter, paging_config)
+        .await
+        .data
+        .unwrap()
+        .handyman_search;
+    assert_eq!(result.paging_info.total_count, 0);
+
+    graphql::update_account_status(&staff, &handyman_id, AccountStatus::ACTIVE)
+        .await
+        .data
+        .unwrap();
+    let (filter, paging_config) = search_air_conditioner_fixing();
+    let result = graphql::handyman_search(&customer, filter, paging_config)
+        .await
+        .data
+        .unwrap()
+        .handyman_search;
+    assert_eq!(result.paging_info.total_count, 1);
+    assert_eq!(result.items[0].id, handyman_id);
+
+    // Test deletion isn't a status staff can set
+    let response =
+        graphql::update_account_status(&staff, &customer_id, AccountStatus::DELETED).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!(null)),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_accounts() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let staff = graphql::back_office_client(format!(
+        "http://{}/back-office/graphql",
+        core_service.service_host
+    ));
+    let customer_phone_number = "+84334445555";
+    let handyman_phone_number = "+84334446666";
+
+    let customer = graphql::GraphqlClient::new(graphql_url.clone());
+    let customer_id =
+        graphql::sign_up_customer(&customer, &core_service.sms_receiver, customer_phone_number)
+            .await?;
+
+    // Test the customer deletes their own account with the current password
+    let response = graphql::user_delete_account(&customer, "wrong-password").await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "PASSWORD",
+                "description": "MISMATCHED",
+            }]
+        })),
+    );
+
+    let account = graphql::user_delete_account(&customer, "12345678")
+        .await
+        .data
+        .unwrap()
+        .user_delete_account
+        .account;
+    let graphql::user_delete_account::UserDeleteAccountUserDeleteAccountAccount::Customer(account) =
+        account
+    else {
+        panic!("Expect a customer account, found {account:?}");
+    };
+    assert_eq!(account.id, customer_id);
+    assert_ne!(account.phone_number, customer_phone_number);
+    assert!(matches!(
+        account.status,
+        graphql::user_delete_account::AccountStatus::DELETED
+    ));
+    assert!(
+        graphql::session(&customer)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_none()
+    );
+
+    // The phone number is anonymized, it can't be used to sign in anymore
+    let response = graphql::user_sign_in_with_password(
+        &customer,
+        sign_in_input(customer_phone_number, AccountType::CUSTOMER),
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::Unauthenticated(None),
+        Some(serde_json::json!(null)),
+    );
+    let found = graphql::back_office_customer(&staff, customer_phone_number)
+        .await
+        .data
+        .unwrap()
+        .customer_by_phone_number;
+    assert!(found.is_none());
+
+    // Test staff deletes a handyman account on behalf of its owner
+    let handyman = graphql::GraphqlClient::new(graphql_url);
+    let handyman_id =
+        graphql::sign_up_handyman(&handyman, &core_service.sms_receiver, handyman_phone_number)
+            .await?;
+    graphql::delete_account(&staff, &handyman_id)
+        .await
+        .data
+        .unwrap();
+    assert!(
+        graphql::session(&handyman)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_none()
+    );
+    let found = graphql::back_office_handyman(&staff, handyman_phone_number)
+        .await
+        .data
+        .unwrap()
+        .handyman_by_phone_number;
+    assert!(found.is_none());
+
+    // Deleted accounts can't be deleted again nor reinstated
+    let response = graphql::delete_account(&staff, &handyman_id).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::FailedPrecondition(None),
+        Some(serde_json::json!(null)),
+    );
+    let response =
+        graphql::update_account_status(&staff, &handyman_id, AccountStatus::ACTIVE).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::FailedPrecondition(None),
+        Some(serde_json::json!(null)),
+    );
+
+    Ok(())
+}
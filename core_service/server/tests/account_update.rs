@@ -0,0 +1,132 @@
+mod graphql;
+
+use error::{ErrorVariant, Result};
+use graphql::user_change_password::UserAccountFragment;
+use graphql::user_sign_in_with_password::{AccountType, UserSignInWithPasswordInput};
+use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+
+#[tokio::test]
+async fn change_password_and_phone_number() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let phone_number = "+84334445555";
+    let new_phone_number = "+84334446666";
+    let taken_phone_number = "+84334447777";
+    let sign_in_input = |phone_number: &str, password: &str| UserSignInWithPasswordInput {
+        phone_number: phone_number.into(),
+        password: password.into(),
+        account_type: AccountType::CUSTOMER,
+    };
+
+    let client = graphql::GraphqlClient::new(graphql_url.clone());
+    let other = graphql::GraphqlClient::new(graphql_url.clone());
+    graphql::sign_up_customer(&other, &core_service.sms_receiver, taken_phone_number).await?;
+    let customer_id =
+        graphql::sign_up_customer(&client, &core_service.sms_receiver, phone_number).await?;
+
+    // Test changing password with a wrong old password
+    let response = graphql::user_change_password(&client, "00000000", "87654321").await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "OLD_PASSWORD",
+                "description": "MISMATCHED",
+            }]
+        })),
+    );
+
+    let other_device = graphql::GraphqlClient::new(graphql_url.clone());
+    graphql::user_sign_in_with_password(&other_device, sign_in_input(phone_number, "12345678"))
+        .await
+        .data
+        .unwrap();
+
+    let account = graphql::user_change_password(&client, "12345678", "87654321")
+        .await
+        .data
+        .unwrap()
+        .user_change_password
+        .account;
+    let UserAccountFragment::Customer(customer) = account else {
+        panic!("Expect customer account");
+    };
+    assert_eq!(customer.id, customer_id);
+    // Test other devices are signed out while the current one stays signed in
+    assert!(
+        graphql::session(&other_device)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_none()
+    );
+    assert!(
+        graphql::session(&client)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_some()
+    );
+    let response =
+        graphql::user_sign_in_with_password(&other, sign_in_input(phone_number, "12345678")).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::Unauthenticated(None),
+        Some(serde_json::json!(null)),
+    );
+
+    // Test changing to a phone number of another account
+    let response = graphql::user_phone_number_change_start(&client, taken_phone_number).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::AlreadyExists(None),
+        Some(serde_json::json!(null)),
+    );
+    assert!(core_service.sms_receiver.receive_sms().await.is_empty());
+
+    let payload = graphql::user_phone_number_change_start(&client, new_phone_number)
+        .await
+        .data
+        .unwrap()
+        .user_phone_number_change_start;
+    assert_eq!(payload.e164_phone_number_str, new_phone_number);
+    let sms = core_service.sms_receiver.receive_sms().await.pop().unwrap();
+    let otp_code = sms
+        .message
+        .try_otp_verification_for_phone_number_change()?
+        .code;
+
+    let account = graphql::user_phone_number_change_finish(&client, new_phone_number, &otp_code)
+        .await
+        .data
+        .unwrap()
+        .user_phone_number_change_finish
+        .account;
+    let graphql::user_phone_number_change_finish::UserAccountFragment::Customer(customer) = account
+    else {
+        panic!("Expect customer account");
+    };
+    assert_eq!(customer.phone_number, new_phone_number);
+
+    // Test signing in with the new phone number only
+    let response =
+        graphql::user_sign_in_with_password(&other, sign_in_input(phone_number, "87654321")).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::Unauthenticated(None),
+        Some(serde_json::json!(null)),
+    );
+    graphql::user_sign_in_with_password(&other, sign_in_input(new_phone_number, "87654321"))
+        .await
+        .data
+        .unwrap();
+
+    Ok(())
+}
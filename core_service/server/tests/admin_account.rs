@@ -0,0 +1,189 @@
+mod graphql;
+
+use account_service_server::AdminCreateAccountRequest;
+use actor_auth::ActorAuth;
+use error::{ErrorVariant, Result};
+use graphql::user_sign_in_with_password::{AccountType, UserSignInWithPasswordInput};
+use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+
+#[tokio::test]
+async fn admin_overrides_account_access() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        account_service,
+        core_service,
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let admin_phone_number = "+84334441111";
+    let password = "12345678";
+
+    // The first admin is created by god
+    account_service
+        .service_client
+        .admin_create_account(AdminCreateAccountRequest {
+            actor_auth: ActorAuth::God,
+            e164_phone_number_str: admin_phone_number.into(),
+            password: password.into(),
+        })
+        .await?;
+
+    let customer = graphql::GraphqlClient::new(graphql_url.clone());
+    let customer_id =
+        graphql::sign_up_customer(&customer, &core_service.sms_receiver, "+84334445555").await?;
+
+    // Test admins can't be registered
+    let response = graphql::user_account_start_registration(
+        &customer,
+        "+84334442222",
+        graphql::user_account_start_registration::AccountType::ADMIN,
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "account_type",
+                "description": "ADMIN",
+            }]
+        })),
+    );
+
+    // Test admins can't sign in with OTP, owning the phone number isn't enough
+    let response = graphql::user_sign_in_with_otp_start(
+        &customer,
+        graphql::user_sign_in_with_otp_start::UserSignInWithOtpStartInput {
+            phone_number: admin_phone_number.into(),
+            account_type: graphql::user_sign_in_with_otp_start::AccountType::ADMIN,
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "account_type",
+                "description": "ADMIN",
+            }]
+        })),
+    );
+    let response = graphql::user_sign_in_with_otp_finish(
+        &customer,
+        graphql::user_sign_in_with_otp_finish::UserSignInWithOtpFinishInput {
+            phone_number: admin_phone_number.into(),
+            otp_code: "000000".into(),
+            account_type: graphql::user_sign_in_with_otp_finish::AccountType::ADMIN,
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "account_type",
+                "description": "ADMIN",
+            }]
+        })),
+    );
+
+    // Test admins can't reset their password with OTP
+    let response = graphql::user_password_reset_start(
+        &customer,
+        graphql::user_password_reset_start::UserPasswordResetStartInput {
+            phone_number: admin_phone_number.into(),
+            account_type: graphql::user_password_reset_start::AccountType::ADMIN,
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "account_type",
+                "description": "ADMIN",
+            }]
+        })),
+    );
+    let response = graphql::user_password_reset_finish(
+        &customer,
+        graphql::user_password_reset_finish::UserPasswordResetFinishInput {
+            phone_number: admin_phone_number.into(),
+            account_type: graphql::user_password_reset_finish::AccountType::ADMIN,
+            otp_code: "000000".into(),
+            new_password: "new-Password1".into(),
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "account_type",
+                "description": "ADMIN",
+            }]
+        })),
+    );
+
+    // Test users can't create admins
+    let response = graphql::admin_create_account(&customer, "+84334442222", password).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::PermissionDenied(None),
+        Some(serde_json::json!(null)),
+    );
+
+    let admin = graphql::GraphqlClient::new(graphql_url.clone());
+    graphql::user_sign_in_with_password(
+        &admin,
+        UserSignInWithPasswordInput {
+            phone_number: admin_phone_number.into(),
+            password: password.into(),
+            account_type: AccountType::ADMIN,
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    let session = graphql::session(&admin)
+        .await
+        .data
+        .unwrap()
+        .session
+        .unwrap();
+    assert_eq!(
+        session.actor_type.try_admin()?.phone_number,
+        admin_phone_number
+    );
+
+    // Test admins act on behalf of customers
+    let profile = graphql::customer_create_profile(
+        &admin,
+        graphql::customer_create_profile::CustomerCreateProfileInput {
+            customer_id: customer_id.clone(),
+            nick_name: "Moderated".into(),
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_create_profile
+    .customer
+    .profile
+    .unwrap();
+    assert_eq!(profile.nick_name, "Moderated");
+
+    // Test admins create other admins
+    let created = graphql::admin_create_account(&admin, "+84334442222", password)
+        .await
+        .data
+        .unwrap()
+        .admin_create_account
+        .admin;
+    assert_eq!(created.phone_number, "+84334442222");
+
+    Ok(())
+}
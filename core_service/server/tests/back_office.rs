@@ -0,0 +1,195 @@
+mod graphql;
+
+use error::{ErrorVariant, Result};
+use graphql::customer_create_profile::CustomerCreateProfileInput;
+use graphql::handyman_create_profile::HandymanCreateProfileInput;
+use graphql::handyman_profile_add_services::{HandymanProfileAddServicesInput, NewHandymanService};
+use graphql::moderate_customer_nick_name::ModerateCustomerNickNameInput;
+use graphql::moderate_handyman_name::{HandymanNameInput, ModerateHandymanNameInput};
+use graphql::moderate_handyman_service_note::ModerateHandymanServiceNoteInput;
+use reqwest::StatusCode;
+use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+
+#[tokio::test]
+async fn back_office_moderation() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let back_office_url = format!("http://{}/back-office/graphql", core_service.service_host);
+    let customer_phone_number = "+84334445555";
+    let handyman_phone_number = "+84334446666";
+
+    let customer = graphql::GraphqlClient::new(graphql_url.clone());
+    let customer_id =
+        graphql::sign_up_customer(&customer, &core_service.sms_receiver, customer_phone_number)
+            .await?;
+    graphql::customer_create_profile(
+        &customer,
+        CustomerCreateProfileInput {
+            customer_id: customer_id.clone(),
+            nick_name: "Abusive".into(),
+        },
+    )
+    .await
+    .data
+    .unwrap();
+
+    let handyman = graphql::GraphqlClient::new(graphql_url);
+    let handyman_id =
+        graphql::sign_up_handyman(&handyman, &core_service.sms_receiver, handyman_phone_number)
+            .await?;
+    graphql::handyman_create_profile(
+        &handyman,
+        HandymanCreateProfileInput {
+            handyman_id: handyman_id.clone(),
+            first_name: "Abusive".into(),
+            last_name: "Name".into(),
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    graphql::handyman_profile_add_services(
+        &handyman,
+        HandymanProfileAddServicesInput {
+            handyman_id: handyman_id.clone(),
+            services: vec![NewHandymanService {
+                service_code: Some("AIR_CONDITIONER_FIXING".into()),
+                service: None,
+                note: Some("Abusive note".into()),
+                rate_vnd: None,
+            }],
+        },
+    )
+    .await
+    .data
+    .unwrap();
+
+    // Test requests without a staff credential are rejected
+    for authorization in [None, Some("Bearer wrong-token")] {
+        let mut request = reqwest::Client::new()
+            .post(&back_office_url)
+            .json(&serde_json::json!({ "query": "{ __typename }" }));
+        if let Some(authorization) = authorization {
+            request = request.header("authorization", authorization);
+        }
+        let response = request.send().await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    let staff = graphql::back_office_client(back_office_url);
+
+    // Test looking up accounts by phone number
+    let found_customer = graphql::back_office_customer(&staff, customer_phone_number)
+        .await
+        .data
+        .unwrap()
+        .customer_by_phone_number
+        .unwrap();
+    assert_eq!(found_customer.id, customer_id);
+    assert_eq!(found_customer.profile.unwrap().nick_name, "Abusive");
+    let missing = graphql::back_office_customer(&staff, "+84334447777")
+        .await
+        .data
+        .unwrap()
+        .customer_by_phone_number;
+    assert!(missing.is_none());
+    let tasks = graphql::back_office_customer_task_requests(&staff, &customer_id)
+        .await
+        .data
+        .unwrap()
+        .customer_task_requests;
+    assert!(tasks.is_empty());
+
+    let found_handyman = graphql::back_office_handyman(&staff, handyman_phone_number)
+        .await
+        .data
+        .unwrap()
+        .handyman_by_phone_number
+        .unwrap();
+    assert_eq!(found_handyman.id, handyman_id);
+    let service = found_handyman
+        .profile
+        .unwrap()
+        .services
+        .pop()
+        .unwrap()
+        .services
+        .pop()
+        .unwrap();
+    assert_eq!(service.note.as_deref(), Some("Abusive note"));
+
+    // Test correcting and hiding profile names and service notes
+    let profile = graphql::moderate_customer_nick_name(
+        &staff,
+        ModerateCustomerNickNameInput {
+            customer_id: customer_id.clone(),
+            nick_name: Some("Corrected".into()),
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .moderate_customer_nick_name
+    .profile;
+    assert_eq!(profile.nick_name, "Corrected");
+
+    let response = graphql::moderate_handyman_name(
+        &staff,
+        ModerateHandymanNameInput {
+            handyman_id: handyman_id.clone(),
+            name: Some(HandymanNameInput {
+                first_name: "".into(),
+                last_name: "Name".into(),
+            }),
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "first_name",
+                "description": "EMPTY",
+            }]
+        })),
+    );
+
+    let profile = graphql::moderate_handyman_name(
+        &staff,
+        ModerateHandymanNameInput {
+            handyman_id: handyman_id.clone(),
+            name: None,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .moderate_handyman_name
+    .profile;
+    assert_eq!(
+        (profile.first_name.as_str(), profile.last_name.as_str()),
+        ("***", "***")
+    );
+
+    let service = graphql::moderate_handyman_service_note(
+        &staff,
+        ModerateHandymanServiceNoteInput {
+            handyman_id: handyman_id.clone(),
+            service_id: service.id,
+            note: None,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .moderate_handyman_service_note
+    .service;
+    assert!(service.note.is_none());
+
+    Ok(())
+}
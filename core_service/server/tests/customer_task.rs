@@ -0,0 +1,460 @@
+mod graphql;
+
+use chrono::{DateTime, NaiveTime, TimeDelta};
+use error::{ErrorVariant, Result};
+use graphql::customer_create_task::{
+    CustomerCreateTaskInput, CustomerTaskStatus, DailyRecurrence, FixedTime, GeoCoordinates,
+    LocationInput, ScheduleInput, ScheduleTimeInput, ScheduleType, ServiceLayer2,
+};
+use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+
+fn new_task_input(draft: bool) -> CustomerCreateTaskInput {
+    CustomerCreateTaskInput {
+        service_code: Some("AIR_CONDITIONER_FIXING".into()),
+        service: None,
+        title: "Sửa điều hòa".into(),
+        note: "Điều hòa không lạnh".into(),
+        schedule: schedule_input(ScheduleTimeInput {
+            fixed_time: Some(FixedTime {
+                time: DateTime::parse_from_rfc3339("2025-11-01T09:30:00+07:00").unwrap(),
+            }),
+            daily_recurrence: None,
+            weekly_recurrence: None,
+            timezone: None,
+        }),
+        draft: Some(draft),
+    }
+}
+
+fn schedule_input(time: ScheduleTimeInput) -> ScheduleInput {
+    ScheduleInput {
+        location: LocationInput {
+            city: "Hà Nội".into(),
+            address_line1: "1 Tràng Tiền".into(),
+            formatted_address: "1 Tràng Tiền, Hoàn Kiếm, Hà Nội".into(),
+            corrdinates: GeoCoordinates {
+                lon: 105.85,
+                lat: 21.02,
+            },
+        },
+        time,
+    }
+}
+
+#[tokio::test]
+async fn customer_create_task() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let client =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+
+    let customer_id =
+        graphql::sign_up_customer(&client, &core_service.sms_receiver, "+84334445555").await?;
+
+    // Test creating a task with fixed time schedule, returned with the schedule time zone offset
+    let time = DateTime::parse_from_rfc3339("2025-11-01T02:30:00Z").unwrap();
+    let task = graphql::customer_create_task(
+        &client,
+        CustomerCreateTaskInput {
+            service_code: Some("AIR_CONDITIONER_CLEANING".into()),
+            service: None,
+            title: "Vệ sinh điều hòa".into(),
+            note: "Phòng ngủ tầng 2".into(),
+            schedule: schedule_input(ScheduleTimeInput {
+                fixed_time: Some(FixedTime { time }),
+                daily_recurrence: None,
+                weekly_recurrence: None,
+                timezone: None,
+            }),
+            draft: None,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_create_task
+    .task;
+    assert_eq!(task.title, "Vệ sinh điều hòa");
+    assert_eq!(task.note.as_deref(), Some("Phòng ngủ tầng 2"));
+    assert!(matches!(
+        task.service.service_type,
+        Some(ServiceLayer2::AIR_CONDITIONER_CLEANING)
+    ));
+    assert_eq!(task.service.code, "AIR_CONDITIONER_CLEANING");
+    assert!(matches!(
+        task.schedule.schedule_type,
+        ScheduleType::FIXED_TIME
+    ));
+    assert_eq!(task.customer.id, customer_id);
+    let location = task.location.as_ref().unwrap();
+    assert_eq!(location.city, "Hà Nội");
+    assert_eq!(location.coordinates.lon, 105.85);
+    assert_eq!(location.coordinates.lat, 21.02);
+    assert!(matches!(task.status, CustomerTaskStatus::OPEN));
+    assert!(task.opened_at.is_some());
+    assert_eq!(task.schedule.timezone, "Asia/Ho_Chi_Minh");
+    let fixed_time = task.schedule.fixed_time.as_ref().unwrap().time;
+    assert_eq!(fixed_time, time);
+    assert_eq!(fixed_time.to_rfc3339(), "2025-11-01T09:30:00+07:00");
+    assert!(task.schedule.daily_recurrence.is_none());
+    // The fixed time is in the past
+    assert!(task.schedule.next_occurrences.is_empty());
+
+    // Test loading the task via node query
+    let node = graphql::customer_task_request_node(&client, &task.id)
+        .await
+        .data
+        .unwrap()
+        .node
+        .unwrap()
+        .try_customer_task_request()?;
+    assert_eq!(node.id, task.id);
+    assert_eq!(node.title, task.title);
+    assert_eq!(node.created_at, task.created_at);
+    assert_eq!(node.schedule.fixed_time.unwrap().time, time);
+
+    // Test creating a task with daily recurrence schedule
+    let times = vec![NaiveTime::from_hms_opt(8, 0, 0).unwrap()];
+    let task = graphql::customer_create_task(
+        &client,
+        CustomerCreateTaskInput {
+            service_code: Some("WASHING_MACHINE_CLEANING".into()),
+            service: None,
+            title: "Vệ sinh máy giặt".into(),
+            note: "".into(),
+            schedule: schedule_input(ScheduleTimeInput {
+                fixed_time: None,
+                daily_recurrence: Some(DailyRecurrence {
+                    times: times.clone(),
+                }),
+                weekly_recurrence: None,
+                timezone: None,
+            }),
+            draft: None,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_create_task
+    .task;
+    assert!(task.note.is_none());
+    assert_eq!(task.schedule.daily_recurrence.unwrap().times, times);
+    let occurrences = task.schedule.next_occurrences;
+    assert_eq!(occurrences.len(), 3);
+    for (occurrence, next) in occurrences.iter().zip(&occurrences[1..]) {
+        assert_eq!(*next - *occurrence, TimeDelta::days(1));
+    }
+    for occurrence in &occurrences {
+        assert_eq!(occurrence.offset().local_minus_utc(), 7 * 60 * 60);
+        assert_eq!(occurrence.time(), times[0]);
+    }
+
+    // Test recurrence times are wall clock times of the schedule time zone
+    let task = graphql::customer_create_task(
+        &client,
+        CustomerCreateTaskInput {
+            service_code: Some("WASHING_MACHINE_CLEANING".into()),
+            service: None,
+            title: "Vệ sinh máy giặt".into(),
+            note: "".into(),
+            schedule: schedule_input(ScheduleTimeInput {
+                fixed_time: None,
+                daily_recurrence: Some(DailyRecurrence {
+                    times: times.clone(),
+                }),
+                weekly_recurrence: None,
+                timezone: Some("America/New_York".into()),
+            }),
+            draft: None,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_create_task
+    .task;
+    assert_eq!(task.schedule.timezone, "America/New_York");
+    for occurrence in &task.schedule.next_occurrences {
+        // Eastern standard or daylight saving time
+        assert!([-5, -4].contains(&(occurrence.offset().local_minus_utc() / 3600)));
+        assert_eq!(occurrence.time(), times[0]);
+    }
+
+    // Test rejecting schedule with more than one time rule
+    let response = graphql::customer_create_task(
+        &client,
+        CustomerCreateTaskInput {
+            service_code: Some("WASHING_MACHINE_CLEANING".into()),
+            service: None,
+            title: "Vệ sinh máy giặt".into(),
+            note: "".into(),
+            schedule: schedule_input(ScheduleTimeInput {
+                fixed_time: Some(FixedTime { time }),
+                daily_recurrence: Some(DailyRecurrence { times }),
+                weekly_recurrence: None,
+                timezone: None,
+            }),
+            draft: None,
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "schedule.time",
+                "description": "REQUIRE_EXACTLY_ONE",
+            }]
+        })),
+    );
+
+    // Test rejecting invalid location coordinates
+    let mut input = new_task_input(false);
+    input.schedule.location.corrdinates.lat = 91.0;
+    let response = graphql::customer_create_task(&client, input).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "location.corrdinates.lat",
+                "description": "INVALID_LAT",
+            }]
+        })),
+    );
+
+    // Test rejecting service not in the catalog
+    let mut input = new_task_input(false);
+    input.service_code = Some("UNKNOWN_SERVICE".into());
+    let response = graphql::customer_create_task(&client, input).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "service_code",
+                "description": "UNKNOWN_SERVICE",
+            }]
+        })),
+    );
+
+    // Test clients predating the catalog still create tasks with the deprecated service enum
+    let mut input = new_task_input(false);
+    input.service_code = None;
+    input.service = Some(ServiceLayer2::WASHING_MACHINE_FIXING);
+    let task = graphql::customer_create_task(&client, input)
+        .await
+        .data
+        .unwrap()
+        .customer_create_task
+        .task;
+    assert_eq!(task.service.code, "WASHING_MACHINE_FIXING");
+
+    let mut input = new_task_input(false);
+    input.service_code = None;
+    let response = graphql::customer_create_task(&client, input).await;
+    graphql::assert_error_response(response, ErrorVariant::InvalidArgument(None), None);
+
+    // Test other customers can't read the task
+    let other_client =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    graphql::sign_up_customer(&other_client, &core_service.sms_receiver, "+84334446666").await?;
+    let response = graphql::customer_task_request_node(&other_client, &task.id).await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::NotFound(None).client_code()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn customer_task_lifecycle() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let customer_client =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    let handyman_client =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    graphql::sign_up_customer(&customer_client, &core_service.sms_receiver, "+84334445555").await?;
+    let handyman_id =
+        graphql::sign_up_handyman(&handyman_client, &core_service.sms_receiver, "+84334446666")
+            .await?;
+
+    // Test draft task is not opened until published
+    let task = graphql::customer_create_task(&customer_client, new_task_input(true))
+        .await
+        .data
+        .unwrap()
+        .customer_create_task
+        .task;
+    assert!(matches!(task.status, CustomerTaskStatus::DRAFT));
+    assert!(task.opened_at.is_none());
+
+    let task = graphql::customer_task_open(
+        &customer_client,
+        graphql::customer_task_open::CustomerTaskTransitionInput {
+            task_id: task.id.clone(),
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_task_open
+    .task;
+    assert!(matches!(
+        task.status,
+        graphql::customer_task_open::CustomerTaskStatus::OPEN
+    ));
+    assert!(task.opened_at.is_some());
+
+    // Test handyman can't start an unassigned task
+    let response = graphql::handyman_task_start(
+        &handyman_client,
+        graphql::handyman_task_start::CustomerTaskTransitionInput {
+            task_id: task.id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::PermissionDenied(None).client_code()
+    );
+
+    // Tasks are assigned by accepting a quote of the handyman
+    let quote_id = graphql::handyman_task_quote_submit(
+        &handyman_client,
+        graphql::handyman_task_quote_submit::HandymanTaskQuoteSubmitInput {
+            task_id: task.id.clone(),
+            handyman_id: handyman_id.clone(),
+            price_vnd: 500_000,
+            eta: DateTime::parse_from_rfc3339("2025-11-01T18:00:00+07:00").unwrap(),
+            message: None,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_task_quote_submit
+    .quote
+    .id;
+    graphql::customer_task_quote_accept(
+        &customer_client,
+        graphql::customer_task_quote_accept::CustomerTaskQuoteAcceptInput { quote_id },
+    )
+    .await
+    .data
+    .unwrap();
+    let task = graphql::customer_task_request_node(&customer_client, &task.id)
+        .await
+        .data
+        .unwrap()
+        .node
+        .unwrap()
+        .try_customer_task_request()?;
+    assert!(matches!(
+        task.status,
+        graphql::customer_task_request_node::CustomerTaskStatus::ASSIGNED
+    ));
+    assert_eq!(task.assigned_handyman.unwrap().id, handyman_id);
+    assert!(task.assigned_at.is_some());
+
+    // Test customer can't start the task on behalf of the handyman
+    let response = graphql::handyman_task_start(
+        &customer_client,
+        graphql::handyman_task_start::CustomerTaskTransitionInput {
+            task_id: task.id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::FailedPrecondition(None).client_code()
+    );
+
+    let task = graphql::handyman_task_start(
+        &handyman_client,
+        graphql::handyman_task_start::CustomerTaskTransitionInput {
+            task_id: task.id.clone(),
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_task_start
+    .task;
+    assert!(matches!(
+        task.status,
+        graphql::handyman_task_start::CustomerTaskStatus::IN_PROGRESS
+    ));
+    assert!(task.started_at.is_some());
+
+    // Test in progress task can't be cancelled
+    let response = graphql::customer_task_cancel(
+        &customer_client,
+        graphql::customer_task_cancel::CustomerTaskTransitionInput {
+            task_id: task.id.clone(),
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::FailedPrecondition(None),
+        Some(serde_json::json!({
+            "violations": [{
+                "type": "INVALID_STATUS_TRANSITION",
+                "subject": "status",
+                "description": "InProgress",
+            }]
+        })),
+    );
+
+    let task = graphql::handyman_task_complete(
+        &handyman_client,
+        graphql::handyman_task_complete::CustomerTaskTransitionInput {
+            task_id: task.id.clone(),
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_task_complete
+    .task;
+    assert!(matches!(
+        task.status,
+        graphql::handyman_task_complete::CustomerTaskStatus::COMPLETED
+    ));
+    assert!(task.completed_at.is_some());
+
+    // Test cancelling an open task
+    let task = graphql::customer_create_task(&customer_client, new_task_input(false))
+        .await
+        .data
+        .unwrap()
+        .customer_create_task
+        .task;
+    let task = graphql::customer_task_cancel(
+        &customer_client,
+        graphql::customer_task_cancel::CustomerTaskTransitionInput { task_id: task.id },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_task_cancel
+    .task;
+    assert!(matches!(
+        task.status,
+        graphql::customer_task_cancel::CustomerTaskStatus::CANCELLED
+    ));
+    assert!(task.cancelled_at.is_some());
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
 #![allow(dead_code)]
 
 use super::GraphqlClient;
-use chrono::NaiveDateTime;
+use chrono::FixedOffset;
 use error::{Error, Result};
 use graphql_client::{GraphQLQuery, Response};
+use sms_sender::TestSmsReceiver;
+
+type DateTime = chrono::DateTime<FixedOffset>;
 
 #[derive(GraphQLQuery)]
 #[graphql(
@@ -13,7 +16,7 @@ use graphql_client::{GraphQLQuery, Response};
 )]
 pub struct UserAccountStartRegistration;
 
-async fn user_account_start_registration(
+pub async fn user_account_start_registration(
     client: &GraphqlClient,
     phone_number: &str,
     account_type: user_account_start_registration::AccountType,
@@ -65,9 +68,7 @@ impl user_account_finish_registration::SessionFragmentActorType {
             user_account_finish_registration::SessionFragmentActorType::Customer(inner) => {
                 Ok(inner)
             }
-            user_account_finish_registration::SessionFragmentActorType::Handyman(_) => {
-                Err(Error::internal("Not a customer session"))
-            }
+            _ => Err(Error::internal("Not a customer session")),
         }
     }
 
@@ -75,12 +76,10 @@ impl user_account_finish_registration::SessionFragmentActorType {
         &self,
     ) -> Result<&user_account_finish_registration::SessionFragmentActorTypeOnHandyman> {
         match self {
-            user_account_finish_registration::SessionFragmentActorType::Customer(_) => {
-                Err(Error::internal("Not a handyman session"))
-            }
             user_account_finish_registration::SessionFragmentActorType::Handyman(inner) => {
                 Ok(inner)
             }
+            _ => Err(Error::internal("Not a handyman session")),
         }
     }
 }
@@ -188,3 +187,63 @@ pub async fn handyman_create_profile(
         .send_query::<HandymanCreateProfile>(handyman_create_profile::Variables { input4: input })
         .await
 }
+
+/// Register a customer account and return its global ID.
+/// The client keeps the new session afterward.
+pub async fn sign_up_customer(
+    client: &GraphqlClient,
+    sms_receiver: &TestSmsReceiver,
+    phone_number: &str,
+) -> Result<String> {
+    customer_account_start_registration(client, phone_number)
+        .await
+        .data
+        .unwrap()
+        .user_account_start_registration
+        .case
+        .try_otp_case()?;
+    let sms = sms_receiver.receive_sms().await.pop().unwrap();
+    let session = customer_account_finish_registration(
+        client,
+        UserAccountFinishRegistrationInput {
+            phone_number,
+            password: "12345678",
+            otp_code: &sms.message.try_otp_verification_for_registration()?.code,
+        },
+    )
+    .await
+    .data
+    .map(|d| d.user_account_finish_registration.session)
+    .unwrap();
+    Ok(session.actor_type.try_customer()?.id.clone())
+}
+
+/// Register a handyman account and return its global ID.
+/// The client keeps the new session afterward.
+pub async fn sign_up_handyman(
+    client: &GraphqlClient,
+    sms_receiver: &TestSmsReceiver,
+    phone_number: &str,
+) -> Result<String> {
+    handyman_account_start_registration(client, phone_number)
+        .await
+        .data
+        .unwrap()
+        .user_account_start_registration
+        .case
+        .try_otp_case()?;
+    let sms = sms_receiver.receive_sms().await.pop().unwrap();
+    let session = handyman_account_finish_registration(
+        client,
+        UserAccountFinishRegistrationInput {
+            phone_number,
+            password: "12345678",
+            otp_code: &sms.message.try_otp_verification_for_registration()?.code,
+        },
+    )
+    .await
+    .data
+    .map(|d| d.user_account_finish_registration.session)
+    .unwrap();
+    Ok(session.actor_type.try_handyman()?.id.clone())
+}
@@ -0,0 +1,92 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use graphql_client::{GraphQLQuery, Response};
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/account_update.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserChangePassword;
+
+pub async fn user_change_password(
+    client: &GraphqlClient,
+    old_password: &str,
+    new_password: &str,
+) -> Response<user_change_password::ResponseData> {
+    client
+        .send_query::<UserChangePassword>(user_change_password::Variables {
+            input1: user_change_password::UserChangePasswordInput {
+                old_password: old_password.into(),
+                new_password: new_password.into(),
+            },
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/account_update.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserPhoneNumberChangeStart;
+
+pub async fn user_phone_number_change_start(
+    client: &GraphqlClient,
+    phone_number: &str,
+) -> Response<user_phone_number_change_start::ResponseData> {
+    client
+        .send_query::<UserPhoneNumberChangeStart>(user_phone_number_change_start::Variables {
+            input2: user_phone_number_change_start::UserPhoneNumberChangeStartInput {
+                phone_number: phone_number.into(),
+            },
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/account_update.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserPhoneNumberChangeFinish;
+
+pub async fn user_phone_number_change_finish(
+    client: &GraphqlClient,
+    phone_number: &str,
+    otp_code: &str,
+) -> Response<user_phone_number_change_finish::ResponseData> {
+    client
+        .send_query::<UserPhoneNumberChangeFinish>(user_phone_number_change_finish::Variables {
+            input3: user_phone_number_change_finish::UserPhoneNumberChangeFinishInput {
+                phone_number: phone_number.into(),
+                otp_code: otp_code.into(),
+            },
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/account_update.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserDeleteAccount;
+
+pub async fn user_delete_account(
+    client: &GraphqlClient,
+    password: &str,
+) -> Response<user_delete_account::ResponseData> {
+    client
+        .send_query::<UserDeleteAccount>(user_delete_account::Variables {
+            input4: user_delete_account::UserDeleteAccountInput {
+                password: password.into(),
+            },
+        })
+        .await
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use graphql_client::{GraphQLQuery, Response};
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/admin_account.graphql",
+    response_derives = "Debug"
+)]
+pub struct AdminCreateAccount;
+
+pub async fn admin_create_account(
+    client: &GraphqlClient,
+    phone_number: &str,
+    password: &str,
+) -> Response<admin_create_account::ResponseData> {
+    client
+        .send_query::<AdminCreateAccount>(admin_create_account::Variables {
+            input1: admin_create_account::AdminCreateAccountInput {
+                phone_number: phone_number.into(),
+                password: password.into(),
+            },
+        })
+        .await
+}
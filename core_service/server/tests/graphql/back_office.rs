@@ -0,0 +1,225 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use graphql_client::{GraphQLQuery, Response};
+use std::collections::HashMap;
+use test_service_orchestration::core_service::TEST_STAFF_TOKEN;
+
+/// Client of the back-office API authenticated with the test staff token
+pub fn back_office_client(back_office_graphql_url: String) -> GraphqlClient {
+    GraphqlClient::new_with_custom_headers(
+        back_office_graphql_url,
+        HashMap::from([(
+            "authorization",
+            format!("Bearer {TEST_STAFF_TOKEN}").as_str(),
+        )]),
+    )
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../back_office_schema.graphql",
+    query_path = "tests/graphql/back_office.graphql",
+    response_derives = "Debug"
+)]
+pub struct BackOfficeCustomer;
+
+pub async fn back_office_customer(
+    client: &GraphqlClient,
+    phone_number: &str,
+) -> Response<back_office_customer::ResponseData> {
+    client
+        .send_query::<BackOfficeCustomer>(back_office_customer::Variables {
+            input1: phone_number.into(),
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../back_office_schema.graphql",
+    query_path = "tests/graphql/back_office.graphql",
+    response_derives = "Debug"
+)]
+pub struct BackOfficeHandyman;
+
+pub async fn back_office_handyman(
+    client: &GraphqlClient,
+    phone_number: &str,
+) -> Response<back_office_handyman::ResponseData> {
+    client
+        .send_query::<BackOfficeHandyman>(back_office_handyman::Variables {
+            input2: phone_number.into(),
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../back_office_schema.graphql",
+    query_path = "tests/graphql/back_office.graphql",
+    response_derives = "Debug"
+)]
+pub struct BackOfficeCustomerTaskRequests;
+
+pub async fn back_office_customer_task_requests(
+    client: &GraphqlClient,
+    customer_id: &str,
+) -> Response<back_office_customer_task_requests::ResponseData> {
+    client
+        .send_query::<BackOfficeCustomerTaskRequests>(
+            back_office_customer_task_requests::Variables {
+                input3: customer_id.into(),
+            },
+        )
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../back_office_schema.graphql",
+    query_path = "tests/graphql/back_office.graphql",
+    response_derives = "Debug"
+)]
+pub struct ModerateCustomerNickName;
+
+pub async fn moderate_customer_nick_name(
+    client: &GraphqlClient,
+    input: moderate_customer_nick_name::ModerateCustomerNickNameInput,
+) -> Response<moderate_customer_nick_name::ResponseData> {
+    client
+        .send_query::<ModerateCustomerNickName>(moderate_customer_nick_name::Variables {
+            input4: input,
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../back_office_schema.graphql",
+    query_path = "tests/graphql/back_office.graphql",
+    response_derives = "Debug"
+)]
+pub struct ModerateHandymanName;
+
+pub async fn moderate_handyman_name(
+    client: &GraphqlClient,
+    input: moderate_handyman_name::ModerateHandymanNameInput,
+) -> Response<moderate_handyman_name::ResponseData> {
+    client
+        .send_query::<ModerateHandymanName>(moderate_handyman_name::Variables { input5: input })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../back_office_schema.graphql",
+    query_path = "tests/graphql/back_office.graphql",
+    response_derives = "Debug"
+)]
+pub struct ModerateHandymanServiceNote;
+
+pub async fn moderate_handyman_service_note(
+    client: &GraphqlClient,
+    input: moderate_handyman_service_note::ModerateHandymanServiceNoteInput,
+) -> Response<moderate_handyman_service_note::ResponseData> {
+    client
+        .send_query::<ModerateHandymanServiceNote>(moderate_handyman_service_note::Variables {
+            input6: input,
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../back_office_schema.graphql",
+    query_path = "tests/graphql/back_office.graphql",
+    response_derives = "Debug"
+)]
+pub struct UpdateAccountStatus;
+
+pub async fn update_account_status(
+    client: &GraphqlClient,
+    account_id: &str,
+    status: update_account_status::AccountStatus,
+) -> Response<update_account_status::ResponseData> {
+    client
+        .send_query::<UpdateAccountStatus>(update_account_status::Variables {
+            input7: update_account_status::UpdateAccountStatusInput {
+                account_id: account_id.into(),
+                status,
+            },
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../back_office_schema.graphql",
+    query_path = "tests/graphql/back_office.graphql",
+    response_derives = "Debug"
+)]
+pub struct DeleteAccount;
+
+pub async fn delete_account(
+    client: &GraphqlClient,
+    account_id: &str,
+) -> Response<delete_account::ResponseData> {
+    client
+        .send_query::<DeleteAccount>(delete_account::Variables {
+            input8: delete_account::DeleteAccountInput {
+                account_id: account_id.into(),
+            },
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../back_office_schema.graphql",
+    query_path = "tests/graphql/back_office.graphql",
+    response_derives = "Debug"
+)]
+pub struct UpsertServiceGroup;
+
+pub async fn upsert_service_group(
+    client: &GraphqlClient,
+    input: upsert_service_group::UpsertServiceGroupInput,
+) -> Response<upsert_service_group::ResponseData> {
+    client
+        .send_query::<UpsertServiceGroup>(upsert_service_group::Variables { input9: input })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../back_office_schema.graphql",
+    query_path = "tests/graphql/back_office.graphql",
+    response_derives = "Debug"
+)]
+pub struct UpsertService;
+
+pub async fn upsert_service(
+    client: &GraphqlClient,
+    input: upsert_service::UpsertServiceInput,
+) -> Response<upsert_service::ResponseData> {
+    client
+        .send_query::<UpsertService>(upsert_service::Variables { input10: input })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../back_office_schema.graphql",
+    query_path = "tests/graphql/back_office.graphql",
+    response_derives = "Debug"
+)]
+pub struct RefreshServiceRateStats;
+
+pub async fn refresh_service_rate_stats(
+    client: &GraphqlClient,
+) -> Response<refresh_service_rate_stats::ResponseData> {
+    client
+        .send_query::<RefreshServiceRateStats>(refresh_service_rate_stats::Variables {})
+        .await
+}
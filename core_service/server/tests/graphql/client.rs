@@ -5,8 +5,9 @@ use graphql_client::{GraphQLQuery, Response};
 use headers::{Cookie, Header};
 use reqwest::{
     Client, StatusCode,
-    header::{COOKIE, HeaderMap, HeaderValue, ORIGIN, SET_COOKIE, USER_AGENT},
+    header::{CONTENT_TYPE, COOKIE, HeaderMap, HeaderValue, ORIGIN, SET_COOKIE, USER_AGENT},
 };
+use serde::de::DeserializeOwned;
 use service_http::{ACCESS_TOKEN_COOKIE_KEY, CSRF_TOKEN_COOKIE_KEY, CSRF_TOKEN_HEADER_KEY};
 use std::{collections::HashMap, str::FromStr};
 use test_service_orchestration::core_service::TEST_ORIGIN;
@@ -18,6 +19,14 @@ pub struct SessionTokens {
     pub csrf_token: String,
 }
 
+/// File sent with [`GraphqlClient::send_upload`]
+#[derive(Debug, Clone)]
+pub struct UploadFile {
+    pub filename: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
 pub struct GraphqlClient {
     client: Client,
     graphql_url: String,
@@ -124,6 +133,64 @@ impl GraphqlClient {
             .await
             .unwrap();
 
+        self.receive_response(response).await
+    }
+
+    /// Send the operation as a GraphQL multipart request, the file is bound to the `Upload`
+    /// variable at `variable_path`, e.g. `input1.file`.
+    /// See <https://github.com/jaydenseric/graphql-multipart-request-spec>
+    pub async fn send_upload<Q>(
+        &self,
+        variables: Q::Variables,
+        variable_path: &str,
+        file: UploadFile,
+    ) -> Response<Q::ResponseData>
+    where
+        Q: GraphQLQuery,
+    {
+        const BOUNDARY: &str = "graphql-upload-boundary";
+        let operations = serde_json::to_string(&Q::build_query(variables)).unwrap();
+        let map = serde_json::json!({ "0": [format!("variables.{variable_path}")] }).to_string();
+
+        let mut body = Vec::new();
+        for (name, value) in [("operations", operations), ("map", map)] {
+            body.extend_from_slice(
+                format!(
+                    "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+                )
+                .as_bytes(),
+            );
+        }
+        body.extend_from_slice(
+            format!(
+                "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"0\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+                file.filename, file.content_type
+            )
+            .as_bytes(),
+        );
+        body.extend_from_slice(&file.data);
+        body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+
+        let response = self
+            .client
+            .post(&self.graphql_url)
+            .headers(self.session_headers().await)
+            .header(
+                CONTENT_TYPE,
+                format!("multipart/form-data; boundary={BOUNDARY}"),
+            )
+            .body(body)
+            .send()
+            .await
+            .unwrap();
+
+        self.receive_response(response).await
+    }
+
+    async fn receive_response<D>(&self, response: reqwest::Response) -> Response<D>
+    where
+        D: DeserializeOwned,
+    {
         if response.status() != StatusCode::OK {
             panic!("Failed to make request to graphql server");
         }
@@ -147,6 +214,6 @@ impl GraphqlClient {
             }
         }
 
-        response.json::<Response<Q::ResponseData>>().await.unwrap()
+        response.json::<Response<D>>().await.unwrap()
     }
 }
@@ -0,0 +1,161 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use chrono::{FixedOffset, NaiveTime};
+use error::{Error, Result};
+use graphql_client::{GraphQLQuery, Response};
+
+type DateTime = chrono::DateTime<FixedOffset>;
+type TimeZone = String;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/customer_task.graphql",
+    response_derives = "Debug"
+)]
+pub struct CustomerCreateTask;
+
+pub async fn customer_create_task(
+    client: &GraphqlClient,
+    input: customer_create_task::CustomerCreateTaskInput,
+) -> Response<customer_create_task::ResponseData> {
+    client
+        .send_query::<CustomerCreateTask>(customer_create_task::Variables { input1: input })
+        .await
+}
+
+/// An open task with a fixed time in Hà Nội
+pub fn new_task_input() -> customer_create_task::CustomerCreateTaskInput {
+    use customer_create_task::{
+        CustomerCreateTaskInput, FixedTime, GeoCoordinates, LocationInput, ScheduleInput,
+        ScheduleTimeInput,
+    };
+
+    CustomerCreateTaskInput {
+        service_code: Some("AIR_CONDITIONER_FIXING".into()),
+        service: None,
+        title: "Sửa điều hòa".into(),
+        note: "".into(),
+        schedule: ScheduleInput {
+            location: LocationInput {
+                city: "Hà Nội".into(),
+                address_line1: "1 Tràng Tiền".into(),
+                formatted_address: "1 Tràng Tiền, Hoàn Kiếm, Hà Nội".into(),
+                corrdinates: GeoCoordinates {
+                    lon: 105.85,
+                    lat: 21.02,
+                },
+            },
+            time: ScheduleTimeInput {
+                fixed_time: Some(FixedTime {
+                    time: DateTime::parse_from_rfc3339("2025-11-01T09:30:00+07:00").unwrap(),
+                }),
+                daily_recurrence: None,
+                weekly_recurrence: None,
+                timezone: None,
+            },
+        },
+        draft: None,
+    }
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/customer_task.graphql",
+    response_derives = "Debug"
+)]
+pub struct CustomerTaskRequestNode;
+
+pub async fn customer_task_request_node(
+    client: &GraphqlClient,
+    id: &str,
+) -> Response<customer_task_request_node::ResponseData> {
+    client
+        .send_query::<CustomerTaskRequestNode>(customer_task_request_node::Variables {
+            input2: id.into(),
+        })
+        .await
+}
+
+impl customer_task_request_node::CustomerTaskRequestNodeNode {
+    pub fn try_customer_task_request(
+        self,
+    ) -> Result<customer_task_request_node::CustomerTaskRequestFragment> {
+        match self {
+            customer_task_request_node::CustomerTaskRequestNodeNode::CustomerTaskRequest(inner) => {
+                Ok(inner)
+            }
+            _ => Err(Error::internal("Not a customer task request node")),
+        }
+    }
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/customer_task.graphql",
+    response_derives = "Debug"
+)]
+pub struct CustomerTaskOpen;
+
+pub async fn customer_task_open(
+    client: &GraphqlClient,
+    input: customer_task_open::CustomerTaskTransitionInput,
+) -> Response<customer_task_open::ResponseData> {
+    client
+        .send_query::<CustomerTaskOpen>(customer_task_open::Variables { input3: input })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/customer_task.graphql",
+    response_derives = "Debug"
+)]
+pub struct CustomerTaskCancel;
+
+pub async fn customer_task_cancel(
+    client: &GraphqlClient,
+    input: customer_task_cancel::CustomerTaskTransitionInput,
+) -> Response<customer_task_cancel::ResponseData> {
+    client
+        .send_query::<CustomerTaskCancel>(customer_task_cancel::Variables { input5: input })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/customer_task.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanTaskStart;
+
+pub async fn handyman_task_start(
+    client: &GraphqlClient,
+    input: handyman_task_start::CustomerTaskTransitionInput,
+) -> Response<handyman_task_start::ResponseData> {
+    client
+        .send_query::<HandymanTaskStart>(handyman_task_start::Variables { input6: input })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/customer_task.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanTaskComplete;
+
+pub async fn handyman_task_complete(
+    client: &GraphqlClient,
+    input: handyman_task_complete::CustomerTaskTransitionInput,
+) -> Response<handyman_task_complete::ResponseData> {
+    client
+        .send_query::<HandymanTaskComplete>(handyman_task_complete::Variables { input7: input })
+        .await
+}
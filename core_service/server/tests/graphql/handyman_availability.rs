@@ -0,0 +1,65 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use chrono::{FixedOffset, NaiveDate, NaiveTime};
+use graphql_client::{GraphQLQuery, Response};
+
+type DateTime = chrono::DateTime<FixedOffset>;
+type TimeZone = String;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/handyman_availability.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanProfileSetWorkingHours;
+
+pub async fn handyman_profile_set_working_hours(
+    client: &GraphqlClient,
+    input: handyman_profile_set_working_hours::HandymanProfileSetWorkingHoursInput,
+) -> Response<handyman_profile_set_working_hours::ResponseData> {
+    client
+        .send_query::<HandymanProfileSetWorkingHours>(
+            handyman_profile_set_working_hours::Variables { input1: input },
+        )
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/handyman_availability.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanProfileAddBlackout;
+
+pub async fn handyman_profile_add_blackout(
+    client: &GraphqlClient,
+    input: handyman_profile_add_blackout::HandymanProfileAddBlackoutInput,
+) -> Response<handyman_profile_add_blackout::ResponseData> {
+    client
+        .send_query::<HandymanProfileAddBlackout>(handyman_profile_add_blackout::Variables {
+            input2: input,
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/handyman_availability.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanProfileRemoveBlackout;
+
+pub async fn handyman_profile_remove_blackout(
+    client: &GraphqlClient,
+    input: handyman_profile_remove_blackout::HandymanProfileRemoveBlackoutInput,
+) -> Response<handyman_profile_remove_blackout::ResponseData> {
+    client
+        .send_query::<HandymanProfileRemoveBlackout>(handyman_profile_remove_blackout::Variables {
+            input3: input,
+        })
+        .await
+}
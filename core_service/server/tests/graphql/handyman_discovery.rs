@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use chrono::FixedOffset;
+use graphql_client::{GraphQLQuery, Response};
+
+type DateTime = chrono::DateTime<FixedOffset>;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/handyman_discovery.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanSearch;
+
+pub async fn handyman_search(
+    client: &GraphqlClient,
+    filter: handyman_search::HandymanSearchFilter,
+    paging_config: handyman_search::PagingOffsetInput,
+) -> Response<handyman_search::ResponseData> {
+    client
+        .send_query::<HandymanSearch>(handyman_search::Variables {
+            input1: filter,
+            input2: paging_config,
+        })
+        .await
+}
@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use graphql_client::{GraphQLQuery, Response};
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/handyman_profile.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanProfileAddServices;
+
+pub async fn handyman_profile_add_services(
+    client: &GraphqlClient,
+    input: handyman_profile_add_services::HandymanProfileAddServicesInput,
+) -> Response<handyman_profile_add_services::ResponseData> {
+    client
+        .send_query::<HandymanProfileAddServices>(handyman_profile_add_services::Variables {
+            input1: input,
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/handyman_profile.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanProfileSetServiceLocation;
+
+pub async fn handyman_profile_set_service_location(
+    client: &GraphqlClient,
+    input: handyman_profile_set_service_location::HandymanProfileSetServiceLocationInput,
+) -> Response<handyman_profile_set_service_location::ResponseData> {
+    client
+        .send_query::<HandymanProfileSetServiceLocation>(
+            handyman_profile_set_service_location::Variables { input2: input },
+        )
+        .await
+}
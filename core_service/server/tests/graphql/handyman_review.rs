@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use error::{Error, Result};
+use graphql_client::{GraphQLQuery, Response};
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/handyman_review.graphql",
+    response_derives = "Debug"
+)]
+pub struct CustomerTaskReview;
+
+pub async fn customer_task_review(
+    client: &GraphqlClient,
+    input: customer_task_review::CustomerTaskReviewInput,
+) -> Response<customer_task_review::ResponseData> {
+    client
+        .send_query::<CustomerTaskReview>(customer_task_review::Variables { input1: input })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/handyman_review.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanReviews;
+
+pub async fn handyman_reviews(
+    client: &GraphqlClient,
+    handyman_id: &str,
+) -> Response<handyman_reviews::ResponseData> {
+    client
+        .send_query::<HandymanReviews>(handyman_reviews::Variables {
+            input2: handyman_id.into(),
+        })
+        .await
+}
+
+impl handyman_reviews::HandymanReviewsNode {
+    pub fn try_profile(self) -> Result<handyman_reviews::HandymanReviewsNodeOnHandymanProfile> {
+        match self {
+            handyman_reviews::HandymanReviewsNode::Handyman(inner) => inner
+                .profile
+                .ok_or_else(|| Error::internal("Handyman has no profile")),
+            _ => Err(Error::internal("Not a handyman node")),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+#![allow(dead_code)]
+
+use super::{GraphqlClient, UploadFile};
+use graphql_client::{GraphQLQuery, Response};
+
+/// Uploaded files are sent as multipart parts, the variable itself is null
+pub type Upload = ();
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/image_upload.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanUploadAvatar;
+
+pub async fn handyman_upload_avatar(
+    client: &GraphqlClient,
+    handyman_id: String,
+    file: UploadFile,
+) -> Response<handyman_upload_avatar::ResponseData> {
+    client
+        .send_upload::<HandymanUploadAvatar>(
+            handyman_upload_avatar::Variables {
+                input1: handyman_upload_avatar::HandymanUploadAvatarInput {
+                    handyman_id,
+                    file: (),
+                },
+            },
+            "input1.file",
+            file,
+        )
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/image_upload.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanRemoveAvatar;
+
+pub async fn handyman_remove_avatar(
+    client: &GraphqlClient,
+    input: handyman_remove_avatar::HandymanRemoveAvatarInput,
+) -> Response<handyman_remove_avatar::ResponseData> {
+    client
+        .send_query::<HandymanRemoveAvatar>(handyman_remove_avatar::Variables { input2: input })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/image_upload.graphql",
+    response_derives = "Debug"
+)]
+pub struct CustomerAddTaskPhoto;
+
+pub async fn customer_add_task_photo(
+    client: &GraphqlClient,
+    task_id: String,
+    file: UploadFile,
+) -> Response<customer_add_task_photo::ResponseData> {
+    client
+        .send_upload::<CustomerAddTaskPhoto>(
+            customer_add_task_photo::Variables {
+                input3: customer_add_task_photo::CustomerAddTaskPhotoInput { task_id, file: () },
+            },
+            "input3.file",
+            file,
+        )
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/image_upload.graphql",
+    response_derives = "Debug"
+)]
+pub struct CustomerRemoveTaskPhoto;
+
+pub async fn customer_remove_task_photo(
+    client: &GraphqlClient,
+    input: customer_remove_task_photo::CustomerRemoveTaskPhotoInput,
+) -> Response<customer_remove_task_photo::ResponseData> {
+    client
+        .send_query::<CustomerRemoveTaskPhoto>(customer_remove_task_photo::Variables {
+            input4: input,
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/image_upload.graphql",
+    response_derives = "Debug"
+)]
+pub struct TaskPhotos;
+
+pub async fn task_photos(
+    client: &GraphqlClient,
+    task_id: String,
+) -> Response<task_photos::ResponseData> {
+    client
+        .send_query::<TaskPhotos>(task_photos::Variables { input5: task_id })
+        .await
+}
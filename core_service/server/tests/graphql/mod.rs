@@ -5,7 +5,69 @@ mod assert_response_error;
 pub use assert_response_error::*;
 
 mod session;
+#[allow(unused_imports)]
 pub use session::*;
 
 mod account_registration;
+#[allow(unused_imports)]
 pub use account_registration::*;
+
+mod customer_task;
+#[allow(unused_imports)]
+pub use customer_task::*;
+
+mod task_quote;
+#[allow(unused_imports)]
+pub use task_quote::*;
+
+mod handyman_profile;
+#[allow(unused_imports)]
+pub use handyman_profile::*;
+
+mod task_discovery;
+#[allow(unused_imports)]
+pub use task_discovery::*;
+
+mod handyman_discovery;
+#[allow(unused_imports)]
+pub use handyman_discovery::*;
+
+mod handyman_review;
+#[allow(unused_imports)]
+pub use handyman_review::*;
+
+mod user_session;
+#[allow(unused_imports)]
+pub use user_session::*;
+
+mod password_reset;
+#[allow(unused_imports)]
+pub use password_reset::*;
+
+mod account_update;
+#[allow(unused_imports)]
+pub use account_update::*;
+
+mod admin_account;
+#[allow(unused_imports)]
+pub use admin_account::*;
+
+mod back_office;
+#[allow(unused_imports)]
+pub use back_office::*;
+
+mod profile_update;
+#[allow(unused_imports)]
+pub use profile_update::*;
+
+mod image_upload;
+#[allow(unused_imports)]
+pub use image_upload::*;
+
+mod service_catalog;
+#[allow(unused_imports)]
+pub use service_catalog::*;
+
+mod handyman_availability;
+#[allow(unused_imports)]
+pub use handyman_availability::*;
@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use graphql_client::{GraphQLQuery, Response};
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/password_reset.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserPasswordResetStart;
+
+pub async fn user_password_reset_start(
+    client: &GraphqlClient,
+    input: user_password_reset_start::UserPasswordResetStartInput,
+) -> Response<user_password_reset_start::ResponseData> {
+    client
+        .send_query::<UserPasswordResetStart>(user_password_reset_start::Variables {
+            input1: input,
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/password_reset.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserPasswordResetFinish;
+
+pub async fn user_password_reset_finish(
+    client: &GraphqlClient,
+    input: user_password_reset_finish::UserPasswordResetFinishInput,
+) -> Response<user_password_reset_finish::ResponseData> {
+    client
+        .send_query::<UserPasswordResetFinish>(user_password_reset_finish::Variables {
+            input2: input,
+        })
+        .await
+}
@@ -0,0 +1,38 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use graphql_client::{GraphQLQuery, Response};
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/profile_update.graphql",
+    response_derives = "Debug"
+)]
+pub struct CustomerUpdateProfile;
+
+pub async fn customer_update_profile(
+    client: &GraphqlClient,
+    input: customer_update_profile::CustomerUpdateProfileInput,
+) -> Response<customer_update_profile::ResponseData> {
+    client
+        .send_query::<CustomerUpdateProfile>(customer_update_profile::Variables { input1: input })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/profile_update.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanUpdateProfile;
+
+pub async fn handyman_update_profile(
+    client: &GraphqlClient,
+    input: handyman_update_profile::HandymanUpdateProfileInput,
+) -> Response<handyman_update_profile::ResponseData> {
+    client
+        .send_query::<HandymanUpdateProfile>(handyman_update_profile::Variables { input2: input })
+        .await
+}
@@ -0,0 +1,58 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use graphql_client::{GraphQLQuery, Response};
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/service_catalog.graphql",
+    response_derives = "Debug"
+)]
+pub struct ServiceGroups;
+
+pub async fn service_groups(client: &GraphqlClient) -> Response<service_groups::ResponseData> {
+    client
+        .send_query::<ServiceGroups>(service_groups::Variables {})
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/service_catalog.graphql",
+    response_derives = "Debug"
+)]
+pub struct CatalogService;
+
+pub async fn catalog_service(
+    client: &GraphqlClient,
+    code: &str,
+) -> Response<catalog_service::ResponseData> {
+    client
+        .send_query::<CatalogService>(catalog_service::Variables {
+            input1: code.into(),
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/service_catalog.graphql",
+    response_derives = "Debug"
+)]
+pub struct ServiceRate;
+
+pub async fn service_rate(
+    client: &GraphqlClient,
+    code: &str,
+    city: Option<&str>,
+) -> Response<service_rate::ResponseData> {
+    client
+        .send_query::<ServiceRate>(service_rate::Variables {
+            input2: code.into(),
+            input3: city.map(Into::into),
+        })
+        .await
+}
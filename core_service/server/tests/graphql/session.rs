@@ -1,10 +1,12 @@
 #![allow(dead_code)]
 
 use super::GraphqlClient;
-use chrono::NaiveDateTime;
+use chrono::FixedOffset;
 use error::{Error, Result};
 use graphql_client::{GraphQLQuery, Response};
 
+type DateTime = chrono::DateTime<FixedOffset>;
+
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "../../schema.graphql",
@@ -21,18 +23,21 @@ impl session::SessionFragmentActorType {
     pub fn try_customer(&self) -> Result<&session::SessionFragmentActorTypeOnCustomer> {
         match self {
             session::SessionFragmentActorType::Customer(inner) => Ok(inner),
-            session::SessionFragmentActorType::Handyman(_) => {
-                Err(Error::internal("Not a customer session"))
-            }
+            _ => Err(Error::internal("Not a customer session")),
         }
     }
 
     pub fn try_handyman(&self) -> Result<&session::SessionFragmentActorTypeOnHandyman> {
         match self {
-            session::SessionFragmentActorType::Customer(_) => {
-                Err(Error::internal("Not a handyman session"))
-            }
             session::SessionFragmentActorType::Handyman(inner) => Ok(inner),
+            _ => Err(Error::internal("Not a handyman session")),
+        }
+    }
+
+    pub fn try_admin(&self) -> Result<&session::SessionFragmentActorTypeOnAdmin> {
+        match self {
+            session::SessionFragmentActorType::Admin(inner) => Ok(inner),
+            _ => Err(Error::internal("Not an admin session")),
         }
     }
 }
@@ -55,3 +60,41 @@ pub async fn user_sign_in_with_password(
         })
         .await
 }
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/session.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserSignInWithOtpStart;
+
+pub async fn user_sign_in_with_otp_start(
+    client: &GraphqlClient,
+    input: user_sign_in_with_otp_start::UserSignInWithOtpStartInput,
+) -> Response<user_sign_in_with_otp_start::ResponseData> {
+    client
+        .send_query::<UserSignInWithOtpStart>(user_sign_in_with_otp_start::Variables {
+            input2: input,
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/session.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserSignInWithOtpFinish;
+
+pub async fn user_sign_in_with_otp_finish(
+    client: &GraphqlClient,
+    input: user_sign_in_with_otp_finish::UserSignInWithOtpFinishInput,
+) -> Response<user_sign_in_with_otp_finish::ResponseData> {
+    client
+        .send_query::<UserSignInWithOtpFinish>(user_sign_in_with_otp_finish::Variables {
+            input3: input,
+        })
+        .await
+}
@@ -0,0 +1,50 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use graphql_client::{GraphQLQuery, Response};
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/task_discovery.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanNearbyTasks;
+
+pub async fn handyman_nearby_tasks(
+    client: &GraphqlClient,
+    handyman_id: &str,
+    distance_within: handyman_nearby_tasks::DistanceWithinInput,
+    paging_config: handyman_nearby_tasks::PagingOffsetInput,
+) -> Response<handyman_nearby_tasks::ResponseData> {
+    client
+        .send_query::<HandymanNearbyTasks>(handyman_nearby_tasks::Variables {
+            input1: handyman_id.into(),
+            input2: distance_within,
+            input3: paging_config,
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/task_discovery.graphql",
+    response_derives = "Debug"
+)]
+pub struct CustomerTaskMatchingHandymen;
+
+pub async fn customer_task_matching_handymen(
+    client: &GraphqlClient,
+    task_id: &str,
+    within_meters: f64,
+    paging_config: customer_task_matching_handymen::PagingOffsetInput,
+) -> Response<customer_task_matching_handymen::ResponseData> {
+    client
+        .send_query::<CustomerTaskMatchingHandymen>(customer_task_matching_handymen::Variables {
+            input4: task_id.into(),
+            input5: within_meters,
+            input6: paging_config,
+        })
+        .await
+}
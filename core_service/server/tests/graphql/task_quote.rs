@@ -0,0 +1,95 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use chrono::FixedOffset;
+use error::{Error, Result};
+use graphql_client::{GraphQLQuery, Response};
+
+type DateTime = chrono::DateTime<FixedOffset>;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/task_quote.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanTaskQuoteSubmit;
+
+pub async fn handyman_task_quote_submit(
+    client: &GraphqlClient,
+    input: handyman_task_quote_submit::HandymanTaskQuoteSubmitInput,
+) -> Response<handyman_task_quote_submit::ResponseData> {
+    client
+        .send_query::<HandymanTaskQuoteSubmit>(handyman_task_quote_submit::Variables {
+            input1: input,
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/task_quote.graphql",
+    response_derives = "Debug"
+)]
+pub struct HandymanTaskQuoteWithdraw;
+
+pub async fn handyman_task_quote_withdraw(
+    client: &GraphqlClient,
+    input: handyman_task_quote_withdraw::HandymanTaskQuoteWithdrawInput,
+) -> Response<handyman_task_quote_withdraw::ResponseData> {
+    client
+        .send_query::<HandymanTaskQuoteWithdraw>(handyman_task_quote_withdraw::Variables {
+            input2: input,
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/task_quote.graphql",
+    response_derives = "Debug"
+)]
+pub struct CustomerTaskQuoteAccept;
+
+pub async fn customer_task_quote_accept(
+    client: &GraphqlClient,
+    input: customer_task_quote_accept::CustomerTaskQuoteAcceptInput,
+) -> Response<customer_task_quote_accept::ResponseData> {
+    client
+        .send_query::<CustomerTaskQuoteAccept>(customer_task_quote_accept::Variables {
+            input3: input,
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/task_quote.graphql",
+    response_derives = "Debug"
+)]
+pub struct CustomerTaskQuotes;
+
+pub async fn customer_task_quotes(
+    client: &GraphqlClient,
+    task_id: &str,
+) -> Response<customer_task_quotes::ResponseData> {
+    client
+        .send_query::<CustomerTaskQuotes>(customer_task_quotes::Variables {
+            input4: task_id.into(),
+        })
+        .await
+}
+
+impl customer_task_quotes::CustomerTaskQuotesNode {
+    pub fn try_quotes(self) -> Result<Vec<customer_task_quotes::TaskQuoteFragment>> {
+        match self {
+            customer_task_quotes::CustomerTaskQuotesNode::CustomerTaskRequest(inner) => {
+                Ok(inner.quotes)
+            }
+            _ => Err(Error::internal("Not a customer task request node")),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+#![allow(dead_code)]
+
+use super::GraphqlClient;
+use chrono::FixedOffset;
+use graphql_client::{GraphQLQuery, Response};
+
+type DateTime = chrono::DateTime<FixedOffset>;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/user_session.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserSessions;
+
+pub async fn user_sessions(client: &GraphqlClient) -> Response<user_sessions::ResponseData> {
+    client
+        .send_query::<UserSessions>(user_sessions::Variables {})
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/user_session.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserSignOut;
+
+pub async fn user_sign_out(client: &GraphqlClient) -> Response<user_sign_out::ResponseData> {
+    client
+        .send_query::<UserSignOut>(user_sign_out::Variables {})
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/user_session.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserRenewSession;
+
+pub async fn user_renew_session(
+    client: &GraphqlClient,
+) -> Response<user_renew_session::ResponseData> {
+    client
+        .send_query::<UserRenewSession>(user_renew_session::Variables {})
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/user_session.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserRevokeSession;
+
+pub async fn user_revoke_session(
+    client: &GraphqlClient,
+    session_id: String,
+) -> Response<user_revoke_session::ResponseData> {
+    client
+        .send_query::<UserRevokeSession>(user_revoke_session::Variables {
+            input1: user_revoke_session::UserRevokeSessionInput { session_id },
+        })
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/user_session.graphql",
+    response_derives = "Debug"
+)]
+pub struct UserRevokeAllSessions;
+
+pub async fn user_revoke_all_sessions(
+    client: &GraphqlClient,
+) -> Response<user_revoke_all_sessions::ResponseData> {
+    client
+        .send_query::<UserRevokeAllSessions>(user_revoke_all_sessions::Variables {})
+        .await
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "../../schema.graphql",
+    query_path = "tests/graphql/user_session.graphql",
+    response_derives = "Debug"
+)]
+pub struct SwitchActiveRole;
+
+pub async fn switch_active_role(
+    client: &GraphqlClient,
+) -> Response<switch_active_role::ResponseData> {
+    client
+        .send_query::<SwitchActiveRole>(switch_active_role::Variables {})
+        .await
+}
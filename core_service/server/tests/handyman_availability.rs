@@ -0,0 +1,301 @@
+mod graphql;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime};
+use error::{ErrorVariant, Result};
+use graphql::handyman_profile_add_blackout::HandymanProfileAddBlackoutInput;
+use graphql::handyman_profile_remove_blackout::HandymanProfileRemoveBlackoutInput;
+use graphql::handyman_profile_set_working_hours::{
+    DailyRecurrence, FixedTime, HandymanProfileSetWorkingHoursInput, ScheduleTimeInput,
+    ScheduleType, Weekday, WeekdayTime, WeeklyRecurrence,
+};
+use graphql::handyman_search::{HandymanSearchFilter, PagingOffsetInput};
+use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+
+fn time(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
+
+/// Time in Vietnam, the default schedule time zone, on a date of the week of Monday 2026-01-05
+fn at(day: u32, hour: u32, minute: u32) -> DateTime<FixedOffset> {
+    NaiveDate::from_ymd_opt(2026, 1, day)
+        .unwrap()
+        .and_time(time(hour, minute))
+        .and_local_timezone(FixedOffset::east_opt(7 * 60 * 60).unwrap())
+        .unwrap()
+}
+
+fn daily(times: Vec<NaiveTime>) -> ScheduleTimeInput {
+    ScheduleTimeInput {
+        fixed_time: None,
+        daily_recurrence: Some(DailyRecurrence { times }),
+        weekly_recurrence: None,
+        timezone: None,
+    }
+}
+
+async fn search_available_at(
+    client: &graphql::GraphqlClient,
+    available_at: DateTime<FixedOffset>,
+) -> Vec<String> {
+    graphql::handyman_search(
+        client,
+        HandymanSearchFilter {
+            service_codes: None,
+            services: None,
+            name: None,
+            ids: None,
+            distance_within: None,
+            available_at: Some(available_at),
+        },
+        PagingOffsetInput {
+            page: 1,
+            page_size: 12,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_search
+    .items
+    .into_iter()
+    .map(|i| i.id)
+    .collect()
+}
+
+#[tokio::test]
+async fn handyman_availability() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let client1 = graphql::GraphqlClient::new(graphql_url.clone());
+    let client2 = graphql::GraphqlClient::new(graphql_url.clone());
+    let client3 = graphql::GraphqlClient::new(graphql_url);
+    let handyman1_id =
+        graphql::sign_up_handyman(&client1, &core_service.sms_receiver, "+84335551111").await?;
+    let handyman2_id =
+        graphql::sign_up_handyman(&client2, &core_service.sms_receiver, "+84335552222").await?;
+    // Handyman without working hours is never found by availability
+    graphql::sign_up_handyman(&client3, &core_service.sms_receiver, "+84335553333").await?;
+
+    // Test weekly working hours, Saturday night slot crosses midnight
+    let working_hours = graphql::handyman_profile_set_working_hours(
+        &client1,
+        HandymanProfileSetWorkingHoursInput {
+            handyman_id: handyman1_id.clone(),
+            time: ScheduleTimeInput {
+                fixed_time: None,
+                daily_recurrence: None,
+                weekly_recurrence: Some(WeeklyRecurrence {
+                    times: vec![
+                        WeekdayTime {
+                            day: Weekday::MON,
+                            times: vec![time(8, 0), time(10, 0)],
+                        },
+                        WeekdayTime {
+                            day: Weekday::SAT,
+                            times: vec![time(23, 0)],
+                        },
+                    ],
+                }),
+                timezone: None,
+            },
+            slot_minutes: 120,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_profile_set_working_hours
+    .profile
+    .working_hours
+    .unwrap();
+    assert_eq!(working_hours.slot_minutes, 120);
+    assert!(matches!(
+        working_hours.schedule.schedule_type,
+        ScheduleType::WEEKLY_RECURRENCE
+    ));
+    assert_eq!(
+        working_hours
+            .schedule
+            .weekly_recurrence
+            .unwrap()
+            .times
+            .len(),
+        2
+    );
+
+    graphql::handyman_profile_set_working_hours(
+        &client2,
+        HandymanProfileSetWorkingHoursInput {
+            handyman_id: handyman2_id.clone(),
+            time: daily(vec![time(14, 0)]),
+            slot_minutes: 60,
+        },
+    )
+    .await
+    .data
+    .unwrap();
+
+    assert_eq!(
+        search_available_at(&client1, at(5, 9, 30)).await,
+        [handyman1_id.clone()]
+    );
+    assert!(search_available_at(&client1, at(5, 12, 0)).await.is_empty());
+    assert_eq!(
+        search_available_at(&client1, at(5, 14, 30)).await,
+        [handyman2_id.clone()]
+    );
+    assert_eq!(
+        search_available_at(&client1, at(11, 0, 30)).await,
+        [handyman1_id.clone()]
+    );
+
+    // Test a day off hides the handyman that date only
+    let blackouts = graphql::handyman_profile_add_blackout(
+        &client1,
+        HandymanProfileAddBlackoutInput {
+            handyman_id: handyman1_id.clone(),
+            date: NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+            note: Some("Tết dương lịch".into()),
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_profile_add_blackout
+    .profile
+    .blackouts;
+    assert_eq!(blackouts.len(), 1);
+    assert_eq!(blackouts[0].note.as_deref(), Some("Tết dương lịch"));
+    assert!(search_available_at(&client1, at(5, 9, 30)).await.is_empty());
+    assert_eq!(
+        search_available_at(&client1, at(12, 9, 30)).await,
+        [handyman1_id.clone()]
+    );
+
+    let removed = graphql::handyman_profile_remove_blackout(
+        &client1,
+        HandymanProfileRemoveBlackoutInput {
+            handyman_id: handyman1_id.clone(),
+            date: NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(),
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_profile_remove_blackout;
+    assert!(removed.profile.blackouts.is_empty());
+    assert_eq!(
+        search_available_at(&client1, at(5, 9, 30)).await,
+        [handyman1_id.clone()]
+    );
+
+    // Test replacing working hours
+    graphql::handyman_profile_set_working_hours(
+        &client1,
+        HandymanProfileSetWorkingHoursInput {
+            handyman_id: handyman1_id.clone(),
+            time: daily(vec![time(14, 0)]),
+            slot_minutes: 30,
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    assert!(search_available_at(&client1, at(5, 9, 30)).await.is_empty());
+    assert_eq!(search_available_at(&client1, at(5, 14, 15)).await.len(), 2);
+
+    // Test the searched time is converted into the time zone of the working hours
+    assert_eq!(
+        search_available_at(&client1, at(5, 14, 15).to_utc().fixed_offset())
+            .await
+            .len(),
+        2
+    );
+    graphql::handyman_profile_set_working_hours(
+        &client2,
+        HandymanProfileSetWorkingHoursInput {
+            handyman_id: handyman2_id.clone(),
+            time: ScheduleTimeInput {
+                timezone: Some("America/New_York".into()),
+                ..daily(vec![time(14, 0)])
+            },
+            slot_minutes: 60,
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    assert_eq!(
+        search_available_at(&client1, at(5, 14, 15)).await,
+        [handyman1_id.clone()]
+    );
+    // 14:30 in New York is 02:30 of the next day in Vietnam
+    assert_eq!(
+        search_available_at(&client1, at(6, 2, 30)).await,
+        [handyman2_id.clone()]
+    );
+
+    // Test rejecting fixed time and invalid slot length
+    let response = graphql::handyman_profile_set_working_hours(
+        &client1,
+        HandymanProfileSetWorkingHoursInput {
+            handyman_id: handyman1_id.clone(),
+            time: ScheduleTimeInput {
+                fixed_time: Some(FixedTime { time: at(5, 8, 0) }),
+                daily_recurrence: None,
+                weekly_recurrence: None,
+                timezone: None,
+            },
+            slot_minutes: 60,
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "schedule",
+                "description": "FIXED_TIME",
+            }]
+        })),
+    );
+
+    let response = graphql::handyman_profile_set_working_hours(
+        &client1,
+        HandymanProfileSetWorkingHoursInput {
+            handyman_id: handyman1_id.clone(),
+            time: daily(vec![time(8, 0)]),
+            slot_minutes: 0,
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "slot_minutes",
+                "description": "OUT_OF_RANGE",
+            }]
+        })),
+    );
+
+    // Test a handyman can't edit the calendar of another handyman
+    let response = graphql::handyman_profile_add_blackout(
+        &client2,
+        HandymanProfileAddBlackoutInput {
+            handyman_id: handyman1_id,
+            date: NaiveDate::from_ymd_opt(2026, 1, 6).unwrap(),
+            note: None,
+        },
+    )
+    .await;
+    graphql::assert_error_response(response, ErrorVariant::PermissionDenied(None), None);
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+mod graphql;
+
+use error::{ErrorVariant, Result};
+use graphql::handyman_profile_add_services::{HandymanProfileAddServicesInput, NewHandymanService};
+use graphql::handyman_profile_set_service_location::{
+    GeoCoordinates, HandymanProfileSetServiceLocationInput, LocationInput,
+};
+use graphql::handyman_search::{DistanceWithinInput, HandymanSearchFilter, PagingOffsetInput};
+use test_service_orchestration::{
+    ServiceEnvironment, ServiceParams, core_service::CoreServiceEnvironment,
+};
+
+/// Hoàn Kiếm, Hà Nội
+const HA_NOI: (f64, f64) = (105.85, 21.02);
+/// Cầu Giấy, Hà Nội, about 6km from Hoàn Kiếm
+const CAU_GIAY: (f64, f64) = (105.79, 21.03);
+/// Quận 1, Hồ Chí Minh
+const HO_CHI_MINH: (f64, f64) = (106.70, 10.78);
+
+fn first_page() -> PagingOffsetInput {
+    PagingOffsetInput {
+        page: 1,
+        page_size: 12,
+    }
+}
+
+fn location_input((lon, lat): (f64, f64)) -> LocationInput {
+    LocationInput {
+        city: "Hà Nội".into(),
+        address_line1: "1 Tràng Tiền".into(),
+        formatted_address: "1 Tràng Tiền, Hoàn Kiếm, Hà Nội".into(),
+        corrdinates: GeoCoordinates { lon, lat },
+    }
+}
+
+async fn sign_up_located_handyman(
+    core_service: &CoreServiceEnvironment,
+    phone_number: &str,
+    location: (f64, f64),
+) -> Result<String> {
+    let client =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    let handyman_id =
+        graphql::sign_up_handyman(&client, &core_service.sms_receiver, phone_number).await?;
+    graphql::handyman_profile_add_services(
+        &client,
+        HandymanProfileAddServicesInput {
+            handyman_id: handyman_id.clone(),
+            services: vec![NewHandymanService {
+                service_code: Some("AIR_CONDITIONER_FIXING".into()),
+                service: None,
+                note: None,
+                rate_vnd: None,
+            }],
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    let profile = graphql::handyman_profile_set_service_location(
+        &client,
+        HandymanProfileSetServiceLocationInput {
+            handyman_id: handyman_id.clone(),
+            location: location_input(location),
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_profile_set_service_location
+    .profile;
+    let coordinates = profile.service_location.unwrap().coordinates;
+    assert_eq!((coordinates.lon, coordinates.lat), location);
+
+    Ok(handyman_id)
+}
+
+#[tokio::test]
+async fn handyman_search_by_location() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let client =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    graphql::sign_up_customer(&client, &core_service.sms_receiver, "+84334445555").await?;
+
+    let ha_noi_id = sign_up_located_handyman(&core_service, "+84334446666", HA_NOI).await?;
+    let cau_giay_id = sign_up_located_handyman(&core_service, "+84334447777", CAU_GIAY).await?;
+    sign_up_located_handyman(&core_service, "+84334448888", HO_CHI_MINH).await?;
+
+    // Test filtering by distance, nearest first
+    let result = graphql::handyman_search(
+        &client,
+        HandymanSearchFilter {
+            service_codes: Some(vec!["AIR_CONDITIONER_FIXING".into()]),
+            services: None,
+            name: None,
+            ids: None,
+            distance_within: Some(DistanceWithinInput {
+                lon: HA_NOI.0,
+                lat: HA_NOI.1,
+                within_meters: 10_000.0,
+            }),
+            available_at: None,
+        },
+        first_page(),
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_search;
+    assert_eq!(result.paging_info.total_count, 2);
+    assert_eq!(result.items[0].id, ha_noi_id);
+    assert_eq!(result.items[1].id, cau_giay_id);
+    let distances = result.distance_meters.unwrap();
+    assert!(distances[0] < 1.0);
+    let distance = distances[1];
+    assert!((5_000.0..7_000.0).contains(&distance), "{distance}");
+
+    // Test distance is not computed without location filter
+    let result = graphql::handyman_search(
+        &client,
+        HandymanSearchFilter {
+            service_codes: Some(vec!["AIR_CONDITIONER_FIXING".into()]),
+            services: None,
+            name: None,
+            ids: None,
+            distance_within: None,
+            available_at: None,
+        },
+        first_page(),
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_search;
+    assert_eq!(result.paging_info.total_count, 3);
+    assert!(result.distance_meters.is_none());
+
+    // Test rejecting invalid coordinates
+    let response = graphql::handyman_search(
+        &client,
+        HandymanSearchFilter {
+            service_codes: None,
+            services: None,
+            name: None,
+            ids: None,
+            distance_within: Some(DistanceWithinInput {
+                lon: 181.0,
+                lat: HA_NOI.1,
+                within_meters: 10_000.0,
+            }),
+            available_at: None,
+        },
+        first_page(),
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "location",
+                "description": "INVALID_LON",
+            }]
+        })),
+    );
+
+    Ok(())
+}
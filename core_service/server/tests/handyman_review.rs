@@ -0,0 +1,249 @@
+mod graphql;
+
+use chrono::DateTime;
+use error::{ErrorVariant, Result};
+use graphql::customer_task_review::CustomerTaskReviewInput;
+use graphql::handyman_create_profile::HandymanCreateProfileInput;
+use graphql::handyman_search::{HandymanSearchFilter, PagingOffsetInput};
+use test_service_orchestration::{
+    ServiceEnvironment, ServiceParams, core_service::CoreServiceEnvironment,
+};
+
+fn review_input(task_id: &str, rating: i64) -> CustomerTaskReviewInput {
+    CustomerTaskReviewInput {
+        task_id: task_id.into(),
+        rating,
+        comment: Some("Làm nhanh, sạch sẽ".into()),
+    }
+}
+
+async fn sign_up_handyman_with_profile(
+    client: &graphql::GraphqlClient,
+    core_service: &CoreServiceEnvironment,
+    phone_number: &str,
+) -> Result<String> {
+    let handyman_id =
+        graphql::sign_up_handyman(client, &core_service.sms_receiver, phone_number).await?;
+    graphql::handyman_create_profile(
+        client,
+        HandymanCreateProfileInput {
+            handyman_id: handyman_id.clone(),
+            first_name: "Văn".into(),
+            last_name: "Nguyễn".into(),
+        },
+    )
+    .await
+    .data
+    .unwrap();
+
+    Ok(handyman_id)
+}
+
+/// Creates a task, accepts a quote of the handyman and let the handyman start it.
+async fn start_task(
+    customer_client: &graphql::GraphqlClient,
+    handyman_client: &graphql::GraphqlClient,
+    handyman_id: &str,
+) -> Result<String> {
+    let task_id = graphql::customer_create_task(customer_client, graphql::new_task_input())
+        .await
+        .data
+        .unwrap()
+        .customer_create_task
+        .task
+        .id;
+    let quote_id = graphql::handyman_task_quote_submit(
+        handyman_client,
+        graphql::handyman_task_quote_submit::HandymanTaskQuoteSubmitInput {
+            task_id: task_id.clone(),
+            handyman_id: handyman_id.into(),
+            price_vnd: 500_000,
+            eta: DateTime::parse_from_rfc3339("2025-11-01T18:00:00+07:00").unwrap(),
+            message: None,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_task_quote_submit
+    .quote
+    .id;
+    graphql::customer_task_quote_accept(
+        customer_client,
+        graphql::customer_task_quote_accept::CustomerTaskQuoteAcceptInput { quote_id },
+    )
+    .await
+    .data
+    .unwrap();
+    graphql::handyman_task_start(
+        handyman_client,
+        graphql::handyman_task_start::CustomerTaskTransitionInput {
+            task_id: task_id.clone(),
+        },
+    )
+    .await
+    .data
+    .unwrap();
+
+    Ok(task_id)
+}
+
+async fn complete_task(handyman_client: &graphql::GraphqlClient, task_id: &str) -> Result<()> {
+    graphql::handyman_task_complete(
+        handyman_client,
+        graphql::handyman_task_complete::CustomerTaskTransitionInput {
+            task_id: task_id.into(),
+        },
+    )
+    .await
+    .data
+    .unwrap();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn handyman_review() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let new_client =
+        || graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    let customer_client = new_client();
+    let handyman1_client = new_client();
+    let handyman2_client = new_client();
+    let handyman3_client = new_client();
+    graphql::sign_up_customer(&customer_client, &core_service.sms_receiver, "+84334445555").await?;
+    let handyman1_id =
+        sign_up_handyman_with_profile(&handyman1_client, &core_service, "+84334446666").await?;
+    let handyman2_id =
+        sign_up_handyman_with_profile(&handyman2_client, &core_service, "+84334447777").await?;
+    let handyman3_id =
+        sign_up_handyman_with_profile(&handyman3_client, &core_service, "+84334448888").await?;
+
+    // Test a task can't be reviewed before it's completed
+    let task1_id = start_task(&customer_client, &handyman1_client, &handyman1_id).await?;
+    let response =
+        graphql::customer_task_review(&customer_client, review_input(&task1_id, 4)).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::FailedPrecondition(None),
+        Some(serde_json::json!({
+            "violations": [{
+                "type": "UNEXPECTED_STATUS",
+                "subject": "task_status",
+                "description": "InProgress",
+            }]
+        })),
+    );
+    complete_task(&handyman1_client, &task1_id).await?;
+
+    // Test rejecting out of range rating
+    let response =
+        graphql::customer_task_review(&customer_client, review_input(&task1_id, 6)).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "rating",
+                "description": "OUT_OF_RANGE",
+            }]
+        })),
+    );
+
+    // Test the handyman can't review the task on behalf of the customer
+    let response =
+        graphql::customer_task_review(&handyman1_client, review_input(&task1_id, 5)).await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::FailedPrecondition(None).client_code()
+    );
+
+    let review = graphql::customer_task_review(&customer_client, review_input(&task1_id, 4))
+        .await
+        .data
+        .unwrap()
+        .customer_task_review
+        .review;
+    assert_eq!(review.task.id, task1_id);
+    assert_eq!(review.handyman.id, handyman1_id);
+    assert_eq!(review.rating, 4);
+
+    // Test a task can only be reviewed once
+    let response =
+        graphql::customer_task_review(&customer_client, review_input(&task1_id, 1)).await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::FailedPrecondition(None).client_code()
+    );
+
+    let task2_id = start_task(&customer_client, &handyman1_client, &handyman1_id).await?;
+    complete_task(&handyman1_client, &task2_id).await?;
+    graphql::customer_task_review(&customer_client, review_input(&task2_id, 3))
+        .await
+        .data
+        .unwrap();
+
+    let task3_id = start_task(&customer_client, &handyman2_client, &handyman2_id).await?;
+    complete_task(&handyman2_client, &task3_id).await?;
+    graphql::customer_task_review(&customer_client, review_input(&task3_id, 5))
+        .await
+        .data
+        .unwrap();
+
+    // Test reviews and average rating on handyman profile, newest review first
+    let profile = graphql::handyman_reviews(&customer_client, &handyman1_id)
+        .await
+        .data
+        .unwrap()
+        .node
+        .unwrap()
+        .try_profile()?;
+    assert_eq!(profile.review_count, 2);
+    assert_eq!(profile.avg_rating, Some(3.5));
+    let ratings = profile.reviews.iter().map(|r| r.rating).collect::<Vec<_>>();
+    assert_eq!(ratings, vec![3, 4]);
+
+    let profile = graphql::handyman_reviews(&customer_client, &handyman3_id)
+        .await
+        .data
+        .unwrap()
+        .node
+        .unwrap()
+        .try_profile()?;
+    assert_eq!(profile.review_count, 0);
+    assert_eq!(profile.avg_rating, None);
+    assert!(profile.reviews.is_empty());
+
+    // Test search ranks handymen by average rating, handymen without review last
+    let result = graphql::handyman_search(
+        &customer_client,
+        HandymanSearchFilter {
+            service_codes: None,
+            services: None,
+            name: None,
+            ids: Some(vec![
+                handyman3_id.clone(),
+                handyman1_id.clone(),
+                handyman2_id.clone(),
+            ]),
+            distance_within: None,
+            available_at: None,
+        },
+        PagingOffsetInput {
+            page: 1,
+            page_size: 12,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_search;
+    let ids = result.items.into_iter().map(|i| i.id).collect::<Vec<_>>();
+    assert_eq!(ids, vec![handyman2_id, handyman1_id, handyman3_id]);
+
+    Ok(())
+}
@@ -0,0 +1,295 @@
+mod graphql;
+
+use error::{ErrorVariant, Result};
+use graphql::UploadFile;
+use graphql::customer_create_profile::CustomerCreateProfileInput;
+use graphql::customer_remove_task_photo::CustomerRemoveTaskPhotoInput;
+use graphql::handyman_create_profile::HandymanCreateProfileInput;
+use graphql::handyman_remove_avatar::HandymanRemoveAvatarInput;
+use graphql::task_photos::TaskPhotosNode;
+use reqwest::{StatusCode, header::CONTENT_TYPE};
+use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+
+const PNG_DATA: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+const JPEG_DATA: &[u8] = b"\xFF\xD8\xFF\xE0\0\x10JFIF\0";
+
+fn png_file() -> UploadFile {
+    UploadFile {
+        filename: "avatar.png".into(),
+        content_type: "image/png".into(),
+        data: PNG_DATA.to_vec(),
+    }
+}
+
+fn jpeg_file() -> UploadFile {
+    UploadFile {
+        filename: "photo.jpg".into(),
+        content_type: "image/jpeg".into(),
+        data: JPEG_DATA.to_vec(),
+    }
+}
+
+/// Download a blob, `None` if the server answers not found
+async fn fetch_blob(url: &str) -> Option<(String, Vec<u8>)> {
+    let response = reqwest::get(url).await.unwrap();
+    if response.status() == StatusCode::NOT_FOUND {
+        return None;
+    }
+    assert_eq!(response.status(), StatusCode::OK);
+    let content_type = response.headers()[CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .to_string();
+    Some((content_type, response.bytes().await.unwrap().to_vec()))
+}
+
+fn photos_of(response: graphql::task_photos::ResponseData) -> Vec<String> {
+    match response.node {
+        Some(TaskPhotosNode::CustomerTaskRequest(task)) => {
+            task.photos.into_iter().map(|p| p.id).collect()
+        }
+        other => panic!("Expect a customer task request, found {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn handyman_avatar() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let handyman = graphql::GraphqlClient::new(graphql_url.clone());
+    let handyman_id =
+        graphql::sign_up_handyman(&handyman, &core_service.sms_receiver, "+84334445555").await?;
+    graphql::handyman_create_profile(
+        &handyman,
+        HandymanCreateProfileInput {
+            handyman_id: handyman_id.clone(),
+            first_name: "Văn".into(),
+            last_name: "Nguyễn".into(),
+        },
+    )
+    .await
+    .data
+    .unwrap();
+
+    let profile = graphql::handyman_upload_avatar(&handyman, handyman_id.clone(), png_file())
+        .await
+        .data
+        .unwrap()
+        .handyman_upload_avatar
+        .profile;
+    let png_url = profile.avatar_url.unwrap();
+    assert_eq!(
+        fetch_blob(&png_url).await,
+        Some(("image/png".into(), PNG_DATA.to_vec()))
+    );
+
+    // Test replacing the avatar deletes the previous image
+    let profile = graphql::handyman_upload_avatar(&handyman, handyman_id.clone(), jpeg_file())
+        .await
+        .data
+        .unwrap()
+        .handyman_upload_avatar
+        .profile;
+    let jpeg_url = profile.avatar_url.unwrap();
+    assert_ne!(jpeg_url, png_url);
+    assert_eq!(
+        fetch_blob(&jpeg_url).await,
+        Some(("image/jpeg".into(), JPEG_DATA.to_vec()))
+    );
+    assert_eq!(fetch_blob(&png_url).await, None);
+
+    // Test rejecting files which aren't images
+    let response = graphql::handyman_upload_avatar(
+        &handyman,
+        handyman_id.clone(),
+        UploadFile {
+            filename: "avatar.png".into(),
+            content_type: "image/png".into(),
+            data: b"<svg></svg>".to_vec(),
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "file",
+                "description": "UNSUPPORTED_TYPE",
+            }]
+        })),
+    );
+
+    // Test rejecting a declared content type which doesn't match the content
+    let response = graphql::handyman_upload_avatar(
+        &handyman,
+        handyman_id.clone(),
+        UploadFile {
+            content_type: "image/webp".into(),
+            ..png_file()
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "file",
+                "description": "MISMATCHED_CONTENT_TYPE",
+            }]
+        })),
+    );
+
+    // Test another handyman can't change the avatar
+    let other = graphql::GraphqlClient::new(graphql_url);
+    graphql::sign_up_handyman(&other, &core_service.sms_receiver, "+84334446666").await?;
+    let response = graphql::handyman_upload_avatar(&other, handyman_id.clone(), png_file()).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::PermissionDenied(None),
+        Some(serde_json::json!(null)),
+    );
+
+    let profile = graphql::handyman_remove_avatar(
+        &handyman,
+        HandymanRemoveAvatarInput {
+            handyman_id: handyman_id.clone(),
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_remove_avatar
+    .profile;
+    assert_eq!(profile.avatar_url, None);
+    assert_eq!(fetch_blob(&jpeg_url).await, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn customer_task_photos() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let customer = graphql::GraphqlClient::new(graphql_url.clone());
+    let customer_id =
+        graphql::sign_up_customer(&customer, &core_service.sms_receiver, "+84334445555").await?;
+    graphql::customer_create_profile(
+        &customer,
+        CustomerCreateProfileInput {
+            customer_id,
+            nick_name: "Nick".into(),
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    let task_id = graphql::customer_create_task(&customer, graphql::new_task_input())
+        .await
+        .data
+        .unwrap()
+        .customer_create_task
+        .task
+        .id;
+
+    let photo1 = graphql::customer_add_task_photo(&customer, task_id.clone(), png_file())
+        .await
+        .data
+        .unwrap()
+        .customer_add_task_photo
+        .photo;
+    assert_eq!(photo1.task.id, task_id);
+    assert_eq!(
+        fetch_blob(&photo1.url).await,
+        Some(("image/png".into(), PNG_DATA.to_vec()))
+    );
+    // Test task photos are only served through the signed url
+    let (unsigned_url, _) = photo1.url.split_once('?').unwrap();
+    assert_eq!(fetch_blob(unsigned_url).await, None);
+    let photo2 = graphql::customer_add_task_photo(&customer, task_id.clone(), jpeg_file())
+        .await
+        .data
+        .unwrap()
+        .customer_add_task_photo
+        .photo;
+
+    let photo_ids = photos_of(
+        graphql::task_photos(&customer, task_id.clone())
+            .await
+            .data
+            .unwrap(),
+    );
+    assert_eq!(photo_ids, vec![photo1.id.clone(), photo2.id.clone()]);
+
+    // Test another customer can't add photos to the task
+    let other = graphql::GraphqlClient::new(graphql_url);
+    graphql::sign_up_customer(&other, &core_service.sms_receiver, "+84334446666").await?;
+    let response = graphql::customer_add_task_photo(&other, task_id.clone(), png_file()).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::PermissionDenied(None),
+        Some(serde_json::json!(null)),
+    );
+    // Test another customer can't read the photos of the task
+    let response = graphql::task_photos(&other, task_id.clone()).await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::NotFound(None).client_code()
+    );
+
+    let payload = graphql::customer_remove_task_photo(
+        &customer,
+        CustomerRemoveTaskPhotoInput {
+            photo_id: photo1.id.clone(),
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_remove_task_photo;
+    assert_eq!(payload.removed_photo_id, photo1.id);
+    assert_eq!(
+        payload
+            .task
+            .photos
+            .into_iter()
+            .map(|p| p.id)
+            .collect::<Vec<_>>(),
+        vec![photo2.id.clone()]
+    );
+    assert_eq!(fetch_blob(&photo1.url).await, None);
+
+    // Test photos can't be changed once the task is cancelled
+    graphql::customer_task_cancel(
+        &customer,
+        graphql::customer_task_cancel::CustomerTaskTransitionInput {
+            task_id: task_id.clone(),
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    let response = graphql::customer_add_task_photo(&customer, task_id, png_file()).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::FailedPrecondition(None),
+        Some(serde_json::json!({
+            "violations": [{
+                "type": "UNEXPECTED_STATUS",
+                "subject": "task_status",
+                "description": "Cancelled",
+            }]
+        })),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+mod graphql;
+
+use error::{ErrorVariant, Result};
+use graphql::user_password_reset_finish::UserPasswordResetFinishInput;
+use graphql::user_password_reset_start::{AccountType, UserPasswordResetStartInput};
+use graphql::user_sign_in_with_password::UserSignInWithPasswordInput;
+use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+
+#[tokio::test]
+async fn reset_password_with_otp() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let phone_number = "+84334445555";
+    let new_password = "87654321";
+    let sign_in_input = |password: &str| UserSignInWithPasswordInput {
+        phone_number: phone_number.into(),
+        password: password.into(),
+        account_type: graphql::user_sign_in_with_password::AccountType::CUSTOMER,
+    };
+
+    let phone = graphql::GraphqlClient::new(graphql_url.clone());
+    graphql::sign_up_customer(&phone, &core_service.sms_receiver, phone_number).await?;
+    let client = graphql::GraphqlClient::new(graphql_url.clone());
+
+    // Test no code is sent for phone numbers without an account
+    let payload = graphql::user_password_reset_start(
+        &client,
+        UserPasswordResetStartInput {
+            phone_number: "+84334446666".into(),
+            account_type: AccountType::CUSTOMER,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .user_password_reset_start;
+    assert_eq!(payload.e164_phone_number_str, "+84334446666");
+    assert!(core_service.sms_receiver.receive_sms().await.is_empty());
+
+    let response = graphql::user_password_reset_start(
+        &client,
+        UserPasswordResetStartInput {
+            phone_number: phone_number.into(),
+            account_type: AccountType::CUSTOMER,
+        },
+    )
+    .await;
+    assert!(response.data.is_some());
+    let sms = core_service.sms_receiver.receive_sms().await.pop().unwrap();
+    let otp_code = sms.message.try_otp_verification_for_password_reset()?.code;
+
+    // Test a reset of a handyman account the phone number doesn't have
+    // keeps the code of the customer account
+    graphql::user_password_reset_start(
+        &client,
+        UserPasswordResetStartInput {
+            phone_number: phone_number.into(),
+            account_type: AccountType::HANDYMAN,
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    assert!(core_service.sms_receiver.receive_sms().await.is_empty());
+
+    // Test wrong codes are rejected
+    let wrong_code = if otp_code == "000000" {
+        "111111"
+    } else {
+        "000000"
+    };
+    let reset_input = |otp_code: &str| UserPasswordResetFinishInput {
+        phone_number: phone_number.into(),
+        account_type: graphql::user_password_reset_finish::AccountType::CUSTOMER,
+        otp_code: otp_code.into(),
+        new_password: new_password.into(),
+    };
+    let response = graphql::user_password_reset_finish(&client, reset_input(wrong_code)).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "OTP_VERIFICATION",
+                "description": "FAILED",
+            }]
+        })),
+    );
+
+    // Test a weak password is rejected without consuming the code
+    let response = graphql::user_password_reset_finish(
+        &client,
+        UserPasswordResetFinishInput {
+            new_password: "1234".into(),
+            ..reset_input(&otp_code)
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::FailedPrecondition(None),
+        Some(serde_json::json!({
+            "violations": [{
+                "type": "TOO_SHORT",
+                "subject": "password",
+                "description": "Password should be at-least 8 characters long",
+            }]
+        })),
+    );
+
+    graphql::user_password_reset_finish(&client, reset_input(&otp_code))
+        .await
+        .data
+        .unwrap();
+    assert!(
+        graphql::session(&client)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_some()
+    );
+
+    // Test existing sessions are revoked
+    assert!(
+        graphql::session(&phone)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_none()
+    );
+
+    // Test the code can't be reused
+    let response = graphql::user_password_reset_finish(&client, reset_input(&otp_code)).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "OTP_VERIFICATION",
+                "description": "EXPIRED",
+            }]
+        })),
+    );
+
+    // Test signing in with the new password only
+    let response = graphql::user_sign_in_with_password(&phone, sign_in_input("12345678")).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::Unauthenticated(None),
+        Some(serde_json::json!(null)),
+    );
+    graphql::user_sign_in_with_password(&phone, sign_in_input(new_password))
+        .await
+        .data
+        .unwrap();
+
+    Ok(())
+}
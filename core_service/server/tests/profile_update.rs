@@ -0,0 +1,266 @@
+mod graphql;
+
+use error::{ErrorVariant, Result};
+use graphql::customer_create_profile::CustomerCreateProfileInput;
+use graphql::customer_update_profile::{
+    CustomerUpdateProfileChangeset, CustomerUpdateProfileInput,
+};
+use graphql::handyman_create_profile::HandymanCreateProfileInput;
+use graphql::handyman_profile_add_services::{HandymanProfileAddServicesInput, NewHandymanService};
+use graphql::handyman_search::{HandymanSearchFilter, PagingOffsetInput};
+use graphql::handyman_update_profile::{
+    HandymanUpdateProfileChangeset, HandymanUpdateProfileInput, SetValueInt32, SetValueString,
+};
+use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+
+fn search_by_name(name: &str) -> (HandymanSearchFilter, PagingOffsetInput) {
+    (
+        HandymanSearchFilter {
+            service_codes: None,
+            services: None,
+            name: Some(name.into()),
+            ids: None,
+            distance_within: None,
+            available_at: None,
+        },
+        PagingOffsetInput {
+            page: 1,
+            page_size: 12,
+        },
+    )
+}
+
+fn handyman_changeset() -> HandymanUpdateProfileChangeset {
+    HandymanUpdateProfileChangeset {
+        first_name: None,
+        last_name: None,
+        bio: None,
+        years_of_experience: None,
+    }
+}
+
+#[tokio::test]
+async fn customer_update_profile() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let customer = graphql::GraphqlClient::new(graphql_url.clone());
+    let customer_id =
+        graphql::sign_up_customer(&customer, &core_service.sms_receiver, "+84334445555").await?;
+    graphql::customer_create_profile(
+        &customer,
+        CustomerCreateProfileInput {
+            customer_id: customer_id.clone(),
+            nick_name: "Nick".into(),
+        },
+    )
+    .await
+    .data
+    .unwrap();
+
+    let profile = graphql::customer_update_profile(
+        &customer,
+        CustomerUpdateProfileInput {
+            customer_id: customer_id.clone(),
+            changeset: CustomerUpdateProfileChangeset {
+                nick_name: Some("New Nick".into()),
+            },
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_update_profile
+    .profile;
+    assert_eq!(profile.nick_name, "New Nick");
+
+    // Test omitted fields are left unchanged
+    let profile = graphql::customer_update_profile(
+        &customer,
+        CustomerUpdateProfileInput {
+            customer_id: customer_id.clone(),
+            changeset: CustomerUpdateProfileChangeset { nick_name: None },
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_update_profile
+    .profile;
+    assert_eq!(profile.nick_name, "New Nick");
+
+    // Test another customer can't update the profile
+    let other = graphql::GraphqlClient::new(graphql_url);
+    graphql::sign_up_customer(&other, &core_service.sms_receiver, "+84334446666").await?;
+    let response = graphql::customer_update_profile(
+        &other,
+        CustomerUpdateProfileInput {
+            customer_id,
+            changeset: CustomerUpdateProfileChangeset {
+                nick_name: Some("Hijacked".into()),
+            },
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::PermissionDenied(None),
+        Some(serde_json::json!(null)),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn handyman_update_profile() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let handyman =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    let handyman_id =
+        graphql::sign_up_handyman(&handyman, &core_service.sms_receiver, "+84334445555").await?;
+    graphql::handyman_create_profile(
+        &handyman,
+        HandymanCreateProfileInput {
+            handyman_id: handyman_id.clone(),
+            first_name: "An".into(),
+            last_name: "Nguyen".into(),
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    graphql::handyman_profile_add_services(
+        &handyman,
+        HandymanProfileAddServicesInput {
+            handyman_id: handyman_id.clone(),
+            services: vec![NewHandymanService {
+                service_code: Some("AIR_CONDITIONER_FIXING".into()),
+                service: None,
+                note: None,
+                rate_vnd: None,
+            }],
+        },
+    )
+    .await
+    .data
+    .unwrap();
+
+    let profile = graphql::handyman_update_profile(
+        &handyman,
+        HandymanUpdateProfileInput {
+            handyman_id: handyman_id.clone(),
+            changeset: HandymanUpdateProfileChangeset {
+                first_name: Some("Binh".into()),
+                bio: Some(SetValueString {
+                    value: Some("Fixing air conditioners since 2015".into()),
+                }),
+                years_of_experience: Some(SetValueInt32 { value: Some(10) }),
+                ..handyman_changeset()
+            },
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_update_profile
+    .profile;
+    assert_eq!(
+        (profile.first_name.as_str(), profile.last_name.as_str()),
+        ("Binh", "Nguyen")
+    );
+    assert_eq!(
+        profile.bio.as_deref(),
+        Some("Fixing air conditioners since 2015")
+    );
+    assert_eq!(profile.years_of_experience, Some(10));
+
+    // Test the search index follows the name change
+    let (filter, paging_config) = search_by_name("Binh");
+    let result = graphql::handyman_search(&handyman, filter, paging_config)
+        .await
+        .data
+        .unwrap()
+        .handyman_search;
+    assert_eq!(result.paging_info.total_count, 1);
+    assert_eq!(result.items[0].id, handyman_id);
+    let (filter, paging_config) = search_by_name("An");
+    let result = graphql::handyman_search(&handyman, filter, paging_config)
+        .await
+        .data
+        .unwrap()
+        .handyman_search;
+    assert_eq!(result.paging_info.total_count, 0);
+
+    // Test optional fields are cleared by null values, omitted fields are left unchanged
+    let profile = graphql::handyman_update_profile(
+        &handyman,
+        HandymanUpdateProfileInput {
+            handyman_id: handyman_id.clone(),
+            changeset: HandymanUpdateProfileChangeset {
+                bio: Some(SetValueString { value: None }),
+                ..handyman_changeset()
+            },
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_update_profile
+    .profile;
+    assert!(profile.bio.is_none());
+    assert_eq!(profile.years_of_experience, Some(10));
+    assert_eq!(profile.first_name, "Binh");
+
+    // Test invalid values are rejected
+    let response = graphql::handyman_update_profile(
+        &handyman,
+        HandymanUpdateProfileInput {
+            handyman_id: handyman_id.clone(),
+            changeset: HandymanUpdateProfileChangeset {
+                years_of_experience: Some(SetValueInt32 { value: Some(-1) }),
+                ..handyman_changeset()
+            },
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "years_of_experience",
+                "description": "OUT_OF_RANGE",
+            }]
+        })),
+    );
+    let response = graphql::handyman_update_profile(
+        &handyman,
+        HandymanUpdateProfileInput {
+            handyman_id,
+            changeset: HandymanUpdateProfileChangeset {
+                last_name: Some(" Tran".into()),
+                ..handyman_changeset()
+            },
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "last_name",
+                "description": "NOT_TRIMMED",
+            }]
+        })),
+    );
+
+    Ok(())
+}
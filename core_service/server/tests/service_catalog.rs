@@ -0,0 +1,352 @@
+mod graphql;
+
+use error::{ErrorVariant, Result};
+use graphql::handyman_profile_add_services::{HandymanProfileAddServicesInput, NewHandymanService};
+use graphql::handyman_profile_set_service_location::{
+    GeoCoordinates, HandymanProfileSetServiceLocationInput, LocationInput,
+};
+use graphql::service_groups::{ServiceLayer1, ServiceLayer2};
+use graphql::update_account_status::AccountStatus;
+use graphql::upsert_service::UpsertServiceInput;
+use graphql::upsert_service_group::UpsertServiceGroupInput;
+use test_service_orchestration::{
+    ServiceEnvironment, ServiceParams, core_service::CoreServiceEnvironment,
+};
+
+#[tokio::test]
+async fn service_catalog() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let back_office_url = format!("http://{}/back-office/graphql", core_service.service_host);
+    let anonymous = graphql::GraphqlClient::new(graphql_url);
+    let staff = graphql::back_office_client(back_office_url);
+
+    // Test the catalog is seeded with the services predating it, no session required
+    let groups = graphql::service_groups(&anonymous)
+        .await
+        .data
+        .unwrap()
+        .service_groups;
+    assert_eq!(
+        groups.iter().map(|g| g.code.as_str()).collect::<Vec<_>>(),
+        ["AIR_CONDITIONER", "WASHING_MACHINE", "OTHER"]
+    );
+    let air_conditioner = &groups[0];
+    assert!(matches!(
+        air_conditioner.group_type,
+        Some(ServiceLayer1::AIR_CONDITIONER)
+    ));
+    assert_eq!(air_conditioner.name_vi, "Máy lạnh");
+    let cleaning = &air_conditioner.children[1];
+    assert!(matches!(
+        cleaning.service_type,
+        Some(ServiceLayer2::AIR_CONDITIONER_CLEANING)
+    ));
+    assert_eq!(cleaning.name_en, "Air conditioner cleaning");
+    assert_eq!(cleaning.recommended_rate_vnd, Some(250_000));
+    assert_eq!(cleaning.service_group.code, "AIR_CONDITIONER");
+
+    // Test staff extends the catalog without any enum value
+    let response = graphql::upsert_service_group(
+        &staff,
+        UpsertServiceGroupInput {
+            code: "plumbing".into(),
+            name_vi: "Điện nước".into(),
+            name_en: "Plumbing".into(),
+            icon: None,
+            display_order: 3,
+            is_active: true,
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "code",
+                "description": "INVALID_FORMAT",
+            }]
+        })),
+    );
+
+    let group = graphql::upsert_service_group(
+        &staff,
+        UpsertServiceGroupInput {
+            code: "PLUMBING".into(),
+            name_vi: "Điện nước".into(),
+            name_en: "Plumbing".into(),
+            icon: Some("plumbing".into()),
+            display_order: 3,
+            is_active: true,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .upsert_service_group
+    .group;
+    assert_eq!(group.code, "PLUMBING");
+    assert!(group.group_type.is_none());
+
+    let new_service = |code: &str, group_code: &str| UpsertServiceInput {
+        code: code.into(),
+        group_code: group_code.into(),
+        name_vi: "Sửa ống nước".into(),
+        name_en: "Pipe repair".into(),
+        icon: None,
+        recommended_rate_vnd: Some(300_000),
+        display_order: 1,
+        is_active: true,
+    };
+    let response =
+        graphql::upsert_service(&staff, new_service("PLUMBING_PIPE_REPAIR", "ELECTRICAL")).await;
+    graphql::assert_error_response(response, ErrorVariant::NotFound(None), None);
+
+    let service = graphql::upsert_service(&staff, new_service("PLUMBING_PIPE_REPAIR", "PLUMBING"))
+        .await
+        .data
+        .unwrap()
+        .upsert_service
+        .service;
+    assert!(service.service_type.is_none());
+    assert_eq!(service.recommended_rate_vnd, Some(300_000));
+    assert_eq!(service.service_group.code, "PLUMBING");
+
+    let groups = graphql::service_groups(&anonymous)
+        .await
+        .data
+        .unwrap()
+        .service_groups;
+    assert_eq!(
+        groups.iter().map(|g| g.code.as_str()).collect::<Vec<_>>(),
+        ["AIR_CONDITIONER", "WASHING_MACHINE", "PLUMBING", "OTHER"]
+    );
+    assert_eq!(groups[2].children[0].code, "PLUMBING_PIPE_REPAIR");
+
+    // Test handymen can offer services added to the catalog after the enums were frozen
+    let handyman =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    let handyman_id =
+        graphql::sign_up_handyman(&handyman, &core_service.sms_receiver, "+84334445555").await?;
+    let add_pipe_repair = || HandymanProfileAddServicesInput {
+        handyman_id: handyman_id.clone(),
+        services: vec![NewHandymanService {
+            service_code: Some("PLUMBING_PIPE_REPAIR".into()),
+            service: None,
+            note: None,
+            rate_vnd: None,
+        }],
+    };
+    graphql::handyman_profile_add_services(&handyman, add_pipe_repair())
+        .await
+        .data
+        .unwrap();
+
+    // Test deactivated services leave the catalog but still resolve by code
+    graphql::upsert_service(
+        &staff,
+        UpsertServiceInput {
+            is_active: false,
+            ..new_service("PLUMBING_PIPE_REPAIR", "PLUMBING")
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    let groups = graphql::service_groups(&anonymous)
+        .await
+        .data
+        .unwrap()
+        .service_groups;
+    assert!(groups[2].children.is_empty());
+    let service = graphql::catalog_service(&anonymous, "PLUMBING_PIPE_REPAIR")
+        .await
+        .data
+        .unwrap()
+        .service
+        .unwrap();
+    assert_eq!(service.name_en, "Pipe repair");
+
+    // Test deactivated services can't be offered anymore
+    let response = graphql::handyman_profile_add_services(&handyman, add_pipe_repair()).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "service_code",
+                "description": "UNKNOWN_SERVICE",
+            }]
+        })),
+    );
+
+    Ok(())
+}
+
+async fn sign_up_handyman_with_rate(
+    core_service: &CoreServiceEnvironment,
+    phone_number: &str,
+    city: &str,
+    rate_vnd: i64,
+) -> Result<String> {
+    let client =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    let handyman_id =
+        graphql::sign_up_handyman(&client, &core_service.sms_receiver, phone_number).await?;
+    graphql::handyman_profile_add_services(
+        &client,
+        HandymanProfileAddServicesInput {
+            handyman_id: handyman_id.clone(),
+            services: vec![NewHandymanService {
+                service_code: Some("AIR_CONDITIONER_CLEANING".into()),
+                service: None,
+                note: None,
+                rate_vnd: Some(rate_vnd),
+            }],
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    graphql::handyman_profile_set_service_location(
+        &client,
+        HandymanProfileSetServiceLocationInput {
+            handyman_id: handyman_id.clone(),
+            location: LocationInput {
+                city: city.into(),
+                address_line1: "1 Tràng Tiền".into(),
+                formatted_address: format!("1 Tràng Tiền, {city}"),
+                corrdinates: GeoCoordinates {
+                    lon: 105.85,
+                    lat: 21.02,
+                },
+            },
+        },
+    )
+    .await
+    .data
+    .unwrap();
+
+    Ok(handyman_id)
+}
+
+#[tokio::test]
+async fn service_rate_distribution() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let back_office_url = format!("http://{}/back-office/graphql", core_service.service_host);
+    let anonymous = graphql::GraphqlClient::new(graphql_url);
+    let staff = graphql::back_office_client(back_office_url);
+
+    let mut handyman_ids = vec![];
+    for (phone_number, city, rate_vnd) in [
+        ("+84334441111", "Hà Nội", 200_000),
+        ("+84334442222", "Hà Nội", 300_000),
+        ("+84334443333", "Hà Nội", 400_000),
+        ("+84334444444", "Đà Nẵng", 500_000),
+    ] {
+        handyman_ids
+            .push(sign_up_handyman_with_rate(&core_service, phone_number, city, rate_vnd).await?);
+    }
+
+    // Test the catalog reference rate is used until prices are computed
+    let service = graphql::service_rate(&anonymous, "AIR_CONDITIONER_CLEANING", Some("Hà Nội"))
+        .await
+        .data
+        .unwrap()
+        .service
+        .unwrap();
+    assert_eq!(service.recommended_rate_vnd, Some(250_000));
+    assert!(service.rate_distribution.is_none());
+
+    // Only AIR_CONDITIONER_CLEANING has enough prices, in Hà Nội and over all cities
+    let refreshed = graphql::refresh_service_rate_stats(&staff)
+        .await
+        .data
+        .unwrap()
+        .refresh_service_rate_stats;
+    assert_eq!(refreshed.distribution_count, 2);
+
+    // Test the distribution of the city
+    let service = graphql::service_rate(&anonymous, "AIR_CONDITIONER_CLEANING", Some("Hà Nội"))
+        .await
+        .data
+        .unwrap()
+        .service
+        .unwrap();
+    assert_eq!(service.recommended_rate_vnd, Some(300_000));
+    let distribution = service.rate_distribution.unwrap();
+    assert_eq!(distribution.city.as_deref(), Some("Hà Nội"));
+    assert_eq!(distribution.sample_count, 3);
+    assert_eq!(
+        (
+            distribution.p25_vnd,
+            distribution.median_vnd,
+            distribution.p75_vnd
+        ),
+        (250_000, 300_000, 350_000)
+    );
+
+    // Test cities with too few prices fall back to the distribution over all cities
+    let service = graphql::service_rate(&anonymous, "AIR_CONDITIONER_CLEANING", Some("Đà Nẵng"))
+        .await
+        .data
+        .unwrap()
+        .service
+        .unwrap();
+    assert_eq!(service.recommended_rate_vnd, Some(350_000));
+    let distribution = service.rate_distribution.unwrap();
+    assert!(distribution.city.is_none());
+    assert_eq!(distribution.sample_count, 4);
+    assert_eq!(
+        (
+            distribution.p25_vnd,
+            distribution.median_vnd,
+            distribution.p75_vnd
+        ),
+        (275_000, 350_000, 425_000)
+    );
+
+    // Test services without enough prices keep their reference rate
+    let service = graphql::service_rate(&anonymous, "AIR_CONDITIONER_FIXING", None)
+        .await
+        .data
+        .unwrap()
+        .service
+        .unwrap();
+    assert!(service.recommended_rate_vnd.is_none());
+    assert!(service.rate_distribution.is_none());
+
+    // Test rates of suspended handymen are left out, Hà Nội is left with too few prices
+    graphql::update_account_status(&staff, &handyman_ids[0], AccountStatus::SUSPENDED)
+        .await
+        .data
+        .unwrap();
+    let refreshed = graphql::refresh_service_rate_stats(&staff)
+        .await
+        .data
+        .unwrap()
+        .refresh_service_rate_stats;
+    assert_eq!(refreshed.distribution_count, 1);
+    let service = graphql::service_rate(&anonymous, "AIR_CONDITIONER_CLEANING", Some("Hà Nội"))
+        .await
+        .data
+        .unwrap()
+        .service
+        .unwrap();
+    let distribution = service.rate_distribution.unwrap();
+    assert!(distribution.city.is_none());
+    assert_eq!(distribution.sample_count, 3);
+    assert_eq!(distribution.median_vnd, 400_000);
+
+    Ok(())
+}
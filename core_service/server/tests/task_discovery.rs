@@ -0,0 +1,268 @@
+mod graphql;
+
+use chrono::DateTime;
+use error::{ErrorVariant, Result};
+use graphql::customer_create_task::{
+    CustomerCreateTaskInput, FixedTime, GeoCoordinates, LocationInput, ScheduleInput,
+    ScheduleTimeInput,
+};
+use graphql::handyman_nearby_tasks::{DistanceWithinInput, PagingOffsetInput};
+use graphql::handyman_profile_add_services::{HandymanProfileAddServicesInput, NewHandymanService};
+use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+
+/// Hoàn Kiếm, Hà Nội
+const HA_NOI: (f64, f64) = (105.85, 21.02);
+/// Quận 1, Hồ Chí Minh
+const HO_CHI_MINH: (f64, f64) = (106.70, 10.78);
+
+fn new_task_input(service_code: &str, (lon, lat): (f64, f64)) -> CustomerCreateTaskInput {
+    CustomerCreateTaskInput {
+        service_code: Some(service_code.into()),
+        service: None,
+        title: "Sửa điều hòa".into(),
+        note: "".into(),
+        schedule: ScheduleInput {
+            location: LocationInput {
+                city: "Hà Nội".into(),
+                address_line1: "1 Tràng Tiền".into(),
+                formatted_address: "1 Tràng Tiền, Hoàn Kiếm, Hà Nội".into(),
+                corrdinates: GeoCoordinates { lon, lat },
+            },
+            time: ScheduleTimeInput {
+                fixed_time: Some(FixedTime {
+                    time: DateTime::parse_from_rfc3339("2025-11-01T09:30:00+07:00").unwrap(),
+                }),
+                daily_recurrence: None,
+                weekly_recurrence: None,
+                timezone: None,
+            },
+        },
+        draft: None,
+    }
+}
+
+fn within(
+    (lon, lat): (f64, f64),
+    within_meters: f64,
+) -> graphql::handyman_nearby_tasks::DistanceWithinInput {
+    DistanceWithinInput {
+        lon,
+        lat,
+        within_meters,
+    }
+}
+
+fn first_page() -> PagingOffsetInput {
+    PagingOffsetInput {
+        page: 1,
+        page_size: 12,
+    }
+}
+
+#[tokio::test]
+async fn handyman_nearby_tasks() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let customer_client =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    let handyman_client =
+        graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    graphql::sign_up_customer(&customer_client, &core_service.sms_receiver, "+84334445555").await?;
+    let handyman_id =
+        graphql::sign_up_handyman(&handyman_client, &core_service.sms_receiver, "+84334446666")
+            .await?;
+    graphql::handyman_profile_add_services(
+        &handyman_client,
+        HandymanProfileAddServicesInput {
+            handyman_id: handyman_id.clone(),
+            services: vec![NewHandymanService {
+                service_code: Some("AIR_CONDITIONER_FIXING".into()),
+                service: None,
+                note: None,
+                rate_vnd: None,
+            }],
+        },
+    )
+    .await
+    .data
+    .unwrap();
+
+    let matching_task = graphql::customer_create_task(
+        &customer_client,
+        new_task_input("AIR_CONDITIONER_FIXING", HA_NOI),
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_create_task
+    .task;
+    // Not matching the handyman's skills
+    graphql::customer_create_task(
+        &customer_client,
+        new_task_input("WASHING_MACHINE_CLEANING", HA_NOI),
+    )
+    .await
+    .data
+    .unwrap();
+    // Too far away
+    graphql::customer_create_task(
+        &customer_client,
+        new_task_input("AIR_CONDITIONER_FIXING", HO_CHI_MINH),
+    )
+    .await
+    .data
+    .unwrap();
+
+    // Test only tasks matching skills and within the radius are found
+    let result = graphql::handyman_nearby_tasks(
+        &handyman_client,
+        &handyman_id,
+        within(HA_NOI, 5_000.0),
+        first_page(),
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_nearby_tasks;
+    assert_eq!(result.paging_info.total_count, 1);
+    assert_eq!(result.items[0].id, matching_task.id);
+    let coordinates = &result.items[0].location.as_ref().unwrap().coordinates;
+    assert_eq!((coordinates.lon, coordinates.lat), HA_NOI);
+
+    // Test tasks are removed from search once they are no longer open
+    graphql::customer_task_cancel(
+        &customer_client,
+        graphql::customer_task_cancel::CustomerTaskTransitionInput {
+            task_id: matching_task.id.clone(),
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    let result = graphql::handyman_nearby_tasks(
+        &handyman_client,
+        &handyman_id,
+        within(HA_NOI, 5_000.0),
+        first_page(),
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_nearby_tasks;
+    assert_eq!(result.paging_info.total_count, 0);
+    assert!(result.items.is_empty());
+
+    // Test rejecting non-positive distance
+    let response = graphql::handyman_nearby_tasks(
+        &handyman_client,
+        &handyman_id,
+        within(HA_NOI, 0.0),
+        first_page(),
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "within_meters",
+                "description": "NOT_POSITIVE",
+            }]
+        })),
+    );
+
+    // Test customers can't search tasks on behalf of a handyman
+    let response = graphql::handyman_nearby_tasks(
+        &customer_client,
+        &handyman_id,
+        within(HA_NOI, 5_000.0),
+        first_page(),
+    )
+    .await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::FailedPrecondition(None).client_code()
+    );
+
+    // Test only the task owner can look up matching handymen
+    let task = graphql::customer_create_task(
+        &customer_client,
+        new_task_input("AIR_CONDITIONER_FIXING", HA_NOI),
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_create_task
+    .task;
+    // No handyman has a service location yet
+    let result = graphql::customer_task_matching_handymen(
+        &customer_client,
+        &task.id,
+        5_000.0,
+        graphql::customer_task_matching_handymen::PagingOffsetInput {
+            page: 1,
+            page_size: 12,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_task_matching_handymen;
+    assert_eq!(result.paging_info.total_count, 0);
+
+    // Test matching handymen once the handyman sets their service location
+    graphql::handyman_profile_set_service_location(
+        &handyman_client,
+        graphql::handyman_profile_set_service_location::HandymanProfileSetServiceLocationInput {
+            handyman_id: handyman_id.clone(),
+            location: graphql::handyman_profile_set_service_location::LocationInput {
+                city: "Hà Nội".into(),
+                address_line1: "2 Tràng Tiền".into(),
+                formatted_address: "2 Tràng Tiền, Hoàn Kiếm, Hà Nội".into(),
+                corrdinates: graphql::handyman_profile_set_service_location::GeoCoordinates {
+                    lon: HA_NOI.0,
+                    lat: HA_NOI.1,
+                },
+            },
+        },
+    )
+    .await
+    .data
+    .unwrap();
+    let result = graphql::customer_task_matching_handymen(
+        &customer_client,
+        &task.id,
+        5_000.0,
+        graphql::customer_task_matching_handymen::PagingOffsetInput {
+            page: 1,
+            page_size: 12,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_task_matching_handymen;
+    assert_eq!(result.paging_info.total_count, 1);
+    assert_eq!(result.items[0].id, handyman_id);
+    assert!(result.distance_meters.unwrap()[0] < 1.0);
+
+    let response = graphql::customer_task_matching_handymen(
+        &handyman_client,
+        &task.id,
+        5_000.0,
+        graphql::customer_task_matching_handymen::PagingOffsetInput {
+            page: 1,
+            page_size: 12,
+        },
+    )
+    .await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::FailedPrecondition(None).client_code()
+    );
+
+    Ok(())
+}
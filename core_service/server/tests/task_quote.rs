@@ -0,0 +1,293 @@
+mod graphql;
+
+use chrono::DateTime;
+use error::{ErrorVariant, Result};
+use graphql::handyman_task_quote_submit::{HandymanTaskQuoteSubmitInput, TaskQuoteStatus};
+use test_service_orchestration::{ServiceEnvironment, ServiceParams};
+
+fn quote_input(task_id: &str, handyman_id: &str, price_vnd: i64) -> HandymanTaskQuoteSubmitInput {
+    HandymanTaskQuoteSubmitInput {
+        task_id: task_id.into(),
+        handyman_id: handyman_id.into(),
+        price_vnd,
+        eta: DateTime::parse_from_rfc3339("2025-11-01T18:00:00+07:00").unwrap(),
+        message: Some("Có thể làm ngay".into()),
+    }
+}
+
+#[tokio::test]
+async fn task_quote() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let new_client =
+        || graphql::GraphqlClient::new(format!("http://{}/graphql", core_service.service_host));
+    let customer_client = new_client();
+    let handyman1_client = new_client();
+    let handyman2_client = new_client();
+    graphql::sign_up_customer(&customer_client, &core_service.sms_receiver, "+84334445555").await?;
+    let handyman1_id = graphql::sign_up_handyman(
+        &handyman1_client,
+        &core_service.sms_receiver,
+        "+84334446666",
+    )
+    .await?;
+    let handyman2_id = graphql::sign_up_handyman(
+        &handyman2_client,
+        &core_service.sms_receiver,
+        "+84334447777",
+    )
+    .await?;
+
+    let task = graphql::customer_create_task(&customer_client, graphql::new_task_input())
+        .await
+        .data
+        .unwrap()
+        .customer_create_task
+        .task;
+
+    // Test submitting quotes by two handymen
+    let quote1 = graphql::handyman_task_quote_submit(
+        &handyman1_client,
+        quote_input(&task.id, &handyman1_id, 500_000),
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_task_quote_submit
+    .quote;
+    assert!(matches!(quote1.status, TaskQuoteStatus::PENDING));
+    assert_eq!(quote1.task.id, task.id);
+    assert_eq!(quote1.handyman.id, handyman1_id);
+    assert_eq!(quote1.price_vnd, 500_000);
+    assert_eq!(quote1.eta.to_rfc3339(), "2025-11-01T11:00:00+00:00");
+
+    let quote2 = graphql::handyman_task_quote_submit(
+        &handyman2_client,
+        quote_input(&task.id, &handyman2_id, 400_000),
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_task_quote_submit
+    .quote;
+
+    // Test a handyman can quote a task only once
+    let response = graphql::handyman_task_quote_submit(
+        &handyman1_client,
+        quote_input(&task.id, &handyman1_id, 450_000),
+    )
+    .await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::FailedPrecondition(None).client_code()
+    );
+
+    // Test rejecting non-positive price
+    let response = graphql::handyman_task_quote_submit(
+        &handyman1_client,
+        quote_input(&task.id, &handyman1_id, 0),
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::InvalidArgument(None),
+        Some(serde_json::json!({
+            "fieldViolations": [{
+                "field": "price_vnd",
+                "description": "NOT_POSITIVE",
+            }]
+        })),
+    );
+
+    // Test customer sees all quotes ordered by price, a handyman sees only their own
+    let quotes = graphql::customer_task_quotes(&customer_client, &task.id)
+        .await
+        .data
+        .unwrap()
+        .node
+        .unwrap()
+        .try_quotes()?;
+    assert_eq!(
+        quotes.iter().map(|q| q.id.as_str()).collect::<Vec<_>>(),
+        vec![quote2.id.as_str(), quote1.id.as_str()]
+    );
+    let quotes = graphql::customer_task_quotes(&handyman1_client, &task.id)
+        .await
+        .data
+        .unwrap()
+        .node
+        .unwrap()
+        .try_quotes()?;
+    assert_eq!(
+        quotes.iter().map(|q| q.id.as_str()).collect::<Vec<_>>(),
+        vec![quote1.id.as_str()]
+    );
+
+    // Test a handyman can't accept a quote
+    let response = graphql::customer_task_quote_accept(
+        &handyman1_client,
+        graphql::customer_task_quote_accept::CustomerTaskQuoteAcceptInput {
+            quote_id: quote1.id.clone(),
+        },
+    )
+    .await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::FailedPrecondition(None).client_code()
+    );
+
+    // Test accepting a quote assigns the task and rejects the other quotes
+    let accepted = graphql::customer_task_quote_accept(
+        &customer_client,
+        graphql::customer_task_quote_accept::CustomerTaskQuoteAcceptInput {
+            quote_id: quote1.id.clone(),
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .customer_task_quote_accept;
+    assert!(matches!(
+        accepted.quote.status,
+        graphql::customer_task_quote_accept::TaskQuoteStatus::ACCEPTED
+    ));
+    assert!(matches!(
+        accepted.task.status,
+        graphql::customer_task_quote_accept::CustomerTaskStatus::ASSIGNED
+    ));
+    assert_eq!(accepted.task.assigned_handyman.unwrap().id, handyman1_id);
+
+    let quotes = graphql::customer_task_quotes(&handyman2_client, &task.id)
+        .await
+        .data
+        .unwrap()
+        .node
+        .unwrap()
+        .try_quotes()?;
+    assert!(matches!(
+        quotes[0].status,
+        graphql::customer_task_quotes::TaskQuoteStatus::REJECTED
+    ));
+
+    // Test rejected quote can't be withdrawn
+    let response = graphql::handyman_task_quote_withdraw(
+        &handyman2_client,
+        graphql::handyman_task_quote_withdraw::HandymanTaskQuoteWithdrawInput {
+            handyman_id: handyman2_id.clone(),
+            quote_id: quote2.id.clone(),
+        },
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::FailedPrecondition(None),
+        Some(serde_json::json!({
+            "violations": [{
+                "type": "UNEXPECTED_STATUS",
+                "subject": "quote_status",
+                "description": "Rejected",
+            }]
+        })),
+    );
+
+    // Test assigned task can't be quoted anymore
+    let handyman3_client = new_client();
+    let handyman3_id = graphql::sign_up_handyman(
+        &handyman3_client,
+        &core_service.sms_receiver,
+        "+84334448888",
+    )
+    .await?;
+    let response = graphql::handyman_task_quote_submit(
+        &handyman3_client,
+        quote_input(&task.id, &handyman3_id, 300_000),
+    )
+    .await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::FailedPrecondition(None),
+        Some(serde_json::json!({
+            "violations": [{
+                "type": "UNEXPECTED_STATUS",
+                "subject": "task_status",
+                "description": "Assigned",
+            }]
+        })),
+    );
+
+    // Test a handyman can't read a task which is no longer open, unless they quoted it
+    let response = graphql::customer_task_quotes(&handyman3_client, &task.id).await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::NotFound(None).client_code()
+    );
+    let quotes = graphql::customer_task_quotes(&handyman2_client, &task.id)
+        .await
+        .data
+        .unwrap()
+        .node
+        .unwrap()
+        .try_quotes()?;
+    assert_eq!(quotes.len(), 1);
+
+    // Test withdrawing a pending quote
+    let task = graphql::customer_create_task(&customer_client, graphql::new_task_input())
+        .await
+        .data
+        .unwrap()
+        .customer_create_task
+        .task;
+    let quote = graphql::handyman_task_quote_submit(
+        &handyman3_client,
+        quote_input(&task.id, &handyman3_id, 300_000),
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_task_quote_submit
+    .quote;
+    let quote = graphql::handyman_task_quote_withdraw(
+        &handyman3_client,
+        graphql::handyman_task_quote_withdraw::HandymanTaskQuoteWithdrawInput {
+            handyman_id: handyman3_id.clone(),
+            quote_id: quote.id,
+        },
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_task_quote_withdraw
+    .quote;
+    assert!(matches!(
+        quote.status,
+        graphql::handyman_task_quote_withdraw::TaskQuoteStatus::WITHDRAWN
+    ));
+
+    // Test a handyman can quote again after withdrawing, but still only once at a time
+    let quote = graphql::handyman_task_quote_submit(
+        &handyman3_client,
+        quote_input(&task.id, &handyman3_id, 350_000),
+    )
+    .await
+    .data
+    .unwrap()
+    .handyman_task_quote_submit
+    .quote;
+    assert!(matches!(quote.status, TaskQuoteStatus::PENDING));
+    assert_eq!(quote.price_vnd, 350_000);
+
+    let response = graphql::handyman_task_quote_submit(
+        &handyman3_client,
+        quote_input(&task.id, &handyman3_id, 320_000),
+    )
+    .await;
+    assert_eq!(
+        graphql::extract_error_details(response).get_code(),
+        ErrorVariant::FailedPrecondition(None).client_code()
+    );
+
+    Ok(())
+}
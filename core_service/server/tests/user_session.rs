@@ -0,0 +1,250 @@
+mod graphql;
+
+use chrono::Duration;
+use error::{ErrorVariant, Result};
+use graphql::switch_active_role::SwitchActiveRoleSwitchActiveRoleSessionActorType as ActorType;
+use graphql::user_sign_in_with_password::{AccountType, UserSignInWithPasswordInput};
+use otp_store::OtpPolicy;
+use test_service_orchestration::{ServiceEnvironment, ServiceParams, core_service};
+
+#[tokio::test]
+async fn sign_out_renew_and_revoke_sessions() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams::default().init().await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let phone_number = "+84334445555";
+    let sign_in_input = || UserSignInWithPasswordInput {
+        phone_number: phone_number.into(),
+        password: "12345678".into(),
+        account_type: AccountType::CUSTOMER,
+    };
+
+    // Sign in on two devices
+    let phone = graphql::GraphqlClient::new(graphql_url.clone());
+    graphql::sign_up_customer(&phone, &core_service.sms_receiver, phone_number).await?;
+    let laptop = graphql::GraphqlClient::new(graphql_url.clone());
+    graphql::user_sign_in_with_password(&laptop, sign_in_input())
+        .await
+        .data
+        .unwrap();
+
+    let sessions = graphql::user_sessions(&phone)
+        .await
+        .data
+        .unwrap()
+        .user_sessions;
+    assert_eq!(sessions.len(), 2);
+    let (current, others): (Vec<_>, Vec<_>) = sessions.into_iter().partition(|s| s.is_current);
+    assert_eq!(current.len(), 1);
+    let phone_session_id = current[0].id.clone();
+    let laptop_session_id = others[0].id.clone();
+
+    // Test renewal rotates the session token
+    let phone_tokens = phone.current_session().await;
+    graphql::user_renew_session(&phone).await.data.unwrap();
+    assert_ne!(phone.current_session().await, phone_tokens);
+    assert!(
+        graphql::session(&phone)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_some()
+    );
+
+    let stale = graphql::GraphqlClient::new(graphql_url.clone());
+    stale.set_session(phone_tokens).await;
+    assert!(
+        graphql::session(&stale)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_none()
+    );
+
+    // The session stays the same across renewal
+    let sessions = graphql::user_sessions(&phone)
+        .await
+        .data
+        .unwrap()
+        .user_sessions;
+    let current = sessions.iter().find(|s| s.is_current).unwrap();
+    assert_eq!(current.id, phone_session_id);
+
+    // Test revoking another device
+    let revoked = graphql::user_revoke_session(&phone, laptop_session_id)
+        .await
+        .data
+        .unwrap()
+        .user_revoke_session
+        .user_session;
+    assert!(revoked.revoked_at.is_some());
+    assert!(!revoked.is_current);
+    assert!(
+        graphql::session(&laptop)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_none()
+    );
+
+    // Test signing out revokes the token kept by the client
+    let phone_tokens = phone.current_session().await;
+    let signed_out = graphql::user_sign_out(&phone)
+        .await
+        .data
+        .unwrap()
+        .user_sign_out
+        .user_session
+        .unwrap();
+    assert!(signed_out.revoked_at.is_some());
+    assert_eq!(phone.current_session().await, None);
+    stale.set_session(phone_tokens).await;
+    assert!(
+        graphql::session(&stale)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_none()
+    );
+
+    // Test signing out without a session
+    let response = graphql::user_sign_out(&phone).await;
+    assert!(response.data.unwrap().user_sign_out.user_session.is_none());
+
+    // Test revoking all sessions at once
+    graphql::user_sign_in_with_password(&phone, sign_in_input())
+        .await
+        .data
+        .unwrap();
+    graphql::user_sign_in_with_password(&laptop, sign_in_input())
+        .await
+        .data
+        .unwrap();
+    let revoked_count = graphql::user_revoke_all_sessions(&phone)
+        .await
+        .data
+        .unwrap()
+        .user_revoke_all_sessions
+        .revoked_count;
+    assert_eq!(revoked_count, 2);
+    assert!(
+        graphql::session(&laptop)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_none()
+    );
+
+    let response = graphql::user_sessions(&phone).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::Unauthenticated(None),
+        Some(serde_json::json!(null)),
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn switch_active_role() -> Result<()> {
+    let ServiceEnvironment {
+        _pg_container,
+        core_service,
+        ..
+    } = ServiceParams {
+        // Both roles are registered with the same phone number right away
+        core_service: core_service::CoreServiceParams {
+            otp_policy: OtpPolicy {
+                resend_cooldown: Duration::zero(),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+    .init()
+    .await?;
+    let graphql_url = format!("http://{}/graphql", core_service.service_host);
+    let phone_number = "+84334445555";
+    let new_phone_number = "+84334446666";
+
+    let client = graphql::GraphqlClient::new(graphql_url.clone());
+    let handyman_id =
+        graphql::sign_up_handyman(&client, &core_service.sms_receiver, phone_number).await?;
+    let customer_id =
+        graphql::sign_up_customer(&client, &core_service.sms_receiver, phone_number).await?;
+
+    // Test switching from customer to handyman replaces the session
+    let customer_tokens = client.current_session().await;
+    let actor_type = graphql::switch_active_role(&client)
+        .await
+        .data
+        .unwrap()
+        .switch_active_role
+        .session
+        .actor_type;
+    let ActorType::Handyman(handyman) = actor_type else {
+        panic!("Expect handyman, found {actor_type:?}");
+    };
+    assert_eq!(handyman.id, handyman_id);
+    assert_eq!(handyman.phone_number, phone_number);
+
+    let stale = graphql::GraphqlClient::new(graphql_url.clone());
+    stale.set_session(customer_tokens).await;
+    assert!(
+        graphql::session(&stale)
+            .await
+            .data
+            .unwrap()
+            .session
+            .is_none()
+    );
+
+    // Test changing the phone number moves both roles
+    graphql::user_phone_number_change_start(&client, new_phone_number)
+        .await
+        .data
+        .unwrap();
+    let sms = core_service.sms_receiver.receive_sms().await.pop().unwrap();
+    let otp_code = sms
+        .message
+        .try_otp_verification_for_phone_number_change()?
+        .code;
+    graphql::user_phone_number_change_finish(&client, new_phone_number, &otp_code)
+        .await
+        .data
+        .unwrap();
+
+    let actor_type = graphql::switch_active_role(&client)
+        .await
+        .data
+        .unwrap()
+        .switch_active_role
+        .session
+        .actor_type;
+    let ActorType::Customer(customer) = actor_type else {
+        panic!("Expect customer, found {actor_type:?}");
+    };
+    assert_eq!(customer.id, customer_id);
+    assert_eq!(customer.phone_number, new_phone_number);
+
+    // Test a person without the other role can't switch
+    let other = graphql::GraphqlClient::new(graphql_url);
+    graphql::sign_up_customer(&other, &core_service.sms_receiver, phone_number).await?;
+    let response = graphql::switch_active_role(&other).await;
+    graphql::assert_error_response(
+        response,
+        ErrorVariant::FailedPrecondition(None),
+        Some(serde_json::json!(null)),
+    );
+
+    Ok(())
+}
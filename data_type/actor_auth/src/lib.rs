@@ -1,5 +1,5 @@
 use chrono::{DateTime, NaiveDateTime};
-use entity_type::{CustomerId, HandymanId};
+use entity_type::{AccountRole, AccountType, AdminId, CustomerId, HandymanId, UserSessionId};
 use error::{
     Error, Result, assert_argument_is_some,
     error_details::{
@@ -25,12 +25,19 @@ pub struct HandymanActor {
     pub handyman_id: HandymanId,
 }
 
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdminActor {
+    pub admin_id: AdminId,
+}
+
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(tag = "account_type"))]
 pub enum ActorType {
     Customer(CustomerActor),
     Handyman(HandymanActor),
+    Admin(AdminActor),
 }
 
 impl ActorType {
@@ -38,45 +45,68 @@ impl ActorType {
         match self {
             ActorType::Customer(actor) => ActorKey::Customer(actor.customer_id),
             ActorType::Handyman(actor) => ActorKey::Handyman(actor.handyman_id),
+            ActorType::Admin(actor) => ActorKey::Admin(actor.admin_id),
+        }
+    }
+
+    pub fn account_type(&self) -> AccountType {
+        match self {
+            ActorType::Customer(_) => AccountType::Customer,
+            ActorType::Handyman(_) => AccountType::Handyman,
+            ActorType::Admin(_) => AccountType::Admin,
+        }
+    }
+
+    pub fn account_role(&self) -> AccountRole {
+        match self {
+            ActorType::Customer(_) | ActorType::Handyman(_) => AccountRole::User,
+            ActorType::Admin(_) => AccountRole::Admin,
         }
     }
 
     pub fn try_customer(&self) -> Result<&CustomerActor> {
         match self {
             ActorType::Customer(actor) => Ok(actor),
-            ActorType::Handyman(_) => Err(Error::failed_precondition_with(
-                "Unexpected handyman session",
-                Some(PreconditionFailure {
-                    violations: vec![Violation {
-                        r#type: "HANDYMAN".into(),
-                        subject: "session".into(),
-                        description: "".into(),
-                    }],
-                }),
-            )),
+            other => Err(other.unexpected_session_error()),
         }
     }
 
     pub fn try_handyman(&self) -> Result<&HandymanActor> {
         match self {
-            ActorType::Customer(_) => Err(Error::failed_precondition_with(
-                "Unexpected customer session",
-                Some(PreconditionFailure {
-                    violations: vec![Violation {
-                        r#type: "CUSTOMER".into(),
-                        subject: "session".into(),
-                        description: "".into(),
-                    }],
-                }),
-            )),
             ActorType::Handyman(actor) => Ok(actor),
+            other => Err(other.unexpected_session_error()),
+        }
+    }
+
+    pub fn try_admin(&self) -> Result<&AdminActor> {
+        match self {
+            ActorType::Admin(actor) => Ok(actor),
+            other => Err(other.unexpected_session_error()),
         }
     }
+
+    fn unexpected_session_error(&self) -> Error {
+        let (message, r#type) = match self {
+            ActorType::Customer(_) => ("Unexpected customer session", "CUSTOMER"),
+            ActorType::Handyman(_) => ("Unexpected handyman session", "HANDYMAN"),
+            ActorType::Admin(_) => ("Unexpected admin session", "ADMIN"),
+        };
+        Error::failed_precondition_with(
+            message,
+            Some(PreconditionFailure {
+                violations: vec![Violation {
+                    r#type: r#type.into(),
+                    subject: "session".into(),
+                    description: "".into(),
+                }],
+            }),
+        )
+    }
 }
 
 impl From<ActorType> for proto::ActorType {
     fn from(value: ActorType) -> Self {
-        use proto::{CustomerActor, HandymanActor, actor_type::Inner};
+        use proto::{AdminActor, CustomerActor, HandymanActor, actor_type::Inner};
 
         let inner = match value {
             ActorType::Customer(c) => Inner::Customer(CustomerActor {
@@ -85,6 +115,9 @@ impl From<ActorType> for proto::ActorType {
             ActorType::Handyman(h) => Inner::Handyman(HandymanActor {
                 handyman_id: h.handyman_id.0,
             }),
+            ActorType::Admin(a) => Inner::Admin(AdminActor {
+                admin_id: a.admin_id.0,
+            }),
         };
 
         proto::ActorType { inner: Some(inner) }
@@ -104,6 +137,9 @@ impl TryFrom<proto::ActorType> for ActorType {
             Some(Inner::Handyman(h)) => Ok(ActorType::Handyman(HandymanActor {
                 handyman_id: HandymanId(h.handyman_id),
             })),
+            Some(Inner::Admin(a)) => Ok(ActorType::Admin(AdminActor {
+                admin_id: AdminId(a.admin_id),
+            })),
             None => Err(Error::invalid_argument_with(
                 "Missing session type inner",
                 Some(BadRequest {
@@ -121,19 +157,19 @@ impl TryFrom<proto::ActorType> for ActorType {
 pub enum ActorKey {
     Customer(CustomerId),
     Handyman(HandymanId),
+    Admin(AdminId),
 }
 
 impl<'a> From<&'a ActorType> for ActorKey {
     fn from(value: &'a ActorType) -> Self {
-        match value {
-            ActorType::Customer(actor) => ActorKey::Customer(actor.customer_id),
-            ActorType::Handyman(actor) => ActorKey::Handyman(actor.handyman_id),
-        }
+        value.actor_key()
     }
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct Session {
+    /// Server side session, stays the same when the session token is renewed
+    pub session_id: UserSessionId,
     pub iat: NaiveDateTime,
     pub exp: NaiveDateTime,
     pub actor_type: ActorType,
@@ -151,6 +187,7 @@ impl From<Session> for proto::Session {
             iat: value.iat.and_utc().timestamp(),
             exp: value.exp.and_utc().timestamp(),
             actor_type: Some(proto::ActorType::from(value.actor_type)),
+            session_id: value.session_id.0,
         }
     }
 }
@@ -163,11 +200,13 @@ impl TryFrom<proto::Session> for Session {
             iat,
             exp,
             actor_type,
+            session_id,
         }: proto::Session,
     ) -> Result<Self> {
         assert_argument_is_some!(actor_type);
 
         Ok(Self {
+            session_id: UserSessionId(session_id),
             iat: timestamp_to_naive_date_time(iat)?,
             exp: timestamp_to_naive_date_time(exp)?,
             actor_type: ActorType::try_from(actor_type)?,
@@ -225,8 +264,12 @@ impl ActorAuth {
     }
 
     pub fn try_session_actor(&self) -> Result<&ActorType> {
-        self.session_actor().ok_or_else(|| {
-            Error::failed_precondition_with(
+        self.try_session().map(|session| &session.actor_type)
+    }
+
+    pub fn try_session(&self) -> Result<&Session> {
+        let ActorAuth::Session(session) = self else {
+            return Err(Error::failed_precondition_with(
                 "Expect a user session",
                 Some(PreconditionFailure {
                     violations: vec![Violation {
@@ -235,8 +278,9 @@ impl ActorAuth {
                         description: "".into(),
                     }],
                 }),
-            )
-        })
+            ));
+        };
+        Ok(session)
     }
 
     /// Check if the actor is the system.
@@ -244,10 +288,10 @@ impl ActorAuth {
         matches!(self, ActorAuth::God)
     }
 
-    /// Check if the actor is admin account.
-    /// N/B: in future, we might support admin account.
+    /// Check if the actor is signed in with an admin account.
     pub fn is_admin(&self) -> bool {
-        false
+        self.session_actor()
+            .is_some_and(|actor| actor.account_role() == AccountRole::Admin)
     }
 
     pub fn is_god_or_admin(&self) -> bool {
@@ -7,4 +7,13 @@ define_graphql_enum!(
     AccountType #[doc = "Types of account"],
     Customer #[doc = "Person needs home repair service"],
     Handyman #[doc = "Person provides home repair service"],
+    Admin #[doc = "Staff operating the platform, can't be registered by themselves"],
+);
+
+define_graphql_enum!(
+    PgType = "text",
+    AccountStatus #[doc = "Whether a customer or handyman account can be used"],
+    Active #[doc = "The account can sign in and use the platform"],
+    Suspended #[doc = "Blocked by staff, sign in and existing sessions are rejected"],
+    Deleted #[doc = "Deleted by the owner or staff, personal data is anonymized"],
 );
@@ -6,4 +6,10 @@ def_id_newtype! {
     HandymanServiceId,
     ScheduleId,
     CustomerTaskRequestId,
+    TaskQuoteId,
+    HandymanReviewId,
+    CustomerTaskPhotoId,
+    UserSessionId,
+    AdminId,
+    PersonId,
 }
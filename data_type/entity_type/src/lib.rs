@@ -21,3 +21,6 @@ pub use service::*;
 
 mod schedule;
 pub use schedule::*;
+
+mod task;
+pub use task::*;
@@ -1,5 +1,9 @@
 /// The def_services! macro generates the ServiceLayer1 and ServiceLayer2 enums,
 /// along with the layer1 and layer2 conversion methods for convenience.
+///
+/// The service catalog lives in the database, these enums only cover the groups and services
+/// predating it. Tasks and handyman services store catalog codes, the enums are only kept to map
+/// the codes of existing rows.
 macro_rules! def_services {
     (
         $(
@@ -10,7 +14,9 @@ macro_rules! def_services {
     ) => {
         #[derive(
             Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize, strum_macros::EnumIter,
+            strum_macros::IntoStaticStr, strum_macros::EnumString,
         )]
+        #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
         #[cfg_attr(
             feature = "db",
             derive(diesel_derive_enum::DbEnum),
@@ -24,7 +30,9 @@ macro_rules! def_services {
 
         #[derive(
             Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize, strum_macros::EnumIter,
+            strum_macros::IntoStaticStr, strum_macros::EnumString,
         )]
+        #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
         #[cfg_attr(
             feature = "db",
             derive(diesel_derive_enum::DbEnum),
@@ -39,6 +47,16 @@ macro_rules! def_services {
         }
 
         impl ServiceLayer2 {
+            /// Code of the service in the service catalog.
+            pub fn code(&self) -> &'static str {
+                self.into()
+            }
+
+            /// Returns None for catalog services added after the enum was frozen.
+            pub fn from_code(code: &str) -> Option<Self> {
+                code.parse().ok()
+            }
+
             /// Returns the parent [`ServiceLayer1`] for the current [`ServiceLayer2`].
             pub fn layer1(&self) -> ServiceLayer1 {
                 match self {
@@ -52,6 +70,16 @@ macro_rules! def_services {
         }
 
         impl ServiceLayer1 {
+            /// Code of the group in the service catalog.
+            pub fn code(&self) -> &'static str {
+                self.into()
+            }
+
+            /// Returns None for catalog groups added after the enum was frozen.
+            pub fn from_code(code: &str) -> Option<Self> {
+                code.parse().ok()
+            }
+
             /// Returns a slice containing all child [`ServiceLayer2`] variants.
             pub fn layer2(&self) -> &[ServiceLayer2] {
                 match self {
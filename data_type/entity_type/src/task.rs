@@ -0,0 +1,22 @@
+use crate::define_graphql_enum;
+
+define_graphql_enum!(
+    PgType = "text",
+    CustomerTaskStatus #[doc = "Lifecycle status of a customer task request"],
+    Draft #[doc = "Task is saved but not visible to handymen yet"],
+    Open #[doc = "Task is published and waiting for a handyman"],
+    Assigned #[doc = "A handyman is assigned to the task"],
+    InProgress #[doc = "The assigned handyman started working on the task"],
+    Completed #[doc = "The assigned handyman finished the task"],
+    Cancelled #[doc = "The customer cancelled the task"],
+    Expired #[doc = "Nobody took the task in time"],
+);
+
+define_graphql_enum!(
+    PgType = "text",
+    TaskQuoteStatus #[doc = "Status of a handyman quote on a customer task"],
+    Pending #[doc = "Waiting for the customer to decide"],
+    Accepted #[doc = "The customer accepted the quote and assigned the task to the handyman"],
+    Rejected #[doc = "The task was assigned to someone else or closed"],
+    Withdrawn #[doc = "The handyman withdrew the quote"],
+);
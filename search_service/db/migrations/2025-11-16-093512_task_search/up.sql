@@ -0,0 +1,12 @@
+-- Search table for open customer tasks. Used to match tasks with handymen by service and location.
+-- Tasks are removed from the index once they are no longer open.
+
+CREATE TABLE task (
+    task_id BIGINT PRIMARY KEY,
+    service TEXT NOT NULL,
+    location GEOGRAPHY(POINT, 4326) NOT NULL,
+    opened_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX task_service_idx ON task(service);
+CREATE INDEX task_location_idx ON task USING GIST (location);
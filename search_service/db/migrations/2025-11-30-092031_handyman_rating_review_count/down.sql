@@ -0,0 +1 @@
+ALTER TABLE handyman DROP COLUMN rating_review_count;
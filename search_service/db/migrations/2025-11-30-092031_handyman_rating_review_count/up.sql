@@ -0,0 +1,4 @@
+-- Number of reviews `avg_rating_score` was computed from.
+-- A score computed from fewer reviews than the indexed one is stale and must not overwrite it.
+
+ALTER TABLE handyman ADD COLUMN rating_review_count INT NOT NULL DEFAULT 0;
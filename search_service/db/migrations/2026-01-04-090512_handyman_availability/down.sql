@@ -0,0 +1,2 @@
+DROP TABLE handyman_blackout;
+DROP TABLE handyman_working_slot;
@@ -0,0 +1,22 @@
+-- Weekly working slots and days off of handymen, for search by availability.
+-- Times are local times of the handyman, slots crossing midnight are split at midnight.
+
+CREATE TABLE handyman_working_slot (
+    handyman_id BIGINT NOT NULL REFERENCES handyman(handyman_id) ON DELETE CASCADE,
+    -- Map to rust enum Weekday
+    weekday TEXT NOT NULL,
+    -- Minutes since midnight, the slot covers [start_minute, end_minute)
+    start_minute INT NOT NULL CHECK (start_minute >= 0 AND start_minute < 1440),
+    end_minute INT NOT NULL CHECK (end_minute > start_minute AND end_minute <= 1440),
+
+    PRIMARY KEY (handyman_id, weekday, start_minute)
+);
+
+CREATE INDEX handyman_working_slot_weekday_idx ON handyman_working_slot(weekday, start_minute, end_minute);
+
+CREATE TABLE handyman_blackout (
+    handyman_id BIGINT NOT NULL REFERENCES handyman(handyman_id) ON DELETE CASCADE,
+    date DATE NOT NULL,
+
+    PRIMARY KEY (handyman_id, date)
+);
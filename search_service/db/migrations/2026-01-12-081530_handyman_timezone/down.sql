@@ -0,0 +1 @@
+ALTER TABLE handyman DROP COLUMN timezone;
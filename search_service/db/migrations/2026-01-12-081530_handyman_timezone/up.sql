@@ -0,0 +1,4 @@
+-- IANA time zone of the working slots and days off of handymen,
+-- searched times are converted into it before matching.
+
+ALTER TABLE handyman ADD COLUMN timezone TEXT NOT NULL DEFAULT 'Asia/Ho_Chi_Minh';
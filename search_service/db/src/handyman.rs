@@ -1,9 +1,17 @@
-use crate::schema::handyman;
+use crate::schema::{handyman, handyman_blackout, handyman_working_slot};
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
 use db_utils::{AsyncPgConnection, PaginateOffset};
-use diesel::{prelude::*, upsert::excluded};
+use diesel::{
+    define_sql_function,
+    dsl::{self, exists, not},
+    prelude::*,
+    sql_types::{Bool, Date, Float8, Nullable, Text, Timestamp, Timestamptz},
+    upsert::excluded,
+};
 use diesel_async::RunQueryDsl;
 use diesel_full_text_search::{self as dfts, TsVectorExtensions};
-use entity_type::{HandymanId, ServiceLayer2};
+use entity_type::{HandymanId, Weekday, WeekdayMapping};
 use error::{
     Error, Result,
     error_details::{BadRequest, bad_request::FieldViolation},
@@ -11,12 +19,37 @@ use error::{
 use paging::{PagingOffsetConfig, PagingOffsetInfo, PagingOffsetPayload};
 use postgis_diesel::types::Point;
 
+/// SRID of WGS 84, the coordinate system of longitude/latitude.
+pub(crate) const SRID: u32 = 4326;
+
+define_sql_function! {
+    #[sql_name = "timezone"]
+    /// `ts AT TIME ZONE zone`, the wall clock time of `ts` in the IANA time zone `zone`
+    fn at_time_zone(zone: Text, ts: Timestamptz) -> Timestamp
+}
+
+define_sql_function! {
+    #[sql_name = "to_char"]
+    /// With the `DY` format, the upper case abbreviated day name which is stored as [`Weekday`]
+    fn weekday_of(ts: Timestamp, format: Text) -> WeekdayMapping
+}
+
+define_sql_function! {
+    fn date_part(field: Text, ts: Timestamp) -> Float8
+}
+
+define_sql_function! {
+    #[sql_name = "date"]
+    fn date_of(ts: Timestamp) -> Date
+}
+
 #[derive(Debug, Queryable, Selectable)]
 #[diesel(table_name = handyman)]
 pub struct HandymanSearch {
     pub handyman_id: HandymanId,
     pub full_name: Option<String>,
-    pub skills: Option<Vec<Option<ServiceLayer2>>>,
+    /// Codes of catalog services
+    pub skills: Option<Vec<Option<String>>>,
     pub avg_rating_score: Option<i16>,
     pub location: Option<Point>,
 }
@@ -44,7 +77,7 @@ impl HandymanSearch {
 
     pub async fn index_add_skills(
         handyman_id: HandymanId,
-        skills: &[ServiceLayer2],
+        skills: &[String],
         conn: &mut AsyncPgConnection,
     ) -> Result<Self> {
         let result = diesel::insert_into(handyman::table)
@@ -64,9 +97,181 @@ impl HandymanSearch {
         Ok(result)
     }
 
+    pub async fn index_location(
+        handyman_id: HandymanId,
+        lon: f64,
+        lat: f64,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        validate_lon_lat(lon, lat)?;
+
+        let result = diesel::insert_into(handyman::table)
+            .values((
+                handyman::handyman_id.eq(handyman_id),
+                handyman::location.eq(Point {
+                    x: lon,
+                    y: lat,
+                    srid: Some(SRID),
+                }),
+            ))
+            .on_conflict(handyman::handyman_id)
+            .do_update()
+            .set(handyman::location.eq(excluded(handyman::location)))
+            .returning(Self::as_returning())
+            .get_result(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Set average rating score (0..500, i.e. stars scaled by 100) computed from `review_count` reviews,
+    /// `None` clears the score of a handyman without review.
+    /// A score computed from fewer reviews than the indexed one is stale and ignored, `None` is returned.
+    pub async fn index_avg_rating_score(
+        handyman_id: HandymanId,
+        avg_rating_score: Option<i16>,
+        review_count: i32,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Option<Self>> {
+        if let Some(score) = avg_rating_score
+            && !(0..=500).contains(&score)
+        {
+            return Err(Error::invalid_argument_with(
+                "Invalid average rating score: Score must be between 0 and 500.",
+                Some(BadRequest {
+                    field_violations: vec![FieldViolation {
+                        field: "avg_rating_score".into(),
+                        description: "OUT_OF_RANGE".into(),
+                    }],
+                }),
+            ));
+        }
+
+        diesel::insert_into(handyman::table)
+            .values(handyman::handyman_id.eq(handyman_id))
+            .on_conflict_do_nothing()
+            .execute(conn)
+            .await?;
+
+        let result = diesel::update(
+            handyman::table
+                .filter(handyman::handyman_id.eq(handyman_id))
+                .filter(handyman::rating_review_count.le(review_count)),
+        )
+        .set((
+            handyman::avg_rating_score.eq(avg_rating_score),
+            handyman::rating_review_count.eq(review_count),
+        ))
+        .returning(Self::as_returning())
+        .get_result(conn)
+        .await
+        .optional()?;
+
+        Ok(result)
+    }
+
+    /// Replace the working slots and days off of a handyman.
+    /// Must be called in a transaction.
+    pub async fn index_availability(
+        handyman_id: HandymanId,
+        HandymanAvailability {
+            timezone,
+            working_slots,
+            blackout_dates,
+        }: HandymanAvailability,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        for slot in &working_slots {
+            slot.validate()?;
+        }
+        // Searches convert times with the time zone database of postgres, which may lag behind
+        // the one of chrono-tz
+        let timezone_known = diesel::select(
+            dsl::sql::<Bool>("EXISTS (SELECT 1 FROM pg_timezone_names WHERE name = ")
+                .bind::<Text, _>(timezone.name())
+                .sql(")"),
+        )
+        .get_result::<bool>(conn)
+        .await?;
+        if !timezone_known {
+            return Err(Error::invalid_argument_with(
+                format!("Unknown time zone {}", timezone.name()),
+                Some(BadRequest {
+                    field_violations: vec![FieldViolation {
+                        field: "timezone".into(),
+                        description: "UNKNOWN_TIMEZONE".into(),
+                    }],
+                }),
+            ));
+        }
+
+        // Make sure the handyman is indexed, slots and days off reference it
+        let result = diesel::insert_into(handyman::table)
+            .values((
+                handyman::handyman_id.eq(handyman_id),
+                handyman::timezone.eq(timezone.name()),
+            ))
+            .on_conflict(handyman::handyman_id)
+            .do_update()
+            .set(handyman::timezone.eq(excluded(handyman::timezone)))
+            .returning(Self::as_returning())
+            .get_result(conn)
+            .await?;
+
+        diesel::delete(
+            handyman_working_slot::table.filter(handyman_working_slot::handyman_id.eq(handyman_id)),
+        )
+        .execute(conn)
+        .await?;
+        diesel::delete(
+            handyman_blackout::table.filter(handyman_blackout::handyman_id.eq(handyman_id)),
+        )
+        .execute(conn)
+        .await?;
+
+        if !working_slots.is_empty() {
+            diesel::insert_into(handyman_working_slot::table)
+                .values(
+                    working_slots
+                        .iter()
+                        .map(|slot| {
+                            (
+                                handyman_working_slot::handyman_id.eq(handyman_id),
+                                handyman_working_slot::weekday.eq(slot.weekday),
+                                handyman_working_slot::start_minute.eq(slot.start_minute),
+                                handyman_working_slot::end_minute.eq(slot.end_minute),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .execute(conn)
+                .await?;
+        }
+
+        if !blackout_dates.is_empty() {
+            diesel::insert_into(handyman_blackout::table)
+                .values(
+                    blackout_dates
+                        .iter()
+                        .map(|date| {
+                            (
+                                handyman_blackout::handyman_id.eq(handyman_id),
+                                handyman_blackout::date.eq(*date),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .on_conflict_do_nothing()
+                .execute(conn)
+                .await?;
+        }
+
+        Ok(result)
+    }
+
     pub async fn index_remove_skill(
         handyman_id: HandymanId,
-        skill: ServiceLayer2,
+        skill: &str,
         conn: &mut AsyncPgConnection,
     ) -> Result<Option<Self>> {
         let result = diesel::update(handyman::table.filter(handyman::handyman_id.eq(handyman_id)))
@@ -92,34 +297,56 @@ impl HandymanSearch {
         Ok(result)
     }
 
+    /// Indexed skills of a handyman, empty if the handyman is not indexed.
+    pub async fn load_skills(
+        handyman_id: HandymanId,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<String>> {
+        let skills = handyman::table
+            .filter(handyman::handyman_id.eq(handyman_id))
+            .select(handyman::skills)
+            .first::<Option<Vec<Option<String>>>>(conn)
+            .await
+            .optional()?
+            .flatten()
+            .unwrap_or_default();
+
+        Ok(skills.into_iter().flatten().collect())
+    }
+
     /// Search handyman order by ranking desc and location distance asc (if location filter is included).
+    /// Distance in meters is returned along with each result if location filter is included.
     /// The resulting query looks like:
-    /// SELECT "handyman"."handyman_id" FROM "handyman"
+    /// SELECT "handyman"."handyman_id", ST_Distance(ST_SetSRID("handyman"."location", 4326), ST_SetSRID(ST_MakePoint(100.0, 90.0), 4326)) FROM "handyman"
     ///     WHERE (
     ///         (
     ///             ("handyman"."handyman_id" = ANY('{1, 2, 3}'))
     ///                 AND
     ///             (("handyman"."search_vector" IS NOT NULL) AND "handyman"."search_vector" @@ plainto_tsquery(unaccent('simple', 'John')))
     ///                 AND
-    ///             ("handyman"."skills" && '{AirConditionerFixing, WashingMachineFixing}')
+    ///             ("handyman"."skills" && '{AIR_CONDITIONER_FIXING, WASHING_MACHINE_FIXING}')
     ///         )
     ///             AND
     ///         (("handyman"."location" IS NOT NULL) AND ST_DWithin(ST_SetSRID("handyman"."location", 4326),ST_SetSRID(ST_MakePoint(100.0, 90.0), 4326), 5000.0)
     ///     )
-    ///     ORDER BY "handyman"."avg_rating_score" DESC, ST_Distance(ST_SetSRID("handyman"."location", 4326), ST_SetSRID(ST_MakePoint(100.0, 90.0), 4326)) ASC;
+    ///     ORDER BY "handyman"."avg_rating_score" DESC NULLS LAST, ST_Distance(ST_SetSRID("handyman"."location", 4326), ST_SetSRID(ST_MakePoint(100.0, 90.0), 4326)) ASC;
     pub async fn search(
         HandymanSearchFilter {
             handyman_ids,
             name,
             skills,
             distance_within,
+            available_at,
         }: HandymanSearchFilter,
         paging_config: PagingOffsetConfig,
         conn: &mut AsyncPgConnection,
-    ) -> Result<PagingOffsetPayload<HandymanId>> {
+    ) -> Result<PagingOffsetPayload<HandymanSearchResult>> {
         let mut query = handyman::table
-            .select(handyman::handyman_id)
-            .order(handyman::avg_rating_score.desc())
+            .select((
+                handyman::handyman_id,
+                None::<f64>.into_sql::<Nullable<Float8>>(),
+            ))
+            .order(handyman::avg_rating_score.desc().nulls_last())
             .into_boxed();
 
         if let Some(handyman_ids) = handyman_ids {
@@ -141,6 +368,39 @@ impl HandymanSearch {
             query = query.filter(handyman::skills.overlaps_with(skills));
         }
 
+        if let Some(available_at) = available_at {
+            // Working slots and days off are wall clock times of the handyman time zone,
+            // so the searched time is converted into the time zone of each handyman
+            let local_time = || at_time_zone(handyman::timezone, available_at);
+            let minute =
+                || date_part("hour", local_time()) * 60.0 + date_part("minute", local_time());
+            query = query
+                .filter(exists(
+                    handyman_working_slot::table.filter(
+                        handyman_working_slot::handyman_id
+                            .eq(handyman::handyman_id)
+                            .and(handyman_working_slot::weekday.eq(weekday_of(local_time(), "DY")))
+                            .and(
+                                handyman_working_slot::start_minute
+                                    .cast::<Float8>()
+                                    .le(minute()),
+                            )
+                            .and(
+                                handyman_working_slot::end_minute
+                                    .cast::<Float8>()
+                                    .gt(minute()),
+                            ),
+                    ),
+                ))
+                .filter(not(exists(
+                    handyman_blackout::table.filter(
+                        handyman_blackout::handyman_id
+                            .eq(handyman::handyman_id)
+                            .and(handyman_blackout::date.eq(date_of(local_time()))),
+                    ),
+                )));
+        }
+
         if let Some(distance_within) = distance_within.map(|f| f.validate()).transpose()? {
             let point = db_utils::st_makepoint(distance_within.lon, distance_within.lat);
             query = query.filter(
@@ -153,19 +413,16 @@ impl HandymanSearch {
                     )),
             );
 
-            query = query.then_order_by(
-                db_utils::st_distance_4326(
-                    // It's ok to assume_not_null here because the filter alread exclude null location records
-                    handyman::location.assume_not_null(),
-                    point,
-                )
-                .asc(),
-            );
+            // It's ok to assume_not_null here because the filter alread exclude null location records
+            let distance = db_utils::st_distance_4326(handyman::location.assume_not_null(), point);
+            query = query
+                .select((handyman::handyman_id, distance.nullable()))
+                .then_order_by(distance.asc());
         }
 
         let (result, total_count) = query
             .paginate_offset(paging_config)
-            .load_and_count_total::<HandymanId>(conn)
+            .load_and_count_total::<HandymanSearchResult>(conn)
             .await?;
 
         Ok(PagingOffsetPayload {
@@ -179,15 +436,25 @@ impl HandymanSearch {
     }
 }
 
+#[derive(Debug, Queryable)]
+pub struct HandymanSearchResult {
+    pub handyman_id: HandymanId,
+    /// Distance in meters to the location of the search filter
+    pub distance_meters: Option<f64>,
+}
+
 #[derive(Debug)]
 /// Filter for handyman search. Fields are AND condition.
 pub struct HandymanSearchFilter {
     /// OR condition on handyman IDs
     pub handyman_ids: Option<Vec<HandymanId>>,
     pub name: Option<String>,
-    /// OR condition on handyman skills
-    pub skills: Option<Vec<ServiceLayer2>>,
+    /// OR condition on handyman skills, by catalog service code
+    pub skills: Option<Vec<String>>,
     pub distance_within: Option<DistanceWithinFilter>,
+    /// Handymen having a working slot at the time and no day off on its date,
+    /// both in the time zone of the handyman
+    pub available_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug)]
@@ -199,35 +466,91 @@ pub struct DistanceWithinFilter {
 
 impl DistanceWithinFilter {
     pub fn validate(self) -> Result<Self> {
-        const MAX_LON: f64 = 180.0;
-        const MIN_LON: f64 = -180.0;
-        const MAX_LAT: f64 = 90.0;
-        const MIN_LAT: f64 = -90.0;
+        validate_lon_lat(self.lon, self.lat)?;
 
-        if self.lon < MIN_LON || self.lon > MAX_LON {
+        if self.within_meters <= 0.0 {
             return Err(Error::invalid_argument_with(
-                "Invalid location: Longitude must be between -180.0 and 180.0.",
+                "Invalid distance: Distance must be positive.",
                 Some(BadRequest {
                     field_violations: vec![FieldViolation {
-                        field: "location".into(),
-                        description: "INVALID_LON".into(),
+                        field: "within_meters".into(),
+                        description: "NOT_POSITIVE".into(),
                     }],
                 }),
             ));
         }
 
-        if self.lat < MIN_LAT || self.lat > MAX_LAT {
+        Ok(self)
+    }
+}
+
+/// Working slots and days off of a handyman, replacing the indexed ones.
+#[derive(Debug)]
+pub struct HandymanAvailability {
+    /// Time zone the working slots and days off are wall clock times of
+    pub timezone: Tz,
+    pub working_slots: Vec<HandymanWorkingSlot>,
+    pub blackout_dates: Vec<NaiveDate>,
+}
+
+/// Weekly working slot covering minutes `[start_minute, end_minute)` since midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandymanWorkingSlot {
+    pub weekday: Weekday,
+    pub start_minute: i32,
+    pub end_minute: i32,
+}
+
+impl HandymanWorkingSlot {
+    pub const MINUTES_PER_DAY: i32 = 24 * 60;
+
+    fn validate(&self) -> Result<()> {
+        if !(0..Self::MINUTES_PER_DAY).contains(&self.start_minute)
+            || !(self.start_minute + 1..=Self::MINUTES_PER_DAY).contains(&self.end_minute)
+        {
             return Err(Error::invalid_argument_with(
-                "Invalid location: Latitude must be between -90.0 and 90.0.",
+                "Invalid working slot: Slot must be within a day and not empty.",
                 Some(BadRequest {
                     field_violations: vec![FieldViolation {
-                        field: "location".into(),
-                        description: "INVALID_LAT".into(),
+                        field: "working_slots".into(),
+                        description: "OUT_OF_RANGE".into(),
                     }],
                 }),
             ));
         }
+        Ok(())
+    }
+}
 
-        Ok(self)
+pub(crate) fn validate_lon_lat(lon: f64, lat: f64) -> Result<()> {
+    const MAX_LON: f64 = 180.0;
+    const MIN_LON: f64 = -180.0;
+    const MAX_LAT: f64 = 90.0;
+    const MIN_LAT: f64 = -90.0;
+
+    if !(MIN_LON..=MAX_LON).contains(&lon) {
+        return Err(Error::invalid_argument_with(
+            "Invalid location: Longitude must be between -180.0 and 180.0.",
+            Some(BadRequest {
+                field_violations: vec![FieldViolation {
+                    field: "location".into(),
+                    description: "INVALID_LON".into(),
+                }],
+            }),
+        ));
     }
+
+    if !(MIN_LAT..=MAX_LAT).contains(&lat) {
+        return Err(Error::invalid_argument_with(
+            "Invalid location: Latitude must be between -90.0 and 90.0.",
+            Some(BadRequest {
+                field_violations: vec![FieldViolation {
+                    field: "location".into(),
+                    description: "INVALID_LAT".into(),
+                }],
+            }),
+        ));
+    }
+
+    Ok(())
 }
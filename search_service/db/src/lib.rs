@@ -5,3 +5,6 @@ pub use migrations::*;
 
 mod handyman;
 pub use handyman::*;
+
+mod task;
+pub use task::*;
@@ -4,9 +4,46 @@ diesel::table! {
     handyman (handyman_id) {
         handyman_id -> Int8,
         full_name -> Nullable<Text>,
-        skills -> Nullable<Array<Nullable<entity_type::ServiceLayer2Mapping>>>,
+        skills -> Nullable<Array<Nullable<Text>>>,
         search_vector -> Nullable<diesel_full_text_search::TsVector>,
         avg_rating_score -> Nullable<Int2>,
         location -> Nullable<postgis_diesel::sql_types::Geography>,
+        rating_review_count -> Int4,
+        timezone -> Text,
     }
 }
+
+diesel::table! {
+    handyman_blackout (handyman_id, date) {
+        handyman_id -> Int8,
+        date -> Date,
+    }
+}
+
+diesel::table! {
+    handyman_working_slot (handyman_id, weekday, start_minute) {
+        handyman_id -> Int8,
+        weekday -> entity_type::WeekdayMapping,
+        start_minute -> Int4,
+        end_minute -> Int4,
+    }
+}
+
+diesel::table! {
+    task (task_id) {
+        task_id -> Int8,
+        service -> Text,
+        location -> postgis_diesel::sql_types::Geography,
+        opened_at -> Timestamp,
+    }
+}
+
+diesel::joinable!(handyman_blackout -> handyman (handyman_id));
+diesel::joinable!(handyman_working_slot -> handyman (handyman_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    handyman,
+    handyman_blackout,
+    handyman_working_slot,
+    task,
+);
@@ -0,0 +1,132 @@
+use crate::{DistanceWithinFilter, SRID, schema::task, validate_lon_lat};
+use chrono::NaiveDateTime;
+use db_utils::{AsyncPgConnection, PaginateOffset};
+use diesel::{prelude::*, upsert::excluded};
+use diesel_async::RunQueryDsl;
+use entity_type::CustomerTaskRequestId;
+use error::Result;
+use paging::{PagingOffsetConfig, PagingOffsetInfo, PagingOffsetPayload};
+use postgis_diesel::types::Point;
+
+#[derive(Debug, Queryable, Selectable)]
+#[diesel(table_name = task)]
+pub struct TaskSearch {
+    pub task_id: CustomerTaskRequestId,
+    /// Code of a catalog service
+    pub service: String,
+    pub location: Point,
+    pub opened_at: NaiveDateTime,
+}
+
+impl TaskSearch {
+    /// Index an open task, replacing the existing index of the task if any.
+    pub async fn index(
+        NewTaskSearch {
+            task_id,
+            service,
+            lon,
+            lat,
+            opened_at,
+        }: NewTaskSearch,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Self> {
+        validate_lon_lat(lon, lat)?;
+
+        let result = diesel::insert_into(task::table)
+            .values((
+                task::task_id.eq(task_id),
+                task::service.eq(service),
+                task::location.eq(Point {
+                    x: lon,
+                    y: lat,
+                    srid: Some(SRID),
+                }),
+                task::opened_at.eq(opened_at),
+            ))
+            .on_conflict(task::task_id)
+            .do_update()
+            .set((
+                task::service.eq(excluded(task::service)),
+                task::location.eq(excluded(task::location)),
+                task::opened_at.eq(excluded(task::opened_at)),
+            ))
+            .returning(Self::as_returning())
+            .get_result(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn delete_index(
+        task_ids: &[CustomerTaskRequestId],
+        conn: &mut AsyncPgConnection,
+    ) -> Result<Vec<Self>> {
+        let result = diesel::delete(task::table.filter(task::task_id.eq_any(task_ids)))
+            .returning(Self::as_returning())
+            .get_results(conn)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Search open tasks order by location distance asc (if location filter is included)
+    /// then by the most recently opened.
+    pub async fn search(
+        TaskSearchFilter {
+            services,
+            distance_within,
+        }: TaskSearchFilter,
+        paging_config: PagingOffsetConfig,
+        conn: &mut AsyncPgConnection,
+    ) -> Result<PagingOffsetPayload<CustomerTaskRequestId>> {
+        let mut query = task::table.select(task::task_id).into_boxed();
+
+        if let Some(services) = services {
+            query = query.filter(task::service.eq_any(services));
+        }
+
+        if let Some(distance_within) = distance_within.map(|f| f.validate()).transpose()? {
+            let point = db_utils::st_makepoint(distance_within.lon, distance_within.lat);
+            query = query
+                .filter(db_utils::st_dwithin_4326(
+                    task::location,
+                    point,
+                    distance_within.within_meters,
+                ))
+                .order(db_utils::st_distance_4326(task::location, point).asc());
+        }
+
+        let (result, total_count) = query
+            .then_order_by(task::opened_at.desc())
+            .paginate_offset(paging_config)
+            .load_and_count_total::<CustomerTaskRequestId>(conn)
+            .await?;
+
+        Ok(PagingOffsetPayload {
+            paging_info: PagingOffsetInfo {
+                page: paging_config.page,
+                page_size: paging_config.page_size,
+                total_count,
+            },
+            items: result,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct NewTaskSearch {
+    pub task_id: CustomerTaskRequestId,
+    /// Code of a catalog service
+    pub service: String,
+    pub lon: f64,
+    pub lat: f64,
+    pub opened_at: NaiveDateTime,
+}
+
+#[derive(Debug)]
+/// Filter for task search. Fields are AND condition.
+pub struct TaskSearchFilter {
+    /// OR condition on task services, by catalog service code
+    pub services: Option<Vec<String>>,
+    pub distance_within: Option<DistanceWithinFilter>,
+}
@@ -1,6 +1,6 @@
 use super::SearchService;
 use db_utils::{with_mutable_db, with_readonly_db};
-use entity_type::{HandymanId, ServiceLayer2};
+use entity_type::HandymanId;
 use error::Result;
 use paging::{PagingOffsetConfig, PagingOffsetPayload};
 use scoped_futures::ScopedFutureExt;
@@ -26,8 +26,27 @@ impl SearchService {
                     HandymanIndexType::AddSkills(services) => Some(
                         db::HandymanSearch::index_add_skills(handyman_id, &services, conn).await?,
                     ),
+                    HandymanIndexType::SetLocation { lon, lat } => {
+                        Some(db::HandymanSearch::index_location(handyman_id, lon, lat, conn).await?)
+                    }
+                    HandymanIndexType::SetAvgRatingScore {
+                        avg_rating_score,
+                        review_count,
+                    } => {
+                        db::HandymanSearch::index_avg_rating_score(
+                            handyman_id,
+                            avg_rating_score,
+                            review_count,
+                            conn,
+                        )
+                        .await?
+                    }
+                    HandymanIndexType::SetAvailability(availability) => Some(
+                        db::HandymanSearch::index_availability(handyman_id, availability, conn)
+                            .await?,
+                    ),
                     HandymanIndexType::RemoveSkill(service) => {
-                        db::HandymanSearch::index_remove_skill(handyman_id, service, conn).await?
+                        db::HandymanSearch::index_remove_skill(handyman_id, &service, conn).await?
                     }
                 };
                 Ok(index)
@@ -81,8 +100,18 @@ pub struct HandymanIndexRequest {
 #[derive(Debug)]
 pub enum HandymanIndexType {
     SetFullName(String),
-    AddSkills(Vec<ServiceLayer2>),
-    RemoveSkill(ServiceLayer2),
+    AddSkills(Vec<String>),
+    SetLocation {
+        lon: f64,
+        lat: f64,
+    },
+    /// Ignored if the indexed score was computed from more reviews than `review_count`
+    SetAvgRatingScore {
+        avg_rating_score: Option<i16>,
+        review_count: i32,
+    },
+    SetAvailability(db::HandymanAvailability),
+    RemoveSkill(String),
 }
 
 #[derive(Debug)]
@@ -108,5 +137,5 @@ pub struct HandymanSearchRequest {
 
 #[derive(Debug)]
 pub struct HandymanSearchResponse {
-    pub result: PagingOffsetPayload<HandymanId>,
+    pub result: PagingOffsetPayload<db::HandymanSearchResult>,
 }
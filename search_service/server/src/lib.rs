@@ -3,3 +3,6 @@ pub use server::*;
 
 mod handyman;
 pub use handyman::*;
+
+mod task;
+pub use task::*;
@@ -0,0 +1,137 @@
+use super::SearchService;
+use db_utils::{with_mutable_db, with_readonly_db};
+use entity_type::{CustomerTaskRequestId, HandymanId};
+use error::Result;
+use paging::{PagingOffsetConfig, PagingOffsetPayload};
+use scoped_futures::ScopedFutureExt;
+use search_service_db as db;
+
+impl SearchService {
+    #[tracing::instrument(skip(self))]
+    pub async fn task_index(&self, request: TaskIndexRequest) -> Result<TaskIndexResponse> {
+        let TaskIndexRequest { task } = request;
+
+        let index = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            db::TaskSearch::index(task, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(TaskIndexResponse { index })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub async fn task_index_delete(
+        &self,
+        request: TaskIndexDeleteRequest,
+    ) -> Result<TaskIndexDeleteResponse> {
+        let TaskIndexDeleteRequest { task_ids } = request;
+
+        let indexes = with_mutable_db(&self.context.db_connection_pool, |conn| {
+            db::TaskSearch::delete_index(&task_ids, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(TaskIndexDeleteResponse { indexes })
+    }
+
+    /// Handymen who have the skill required by the task and are located within the radius,
+    /// ranked the same way as handyman search.
+    #[tracing::instrument(skip(self))]
+    pub async fn task_match_handymen(
+        &self,
+        request: TaskMatchHandymenRequest,
+    ) -> Result<TaskMatchHandymenResponse> {
+        let TaskMatchHandymenRequest {
+            service,
+            distance_within,
+            paging_config,
+        } = request;
+
+        let filter = db::HandymanSearchFilter {
+            handyman_ids: None,
+            name: None,
+            skills: Some(vec![service]),
+            distance_within: Some(distance_within),
+            available_at: None,
+        };
+        let result = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            db::HandymanSearch::search(filter, paging_config, conn).scope_boxed()
+        })
+        .await?;
+
+        Ok(TaskMatchHandymenResponse { result })
+    }
+
+    /// Open tasks within the radius which require any of the handyman's indexed skills.
+    #[tracing::instrument(skip(self))]
+    pub async fn handyman_task_search(
+        &self,
+        request: HandymanTaskSearchRequest,
+    ) -> Result<HandymanTaskSearchResponse> {
+        let HandymanTaskSearchRequest {
+            handyman_id,
+            distance_within,
+            paging_config,
+        } = request;
+
+        let result = with_readonly_db(&self.context.db_connection_pool, |conn| {
+            async move {
+                let skills = db::HandymanSearch::load_skills(handyman_id, conn).await?;
+                let filter = db::TaskSearchFilter {
+                    services: Some(skills),
+                    distance_within: Some(distance_within),
+                };
+                db::TaskSearch::search(filter, paging_config, conn).await
+            }
+            .scope_boxed()
+        })
+        .await?;
+
+        Ok(HandymanTaskSearchResponse { result })
+    }
+}
+
+#[derive(Debug)]
+pub struct TaskIndexRequest {
+    pub task: db::NewTaskSearch,
+}
+
+#[derive(Debug)]
+pub struct TaskIndexResponse {
+    pub index: db::TaskSearch,
+}
+
+#[derive(Debug)]
+pub struct TaskIndexDeleteRequest {
+    pub task_ids: Vec<CustomerTaskRequestId>,
+}
+
+#[derive(Debug)]
+pub struct TaskIndexDeleteResponse {
+    pub indexes: Vec<db::TaskSearch>,
+}
+
+#[derive(Debug)]
+pub struct TaskMatchHandymenRequest {
+    /// Catalog service code required by the task
+    pub service: String,
+    pub distance_within: db::DistanceWithinFilter,
+    pub paging_config: PagingOffsetConfig,
+}
+
+#[derive(Debug)]
+pub struct TaskMatchHandymenResponse {
+    pub result: PagingOffsetPayload<db::HandymanSearchResult>,
+}
+
+#[derive(Debug)]
+pub struct HandymanTaskSearchRequest {
+    pub handyman_id: HandymanId,
+    pub distance_within: db::DistanceWithinFilter,
+    pub paging_config: PagingOffsetConfig,
+}
+
+#[derive(Debug)]
+pub struct HandymanTaskSearchResponse {
+    pub result: PagingOffsetPayload<CustomerTaskRequestId>,
+}
@@ -42,6 +42,8 @@ impl ServiceParams {
             account_service_client: account_service.service_client.clone(),
             search_service_client: search_service.service_client.clone(),
             features: self.core_service.features,
+            rate_limit_config: self.core_service.rate_limit_config,
+            otp_policy: self.core_service.otp_policy,
         }
         .init()
         .await?;
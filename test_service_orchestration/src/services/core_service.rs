@@ -1,16 +1,19 @@
 use crate::service_database::CORE_SERVICE_DATABASE;
 use account_service_server::AccountService;
+use blob_store::{LocalBlobStore, LocalBlobStoreConfig};
 use cookie::SameSite;
 use core_service_db as db;
+use core_service_graphql_context::ApiRateLimit;
 use core_service_graphql_context::CookieConfig;
 use core_service_graphql_context::EnvironmentConfig;
 use core_service_graphql_context::Features;
-use core_service_graphql_context::OTP_CODE_TTL_SECONDS;
+use core_service_graphql_context::RateLimitConfig;
 use core_service_server::Server;
-use core_service_server::config_types::HttpConfig;
+use core_service_server::config_types::{BackOfficeConfig, HttpConfig, StaffCredential};
 use db_utils::PgConnectionPool;
 use error::{Error, Result};
-use moka::future::CacheBuilder;
+use otp_store::OtpPolicy;
+use rate_limiter::RateLimit;
 use search_service_server::SearchService;
 use sms_sender::{TestSmsReceiver, TestSmsSender};
 use std::sync::Arc;
@@ -18,9 +21,46 @@ use test_utils::PostgresContainer;
 
 pub const TEST_ORIGIN: &str = "http://localhost:3000";
 
-#[derive(Debug, Default)]
+/// Bearer token accepted by the back-office API
+pub const TEST_STAFF_TOKEN: &str = "test-staff-token";
+
+/// Limits high enough for tests that don't exercise rate limiting,
+/// since every test client connects from the same address.
+pub const TEST_RATE_LIMIT_CONFIG: RateLimitConfig = {
+    const LIMIT: ApiRateLimit = ApiRateLimit {
+        per_remote_addr: RateLimit {
+            max_requests: 1_000,
+            window_seconds: 60,
+        },
+        per_phone_number: RateLimit {
+            max_requests: 1_000,
+            window_seconds: 60,
+        },
+    };
+    RateLimitConfig {
+        start_registration: LIMIT,
+        sign_in_with_password: LIMIT,
+        start_password_reset: LIMIT,
+        start_phone_number_change: LIMIT,
+        start_sign_in_with_otp: LIMIT,
+    }
+};
+
+#[derive(Debug)]
 pub struct CoreServiceParams {
     pub features: Features,
+    pub rate_limit_config: RateLimitConfig,
+    pub otp_policy: OtpPolicy,
+}
+
+impl Default for CoreServiceParams {
+    fn default() -> Self {
+        Self {
+            features: Features::default(),
+            rate_limit_config: TEST_RATE_LIMIT_CONFIG,
+            otp_policy: OtpPolicy::default(),
+        }
+    }
 }
 
 pub(crate) struct CoreServiceParamsInner<'a> {
@@ -28,6 +68,8 @@ pub(crate) struct CoreServiceParamsInner<'a> {
     pub account_service_client: AccountService,
     pub search_service_client: SearchService,
     pub features: Features,
+    pub rate_limit_config: RateLimitConfig,
+    pub otp_policy: OtpPolicy,
 }
 
 pub struct CoreServiceEnvironment {
@@ -44,6 +86,8 @@ impl CoreServiceParamsInner<'_> {
             account_service_client,
             search_service_client,
             features,
+            rate_limit_config,
+            otp_policy,
         } = self;
 
         // No need to run core service migration here because auth service already handle it.
@@ -64,6 +108,12 @@ impl CoreServiceParamsInner<'_> {
             frontend_host: TEST_ORIGIN.into(),
         });
         let (sms_sender, sms_receiver) = TestSmsSender::new();
+        // Ports are unique among running servers, so are the blob directories
+        let blob_store = LocalBlobStore::new(LocalBlobStoreConfig {
+            root_dir: std::env::temp_dir().join(format!("core_service_blobs_{service_port}")),
+            public_url: format!("http://{local_addr}/blobs"),
+            url_signing_secret: "test-blob-url-secret".into(),
+        });
 
         // Server will be dropped when tokio runtime is dropped
         tokio::spawn(async move {
@@ -82,11 +132,15 @@ impl CoreServiceParamsInner<'_> {
                 account_service_client,
                 search_service_client,
                 sms_sender: Arc::new(sms_sender),
-                phone_pending_registration_cache: Arc::new(
-                    CacheBuilder::new(10_000)
-                        .time_to_live(std::time::Duration::from_secs(OTP_CODE_TTL_SECONDS))
-                        .build(),
-                ),
+                otp_store: Arc::new(db::PgOtpStore::new(db_pool_cloned.clone(), otp_policy)),
+                blob_store: Arc::new(blob_store),
+                rate_limit_config,
+                back_office_config: Arc::new(BackOfficeConfig {
+                    staff: vec![StaffCredential {
+                        name: "test-staff".into(),
+                        token: TEST_STAFF_TOKEN.into(),
+                    }],
+                }),
             }
             .serve(server_socket)
             .await
@@ -0,0 +1,164 @@
+//! In-process fake of the S3 object endpoints, so that the S3 blob store
+//! can be tested without reaching an object storage.
+
+use crate::register_random_os_socket;
+use axum::{
+    Router,
+    body::Bytes,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::put,
+};
+use error::{Error, Result};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use tokio::task::JoinHandle;
+
+/// Object stored by the fake server.
+#[derive(Debug, Clone)]
+pub struct FakeS3Object {
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct FakeS3State {
+    bucket: String,
+    access_key_id: String,
+    objects: HashMap<String, FakeS3Object>,
+}
+
+type SharedState = Arc<Mutex<FakeS3State>>;
+
+pub struct FakeS3Server {
+    /// Endpoint serving the bucket path-style
+    pub url: String,
+    state: SharedState,
+    handle: JoinHandle<()>,
+}
+
+impl FakeS3Server {
+    /// Start the fake server on a random port with a single bucket,
+    /// accepting requests signed with the given access key.
+    pub async fn start(bucket: &str, access_key_id: &str) -> Result<Self> {
+        let state = Arc::new(Mutex::new(FakeS3State {
+            bucket: bucket.into(),
+            access_key_id: access_key_id.into(),
+            objects: HashMap::new(),
+        }));
+        let app = Router::new()
+            .route(
+                "/{bucket}/{*key}",
+                put(put_object).get(get_object).delete(delete_object),
+            )
+            .with_state(Arc::clone(&state));
+
+        let listener = register_random_os_socket().await?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| Error::internal(format!("Cannot get fake S3 address {e:?}")))?;
+        let handle = tokio::spawn(async move {
+            axum::serve(listener, app)
+                .await
+                .expect("Failed to run fake S3 server");
+        });
+
+        Ok(Self {
+            url: format!("http://{addr}"),
+            state,
+            handle,
+        })
+    }
+
+    /// Object stored under the key, if any.
+    pub fn object(&self, key: &str) -> Option<FakeS3Object> {
+        self.lock().objects.get(key).cloned()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, FakeS3State> {
+        self.state.lock().expect("Fake S3 state is poisoned")
+    }
+}
+
+impl Drop for FakeS3Server {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Only check the shape of the signature, the signing itself is tested against AWS examples.
+fn check_request(state: &FakeS3State, bucket: &str, headers: &HeaderMap) -> Option<Response> {
+    let credential_prefix = format!("AWS4-HMAC-SHA256 Credential={}/", state.access_key_id);
+    let is_signed = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with(&credential_prefix) && v.contains("Signature="))
+        && headers.contains_key("x-amz-date")
+        && headers.contains_key("x-amz-content-sha256");
+    if !is_signed {
+        return Some(StatusCode::FORBIDDEN.into_response());
+    }
+    if bucket != state.bucket {
+        return Some(StatusCode::NOT_FOUND.into_response());
+    }
+    None
+}
+
+async fn put_object(
+    State(state): State<SharedState>,
+    Path((bucket, key)): Path<(String, String)>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let mut state = state.lock().expect("Fake S3 state is poisoned");
+    if let Some(rejected) = check_request(&state, &bucket, &headers) {
+        return rejected;
+    }
+
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+    state.objects.insert(
+        key,
+        FakeS3Object {
+            content_type,
+            data: body.to_vec(),
+        },
+    );
+    StatusCode::OK.into_response()
+}
+
+async fn get_object(
+    State(state): State<SharedState>,
+    Path((bucket, key)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> Response {
+    let state = state.lock().expect("Fake S3 state is poisoned");
+    if let Some(rejected) = check_request(&state, &bucket, &headers) {
+        return rejected;
+    }
+
+    match state.objects.get(&key) {
+        Some(object) => object.data.clone().into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn delete_object(
+    State(state): State<SharedState>,
+    Path((bucket, key)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> Response {
+    let mut state = state.lock().expect("Fake S3 state is poisoned");
+    if let Some(rejected) = check_request(&state, &bucket, &headers) {
+        return rejected;
+    }
+
+    // S3 answers 204 whether or not the object exists
+    state.objects.remove(&key);
+    StatusCode::NO_CONTENT.into_response()
+}
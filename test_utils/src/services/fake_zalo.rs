@@ -0,0 +1,187 @@
+//! In-process fake of Zalo OAuth and ZNS endpoints, so that the Zalo SMS sender
+//! can be tested without reaching Zalo servers.
+
+use crate::register_random_os_socket;
+use axum::{Form, Json, Router, extract::State, http::HeaderMap, routing::post};
+use error::{Error, Result};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use tokio::task::JoinHandle;
+
+/// Lifetime of access tokens issued by the fake server, same as Zalo's 25 hours.
+const ACCESS_TOKEN_EXPIRES_IN_SECONDS: u64 = 90_000;
+
+/// ZNS message accepted by the fake server.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FakeZnsMessage {
+    pub phone: String,
+    pub template_id: String,
+    pub template_data: HashMap<String, String>,
+    pub tracking_id: String,
+}
+
+#[derive(Debug)]
+struct FakeZaloState {
+    app_id: String,
+    secret_key: String,
+    refresh_token: String,
+    access_token: Option<String>,
+    issued_token_count: u32,
+    /// Error code returned by the next ZNS request instead of sending the message
+    next_zns_error: Option<i32>,
+    messages: Vec<FakeZnsMessage>,
+}
+
+type SharedState = Arc<Mutex<FakeZaloState>>;
+
+pub struct FakeZaloServer {
+    /// Base url serving both OAuth and ZNS endpoints
+    pub url: String,
+    state: SharedState,
+    handle: JoinHandle<()>,
+}
+
+impl FakeZaloServer {
+    /// Start the fake server on a random port, accepting the given app credentials.
+    pub async fn start(app_id: &str, secret_key: &str, refresh_token: &str) -> Result<Self> {
+        let state = Arc::new(Mutex::new(FakeZaloState {
+            app_id: app_id.into(),
+            secret_key: secret_key.into(),
+            refresh_token: refresh_token.into(),
+            access_token: None,
+            issued_token_count: 0,
+            next_zns_error: None,
+            messages: Vec::new(),
+        }));
+        let app = Router::new()
+            .route("/v4/oa/access_token", post(access_token))
+            .route("/message/template", post(send_template))
+            .with_state(Arc::clone(&state));
+
+        let listener = register_random_os_socket().await?;
+        let addr = listener
+            .local_addr()
+            .map_err(|e| Error::internal(format!("Cannot get fake Zalo address {e:?}")))?;
+        let handle = tokio::spawn(async move {
+            axum::serve(listener, app)
+                .await
+                .expect("Failed to run fake Zalo server");
+        });
+
+        Ok(Self {
+            url: format!("http://{addr}"),
+            state,
+            handle,
+        })
+    }
+
+    /// Messages sent successfully so far.
+    pub fn sent_messages(&self) -> Vec<FakeZnsMessage> {
+        self.lock().messages.clone()
+    }
+
+    /// Number of access tokens issued so far.
+    pub fn issued_token_count(&self) -> u32 {
+        self.lock().issued_token_count
+    }
+
+    /// Revoke the current access token before its expiry.
+    pub fn revoke_access_token(&self) {
+        self.lock().access_token = None;
+    }
+
+    /// Make the next ZNS request fail with the given error code.
+    pub fn fail_next_message(&self, error_code: i32) {
+        self.lock().next_zns_error = Some(error_code);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, FakeZaloState> {
+        self.state.lock().expect("Fake Zalo state is poisoned")
+    }
+}
+
+impl Drop for FakeZaloServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AccessTokenForm {
+    refresh_token: String,
+    app_id: String,
+    grant_type: String,
+}
+
+async fn access_token(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Form(form): Form<AccessTokenForm>,
+) -> Json<Value> {
+    let mut state = state.lock().expect("Fake Zalo state is poisoned");
+    let secret_key = headers.get("secret_key").and_then(|v| v.to_str().ok());
+    if secret_key != Some(state.secret_key.as_str())
+        || form.app_id != state.app_id
+        || form.grant_type != "refresh_token"
+    {
+        return Json(json!({
+            "error": -14002,
+            "error_name": "Invalid app",
+            "error_description": "app_id or secret_key is invalid",
+        }));
+    }
+    if form.refresh_token != state.refresh_token {
+        return Json(json!({
+            "error": -14014,
+            "error_name": "Invalid refresh token",
+            "error_description": "refresh_token is invalid or already used",
+        }));
+    }
+
+    // Zalo rotates the refresh token on every refresh
+    state.issued_token_count += 1;
+    let access_token = format!("access-token-{}", state.issued_token_count);
+    state.refresh_token = format!("refresh-token-{}", state.issued_token_count);
+    state.access_token = Some(access_token.clone());
+
+    Json(json!({
+        "access_token": access_token,
+        "refresh_token": state.refresh_token,
+        "expires_in": ACCESS_TOKEN_EXPIRES_IN_SECONDS.to_string(),
+    }))
+}
+
+async fn send_template(
+    State(state): State<SharedState>,
+    headers: HeaderMap,
+    Json(message): Json<FakeZnsMessage>,
+) -> Json<Value> {
+    let mut state = state.lock().expect("Fake Zalo state is poisoned");
+    let access_token = headers.get("access_token").and_then(|v| v.to_str().ok());
+    if state.access_token.is_none() || access_token != state.access_token.as_deref() {
+        return Json(json!({
+            "error": -124,
+            "message": "Access token is invalid",
+        }));
+    }
+    if let Some(error) = state.next_zns_error.take() {
+        return Json(json!({
+            "error": error,
+            "message": "Injected error",
+        }));
+    }
+
+    state.messages.push(message);
+    Json(json!({
+        "error": 0,
+        "message": "Success",
+        "data": {
+            "msg_id": format!("msg-{}", state.messages.len()),
+            "sent_time": "1700000000000",
+        },
+    }))
+}
@@ -5,3 +5,9 @@ pub(crate) use container_tags::*;
 
 mod postgres;
 pub use postgres::*;
+
+mod fake_zalo;
+pub use fake_zalo::*;
+
+mod fake_s3;
+pub use fake_s3::*;